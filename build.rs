@@ -0,0 +1,507 @@
+//! Generates the table-driven part of the decoder from the opcode descriptions in `opcodes/`.
+//!
+//! Each `rv_<extension>` file follows the riscv-opcodes layout, with one instruction per line:
+//!
+//! ```text
+//! add       rd rs1 rs2             31..25=0x00 14..12=0 6..2=0x0C 1..0=3
+//! ```
+//!
+//! The mnemonic is followed by its operands, in the order they are displayed, and by the fixed
+//! bits of the encoding as `hi..lo=value` or `bit=value`. Operands are the registers `rd`, `rs1`
+//! and `rs2`, the immediates `imm12`, `simm12`, `bimm12`, `jimm20`, `imm20` and `pimm12`, the
+//! `shamt`, `csr`, `zimm`, `pred` and `succ` fields, an offset written as `imm12(rs1)` or a
+//! plain `(rs1)` address. A mnemonic ending in `.N` takes its number from an `N[...]` operand,
+//! which lists the bits it is gathered from. Comment lines directly above an instruction become
+//! the documentation of its struct.
+//!
+//! Fixed bits written as a whole funct3, rd, rs1, rs2 or funct7 field are reported as that field
+//! when an instruction does not match, and the other fixed bits as an invalid immediate: ecall
+//! fixes `31..20` as its immediate, while the hypervisor loads fix a funct7 and an rs2.
+//!
+//! The file name selects the extensions the instructions require: `rv_i`, `rv_zicsr`,
+//! `rv_zifencei` and `rv_system` are always decoded, and the other files name fields of
+//! `Extensions`, separated by underscores when several are required.
+//!
+//! Three files are written to `OUT_DIR`: the instruction structs with their `Display`
+//! implementations, the decode table with the constructor of each instruction, and the list of
+//! `ParsedInstruction32` variants.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const OPCODES_DIR: &str = "opcodes";
+const BASE_EXTENSIONS: [&str; 4] = ["i", "zicsr", "zifencei", "system"];
+/// The funct3, rd, rs1, rs2 and funct7 fields, which invalid instructions are reported by when
+/// they are fixed as a whole.
+const DECODE_FIELDS: [(u32, u32); 5] = [(14, 12), (11, 7), (19, 15), (24, 20), (31, 25)];
+
+enum Operand {
+    Register(&'static str),
+    Immediate(&'static str),
+    Offset(Option<&'static str>, &'static str),
+    Shamt,
+    Csr,
+    Zimm,
+    Pred,
+    Succ,
+    Number(Vec<(u32, u32)>),
+}
+
+impl Operand {
+    fn parse(token: &str) -> Option<Operand> {
+        if let Some(ranges) = token.strip_prefix("N[").and_then(|t| t.strip_suffix(']')) {
+            let ranges = ranges.split(',').map(parse_range).collect::<Option<_>>()?;
+            return Some(Operand::Number(ranges));
+        }
+        if let Some((offset, register)) = token.strip_suffix(')').and_then(|t| t.split_once('(')) {
+            let register = register_name(register)?;
+            let offset = match offset {
+                "" => None,
+                _ => Some(immediate_name(offset)?),
+            };
+            return Some(Operand::Offset(offset, register));
+        }
+        match token {
+            "shamt" => Some(Operand::Shamt),
+            "csr" => Some(Operand::Csr),
+            "zimm" => Some(Operand::Zimm),
+            "pred" => Some(Operand::Pred),
+            "succ" => Some(Operand::Succ),
+            _ => register_name(token)
+                .map(Operand::Register)
+                .or_else(|| immediate_name(token).map(Operand::Immediate)),
+        }
+    }
+
+    /// Bits of the encoding held by the operand.
+    fn mask(&self) -> u32 {
+        match self {
+            Operand::Register(register) => register_mask(register),
+            Operand::Immediate(immediate) => immediate_mask(immediate),
+            Operand::Offset(offset, register) => {
+                offset.map_or(0, immediate_mask) | register_mask(register)
+            }
+            Operand::Shamt => bit_mask(24, 20),
+            Operand::Csr => bit_mask(31, 20),
+            Operand::Zimm => bit_mask(19, 15),
+            Operand::Pred => bit_mask(27, 24),
+            Operand::Succ => bit_mask(23, 20),
+            Operand::Number(ranges) => ranges
+                .iter()
+                .fold(0, |mask, (hi, lo)| mask | bit_mask(*hi, *lo)),
+        }
+    }
+
+    /// Struct fields of the operand, as (name, type, constructor expression).
+    fn fields(&self) -> Vec<(&'static str, &'static str, String)> {
+        let register = |name: &'static str| {
+            (
+                name,
+                "RegisterName",
+                format!("T::try_from_u8({}(instruction))?.as_str()", name),
+            )
+        };
+        let immediate = |name: &str| ("imm", "i32", format!("{}(instruction)", name));
+
+        match self {
+            Operand::Register(name) => vec![register(name)],
+            Operand::Immediate(name) => vec![immediate(name)],
+            Operand::Offset(offset, name) => {
+                let mut fields: Vec<_> = offset.iter().map(|offset| immediate(offset)).collect();
+                fields.push(register(name));
+                fields
+            }
+            Operand::Shamt => vec![("shamt", "u8", "shamt(instruction)".to_string())],
+            Operand::Csr => vec![("csr", "u16", "csr(instruction)".to_string())],
+            Operand::Zimm => vec![("uimm", "u8", "zimm(instruction)".to_string())],
+            Operand::Pred => vec![("pred", "u8", "pred(instruction)".to_string())],
+            Operand::Succ => vec![("succ", "u8", "succ(instruction)".to_string())],
+            Operand::Number(ranges) => {
+                let mut shift = 0;
+                let mut parts = Vec::new();
+                for (hi, lo) in ranges.iter().rev() {
+                    parts.push(match shift {
+                        0 => format!("bits(instruction, {}, {})", hi, lo),
+                        _ => format!("bits(instruction, {}, {}) << {}", hi, lo, shift),
+                    });
+                    shift += hi - lo + 1;
+                }
+                parts.reverse();
+                vec![("n", "u8", format!("({}) as u8", parts.join(" | ")))]
+            }
+        }
+    }
+
+    /// Display of the operand, as a format string and its arguments. `pred` and `succ` are
+    /// written by `write_fence_set` and have no format string.
+    fn display(&self) -> Option<(String, Vec<&'static str>)> {
+        match self {
+            Operand::Register(name) => Some(("{}".to_string(), vec![name])),
+            Operand::Immediate(_) => Some(("{}".to_string(), vec!["imm"])),
+            Operand::Offset(Some(_), name) => Some(("{}({})".to_string(), vec!["imm", name])),
+            Operand::Offset(None, name) => Some(("({})".to_string(), vec![name])),
+            Operand::Shamt => Some(("{}".to_string(), vec!["shamt"])),
+            Operand::Csr => Some(("{:#x}".to_string(), vec!["csr"])),
+            Operand::Zimm => Some(("{}".to_string(), vec!["uimm"])),
+            Operand::Pred | Operand::Succ | Operand::Number(_) => None,
+        }
+    }
+}
+
+fn register_name(token: &str) -> Option<&'static str> {
+    ["rd", "rs1", "rs2"].into_iter().find(|name| *name == token)
+}
+
+fn immediate_name(token: &str) -> Option<&'static str> {
+    ["imm12", "simm12", "bimm12", "jimm20", "imm20", "pimm12"]
+        .into_iter()
+        .find(|name| *name == token)
+}
+
+fn register_mask(register: &str) -> u32 {
+    match register {
+        "rd" => bit_mask(11, 7),
+        "rs1" => bit_mask(19, 15),
+        _ => bit_mask(24, 20),
+    }
+}
+
+fn immediate_mask(immediate: &str) -> u32 {
+    match immediate {
+        "imm12" => bit_mask(31, 20),
+        "simm12" | "bimm12" => bit_mask(31, 25) | bit_mask(11, 7),
+        "jimm20" | "imm20" => bit_mask(31, 12),
+        _ => bit_mask(31, 25),
+    }
+}
+
+fn bit_mask(hi: u32, lo: u32) -> u32 {
+    (u32::MAX >> (31 - hi)) & (u32::MAX << lo)
+}
+
+fn parse_range(range: &str) -> Option<(u32, u32)> {
+    let (hi, lo) = match range.split_once("..") {
+        Some((hi, lo)) => (hi.parse().ok()?, lo.parse().ok()?),
+        None => (range.parse().ok()?, range.parse().ok()?),
+    };
+    (hi >= lo && hi < 32).then_some((hi, lo))
+}
+
+fn parse_value(value: &str) -> Option<u32> {
+    match value.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
+struct Instruction {
+    mnemonic: String,
+    doc: Vec<String>,
+    extensions: Vec<String>,
+    operands: Vec<Operand>,
+    mask: u32,
+    value: u32,
+    fields: u32,
+}
+
+impl Instruction {
+    fn parse(line: &str, doc: Vec<String>, extensions: &[String]) -> Result<Self, String> {
+        let mut tokens = line.split_whitespace();
+        let mnemonic = tokens.next().unwrap().to_string();
+        let (mut operands, mut mask, mut value, mut fields) = (Vec::new(), 0, 0, 0);
+
+        for token in tokens {
+            if let Some((range, field)) = token.split_once('=') {
+                let (hi, lo) = parse_range(range).ok_or(format!("invalid range {}", range))?;
+                let field = parse_value(field).ok_or(format!("invalid value {}", field))?;
+                let bits = bit_mask(hi, lo);
+                if field > bits >> lo {
+                    return Err(format!("value {} does not fit in {}", field, range));
+                }
+                if mask & bits != 0 {
+                    return Err(format!("bits {} are fixed twice", range));
+                }
+                mask |= bits;
+                value |= field << lo;
+                if DECODE_FIELDS.contains(&(hi, lo)) {
+                    fields |= bits;
+                }
+            } else {
+                let operand = Operand::parse(token).ok_or(format!("unknown operand {}", token))?;
+                operands.push(operand);
+            }
+        }
+
+        let mut operand_mask = 0;
+        for operand in &operands {
+            if operand_mask & operand.mask() != 0 {
+                return Err("operands overlap".to_string());
+            }
+            operand_mask |= operand.mask();
+        }
+        if operand_mask & mask != 0 {
+            return Err("operands overlap the fixed bits".to_string());
+        }
+        if mask & 0x7f != 0x7f {
+            return Err("the opcode is not fixed".to_string());
+        }
+        let numbered = mnemonic.ends_with(".N");
+        if numbered != operands.iter().any(|o| matches!(o, Operand::Number(_))) {
+            return Err("`.N` mnemonics need exactly one N operand".to_string());
+        }
+
+        Ok(Instruction {
+            mnemonic,
+            doc,
+            extensions: extensions.to_vec(),
+            operands,
+            mask,
+            value,
+            fields,
+        })
+    }
+
+    fn struct_name(&self) -> String {
+        self.mnemonic.trim_end_matches(".N").replace('.', "_")
+    }
+
+    fn fields(&self) -> Vec<(&'static str, &'static str, String)> {
+        self.operands.iter().flat_map(Operand::fields).collect()
+    }
+
+    fn write_struct(&self, out: &mut String) {
+        for line in &self.doc {
+            writeln!(out, "///{}", line).unwrap();
+        }
+        let fields = self.fields();
+        writeln!(out, "#[derive(Debug, PartialEq)]").unwrap();
+        writeln!(
+            out,
+            "#[cfg_attr(feature = \"serde\", derive(Serialize, Deserialize))]"
+        )
+        .unwrap();
+        if fields.is_empty() {
+            writeln!(out, "pub struct {} {{}}\n", self.struct_name()).unwrap();
+        } else {
+            writeln!(out, "pub struct {} {{", self.struct_name()).unwrap();
+            for (name, ty, _) in fields {
+                // Register names are deserialized into the static names of the registers.
+                if ty == "RegisterName" {
+                    writeln!(
+                        out,
+                        "    #[cfg_attr(feature = \"serde\", serde(deserialize_with = \"register\"))]"
+                    )
+                    .unwrap();
+                }
+                writeln!(out, "    pub {}: {},", name, ty).unwrap();
+            }
+            writeln!(out, "}}\n").unwrap();
+        }
+
+        writeln!(out, "impl fmt::Display for {} {{", self.struct_name()).unwrap();
+        writeln!(
+            out,
+            "    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {{"
+        )
+        .unwrap();
+
+        // The display is a sequence of format strings with their arguments, interrupted by the
+        // fence sets, which are written by `write_fence_set`.
+        let (mut format, mut arguments) = match self.mnemonic.strip_suffix(".N") {
+            Some(prefix) => (format!("{}.{{}}", prefix), vec!["n"]),
+            None => (self.mnemonic.clone(), vec![]),
+        };
+        let mut statements = Vec::new();
+        let mut separator = " ";
+        for operand in &self.operands {
+            if let Some((operand_format, operand_arguments)) = operand.display() {
+                format += separator;
+                format += &operand_format;
+                arguments.extend(operand_arguments);
+            } else if let Operand::Pred | Operand::Succ = operand {
+                format += separator;
+                statements.push(format_statement(&format, &arguments));
+                let field = if let Operand::Pred = operand {
+                    "pred"
+                } else {
+                    "succ"
+                };
+                statements.push(format!("write_fence_set(f, self.{})", field));
+                (format, arguments) = (String::new(), vec![]);
+            } else {
+                continue;
+            }
+            separator = ", ";
+        }
+        if !format.is_empty() {
+            statements.push(format_statement(&format, &arguments));
+        }
+
+        let last = statements.pop().unwrap();
+        for statement in statements {
+            writeln!(out, "        {}?;", statement).unwrap();
+        }
+        writeln!(out, "        {}", last).unwrap();
+        writeln!(out, "    }}\n}}\n").unwrap();
+    }
+
+    fn write_table_entry(&self, out: &mut String) {
+        let enabled = match self.extensions.as_slice() {
+            [] => "|_| true".to_string(),
+            extensions => format!(
+                "|extensions| {}",
+                extensions
+                    .iter()
+                    .map(|extension| format!("extensions.{}", extension))
+                    .collect::<Vec<_>>()
+                    .join(" && ")
+            ),
+        };
+        writeln!(
+            out,
+            "    // {}\n    Opcode {{ mask: {:#010x}, value: {:#010x}, fields: {:#010x}, enabled: {} }},",
+            self.mnemonic, self.mask, self.value, self.fields, enabled
+        )
+        .unwrap();
+    }
+
+    fn write_constructor(&self, index: usize, out: &mut String) {
+        let name = self.struct_name();
+        let fields = self.fields();
+        if fields.is_empty() {
+            writeln!(
+                out,
+                "        {} => Ok(ParsedInstruction32::{}({} {{}})),",
+                index, name, name
+            )
+            .unwrap();
+            return;
+        }
+        writeln!(
+            out,
+            "        {} => Ok(ParsedInstruction32::{}({} {{",
+            index, name, name
+        )
+        .unwrap();
+        for (field, _, expression) in fields {
+            writeln!(out, "            {}: {},", field, expression).unwrap();
+        }
+        writeln!(out, "        }})),").unwrap();
+    }
+}
+
+fn format_statement(format: &str, arguments: &[&str]) -> String {
+    let mut statement = format!("write!(f, {:?}", format);
+    for argument in arguments {
+        statement += &format!(", self.{}", argument);
+    }
+    statement + ")"
+}
+
+fn load_instructions() -> Vec<Instruction> {
+    let mut files = BTreeMap::new();
+    for entry in fs::read_dir(OPCODES_DIR).expect("opcodes directory is missing") {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        if let Some(extensions) = name.strip_prefix("rv_") {
+            files.insert(extensions.to_string(), fs::read_to_string(&path).unwrap());
+        }
+    }
+
+    let mut instructions = Vec::new();
+    for (name, contents) in &files {
+        let extensions: Vec<String> = name
+            .split('_')
+            .filter(|extension| !BASE_EXTENSIONS.contains(extension))
+            .map(str::to_string)
+            .collect();
+        let mut doc = Vec::new();
+        for (number, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if let Some(comment) = line.strip_prefix('#') {
+                doc.push(comment.to_string());
+            } else if line.is_empty() {
+                doc.clear();
+            } else {
+                let instruction = Instruction::parse(line, std::mem::take(&mut doc), &extensions)
+                    .unwrap_or_else(|error| {
+                        panic!("{}/rv_{}:{}: {}", OPCODES_DIR, name, number + 1, error)
+                    });
+                instructions.push(instruction);
+            }
+        }
+    }
+
+    // Within an opcode, more specific encodings are matched first, so that for example the
+    // prefetch hints take precedence over the `ori` they are encoded as.
+    instructions.sort_by_key(|instruction| {
+        (
+            instruction.value & 0x7f,
+            u32::MAX - instruction.mask.count_ones(),
+        )
+    });
+    instructions
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", OPCODES_DIR);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let instructions = load_instructions();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    let mut structs = String::new();
+    for instruction in &instructions {
+        instruction.write_struct(&mut structs);
+    }
+
+    let mut table = String::new();
+    writeln!(
+        table,
+        "pub(crate) static OPCODES: [Opcode; {}] = [",
+        instructions.len()
+    )
+    .unwrap();
+    for instruction in &instructions {
+        instruction.write_table_entry(&mut table);
+    }
+    writeln!(table, "];\n").unwrap();
+    writeln!(
+        table,
+        "fn build_opcode32<T: Register>(\n    index: usize,\n    instruction: Instruction32,\n) -> Result<ParsedInstruction32, DisassemblerError> {{\n    match index {{"
+    )
+    .unwrap();
+    for (index, instruction) in instructions.iter().enumerate() {
+        instruction.write_constructor(index, &mut table);
+    }
+    writeln!(table, "        _ => unreachable!(),\n    }}\n}}").unwrap();
+
+    let mut variants = String::new();
+    writeln!(
+        variants,
+        "macro_rules! with_opcode_instructions {{\n    ($macro:ident! {{ $($instructions:tt)* }}) => {{\n        $macro! {{"
+    )
+    .unwrap();
+    for instruction in &instructions {
+        writeln!(
+            variants,
+            "            {} = {:?},",
+            instruction.struct_name(),
+            instruction.mnemonic.trim_end_matches(".N")
+        )
+        .unwrap();
+    }
+    writeln!(
+        variants,
+        "            $($instructions)*\n        }}\n    }};\n}}"
+    )
+    .unwrap();
+
+    let out_dir = Path::new(&out_dir);
+    fs::write(out_dir.join("opcode_instructions.rs"), structs).unwrap();
+    fs::write(out_dir.join("opcode_table.rs"), table).unwrap();
+    fs::write(out_dir.join("opcode_variants.rs"), variants).unwrap();
+}
@@ -0,0 +1,302 @@
+//! Command line arguments of `rvdis`.
+
+use crate::Error;
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: rvdis [OPTIONS] [WORD]...
+       rvdis [OPTIONS] --binary FILE
+       rvdis [OPTIONS] --ihex FILE
+       rvdis [OPTIONS] --srec FILE
+       rvdis [OPTIONS] --elf FILE
+
+Disassembles RISC-V machine code into an objdump-style listing.
+
+Each WORD is an instruction in hex, with an optional 0x prefix: a 16 bit parcel of up to four
+digits or a 32 bit word of five to eight. Without words or a file, whitespace or comma separated
+words are read from standard input, as they are for a WORD of `-`.
+
+Vendor extensions in the ISA string, such as xtheadba or xcvalu, are decoded when rvdis is
+built with the xthead or xcv feature. Other vendor extensions are rejected. Only the instructions
+of the vendor extensions named are decoded, so that rv32i_xcvmem lists the CORE-V hardware loops
+as invalid.
+
+The gnu format lists instructions as GNU `objdump -d` does, in its syntax and column layout, so
+that the listings can be compared with those of objdump. The llvm format does the same for
+`llvm-objdump -d`.
+
+With --color, mnemonics, registers, immediates, addresses and symbols are coloured with ANSI
+escape sequences. The auto mode colours the listing when it is written to a terminal and the
+NO_COLOR environment variable is not set.
+
+Options:
+  -b, --binary FILE     Disassemble a flat binary file
+  -x, --ihex FILE       Disassemble the data records of an Intel HEX file
+  -s, --srec FILE       Disassemble the data records of a Motorola S-record file
+  -e, --elf FILE        Disassemble the executable sections of an ELF file
+  -a, --base ADDRESS    Address of the first word or byte [default: 0]
+      --isa ISA         Extensions to decode, as an ISA string such as rv32imac_zcb
+      --big-endian      Read words and binary files as big endian
+      --little-endian   Read words and binary files as little endian [default]
+      --numeric         Name registers x0 to x31 instead of by their ABI names
+      --no-pseudo       List auipc pairs as two instructions instead of la, call or tail
+  -f, --format FORMAT   Output format: objdump, gnu, llvm or plain [default: objdump]
+      --color WHEN      Colour the listing: auto, always or never [default: auto]
+  -h, --help            Print this help
+  -V, --version         Print the version";
+
+/// What the instructions are read from.
+#[derive(Debug, PartialEq)]
+pub enum Input {
+    /// Hex words given as arguments.
+    Words(Vec<String>),
+    /// Hex words read from standard input.
+    Stdin,
+    Binary(PathBuf),
+    IntelHex(PathBuf),
+    Srecord(PathBuf),
+    Elf(PathBuf),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    /// Address, encoding and instruction of every line, with symbol labels.
+    Objdump,
+    /// The output of GNU `objdump -d`, in its syntax and layout.
+    Gnu,
+    /// The output of `llvm-objdump -d`, in its syntax and layout.
+    Llvm,
+    /// The instructions alone, one per line.
+    Plain,
+}
+
+/// When the listing is coloured with ANSI escape sequences.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Color {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub input: Input,
+    pub base: u64,
+    pub isa: Option<String>,
+    pub big_endian: bool,
+    pub use_abi_register_names: bool,
+    pub pseudo_instructions: bool,
+    pub format: Format,
+    pub color: Color,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Disassemble(Options),
+    Help,
+    Version,
+}
+
+/// Parses the arguments following the program name. Options taking a value accept it as the
+/// next argument or after `=`, as in `--isa=rv32imac`.
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, Error> {
+    let mut options = Options {
+        input: Input::Words(Vec::new()),
+        base: 0,
+        isa: None,
+        big_endian: false,
+        use_abi_register_names: true,
+        pseudo_instructions: true,
+        format: Format::Objdump,
+        color: Color::Auto,
+    };
+    let mut words = Vec::new();
+    let mut file = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with('-') || arg == "-" {
+            words.push(arg);
+            continue;
+        }
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value)),
+            _ => (arg.clone(), None),
+        };
+        let mut value = || match inline_value {
+            Some(value) => Ok(value.to_string()),
+            None => args
+                .next()
+                .ok_or_else(|| Error::Usage(format!("Missing value for {}.", flag))),
+        };
+        match flag.as_str() {
+            "-b" | "--binary" => file = Some(Input::Binary(value()?.into())),
+            "-x" | "--ihex" => file = Some(Input::IntelHex(value()?.into())),
+            "-s" | "--srec" => file = Some(Input::Srecord(value()?.into())),
+            "-e" | "--elf" => file = Some(Input::Elf(value()?.into())),
+            "-a" | "--base" => options.base = parse_address(&value()?)?,
+            "--isa" => options.isa = Some(value()?),
+            "--big-endian" => options.big_endian = true,
+            "--little-endian" => options.big_endian = false,
+            "--numeric" => options.use_abi_register_names = false,
+            "--no-pseudo" => options.pseudo_instructions = false,
+            "-f" | "--format" => {
+                options.format = match value()?.as_str() {
+                    "objdump" => Format::Objdump,
+                    "gnu" => Format::Gnu,
+                    "llvm" => Format::Llvm,
+                    "plain" => Format::Plain,
+                    format => return Err(Error::Usage(format!("Unknown format {:?}.", format))),
+                }
+            }
+            "--color" => {
+                options.color = match value()?.as_str() {
+                    "auto" => Color::Auto,
+                    "always" => Color::Always,
+                    "never" => Color::Never,
+                    when => return Err(Error::Usage(format!("Unknown color mode {:?}.", when))),
+                }
+            }
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            _ => return Err(Error::Usage(format!("Unknown option {:?}.", arg))),
+        }
+    }
+
+    options.input = match (file, words.is_empty()) {
+        (Some(_), false) => {
+            return Err(Error::Usage(
+                "Words cannot be given together with a file.".to_string(),
+            ))
+        }
+        (Some(file), true) => file,
+        (None, true) => Input::Stdin,
+        (None, false) if words.iter().all(|word| word == "-") => Input::Stdin,
+        (None, false) => Input::Words(words),
+    };
+    Ok(Command::Disassemble(options))
+}
+
+/// Parses a 32-bit address given in hex with a `0x` prefix, or in decimal.
+fn parse_address(text: &str) -> Result<u64, Error> {
+    let parsed = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => text.parse(),
+    };
+    parsed
+        .map(u64::from)
+        .map_err(|_| Error::Usage(format!("Invalid address {:?}.", text)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_args(args: &[&str]) -> Result<Command, Error> {
+        parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn options(args: &[&str]) -> Options {
+        match parse_args(args) {
+            Ok(Command::Disassemble(options)) => options,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    fn options_input(args: &[&str]) -> Input {
+        options(args).input
+    }
+
+    fn options_format(args: &[&str]) -> Format {
+        options(args).format
+    }
+
+    #[test]
+    fn test_defaults() {
+        let options = options(&["00050513", "0x9d61"]);
+        assert_eq!(
+            options,
+            Options {
+                input: Input::Words(vec!["00050513".to_string(), "0x9d61".to_string()]),
+                base: 0,
+                isa: None,
+                big_endian: false,
+                use_abi_register_names: true,
+                pseudo_instructions: true,
+                format: Format::Objdump,
+                color: Color::Auto,
+            }
+        );
+        assert_eq!(options_input(&[]), Input::Stdin);
+        assert_eq!(options_input(&["-"]), Input::Stdin);
+    }
+
+    #[test]
+    fn test_options() {
+        let options = options(&[
+            "--elf",
+            "a.out",
+            "--isa=rv32imac_zcb",
+            "-a",
+            "0x80000000",
+            "--big-endian",
+            "--numeric",
+            "--no-pseudo",
+            "-f",
+            "plain",
+            "--color",
+            "always",
+        ]);
+        assert_eq!(options.input, Input::Elf("a.out".into()));
+        assert_eq!(options.isa.as_deref(), Some("rv32imac_zcb"));
+        assert_eq!(options.base, 0x8000_0000);
+        assert!(options.big_endian);
+        assert!(!options.use_abi_register_names);
+        assert!(!options.pseudo_instructions);
+        assert_eq!(options.format, Format::Plain);
+        assert_eq!(options.color, Color::Always);
+        assert_eq!(options_format(&["--format=gnu", "13"]), Format::Gnu);
+        assert_eq!(options_format(&["-f", "llvm", "13"]), Format::Llvm);
+        assert_eq!(
+            options_input(&["--binary", "image.bin", "--base", "4096"]),
+            Input::Binary("image.bin".into())
+        );
+        assert_eq!(
+            options_input(&["-x", "image.hex"]),
+            Input::IntelHex("image.hex".into())
+        );
+        assert_eq!(
+            options_input(&["--srec=image.srec"]),
+            Input::Srecord("image.srec".into())
+        );
+    }
+
+    #[test]
+    fn test_commands() {
+        assert!(matches!(parse_args(&["-h"]), Ok(Command::Help)));
+        assert!(matches!(
+            parse_args(&["13", "--version"]),
+            Ok(Command::Version)
+        ));
+    }
+
+    #[test]
+    fn test_usage_errors() {
+        for args in [
+            &["--isa"][..],
+            &["--format", "json"],
+            &["--color=sometimes"],
+            &["--base", "0xg"],
+            &["--base", "0x100000000"],
+            &["--base", "4294967296"],
+            &["--verbose"],
+            &["--elf", "a.out", "13"],
+        ] {
+            assert!(
+                matches!(parse_args(args), Err(Error::Usage(_))),
+                "{:?}",
+                args
+            );
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! ANSI colouring of listings, selected by `--color`.
+
+use crate::args::Color;
+use risc_v_disassembler::format::{Token, TokenKind};
+use risc_v_disassembler::Directive;
+use std::io::IsTerminal;
+
+/// Returns whether the listing is coloured: always, never, or when standard output is a
+/// terminal and `NO_COLOR` is not set.
+pub fn enabled(color: Color) -> bool {
+    match color {
+        Color::Always => true,
+        Color::Never => false,
+        Color::Auto => {
+            std::io::stdout().is_terminal()
+                && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+        }
+    }
+}
+
+/// Returns the SGR parameter of the colour of `kind`, following those of
+/// `objdump --disassembler-color=color`, or `None` for tokens left uncoloured.
+fn sgr(kind: TokenKind) -> Option<&'static str> {
+    match kind {
+        TokenKind::Mnemonic => Some("33"),
+        TokenKind::Register => Some("34"),
+        TokenKind::Immediate => Some("35"),
+        TokenKind::Address => Some("36"),
+        TokenKind::Symbol => Some("32"),
+        TokenKind::MemoryOpen | TokenKind::MemoryClose | TokenKind::Separator | TokenKind::Text => {
+            None
+        }
+    }
+}
+
+/// Concatenates `tokens`, each in the colour of its kind when `enabled`.
+pub fn paint(tokens: &[Token], enabled: bool) -> String {
+    tokens
+        .iter()
+        .map(|token| match sgr(token.kind).filter(|_| enabled) {
+            Some(sgr) => format!("\x1b[{}m{}\x1b[0m", sgr, token.text),
+            None => token.text.clone(),
+        })
+        .collect()
+}
+
+/// Returns the tokens of a symbol name.
+pub fn symbol(name: impl ToString) -> [Token; 1] {
+    [Token::new(TokenKind::Symbol, name.to_string())]
+}
+
+/// Returns the tokens of a data directive, with `separator` between its name and value.
+pub fn directive(directive: Directive, separator: &str) -> [Token; 3] {
+    let (name, value) = match directive {
+        Directive::Word(value) => (".word", format!("{:#010x}", value)),
+        Directive::Byte(value) => (".byte", format!("{:#04x}", value)),
+    };
+    [
+        Token::new(TokenKind::Mnemonic, name),
+        Token::new(TokenKind::Separator, separator),
+        Token::new(TokenKind::Immediate, value),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use risc_v_disassembler::format::tokenize;
+
+    #[test]
+    fn test_paint() {
+        let tokens = tokenize("lw a0, -4(a1)");
+        assert_eq!(paint(&tokens, false), "lw a0, -4(a1)");
+        assert_eq!(
+            paint(&tokens, true),
+            "\x1b[33mlw\x1b[0m \x1b[34ma0\x1b[0m, \x1b[35m-4\x1b[0m(\x1b[34ma1\x1b[0m)"
+        );
+        assert_eq!(
+            paint(&directive(Directive::Byte(0xa), "\t"), false),
+            ".byte\t0x0a"
+        );
+        assert_eq!(paint(&symbol("main"), true), "\x1b[32mmain\x1b[0m");
+    }
+}
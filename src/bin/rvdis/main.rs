@@ -0,0 +1,635 @@
+//! `rvdis`, which disassembles hex words, flat binary, Intel HEX, S-record and ELF files from the
+//! command line.
+//!
+//! ```text
+//! $ rvdis --isa rv32i_zcb 00050513 9d61
+//!        0:   00050513            addi a0, a0, 0
+//!        4:   9d61                c.zext.b a0
+//! ```
+
+mod args;
+mod color;
+
+use args::{Command, Format, Input, Options};
+#[cfg(feature = "elf")]
+use risc_v_disassembler::elf::{ElfClass, ElfError, ElfFile, ET_REL};
+use risc_v_disassembler::gnu::{self, GnuFormatter};
+use risc_v_disassembler::image::{Image, ImageError};
+use risc_v_disassembler::llvm::LlvmFormatter;
+#[cfg(feature = "xcv")]
+use risc_v_disassembler::xcv;
+#[cfg(feature = "xthead")]
+use risc_v_disassembler::xthead;
+#[cfg(feature = "elf")]
+use risc_v_disassembler::Symbol;
+#[cfg(any(feature = "xthead", feature = "xcv"))]
+use risc_v_disassembler::{CustomDecoder, CustomInstruction};
+use risc_v_disassembler::{
+    CustomDecoders, DisassemblerError, Extensions, Line, Listing, SymbolMap,
+};
+use std::io::{self, BufWriter, Read, Write};
+use std::process::ExitCode;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{0}\nTry `rvdis --help` for more information.")]
+    Usage(String),
+
+    #[error("Cannot read {path}: {source}.")]
+    Read { path: String, source: io::Error },
+
+    #[error("Cannot write the listing: {0}.")]
+    Write(#[from] io::Error),
+
+    #[error(
+        "Invalid hex word: {0:?}. Words are given as 1 to 8 hex digits, with an optional 0x prefix."
+    )]
+    InvalidWord(String),
+
+    #[cfg(not(feature = "elf"))]
+    #[error("ELF files are not supported. rvdis was built without the `elf` feature.")]
+    ElfUnsupported,
+
+    #[error(transparent)]
+    Disassembler(#[from] DisassemblerError),
+
+    #[error("{path}: {source}")]
+    Image { path: String, source: ImageError },
+
+    #[cfg(feature = "elf")]
+    #[error(transparent)]
+    Elf(#[from] ElfError),
+}
+
+fn main() -> ExitCode {
+    let result = args::parse(std::env::args().skip(1)).and_then(|command| match command {
+        Command::Disassemble(options) => run(&options),
+        Command::Help => {
+            println!("{}", args::USAGE);
+            Ok(())
+        }
+        Command::Version => {
+            println!("rvdis {}", env!("CARGO_PKG_VERSION"));
+            Ok(())
+        }
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        // A closed pipe, as in `rvdis ... | head`, ends the listing early.
+        Err(Error::Write(error)) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error @ Error::Usage(_)) => {
+            eprintln!("rvdis: {}", error);
+            ExitCode::from(2)
+        }
+        Err(error) => {
+            eprintln!("rvdis: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(options: &Options) -> Result<(), Error> {
+    let mut out = BufWriter::new(io::stdout().lock());
+    match &options.input {
+        Input::Words(words) => {
+            let bytes = words_to_bytes(words, options.big_endian)?;
+            write_image(&mut out, &Image::from_binary(&bytes, options.base), options)?;
+        }
+        Input::Stdin => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|source| Error::Read {
+                    path: "standard input".to_string(),
+                    source,
+                })?;
+            let words: Vec<String> = text
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|word| !word.is_empty())
+                .map(str::to_string)
+                .collect();
+            let bytes = words_to_bytes(&words, options.big_endian)?;
+            write_image(&mut out, &Image::from_binary(&bytes, options.base), options)?;
+        }
+        Input::Binary(path) => {
+            let bytes = read_file(path)?;
+            write_image(&mut out, &Image::from_binary(&bytes, options.base), options)?;
+        }
+        Input::IntelHex(path) => {
+            let image = read_image(path, Image::from_intel_hex)?;
+            write_image(&mut out, &image, options)?;
+        }
+        Input::Srecord(path) => {
+            let image = read_image(path, Image::from_srecord)?;
+            write_image(&mut out, &image, options)?;
+        }
+        Input::Elf(path) => write_elf(&mut out, path, options)?,
+    }
+    out.flush()?;
+    Ok(())
+}
+
+fn read_file(path: &std::path::Path) -> Result<Vec<u8>, Error> {
+    std::fs::read(path).map_err(|source| Error::Read {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+/// Reads an Intel HEX or S-record file, naming the file in the errors of `parse`.
+fn read_image(
+    path: &std::path::Path,
+    parse: fn(&str) -> Result<Image, ImageError>,
+) -> Result<Image, Error> {
+    let text = std::fs::read_to_string(path).map_err(|source| Error::Read {
+        path: path.display().to_string(),
+        source,
+    })?;
+    parse(&text).map_err(|source| Error::Image {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+/// Returns the bytes of hex words, storing parcels of up to four digits as two bytes and longer
+/// words as four.
+fn words_to_bytes(words: &[String], big_endian: bool) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::new();
+    for word in words {
+        let digits = word
+            .strip_prefix("0x")
+            .or_else(|| word.strip_prefix("0X"))
+            .unwrap_or(word);
+        let value = match digits.len() {
+            1..=8 => {
+                u32::from_str_radix(digits, 16).map_err(|_| Error::InvalidWord(word.to_string()))?
+            }
+            _ => return Err(Error::InvalidWord(word.to_string())),
+        };
+        match (digits.len() <= 4, big_endian) {
+            (true, false) => bytes.extend((value as u16).to_le_bytes()),
+            (true, true) => bytes.extend((value as u16).to_be_bytes()),
+            (false, false) => bytes.extend(value.to_le_bytes()),
+            (false, true) => bytes.extend(value.to_be_bytes()),
+        }
+    }
+    Ok(bytes)
+}
+
+/// Returns the extensions of `--isa`, and whether it allows 16 bit instructions.
+fn extensions(options: &Options) -> Result<(Extensions, Option<bool>), Error> {
+    match &options.isa {
+        Some(isa) => Ok((
+            Extensions::from_isa_string(isa)?,
+            Some(Extensions::compressed_from_isa_string(isa)?),
+        )),
+        None => Ok((Extensions::default(), None)),
+    }
+}
+
+/// Vendor extensions rvdis decodes, with the function registering their decoder for those of
+/// them named in `--isa`.
+type Vendor = (
+    &'static [&'static str],
+    fn(&mut CustomDecoders, Vec<&'static str>) -> Result<(), DisassemblerError>,
+);
+
+const VENDORS: &[Vendor] = &[
+    #[cfg(feature = "xthead")]
+    (&xthead::XTHEAD_EXTENSIONS, |decoders, named| {
+        let decoder = Named {
+            decoder: xthead::XTheadDecoder,
+            named,
+            extension: |instruction| {
+                let instruction = instruction.as_any().downcast_ref();
+                instruction.map(xthead::XTheadInstruction::extension)
+            },
+        };
+        decoders.register(xthead::XTHEAD_OPCODE, decoder)
+    }),
+    #[cfg(feature = "xcv")]
+    (&xcv::XCV_EXTENSIONS, |decoders, named| {
+        for opcode in xcv::XCV_OPCODES {
+            let decoder = Named {
+                decoder: xcv::XcvDecoder,
+                named: named.clone(),
+                extension: |instruction| {
+                    let instruction = instruction.as_any().downcast_ref();
+                    instruction.map(xcv::XcvInstruction::extension)
+                },
+            };
+            decoders.register(opcode, decoder)?;
+        }
+        Ok(())
+    }),
+];
+
+/// Vendor decoder restricted to the extensions named in `--isa`. The instructions of the other
+/// extensions of the vendor are rejected as if no decoder was registered for their opcode.
+#[cfg(any(feature = "xthead", feature = "xcv"))]
+struct Named<D> {
+    decoder: D,
+    named: Vec<&'static str>,
+    extension: fn(&dyn CustomInstruction) -> Option<&'static str>,
+}
+
+#[cfg(any(feature = "xthead", feature = "xcv"))]
+impl<D: CustomDecoder> CustomDecoder for Named<D> {
+    fn decode(
+        &self,
+        instruction: u32,
+        register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+        float_register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+    ) -> Result<Box<dyn CustomInstruction>, DisassemblerError> {
+        let decoded = self
+            .decoder
+            .decode(instruction, register_name, float_register_name)?;
+        match (self.extension)(decoded.as_ref()) {
+            Some(extension) if self.named.contains(&extension) => Ok(decoded),
+            _ => Err(DisassemblerError::InvalidOpcode((instruction & 0x7f) as u8)),
+        }
+    }
+}
+
+/// Returns the decoders of the vendor extensions of `--isa`, which are only known when rvdis is
+/// built with the `xthead` or `xcv` feature.
+fn decoders(options: &Options) -> Result<CustomDecoders, Error> {
+    let names = match &options.isa {
+        Some(isa) => Extensions::vendor_extensions_from_isa_string(isa)?,
+        None => Vec::new(),
+    };
+    let mut selected: Vec<(usize, Vec<&'static str>)> = Vec::new();
+    for name in names {
+        let (vendor, extension) = VENDORS
+            .iter()
+            .enumerate()
+            .find_map(|(vendor, (extensions, _))| {
+                let extension = extensions.iter().find(|extension| **extension == name)?;
+                Some((vendor, *extension))
+            })
+            .ok_or_else(|| {
+                Error::Usage(format!(
+                    "Unsupported vendor extension {:?} in --isa. The T-Head and CORE-V \
+                     extensions need rvdis to be built with the `xthead` and `xcv` features.",
+                    name
+                ))
+            })?;
+        match selected
+            .iter_mut()
+            .find(|(selected, _)| *selected == vendor)
+        {
+            Some((_, named)) => named.push(extension),
+            None => selected.push((vendor, vec![extension])),
+        }
+    }
+    if selected.len() > 1 {
+        return Err(Error::Usage(
+            "The T-Head and CORE-V extensions both use the custom-0 opcode and cannot be \
+             decoded together."
+                .to_string(),
+        ));
+    }
+    let mut decoders = CustomDecoders::new();
+    for (vendor, named) in selected {
+        (VENDORS[vendor].1)(&mut decoders, named)?;
+    }
+    Ok(decoders)
+}
+
+/// Lists the regions of an image in order, separated by a blank line in every format but plain.
+fn write_image(out: &mut impl Write, image: &Image, options: &Options) -> Result<(), Error> {
+    let (extensions, compressed) = extensions(options)?;
+    let decoders = decoders(options)?;
+    let symbols = SymbolMap::new();
+    for (index, region) in image.regions.iter().enumerate() {
+        if index > 0 && options.format != Format::Plain {
+            writeln!(out)?;
+        }
+        let mut instructions = region
+            .disassemble(
+                options.big_endian,
+                options.use_abi_register_names,
+                &extensions,
+            )
+            .custom_decoders(&decoders);
+        if let Some(compressed) = compressed {
+            instructions = instructions.compressed(compressed);
+        }
+        write_listing(out, instructions.listing(&symbols), options, 8)?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "elf")]
+fn write_elf(out: &mut impl Write, path: &std::path::Path, options: &Options) -> Result<(), Error> {
+    let data = read_file(path)?;
+    let elf = ElfFile::parse(&data)?;
+    let (extensions, compressed) = extensions(options)?;
+    let decoders = decoders(options)?;
+    let class = match elf.header.class {
+        ElfClass::Elf32 => 32,
+        ElfClass::Elf64 => 64,
+    };
+    // GNU objdump and llvm-objdump give the addresses of labels in full.
+    let address_digits = match options.format {
+        Format::Gnu | Format::Llvm => class / 4,
+        Format::Objdump | Format::Plain => 8,
+    };
+    if options.format != Format::Plain {
+        let order = if elf.header.is_big_endian {
+            "big"
+        } else {
+            "little"
+        };
+        let separator = match options.format {
+            Format::Llvm => "\t",
+            _ => "     ",
+        };
+        writeln!(
+            out,
+            "\n{}:{}file format elf{}-{}riscv",
+            path.display(),
+            separator,
+            class,
+            order
+        )?;
+        // GNU objdump leaves a second blank line before the first section.
+        if options.format == Format::Gnu {
+            writeln!(out)?;
+        }
+    }
+
+    // The sections of relocatable files all start at zero, and are labelled with their own
+    // symbols.
+    let relocatable = elf.header.file_type == ET_REL;
+    let file_symbols = match relocatable {
+        true => SymbolMap::new(),
+        false => elf.symbols()?,
+    };
+    for section in elf.executable_sections() {
+        let (mut symbols, relocations) = match relocatable {
+            true => (elf.section_symbols(section)?, elf.relocations(section)?),
+            false => (file_symbols.clone(), Vec::new()),
+        };
+        // GNU objdump and llvm-objdump fall back to the section name for the label of a
+        // section without a symbol at its start, and for the targets that follow it.
+        if matches!(options.format, Format::Gnu | Format::Llvm)
+            && symbols.at(section.address).is_empty()
+        {
+            symbols.insert(Symbol::new(section.name.clone(), section.address));
+        }
+        let instructions = match compressed {
+            Some(compressed) => elf
+                .disassemble_with_extensions(section, options.use_abi_register_names, &extensions)?
+                .compressed(compressed),
+            None => elf.disassemble(section, options.use_abi_register_names)?,
+        }
+        .custom_decoders(&decoders);
+        if options.format != Format::Plain {
+            writeln!(out, "\nDisassembly of section {}:", section.name)?;
+        }
+        let listing = instructions.listing(&symbols).relocations(&relocations);
+        write_listing(out, listing, options, address_digits)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "elf"))]
+fn write_elf(_: &mut impl Write, _: &std::path::Path, _: &Options) -> Result<(), Error> {
+    Err(Error::ElfUnsupported)
+}
+
+/// Writes the lines of a listing, with labels whose addresses have `address_digits` digits.
+fn write_listing(
+    out: &mut impl Write,
+    listing: Listing,
+    options: &Options,
+    address_digits: usize,
+) -> Result<(), Error> {
+    let big_endian = options.big_endian;
+    let color = color::enabled(options.color);
+    let paint = |tokens: &[_]| color::paint(tokens, color);
+    let listing = listing.pseudo_instructions(options.pseudo_instructions);
+    let listing = match options.format {
+        Format::Gnu => listing.formatter(GnuFormatter::new()),
+        Format::Llvm => listing.formatter(LlvmFormatter::new()),
+        Format::Objdump | Format::Plain => listing,
+    };
+    for line in listing {
+        match (options.format, line) {
+            (Format::Objdump | Format::Gnu | Format::Llvm, Line::Label(symbol)) => writeln!(
+                out,
+                "\n{:0width$x} <{}>:",
+                symbol.address,
+                paint(&color::symbol(&symbol.name)),
+                width = address_digits
+            )?,
+            (Format::Plain, Line::Label(_)) => {}
+            (Format::Gnu, Line::Instruction(instruction)) => {
+                let chunk = if instruction.bytes.len().is_multiple_of(4) {
+                    4
+                } else {
+                    2
+                };
+                write!(
+                    out,
+                    "{:8x}:\t{}\t",
+                    instruction.address,
+                    gnu_encoding(instruction.bytes, chunk, big_endian)
+                )?;
+                match &instruction.instruction {
+                    Ok(_) => write!(out, "{}", paint(&instruction.tokens))?,
+                    Err(_) => write!(
+                        out,
+                        "{}",
+                        gnu::insn_directive(instruction.bytes, big_endian)
+                    )?,
+                }
+                if let Some(symbol) = &instruction.symbol {
+                    write!(out, " <{}>", paint(&color::symbol(symbol)))?;
+                }
+                writeln!(out)?;
+            }
+            (Format::Gnu, Line::Data(data)) => writeln!(
+                out,
+                "{:8x}:\t{}\t{}",
+                data.address,
+                gnu_encoding(data.bytes, data.bytes.len(), big_endian),
+                paint(&color::directive(data.directive, "\t"))
+            )?,
+            (Format::Llvm, Line::Instruction(instruction)) => {
+                write!(
+                    out,
+                    "{:8x}: {:<13}\t",
+                    instruction.address,
+                    llvm_encoding(instruction.bytes)
+                )?;
+                match &instruction.instruction {
+                    Ok(_) => write!(out, "{}", paint(&instruction.tokens))?,
+                    Err(_) => write!(out, "<unknown>")?,
+                }
+                if let Some(symbol) = &instruction.symbol {
+                    write!(out, " <{}>", paint(&color::symbol(symbol)))?;
+                }
+                writeln!(out)?;
+            }
+            (Format::Llvm, Line::Data(data)) => writeln!(
+                out,
+                "{:8x}: {:<13}\t{}",
+                data.address,
+                llvm_encoding(data.bytes),
+                paint(&color::directive(data.directive, "\t"))
+            )?,
+            (format, Line::Instruction(instruction)) => {
+                if format == Format::Objdump {
+                    write!(
+                        out,
+                        "{:8x}:\t{:<17}\t",
+                        instruction.address,
+                        encoding(instruction.bytes, big_endian)
+                    )?;
+                }
+                match &instruction.instruction {
+                    Ok(_) => write!(out, "{}", paint(&instruction.tokens))?,
+                    Err(error) => write!(out, "{}", error)?,
+                }
+                if let Some(symbol) = &instruction.symbol {
+                    write!(out, " <{}>", paint(&color::symbol(symbol)))?;
+                }
+                if let Some(relocation) = instruction.relocation {
+                    write!(out, " # {}", relocation)?;
+                }
+                writeln!(out)?;
+            }
+            (Format::Objdump, Line::Data(data)) => writeln!(
+                out,
+                "{:8x}:\t{:<17}\t{}",
+                data.address,
+                encoding(data.bytes, big_endian),
+                paint(&color::directive(data.directive, " "))
+            )?,
+            (Format::Plain, Line::Data(data)) => {
+                writeln!(out, "{}", paint(&color::directive(data.directive, " ")))?
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the encoding column of GNU objdump: the bytes in chunks of `chunk` bytes, each
+/// followed by a space, padded to the width of eight bytes.
+fn gnu_encoding(bytes: &[u8], chunk: usize, big_endian: bool) -> String {
+    let mut column = String::new();
+    for chunk in bytes.chunks(chunk) {
+        let mut digits: Vec<String> = chunk.iter().map(|byte| format!("{:02x}", byte)).collect();
+        if !big_endian {
+            digits.reverse();
+        }
+        column.push_str(&digits.concat());
+        column.push(' ');
+    }
+    let missing = 8usize.saturating_sub(bytes.len()).div_ceil(chunk);
+    column.push_str(&" ".repeat(missing * (2 * chunk + 1)));
+    column
+}
+
+/// Returns the encoding column of llvm-objdump: the bytes in memory order, separated by spaces.
+fn llvm_encoding(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the encoding of an instruction as objdump shows it: as a 16 bit parcel, as one or
+/// more 32 bit words, or byte by byte for other lengths.
+fn encoding(bytes: &[u8], big_endian: bool) -> String {
+    let read = |chunk: &[u8]| {
+        let mut value = 0u32;
+        for (i, byte) in chunk.iter().enumerate() {
+            let shift = match big_endian {
+                true => 8 * (chunk.len() - 1 - i),
+                false => 8 * i,
+            };
+            value |= (*byte as u32) << shift;
+        }
+        value
+    };
+    match bytes.len() {
+        2 => format!("{:04x}", read(bytes)),
+        length if length.is_multiple_of(4) => bytes
+            .chunks(4)
+            .map(|word| format!("{:08x}", read(word)))
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => bytes
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words_to_bytes() {
+        let words = ["00050513".to_string(), "0x9d61".to_string()];
+        assert_eq!(
+            words_to_bytes(&words, false).unwrap(),
+            [0x13, 0x05, 0x05, 0x00, 0x61, 0x9d]
+        );
+        assert_eq!(
+            words_to_bytes(&words, true).unwrap(),
+            [0x00, 0x05, 0x05, 0x13, 0x9d, 0x61]
+        );
+        for word in ["", "0x", "123456789", "addi"] {
+            assert!(matches!(
+                words_to_bytes(&[word.to_string()], false),
+                Err(Error::InvalidWord(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_encoding() {
+        assert_eq!(encoding(&[0x61, 0x9d], false), "9d61");
+        assert_eq!(encoding(&[0x13, 0x05, 0x05, 0x00], false), "00050513");
+        assert_eq!(encoding(&[0x00, 0x05, 0x05, 0x13], true), "00050513");
+        assert_eq!(
+            encoding(&[0x97, 0, 0, 0, 0xe7, 0x80, 0x80, 0], false),
+            "00000097 008080e7"
+        );
+        assert_eq!(encoding(&[0x1f, 0, 0, 0, 0, 0], false), "1f 00 00 00 00 00");
+    }
+
+    #[test]
+    fn test_llvm_encoding() {
+        assert_eq!(llvm_encoding(&[0x13, 0x05, 0x05, 0x00]), "13 05 05 00");
+        assert_eq!(llvm_encoding(&[0x61, 0x9d]), "61 9d");
+    }
+
+    #[test]
+    fn test_gnu_encoding() {
+        assert_eq!(
+            gnu_encoding(&[0x13, 0x05, 0x05, 0x00], 4, false),
+            format!("00050513{}", " ".repeat(10))
+        );
+        assert_eq!(
+            gnu_encoding(&[0x00, 0x05, 0x05, 0x13], 4, true),
+            format!("00050513{}", " ".repeat(10))
+        );
+        assert_eq!(
+            gnu_encoding(&[0x82, 0x80], 2, false),
+            format!("8082{}", " ".repeat(16))
+        );
+        assert_eq!(
+            gnu_encoding(&[0x12], 1, false),
+            format!("12{}", " ".repeat(22))
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use crate::instructions::{FloatFormat, Instruction32, MemoryAccess, PrivilegeLevel};
+use crate::DisassemblerError;
+use std::any::Any;
+use std::fmt;
+
+/// Opcodes reserved for custom extensions: custom-0, custom-1, and custom-2 and custom-3, which
+/// are only reserved for RV128 and therefore free for custom use on RV32.
+pub const CUSTOM_OPCODES: [u8; 4] = [0b0001011, 0b0101011, 0b1011011, 0b1111011];
+
+/// Instruction decoded by a [`CustomDecoder`]. It is returned as the
+/// [`custom`](crate::parsed_instructions::custom) variant of
+/// [`ParsedInstruction32`](crate::ParsedInstruction32), which forwards `Display`, equality and the
+/// metadata queries to it.
+///
+/// The metadata queries default to an instruction without memory access or floating point
+/// operands that can be executed in user mode.
+pub trait CustomInstruction: fmt::Debug + fmt::Display {
+    /// Returns the instruction as `Any`, so it can be downcast to its concrete type.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns whether the instruction is equal to `other`, which is usually implemented by
+    /// downcasting `other` through [`as_any`](CustomInstruction::as_any).
+    fn eq_custom(&self, other: &dyn CustomInstruction) -> bool;
+
+    fn memory_access(&self) -> Option<MemoryAccess> {
+        None
+    }
+
+    fn privilege_level(&self) -> PrivilegeLevel {
+        PrivilegeLevel::User
+    }
+
+    fn float_format(&self) -> Option<FloatFormat> {
+        None
+    }
+}
+
+/// Decoder for the instructions of a custom opcode, registered through [`CustomDecoders`].
+pub trait CustomDecoder {
+    /// Decodes a 32 bit instruction whose opcode the decoder was registered for. Integer
+    /// registers are named through `register_name` and floating point registers through
+    /// `float_register_name`, which follow the naming selected by the caller of
+    /// [`parse_with_custom_decoders`](crate::parse_with_custom_decoders).
+    fn decode(
+        &self,
+        instruction: Instruction32,
+        register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+        float_register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+    ) -> Result<Box<dyn CustomInstruction>, DisassemblerError>;
+}
+
+/// Registry of the decoders used for the custom opcodes.
+///
+/// ### Example
+///
+/// ```
+/// use risc_v_disassembler::{
+///     parse_with_custom_decoders, CustomDecoder, CustomDecoders, CustomInstruction,
+///     DisassemblerError, Extensions,
+/// };
+/// use std::any::Any;
+/// use std::fmt;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Mac {
+///     rd: &'static str,
+///     rs1: &'static str,
+///     rs2: &'static str,
+/// }
+///
+/// impl fmt::Display for Mac {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "mac {}, {}, {}", self.rd, self.rs1, self.rs2)
+///     }
+/// }
+///
+/// impl CustomInstruction for Mac {
+///     fn as_any(&self) -> &dyn Any {
+///         self
+///     }
+///
+///     fn eq_custom(&self, other: &dyn CustomInstruction) -> bool {
+///         other.as_any().downcast_ref::<Self>() == Some(self)
+///     }
+/// }
+///
+/// struct MacDecoder;
+///
+/// impl CustomDecoder for MacDecoder {
+///     fn decode(
+///         &self,
+///         instruction: u32,
+///         register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+///         _float_register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+///     ) -> Result<Box<dyn CustomInstruction>, DisassemblerError> {
+///         Ok(Box::new(Mac {
+///             rd: register_name((instruction >> 7 & 0x1f) as u8)?,
+///             rs1: register_name((instruction >> 15 & 0x1f) as u8)?,
+///             rs2: register_name((instruction >> 20 & 0x1f) as u8)?,
+///         }))
+///     }
+/// }
+///
+/// let mut decoders = CustomDecoders::new();
+/// decoders.register(0b0001011, MacDecoder).unwrap();
+///
+/// let bytes = 0x00c5850bu32.to_le_bytes();
+/// let parsed_instruction =
+///     parse_with_custom_decoders(&bytes, false, true, &Extensions::default(), &decoders).unwrap();
+/// assert_eq!(parsed_instruction.to_string(), "mac a0, a1, a2");
+/// ```
+#[derive(Default)]
+pub struct CustomDecoders {
+    decoders: Vec<(u8, Box<dyn CustomDecoder>)>,
+}
+
+impl CustomDecoders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `decoder` for `opcode`, replacing any decoder already registered for it. Only
+    /// the opcodes in [`CUSTOM_OPCODES`] can be registered.
+    pub fn register(
+        &mut self,
+        opcode: u8,
+        decoder: impl CustomDecoder + 'static,
+    ) -> Result<(), DisassemblerError> {
+        if !CUSTOM_OPCODES.contains(&opcode) {
+            return Err(DisassemblerError::InvalidOpcode(opcode));
+        }
+
+        self.decoders
+            .retain(|(registered, _)| *registered != opcode);
+        self.decoders.push((opcode, Box::new(decoder)));
+        Ok(())
+    }
+
+    pub(crate) fn get(&self, opcode: u8) -> Option<&dyn CustomDecoder> {
+        self.decoders
+            .iter()
+            .find(|(registered, _)| *registered == opcode)
+            .map(|(_, decoder)| decoder.as_ref())
+    }
+}
+
+impl fmt::Debug for CustomDecoders {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.decoders.iter().map(|(opcode, _)| opcode))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NopDecoder;
+
+    impl CustomDecoder for NopDecoder {
+        fn decode(
+            &self,
+            _instruction: Instruction32,
+            _register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+            _float_register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+        ) -> Result<Box<dyn CustomInstruction>, DisassemblerError> {
+            Err(DisassemblerError::InvalidFunct3(0))
+        }
+    }
+
+    #[test]
+    fn test_register_custom_opcode() {
+        let mut decoders = CustomDecoders::new();
+        assert_eq!(decoders.register(0b0101011, NopDecoder), Ok(()));
+        assert!(decoders.get(0b0101011).is_some());
+        assert!(decoders.get(0b0001011).is_none());
+    }
+
+    #[test]
+    fn test_register_replaces_decoder() {
+        let mut decoders = CustomDecoders::new();
+        decoders.register(0b0001011, NopDecoder).unwrap();
+        decoders.register(0b0001011, NopDecoder).unwrap();
+        assert_eq!(decoders.decoders.len(), 1);
+    }
+
+    #[test]
+    fn test_register_standard_opcode() {
+        let mut decoders = CustomDecoders::new();
+        assert_eq!(
+            decoders.register(0b0110011, NopDecoder),
+            Err(DisassemblerError::InvalidOpcode(0b0110011))
+        );
+    }
+}
@@ -1,23 +1,43 @@
 use crate::macros::{extract_bits, sign_extend32};
-use crate::instructions::{DecodedInstruction32, DecodeInstruction32, Instruction32};
+use crate::instructions::{
+    DecodedInstruction16, DecodedInstruction32, DecodeInstruction16, DecodeInstruction32,
+    Instruction16, Instruction32,
+};
 use crate::DisassemblerError;
 
 impl DecodeInstruction32 for Instruction32 {
     fn decode_instruction32(&self) -> Result<DecodedInstruction32, DisassemblerError> {
         let opcode = extract_bits!(*self, 0, 6)?;
         let decoded = match opcode {
-            0b0110011 => decode_rtype32(*self)?,
-            0b0010011 | 0b0000011 | 0b1100111 => decode_itype32(*self)?,
-            0b0100011 => decode_stype32(*self)?,
-            0b1100011 => decode_btype32(*self)?,
-            0b0110111 | 0b0010111 => decode_utype32(*self)?,
-            0b1101111 => decode_jtype32(*self)?,
+            0b1010011 | 0b0101111 | 0b1010111 => decode_rtype32(*self)?,
+            0b1000011 | 0b1000111 | 0b1001011 | 0b1001111 => decode_r4type32(*self)?,
             _ => return Err(DisassemblerError::InvalidOpcode(opcode as u8)),
         };
         Ok(decoded)
     }
 }
 
+impl DecodeInstruction16 for Instruction16 {
+    fn decode_instruction16(&self) -> Result<DecodedInstruction16, DisassemblerError> {
+        let opcode = extract_bits!(*self, 0, 1)?;
+        let funct3 = extract_bits!(*self, 13, 15)?;
+        let funct6 = extract_bits!(*self, 10, 15)?;
+        let decoded = match (opcode, funct3) {
+            (0b00, 0b100) => decode_catype16(*self)?,
+            (0b01, 0b000) | (0b01, 0b011) => decode_citype16(*self)?,
+            (0b01, 0b100) if funct6 == 0b100111 => decode_catype16(*self)?,
+            (0b10, 0b101) => match funct6 {
+                0b101000 => decode_cmjttype16(*self)?,
+                0b101011 => decode_catype16(*self)?,
+                0b101110 | 0b101111 => decode_cmpptype16(*self)?,
+                _ => return Err(DisassemblerError::UnsupportedCompressedInstruction(*self)),
+            },
+            _ => return Err(DisassemblerError::UnsupportedCompressedInstruction(*self)),
+        };
+        Ok(decoded)
+    }
+}
+
 fn decode_rtype32(instruction: Instruction32) -> Result<DecodedInstruction32, DisassemblerError> {
     let opcode = extract_bits!(instruction, 0, 6)? as u8;
     let rd = extract_bits!(instruction, 7, 11)? as u8;
@@ -35,90 +55,69 @@ fn decode_rtype32(instruction: Instruction32) -> Result<DecodedInstruction32, Di
     })
 }
 
-fn decode_itype32(instruction: Instruction32) -> Result<DecodedInstruction32, DisassemblerError> {
+fn decode_r4type32(instruction: Instruction32) -> Result<DecodedInstruction32, DisassemblerError> {
     let opcode = extract_bits!(instruction, 0, 6)? as u8;
     let rd = extract_bits!(instruction, 7, 11)? as u8;
     let funct3 = extract_bits!(instruction, 12, 14)? as u8;
     let rs1 = extract_bits!(instruction, 15, 19)? as u8;
-    let imm_bits= extract_bits!(instruction, 20, 31)? as u16;
-    let imm = sign_extend32!(imm_bits as u32, 12)?;
-    Ok(DecodedInstruction32::IType {
+    let rs2 = extract_bits!(instruction, 20, 24)? as u8;
+    let funct2 = extract_bits!(instruction, 25, 26)? as u8;
+    let rs3 = extract_bits!(instruction, 27, 31)? as u8;
+    Ok(DecodedInstruction32::R4Type {
         opcode,
         rd,
         funct3,
         rs1,
-        imm,
+        rs2,
+        funct2,
+        rs3,
     })
 }
 
-fn decode_stype32(instruction: Instruction32) -> Result<DecodedInstruction32, DisassemblerError> {
-    let opcode = extract_bits!(instruction, 0, 6)? as u8;
-    let imm4_0 = extract_bits!(instruction, 7, 11)? as u16;
-    let funct3 = extract_bits!(instruction, 12, 14)? as u8;
-    let rs1 = extract_bits!(instruction, 15, 19)? as u8;
-    let rs2 = extract_bits!(instruction, 20, 24)? as u8;
-    let imm11_5 = extract_bits!(instruction, 25, 31)? as u16;
-    let imm_bits = imm11_5 << 5 | imm4_0; 
-    let imm = sign_extend32!(imm_bits as u32, 12)?;
-
-    Ok(DecodedInstruction32::SType {
+fn decode_catype16(instruction: Instruction16) -> Result<DecodedInstruction16, DisassemblerError> {
+    let opcode = extract_bits!(instruction, 0, 1)? as u8;
+    let rs2 = extract_bits!(instruction, 2, 4)? as u8;
+    let funct2 = extract_bits!(instruction, 5, 6)? as u8;
+    let rs1 = extract_bits!(instruction, 7, 9)? as u8;
+    let funct6 = extract_bits!(instruction, 10, 15)? as u8;
+    Ok(DecodedInstruction16::CAType {
         opcode,
-        imm,
-        funct3,
+        funct6,
         rs1,
+        funct2,
         rs2,
     })
 }
 
-fn decode_btype32(instruction: Instruction32) -> Result<DecodedInstruction32, DisassemblerError> {
-    let opcode = extract_bits!(instruction, 0, 6)? as u8;
-    let imm11 = extract_bits!(instruction, 7, 7)? as u16;
-    let imm4_1 = extract_bits!(instruction, 8, 11)? as u16;
-    let funct3 = extract_bits!(instruction, 12, 14)? as u8;
-    let rs1 = extract_bits!(instruction, 15, 19)? as u8;
-    let rs2 = extract_bits!(instruction, 20, 24)? as u8;
-    let imm10_5 = extract_bits!(instruction, 25, 30)? as u16;
-    let imm12 = extract_bits!(instruction, 31, 31)? as u16;
-
-    let imm_bits = imm12 << 12 | imm11<< 11 | imm10_5<< 5 | imm4_1 << 1 | 0;
-    let imm = sign_extend32!(imm_bits as u32, 13)?;
-
-    Ok(DecodedInstruction32::BType {
-        opcode,
-        imm: imm,
-        funct3,
-        rs1,
-        rs2,
+fn decode_cmpptype16(instruction: Instruction16) -> Result<DecodedInstruction16, DisassemblerError> {
+    let spimm = extract_bits!(instruction, 2, 3)? as u8;
+    let rlist = extract_bits!(instruction, 4, 7)? as u8;
+    let funct2 = extract_bits!(instruction, 8, 9)? as u8;
+    let funct6 = extract_bits!(instruction, 10, 15)? as u8;
+    Ok(DecodedInstruction16::CMPPType {
+        funct6,
+        funct2,
+        rlist,
+        spimm,
     })
 }
 
-fn decode_utype32(instruction: Instruction32) -> Result<DecodedInstruction32, DisassemblerError> {
-    let opcode = extract_bits!(instruction, 0, 6)? as u8;
-    let rd = extract_bits!(instruction, 7, 11)? as u8;
-    let mut imm_bits = extract_bits!(instruction, 12, 31)? as u32;
-    imm_bits <<= 12;
-    let imm = sign_extend32!(imm_bits, 32)?;
-
-    Ok(DecodedInstruction32::UType {
-        opcode,
-        rd,
-        imm,
+fn decode_cmjttype16(instruction: Instruction16) -> Result<DecodedInstruction16, DisassemblerError> {
+    let index = extract_bits!(instruction, 2, 9)? as u8;
+    let funct6 = extract_bits!(instruction, 10, 15)? as u8;
+    Ok(DecodedInstruction16::CMJTType {
+        funct6,
+        index,
     })
 }
 
-fn decode_jtype32(instruction: Instruction32) -> Result<DecodedInstruction32, DisassemblerError> {
-    let opcode = extract_bits!(instruction, 0, 6)? as u8;
+fn decode_citype16(instruction: Instruction16) -> Result<DecodedInstruction16, DisassemblerError> {
+    let imm = (extract_bits!(instruction, 12, 12)? << 5 | extract_bits!(instruction, 2, 6)?) as u32;
+    let imm = sign_extend32!(imm, 6)?;
     let rd = extract_bits!(instruction, 7, 11)? as u8;
-    let imm19_12 = extract_bits!(instruction, 12, 19)? as u32;
-    let imm11 = extract_bits!(instruction, 20, 20)? as u32;
-    let imm10_1 = extract_bits!(instruction, 21, 30)? as u32;
-    let imm20 = extract_bits!(instruction, 31, 31)? as u32;
-
-    let imm_bits = imm20 << 20 | imm19_12 << 12 | imm11 << 11 | imm10_1 << 1 | 0;
-    let imm = sign_extend32!(imm_bits, 21)?;
-
-    Ok(DecodedInstruction32::JType {
-        opcode,
+    let funct3 = extract_bits!(instruction, 13, 15)? as u8;
+    Ok(DecodedInstruction16::CIType {
+        funct3,
         rd,
         imm,
     })
@@ -130,56 +129,25 @@ mod tests {
 
     #[test]
     fn test_decode_instruction32() {
-        let rtype_instruction : Instruction32 = 0x007212b3;
+        let rtype_instruction : Instruction32 = 0x007212d3;
+        let r4type_instruction : Instruction32 = 0x18a5f543;
         let itype_instruction : Instruction32 = 0x02a24193;
-        let stype_instruction : Instruction32 = 0x045219a3;
-        let btype_instruction : Instruction32 = 0x00620c63;
-        let utype_instruction : Instruction32 = 0x00038197;
-        let jtype_instruction : Instruction32 = 0x062001ef;
 
         match rtype_instruction.decode_instruction32().unwrap() {
-            DecodedInstruction32::RType { .. } => {
-                assert!(true);
-            }
+            DecodedInstruction32::RType { .. } => {}
             _ => panic!("R-type instruction decoded as a different type"),
         }
 
-        match itype_instruction.decode_instruction32().unwrap() {
-            DecodedInstruction32::IType { .. } => {
-                assert!(true);
-            }
-            _ => panic!("I-type instruction decoded as a different type"),
-        }
-
-        match stype_instruction.decode_instruction32().unwrap() {
-            DecodedInstruction32::SType { .. } => {
-                assert!(true);
-            }
-            _ => panic!("S-type instruction decoded as a different type"),
-        }
-
-        match btype_instruction.decode_instruction32().unwrap() {
-            DecodedInstruction32::BType { .. } => {
-                assert!(true);
-            }
-            _ => panic!("B-type instruction decoded as a different type"),
-        }
-
-        match utype_instruction.decode_instruction32().unwrap() {
-            DecodedInstruction32::UType { .. } => {
-                assert!(true);
-            }
-            _ => panic!("U-type instruction decoded as a different type"),
+        match r4type_instruction.decode_instruction32().unwrap() {
+            DecodedInstruction32::R4Type { .. } => {}
+            _ => panic!("R4-type instruction decoded as a different type"),
         }
 
-        match jtype_instruction.decode_instruction32().unwrap() {
-            DecodedInstruction32::JType { .. } => {
-                assert!(true);
-            }
-            _ => panic!("J-type instruction decoded as a different type"),
-        }
-
-        
+        // The base opcodes are decoded from the generated opcode tables instead.
+        assert!(matches!(
+            itype_instruction.decode_instruction32(),
+            Err(DisassemblerError::InvalidOpcode(0b0010011))
+        ));
     }
 
     #[test]
@@ -199,142 +167,65 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_decode_itype32() {
-        let instruction : Instruction32 = 0x02a0e013;
-        let result = decode_itype32(instruction).unwrap();
-        match result {
-            DecodedInstruction32::IType { opcode, rd, funct3, rs1, imm } => {
-                assert_eq!(opcode, 0b001_0011, "Opcode mismatch");
-                assert_eq!(rd, 0, "RD mismatch");
-                assert_eq!(funct3, 0b110, "Funct3 mismatch");
-                assert_eq!(rs1, 1, "RS1 mismatch");
-                assert_eq!(imm, 42, "Immediate mismatch");
-            }
-            _ => panic!("I-type instruction decoded as a different type"),
-        }
 
-        let instruction : Instruction32 = 0xfd60e013;
-        let result = decode_itype32(instruction).unwrap();
-        match result {
-            DecodedInstruction32::IType { opcode, rd, funct3, rs1, imm } => {
-                assert_eq!(opcode, 0b001_0011, "Opcode mismatch");
-                assert_eq!(rd, 0, "RD mismatch");
-                assert_eq!(funct3, 0b110, "Funct3 mismatch");
-                assert_eq!(rs1, 1, "RS1 mismatch");
-                assert_eq!(imm, -42, "Immediate mismatch");
-            }
-            _ => panic!("I-type instruction decoded as a different type"),
-        }
-    }
 
     #[test]
-    fn test_decode_stype32() {
-        let instruction : Instruction32 = 0x003107a3;
-        let result = decode_stype32(instruction).unwrap();
-        match result {
-            DecodedInstruction32::SType { opcode, imm, funct3, rs1, rs2 } => {
-                assert_eq!(opcode, 0b010_0011, "Opcode mismatch");
-                assert_eq!(imm, 15, "Immediate mismatch");
-                assert_eq!(funct3, 0b000, "Funct3 mismatch");
-                assert_eq!(rs1, 2, "RS1 mismatch");
-                assert_eq!(rs2, 3, "RS2 mismatch");
-            }
-            _ => panic!("S-type instruction decoded as a different type"),
-        }
+    fn test_decode_instruction16() {
+        let catype_instruction: Instruction16 = 0x9d4d;
+        let cmpptype_instruction: Instruction16 = 0xb892;
+        let cmjttype_instruction: Instruction16 = 0xa082;
 
-        let instruction : Instruction32 = 0xfe3108a3;
-        let result = decode_stype32(instruction).unwrap();
-        match result {
-            DecodedInstruction32::SType { opcode, imm, funct3, rs1, rs2 } => {
-                assert_eq!(opcode, 0b010_0011, "Opcode mismatch");
-                assert_eq!(imm, -15, "Immediate mismatch");
-                assert_eq!(funct3, 0b000, "Funct3 mismatch");
-                assert_eq!(rs1, 2, "RS1 mismatch");
-                assert_eq!(rs2, 3, "RS2 mismatch");
-            }
-            _ => panic!("S-type instruction decoded as a different type"),
+        match catype_instruction.decode_instruction16().unwrap() {
+            DecodedInstruction16::CAType { .. } => {}
+            _ => panic!("CA-type instruction decoded as a different type"),
         }
-    }
 
-    #[test]
-    fn test_decode_btype32() {
-        let instruction : Instruction32 = 0x02619d63;
-        let result = decode_btype32(instruction).unwrap();
-        match result {
-            DecodedInstruction32::BType { opcode, imm, funct3, rs1, rs2 } => {
-                assert_eq!(opcode, 0b110_0011, "Opcode mismatch");
-                assert_eq!(imm, 58, "Immediate mismatch");
-                assert_eq!(funct3, 0b001, "Funct3 mismatch");
-                assert_eq!(rs1, 3, "RS1 mismatch");
-                assert_eq!(rs2, 6, "RS2 mismatch");
-            }
-            _ => panic!("B-type instruction decoded as a different type"),
+        match cmpptype_instruction.decode_instruction16().unwrap() {
+            DecodedInstruction16::CMPPType { .. } => {}
+            _ => panic!("CMPP-type instruction decoded as a different type"),
         }
 
-        let instruction : Instruction32 = 0xfc6193e3;
-        let result = decode_btype32(instruction).unwrap();
-        match result {
-            DecodedInstruction32::BType { opcode, imm, funct3, rs1, rs2 } => {
-                assert_eq!(opcode, 0b110_0011, "Opcode mismatch");
-                assert_eq!(imm, -58, "Immediate mismatch");
-                assert_eq!(funct3, 0b001, "Funct3 mismatch");
-                assert_eq!(rs1, 3, "RS1 mismatch");
-                assert_eq!(rs2, 6, "RS2 mismatch");
-            }
-            _ => panic!("B-type instruction decoded as a different type"),
+        match cmjttype_instruction.decode_instruction16().unwrap() {
+            DecodedInstruction16::CMJTType { .. } => {}
+            _ => panic!("CMJT-type instruction decoded as a different type"),
         }
     }
 
     #[test]
-    fn test_decode_utype32() {
-        let instruction : Instruction32 = 0x0005b3b7;
-        let result = decode_utype32(instruction).unwrap();
-        match result {
-            DecodedInstruction32::UType { opcode, rd, imm } => {
-                assert_eq!(opcode, 0b011_0111, "Opcode mismatch");
-                assert_eq!(rd, 7, "RD mismatch");
-                assert_eq!(imm, 91<<12, "Immediate mismatch");
-            }
-            _ => panic!("U-type instruction decoded as a different type"),
-        }
-
-        let instruction : Instruction32 = 0xfffa53b7;
-        let result = decode_utype32(instruction).unwrap();
-        match result {
-            DecodedInstruction32::UType { opcode, rd, imm } => {
-                assert_eq!(opcode, 0b011_0111, "Opcode mismatch");
-                assert_eq!(rd, 7, "RD mismatch");
-                assert_eq!(imm, (-91)<<12, "Immediate mismatch");
+    fn test_decode_cmpptype16() {
+        let instruction: Instruction16 = 0xb892;
+        match decode_cmpptype16(instruction).unwrap() {
+            DecodedInstruction16::CMPPType { funct6, funct2, rlist, spimm } => {
+                assert_eq!(funct6, 0b101110, "Funct6 mismatch");
+                assert_eq!(funct2, 0b00, "Funct2 mismatch");
+                assert_eq!(rlist, 9, "Rlist mismatch");
+                assert_eq!(spimm, 0, "Spimm mismatch");
             }
-            _ => panic!("U-type instruction decoded as a different type"),
+            _ => panic!("CMPP-type instruction decoded as a different type"),
         }
     }
 
-
     #[test]
-    fn test_decode_jtype32() {
-        let instruction : Instruction32 = 0x0360066f;
-        let result = decode_jtype32(instruction).unwrap();
-        match result {
-            DecodedInstruction32::JType { opcode, rd, imm } => {
-                assert_eq!(opcode, 0b110_1111, "Opcode mismatch");
-                assert_eq!(rd, 12, "RD mismatch");
-                assert_eq!(imm, 54, "Immediate mismatch");
-            }
-            _ => panic!("J-type instruction decoded as a different type"),
-        }
+    fn test_decode_instruction16_unsupported() {
+        // c.lw a0, 0(a1)
+        let instruction: Instruction16 = 0x4188;
+        assert!(matches!(
+            instruction.decode_instruction16(),
+            Err(DisassemblerError::UnsupportedCompressedInstruction(0x4188))
+        ));
+    }
 
-        let instruction : Instruction32 = 0xfcbff66f;
-        let result = decode_jtype32(instruction).unwrap();
-        match result {
-            DecodedInstruction32::JType { opcode, rd, imm } => {
-                assert_eq!(opcode, 0b110_1111, "Opcode mismatch");
-                assert_eq!(rd, 12, "RD mismatch");
-                assert_eq!(imm, -54, "Immediate mismatch");
+    #[test]
+    fn test_decode_citype16() {
+        // c.nop -1
+        let instruction: Instruction16 = 0x107d;
+        match decode_citype16(instruction).unwrap() {
+            DecodedInstruction16::CIType { funct3, rd, imm } => {
+                assert_eq!(funct3, 0b000, "Funct3 mismatch");
+                assert_eq!(rd, 0, "RD mismatch");
+                assert_eq!(imm, -1, "Immediate mismatch");
             }
-            _ => panic!("J-type instruction decoded as a different type"),
+            _ => panic!("CI-type instruction decoded as a different type"),
         }
     }
-
-}
\ No newline at end of file
+}
@@ -0,0 +1,1044 @@
+//! Loading of RISC-V ELF files, enabled with the `elf` cargo feature.
+//!
+//! [`ElfFile`] parses the file, section and program headers of ELF32 and ELF64 files in either
+//! byte order, and disassembles the executable sections of ELF32 files with the streaming
+//! decoder at the addresses they are loaded at. The extensions decoded default to those named by
+//! the `Tag_RISCV_arch` attribute of the `.riscv.attributes` section, while the `e_flags` of the
+//! file header select whether 16 bit instructions are present and whether only the registers of
+//! RV32E exist. The symbols of `.symtab` and
+//! `.dynsym` label the listings of [`Instructions::listing`], and in object files the
+//! relocations of `.rela.text` give the symbols their instructions refer to.
+//!
+//! ### Example
+//!
+//! ```no_run
+//! use risc_v_disassembler::elf::ElfFile;
+//!
+//! let data = std::fs::read("firmware.elf").unwrap();
+//! let elf = ElfFile::parse(&data).unwrap();
+//! for section in elf.executable_sections() {
+//!     println!("{}:", section.name);
+//!     for disassembled in elf.disassemble(section, true).unwrap() {
+//!         match disassembled.instruction {
+//!             Ok(instruction) => println!("{:8x}: {}", disassembled.address, instruction),
+//!             Err(error) => println!("{:8x}: {}", disassembled.address, error),
+//!         }
+//!     }
+//! }
+//!
+//! // Or with function labels and symbolised targets.
+//! let symbols = elf.symbols().unwrap();
+//! for section in elf.executable_sections() {
+//!     for line in elf.disassemble(section, true).unwrap().listing(&symbols) {
+//!         println!("{}", line);
+//!     }
+//! }
+//!
+//! // Or for an object file, showing `call printf` for a relocated `auipc` and `jalr`.
+//! for section in elf.executable_sections() {
+//!     let symbols = elf.section_symbols(section).unwrap();
+//!     let relocations = elf.relocations(section).unwrap();
+//!     let instructions = elf.disassemble(section, true).unwrap();
+//!     for line in instructions.listing(&symbols).relocations(&relocations) {
+//!         println!("{}", line);
+//!     }
+//! }
+//! ```
+
+use crate::extensions::isa_string_compressed;
+use crate::{
+    disassemble, DisassemblerError, Extensions, Instructions, Mapping, Relocation, Symbol,
+    SymbolMap,
+};
+use thiserror::Error;
+
+/// `e_machine` of RISC-V.
+pub const EM_RISCV: u16 = 243;
+
+pub const ET_REL: u16 = 1;
+pub const ET_EXEC: u16 = 2;
+pub const ET_DYN: u16 = 3;
+
+pub const SHT_PROGBITS: u32 = 1;
+pub const SHT_SYMTAB: u32 = 2;
+pub const SHT_STRTAB: u32 = 3;
+pub const SHT_RELA: u32 = 4;
+pub const SHT_NOBITS: u32 = 8;
+pub const SHT_DYNSYM: u32 = 11;
+pub const SHT_RISCV_ATTRIBUTES: u32 = 0x70000003;
+
+pub const SHF_WRITE: u64 = 0x1;
+pub const SHF_ALLOC: u64 = 0x2;
+pub const SHF_EXECINSTR: u64 = 0x4;
+
+pub const PT_LOAD: u32 = 1;
+
+pub const EF_RISCV_RVC: u32 = 0x1;
+pub const EF_RISCV_FLOAT_ABI: u32 = 0x6;
+pub const EF_RISCV_RVE: u32 = 0x8;
+pub const EF_RISCV_TSO: u32 = 0x10;
+
+pub const STT_NOTYPE: u8 = 0;
+pub const STT_OBJECT: u8 = 1;
+pub const STT_FUNC: u8 = 2;
+pub const STT_SECTION: u8 = 3;
+pub const STT_FILE: u8 = 4;
+
+pub const STB_LOCAL: u8 = 0;
+pub const STB_GLOBAL: u8 = 1;
+pub const STB_WEAK: u8 = 2;
+
+pub const SHN_UNDEF: u16 = 0;
+pub const SHN_ABS: u16 = 0xfff1;
+const SHN_XINDEX: u16 = 0xffff;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ElfError {
+    #[error("Invalid ELF magic. The file is not an ELF file.")]
+    InvalidMagic,
+
+    #[error("Unsupported ELF class: {0}. Only ELF32 and ELF64 files are supported.")]
+    UnsupportedClass(u8),
+
+    #[error("Unsupported ELF data encoding: {0}. Only little and big endian files are supported.")]
+    UnsupportedEncoding(u8),
+
+    #[error("Unsupported machine: {0}. Only RISC-V files are supported.")]
+    UnsupportedMachine(u16),
+
+    #[error("Truncated ELF file: {size} bytes at offset {offset:#x} are outside the file.")]
+    Truncated { offset: u64, size: u64 },
+
+    #[error("Invalid {table} header size: {size} bytes is smaller than a header.")]
+    InvalidEntrySize { table: &'static str, size: u64 },
+
+    #[error("Invalid string at offset {0:#x}. The string is not terminated or not UTF-8.")]
+    InvalidString(u64),
+
+    #[error("Invalid RISC-V attributes: {0}.")]
+    InvalidAttributes(&'static str),
+
+    #[error(transparent)]
+    Disassembler(#[from] DisassemblerError),
+}
+
+/// Word size of an ELF file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ElfClass {
+    Elf32,
+    Elf64,
+}
+
+/// Calling convention for floating point arguments, recorded in `e_flags`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FloatAbi {
+    Soft,
+    Single,
+    Double,
+    Quad,
+}
+
+/// The RISC-V specific `e_flags` of the file header.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElfFlags {
+    /// The file contains 16 bit instructions.
+    pub rvc: bool,
+    pub float_abi: FloatAbi,
+    /// The file targets the RV32E or RV64E base ISA.
+    pub rve: bool,
+    /// The file requires the RVTSO memory consistency model.
+    pub tso: bool,
+}
+
+impl From<u32> for ElfFlags {
+    fn from(flags: u32) -> Self {
+        ElfFlags {
+            rvc: flags & EF_RISCV_RVC != 0,
+            float_abi: match flags & EF_RISCV_FLOAT_ABI {
+                0x0 => FloatAbi::Soft,
+                0x2 => FloatAbi::Single,
+                0x4 => FloatAbi::Double,
+                _ => FloatAbi::Quad,
+            },
+            rve: flags & EF_RISCV_RVE != 0,
+            tso: flags & EF_RISCV_TSO != 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileHeader {
+    pub class: ElfClass,
+    /// Encoding of the headers, symbols and other data of the file. Instructions are always
+    /// little endian.
+    pub is_big_endian: bool,
+    /// `e_type`, such as [`ET_EXEC`] or [`ET_REL`].
+    pub file_type: u16,
+    pub machine: u16,
+    pub entry: u64,
+    pub flags: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionHeader {
+    /// Index of the section in the section header table.
+    pub index: usize,
+    pub name: String,
+    pub section_type: u32,
+    pub flags: u64,
+    /// Address of the section in memory, zero in relocatable files.
+    pub address: u64,
+    pub offset: u64,
+    pub size: u64,
+    pub link: u32,
+    pub info: u32,
+    pub alignment: u64,
+    pub entry_size: u64,
+}
+
+impl SectionHeader {
+    /// Returns whether the section holds instructions that are present in the file.
+    pub fn is_executable(&self) -> bool {
+        self.flags & SHF_EXECINSTR != 0 && self.section_type == SHT_PROGBITS
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgramHeader {
+    pub segment_type: u32,
+    pub flags: u32,
+    pub offset: u64,
+    pub virtual_address: u64,
+    pub physical_address: u64,
+    pub file_size: u64,
+    pub memory_size: u64,
+    pub alignment: u64,
+}
+
+/// An entry of a `.symtab` or `.dynsym` symbol table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElfSymbol {
+    pub name: String,
+    /// `st_value`: the address of the symbol, or its offset in its section in relocatable
+    /// files.
+    pub value: u64,
+    pub size: u64,
+    /// The low bits of `st_info`, such as [`STT_FUNC`].
+    pub symbol_type: u8,
+    /// The high bits of `st_info`, such as [`STB_GLOBAL`].
+    pub binding: u8,
+    /// `st_shndx`: the index of the section defining the symbol, or [`SHN_UNDEF`].
+    pub section_index: u16,
+}
+
+/// The file attributes of the `.riscv.attributes` section.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Attributes {
+    /// `Tag_RISCV_arch`: the ISA string the file was built for.
+    pub arch: Option<String>,
+    /// `Tag_RISCV_stack_align`: the stack alignment in bytes.
+    pub stack_align: Option<u64>,
+    /// `Tag_RISCV_unaligned_access`: whether the code may access memory unaligned.
+    pub unaligned_access: Option<bool>,
+    /// `Tag_RISCV_priv_spec`, `Tag_RISCV_priv_spec_minor` and `Tag_RISCV_priv_spec_revision`.
+    pub priv_spec: Option<(u64, u64, u64)>,
+}
+
+const TAG_FILE: u64 = 1;
+const TAG_RISCV_STACK_ALIGN: u64 = 4;
+const TAG_RISCV_ARCH: u64 = 5;
+const TAG_RISCV_UNALIGNED_ACCESS: u64 = 6;
+const TAG_RISCV_PRIV_SPEC: u64 = 8;
+const TAG_RISCV_PRIV_SPEC_MINOR: u64 = 10;
+const TAG_RISCV_PRIV_SPEC_REVISION: u64 = 12;
+
+/// Reads the fields of an ELF file in its byte order.
+#[derive(Debug, Clone, Copy)]
+struct Reader<'a> {
+    data: &'a [u8],
+    is_big_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&self, offset: u64, size: u64) -> Result<&'a [u8], ElfError> {
+        let truncated = || ElfError::Truncated { offset, size };
+        let end = offset.checked_add(size).ok_or_else(truncated)?;
+        if end > self.data.len() as u64 {
+            return Err(truncated());
+        }
+        Ok(&self.data[offset as usize..end as usize])
+    }
+
+    fn u16(&self, offset: u64) -> Result<u16, ElfError> {
+        let bytes = self.bytes(offset, 2)?.try_into().unwrap();
+        Ok(match self.is_big_endian {
+            true => u16::from_be_bytes(bytes),
+            false => u16::from_le_bytes(bytes),
+        })
+    }
+
+    fn u32(&self, offset: u64) -> Result<u32, ElfError> {
+        let bytes = self.bytes(offset, 4)?.try_into().unwrap();
+        Ok(match self.is_big_endian {
+            true => u32::from_be_bytes(bytes),
+            false => u32::from_le_bytes(bytes),
+        })
+    }
+
+    fn u64(&self, offset: u64) -> Result<u64, ElfError> {
+        let bytes = self.bytes(offset, 8)?.try_into().unwrap();
+        Ok(match self.is_big_endian {
+            true => u64::from_be_bytes(bytes),
+            false => u64::from_le_bytes(bytes),
+        })
+    }
+
+    /// Reads an address or offset, which is 32 bits wide in ELF32 and 64 bits wide in ELF64.
+    fn word(&self, class: ElfClass, offset: u64) -> Result<u64, ElfError> {
+        match class {
+            ElfClass::Elf32 => self.u32(offset).map(u64::from),
+            ElfClass::Elf64 => self.u64(offset),
+        }
+    }
+
+    /// Reads a NUL terminated string.
+    fn string(&self, offset: u64) -> Result<&'a str, ElfError> {
+        let invalid = || ElfError::InvalidString(offset);
+        let bytes = self.data.get(offset as usize..).ok_or_else(invalid)?;
+        let end = bytes.iter().position(|&b| b == 0).ok_or_else(invalid)?;
+        std::str::from_utf8(&bytes[..end]).map_err(|_| invalid())
+    }
+}
+
+/// Returns the offset of entry `index` of a table of `size` byte entries at `base`, or
+/// `Truncated` when it overflows.
+fn entry_offset(base: u64, index: u64, size: u64) -> Result<u64, ElfError> {
+    index
+        .checked_mul(size)
+        .and_then(|offset| base.checked_add(offset))
+        .ok_or(ElfError::Truncated { offset: base, size })
+}
+
+/// Returns the offset of the string at `index` of the string table at `table`.
+fn string_offset(table: u64, index: u32) -> Result<u64, ElfError> {
+    table.checked_add(index as u64).ok_or(ElfError::Truncated {
+        offset: table,
+        size: index as u64,
+    })
+}
+
+/// A parsed RISC-V ELF file, borrowing its contents.
+#[derive(Debug, Clone)]
+pub struct ElfFile<'a> {
+    pub header: FileHeader,
+    pub sections: Vec<SectionHeader>,
+    pub segments: Vec<ProgramHeader>,
+    data: &'a [u8],
+}
+
+impl<'a> ElfFile<'a> {
+    /// Parses the headers of an ELF file.
+    pub fn parse(data: &'a [u8]) -> Result<Self, ElfError> {
+        if data.len() < 16 || data[..4] != *b"\x7fELF" {
+            return Err(ElfError::InvalidMagic);
+        }
+        let class = match data[4] {
+            1 => ElfClass::Elf32,
+            2 => ElfClass::Elf64,
+            class => return Err(ElfError::UnsupportedClass(class)),
+        };
+        let is_big_endian = match data[5] {
+            1 => false,
+            2 => true,
+            encoding => return Err(ElfError::UnsupportedEncoding(encoding)),
+        };
+        let reader = Reader {
+            data,
+            is_big_endian,
+        };
+
+        let machine = reader.u16(18)?;
+        if machine != EM_RISCV {
+            return Err(ElfError::UnsupportedMachine(machine));
+        }
+        // Offsets of e_phoff, e_shoff, e_flags and e_phentsize, after which the header fields
+        // are laid out alike.
+        let (phoff, shoff, flags, rest) = match class {
+            ElfClass::Elf32 => (28, 32, 36, 42),
+            ElfClass::Elf64 => (32, 40, 48, 54),
+        };
+        // Sizes of a section and a program header, below which the entries would overlap.
+        let (section_size, program_size) = match class {
+            ElfClass::Elf32 => (40, 32),
+            ElfClass::Elf64 => (64, 56),
+        };
+        let header = FileHeader {
+            class,
+            is_big_endian,
+            file_type: reader.u16(16)?,
+            machine,
+            entry: reader.word(class, 24)?,
+            flags: reader.u32(flags)?,
+        };
+        let phoff = reader.word(class, phoff)?;
+        let shoff = reader.word(class, shoff)?;
+        let phentsize = reader.u16(rest)? as u64;
+        let mut phnum = reader.u16(rest + 2)? as u64;
+        let shentsize = reader.u16(rest + 4)? as u64;
+        let mut shnum = reader.u16(rest + 6)? as u64;
+        let mut shstrndx = reader.u16(rest + 8)? as u64;
+        if (shoff != 0 || shnum != 0) && shentsize < section_size {
+            return Err(ElfError::InvalidEntrySize {
+                table: "section",
+                size: shentsize,
+            });
+        }
+
+        // With more sections or segments than fit in the header, the counts and the index of
+        // the section name table are held by the first section header.
+        if shoff != 0 && (shnum == 0 || shstrndx == SHN_XINDEX as u64 || phnum == 0xffff) {
+            let first = Self::section_header(&reader, class, shoff)?;
+            if shnum == 0 {
+                shnum = first.size;
+            }
+            if shstrndx == SHN_XINDEX as u64 {
+                shstrndx = first.link as u64;
+            }
+            if phnum == 0xffff {
+                phnum = first.info as u64;
+            }
+        }
+
+        if phnum != 0 && phentsize < program_size {
+            return Err(ElfError::InvalidEntrySize {
+                table: "program",
+                size: phentsize,
+            });
+        }
+        // The counts are below 2^32 and the entry sizes below 2^16, so the table sizes do not
+        // overflow. Checking them first bounds the counts by the file size.
+        reader.bytes(phoff, phnum * phentsize)?;
+        reader.bytes(shoff, shnum * shentsize)?;
+
+        let segments = (0..phnum)
+            .map(|i| {
+                let offset = entry_offset(phoff, i, phentsize)?;
+                Self::program_header(&reader, class, offset)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut sections = (0..shnum)
+            .map(|i| {
+                let offset = entry_offset(shoff, i, shentsize)?;
+                Ok(SectionHeader {
+                    index: i as usize,
+                    ..Self::section_header(&reader, class, offset)?
+                })
+            })
+            .collect::<Result<Vec<_>, ElfError>>()?;
+
+        if let Some(names) = sections.get(shstrndx as usize).map(|names| names.offset) {
+            let name_offsets = (0..shnum)
+                .map(|i| reader.u32(entry_offset(shoff, i, shentsize)?))
+                .collect::<Result<Vec<_>, _>>()?;
+            for (section, name) in sections.iter_mut().zip(name_offsets) {
+                section.name = reader.string(string_offset(names, name)?)?.to_string();
+            }
+        }
+
+        Ok(ElfFile {
+            header,
+            sections,
+            segments,
+            data,
+        })
+    }
+
+    fn section_header(
+        reader: &Reader,
+        class: ElfClass,
+        offset: u64,
+    ) -> Result<SectionHeader, ElfError> {
+        reader.bytes(offset, if class == ElfClass::Elf32 { 40 } else { 64 })?;
+        let word = |field32: u64, field64: u64| match class {
+            ElfClass::Elf32 => reader.word(class, offset + field32),
+            ElfClass::Elf64 => reader.word(class, offset + field64),
+        };
+        Ok(SectionHeader {
+            index: 0,
+            name: String::new(),
+            section_type: reader.u32(offset + 4)?,
+            flags: word(8, 8)?,
+            address: word(12, 16)?,
+            offset: word(16, 24)?,
+            size: word(20, 32)?,
+            link: reader.u32(offset + if class == ElfClass::Elf32 { 24 } else { 40 })?,
+            info: reader.u32(offset + if class == ElfClass::Elf32 { 28 } else { 44 })?,
+            alignment: word(32, 48)?,
+            entry_size: word(36, 56)?,
+        })
+    }
+
+    fn program_header(
+        reader: &Reader,
+        class: ElfClass,
+        offset: u64,
+    ) -> Result<ProgramHeader, ElfError> {
+        reader.bytes(offset, if class == ElfClass::Elf32 { 32 } else { 56 })?;
+        let word = |field32: u64, field64: u64| match class {
+            ElfClass::Elf32 => reader.word(class, offset + field32),
+            ElfClass::Elf64 => reader.word(class, offset + field64),
+        };
+        Ok(ProgramHeader {
+            segment_type: reader.u32(offset)?,
+            flags: reader.u32(offset + if class == ElfClass::Elf32 { 24 } else { 4 })?,
+            offset: word(4, 8)?,
+            virtual_address: word(8, 16)?,
+            physical_address: word(12, 24)?,
+            file_size: word(16, 32)?,
+            memory_size: word(20, 40)?,
+            alignment: word(28, 48)?,
+        })
+    }
+
+    fn reader(&self) -> Reader<'a> {
+        Reader {
+            data: self.data,
+            is_big_endian: self.header.is_big_endian,
+        }
+    }
+
+    /// Returns the RISC-V specific flags of the file header.
+    pub fn flags(&self) -> ElfFlags {
+        ElfFlags::from(self.header.flags)
+    }
+
+    /// Returns the first section called `name`.
+    pub fn section(&self, name: &str) -> Option<&SectionHeader> {
+        self.sections.iter().find(|section| section.name == name)
+    }
+
+    /// Returns the contents of a section, which are empty for sections without data in the
+    /// file, such as `.bss`.
+    pub fn section_data(&self, section: &SectionHeader) -> Result<&'a [u8], ElfError> {
+        match section.section_type {
+            SHT_NOBITS => Ok(&[]),
+            _ => self.reader().bytes(section.offset, section.size),
+        }
+    }
+
+    /// Returns the sections holding instructions, in the order of the section headers.
+    pub fn executable_sections(&self) -> impl Iterator<Item = &SectionHeader> {
+        self.sections
+            .iter()
+            .filter(|section| section.is_executable())
+    }
+
+    /// Parses the entries of a symbol table section, including the null symbol at index 0, so
+    /// that relocations can index them directly.
+    pub fn symbol_table(&self, section: &SectionHeader) -> Result<Vec<ElfSymbol>, ElfError> {
+        let reader = self.reader();
+        let class = self.header.class;
+        let entry_size = match class {
+            ElfClass::Elf32 => 16,
+            ElfClass::Elf64 => 24,
+        };
+        let names = self
+            .sections
+            .get(section.link as usize)
+            .map_or(0, |names| names.offset);
+
+        (0..section.size / entry_size)
+            .map(|i| {
+                let offset = entry_offset(section.offset, i, entry_size)?;
+                reader.bytes(offset, entry_size)?;
+                // st_value, st_size, st_info and st_shndx.
+                let (value, size, info, index) = match class {
+                    ElfClass::Elf32 => (4, 8, 12, 14),
+                    ElfClass::Elf64 => (8, 16, 4, 6),
+                };
+                let info = reader.bytes(offset + info, 1)?[0];
+                Ok(ElfSymbol {
+                    name: reader
+                        .string(string_offset(names, reader.u32(offset)?)?)?
+                        .to_string(),
+                    value: reader.word(class, offset + value)?,
+                    size: reader.word(class, offset + size)?,
+                    symbol_type: info & 0xf,
+                    binding: info >> 4,
+                    section_index: reader.u16(offset + index)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the functions, objects and untyped labels defined by the `.symtab` and `.dynsym`
+    /// sections, with the `$x` and `$d` mapping symbols, for listing with
+    /// [`Instructions::listing`]. The symbols of relocatable files are placed at the address of
+    /// their section.
+    pub fn symbols(&self) -> Result<SymbolMap, ElfError> {
+        let mut symbols = SymbolMap::new();
+        for section in self.sections.iter().filter(|section| {
+            section.section_type == SHT_SYMTAB || section.section_type == SHT_DYNSYM
+        }) {
+            for symbol in self.symbol_table(section)? {
+                if symbol.name.is_empty()
+                    || (symbol.name.starts_with('$') && Mapping::from_name(&symbol.name).is_none())
+                    || symbol.section_index == SHN_UNDEF
+                    || !matches!(symbol.symbol_type, STT_NOTYPE | STT_OBJECT | STT_FUNC)
+                {
+                    continue;
+                }
+                symbols.insert(Symbol {
+                    address: self.symbol_address(&symbol),
+                    size: symbol.size,
+                    is_function: symbol.symbol_type == STT_FUNC,
+                    name: symbol.name,
+                });
+            }
+        }
+        Ok(symbols)
+    }
+
+    /// Returns the symbols defined in `section`, with its mapping symbols. The sections of
+    /// relocatable files all start at address zero, so their listings are labelled with the
+    /// symbols of their own section only.
+    pub fn section_symbols(&self, section: &SectionHeader) -> Result<SymbolMap, ElfError> {
+        let index = self.section_index(section);
+        let mut symbols = SymbolMap::new();
+        for table in self
+            .sections
+            .iter()
+            .filter(|table| table.section_type == SHT_SYMTAB)
+        {
+            for symbol in self.symbol_table(table)? {
+                if symbol.name.is_empty()
+                    || Some(symbol.section_index as usize) != index
+                    || (symbol.name.starts_with('$') && Mapping::from_name(&symbol.name).is_none())
+                    || !matches!(symbol.symbol_type, STT_NOTYPE | STT_OBJECT | STT_FUNC)
+                {
+                    continue;
+                }
+                symbols.insert(Symbol {
+                    address: self.symbol_address(&symbol),
+                    size: symbol.size,
+                    is_function: symbol.symbol_type == STT_FUNC,
+                    name: symbol.name,
+                });
+            }
+        }
+        Ok(symbols)
+    }
+
+    /// Returns the relocations of `section` in order of address, read from the `SHT_RELA`
+    /// sections applying to it, such as `.rela.text`. Relocations against a section symbol
+    /// name the section.
+    pub fn relocations(&self, section: &SectionHeader) -> Result<Vec<Relocation>, ElfError> {
+        let Some(index) = self.section_index(section) else {
+            return Ok(Vec::new());
+        };
+        let reader = self.reader();
+        let class = self.header.class;
+        let entry_size = match class {
+            ElfClass::Elf32 => 12,
+            ElfClass::Elf64 => 24,
+        };
+        let base = match self.header.file_type {
+            ET_REL => section.address,
+            _ => 0,
+        };
+
+        let mut relocations = Vec::new();
+        for table in self
+            .sections
+            .iter()
+            .filter(|table| table.section_type == SHT_RELA && table.info as usize == index)
+        {
+            let symbols = match self.sections.get(table.link as usize) {
+                Some(symbols) => self.symbol_table(symbols)?,
+                None => Vec::new(),
+            };
+            for i in 0..table.size / entry_size {
+                let offset = entry_offset(table.offset, i, entry_size)?;
+                reader.bytes(offset, entry_size)?;
+                let (address, info, addend) = match class {
+                    ElfClass::Elf32 => {
+                        let info = reader.u32(offset + 4)?;
+                        let addend = reader.u32(offset + 8)? as i32 as i64;
+                        (reader.u32(offset)? as u64, info as u64, addend)
+                    }
+                    ElfClass::Elf64 => (
+                        reader.u64(offset)?,
+                        reader.u64(offset + 8)?,
+                        reader.u64(offset + 16)? as i64,
+                    ),
+                };
+                let (symbol, relocation_type) = match class {
+                    ElfClass::Elf32 => (info >> 8, info as u8 as u32),
+                    ElfClass::Elf64 => (info >> 32, info as u32),
+                };
+                let symbol = match symbols.get(symbol as usize) {
+                    Some(symbol) if symbol.symbol_type == STT_SECTION => self
+                        .sections
+                        .get(symbol.section_index as usize)
+                        .map_or(String::new(), |section| section.name.clone()),
+                    Some(symbol) => symbol.name.clone(),
+                    None => String::new(),
+                };
+                relocations.push(Relocation {
+                    address: base.wrapping_add(address),
+                    relocation_type,
+                    symbol,
+                    addend,
+                });
+            }
+        }
+        relocations.sort_by_key(|relocation| relocation.address);
+        Ok(relocations)
+    }
+
+    /// Returns the index of `section`, if it is a section of this file.
+    fn section_index(&self, section: &SectionHeader) -> Option<usize> {
+        (self.sections.get(section.index) == Some(section)).then_some(section.index)
+    }
+
+    /// Returns the address of a symbol, which relocatable files give as an offset in its
+    /// section.
+    fn symbol_address(&self, symbol: &ElfSymbol) -> u64 {
+        let base = match self.header.file_type {
+            ET_REL => self
+                .sections
+                .get(symbol.section_index as usize)
+                .map_or(0, |section| section.address),
+            _ => 0,
+        };
+        base.wrapping_add(symbol.value)
+    }
+
+    /// Parses the file attributes of the `.riscv.attributes` section, if present.
+    pub fn attributes(&self) -> Result<Option<Attributes>, ElfError> {
+        let Some(section) = self
+            .sections
+            .iter()
+            .find(|section| section.section_type == SHT_RISCV_ATTRIBUTES)
+        else {
+            return Ok(None);
+        };
+        parse_attributes(self.section_data(section)?, self.header.is_big_endian).map(Some)
+    }
+
+    /// Returns the extensions named by the `Tag_RISCV_arch` attribute, or none when the file
+    /// has no attributes, with the RV32E base when the RVE flag is set.
+    pub fn extensions(&self) -> Result<Extensions, ElfError> {
+        let mut extensions = match self.attributes()?.and_then(|attributes| attributes.arch) {
+            Some(arch) => Extensions::from_isa_string(&arch)?,
+            None => Extensions::default(),
+        };
+        extensions.e |= self.flags().rve;
+        Ok(extensions)
+    }
+
+    /// Returns whether the file contains 16 bit instructions, as recorded by the RVC flag or by
+    /// the C extension or one of its Zc* subsets in `Tag_RISCV_arch`.
+    pub fn compressed(&self) -> Result<bool, ElfError> {
+        if self.flags().rvc {
+            return Ok(true);
+        }
+        match self.attributes()?.and_then(|attributes| attributes.arch) {
+            Some(arch) => Ok(isa_string_compressed(&arch)?),
+            None => Ok(false),
+        }
+    }
+
+    /// Disassembles a section with the configuration derived from the file, starting at the
+    /// address the section is loaded at.
+    pub fn disassemble(
+        &self,
+        section: &SectionHeader,
+        use_abi_register_names: bool,
+    ) -> Result<Instructions<'a>, ElfError> {
+        self.disassemble_with_extensions(section, use_abi_register_names, &self.extensions()?)
+    }
+
+    /// Disassembles a section like [`disassemble`](ElfFile::disassemble), decoding the given
+    /// `extensions` instead of those named by the file. Vendor extensions are decoded by passing
+    /// their decoders to [`Instructions::custom_decoders`]. The code of ELF64 files is RV64
+    /// code, which is rejected with [`DisassemblerError::UnsupportedXlen`].
+    pub fn disassemble_with_extensions(
+        &self,
+        section: &SectionHeader,
+        use_abi_register_names: bool,
+        extensions: &Extensions,
+    ) -> Result<Instructions<'a>, ElfError> {
+        if self.header.class == ElfClass::Elf64 {
+            return Err(DisassemblerError::UnsupportedXlen(64).into());
+        }
+        // Instruction parcels are little endian whatever the data encoding of the file.
+        Ok(disassemble(
+            self.section_data(section)?,
+            section.address,
+            false,
+            use_abi_register_names,
+            extensions,
+        )
+        .compressed(self.compressed()?))
+    }
+}
+
+/// Reads an unsigned LEB128 number, returning it with the offset following it.
+fn uleb128(data: &[u8], mut offset: usize) -> Result<(u64, usize), ElfError> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *data
+            .get(offset)
+            .ok_or(ElfError::InvalidAttributes("truncated number"))?;
+        offset += 1;
+        if shift < 64 {
+            value |= ((byte & 0x7f) as u64) << shift;
+        }
+        shift += 7;
+        if byte & 0x80 == 0 {
+            return Ok((value, offset));
+        }
+    }
+}
+
+/// Parses the contents of a `.riscv.attributes` section: a format version `A`, followed by
+/// vendor subsections, of which the `riscv` one holds the file attributes. Attributes with an
+/// odd tag have a string value, and those with an even tag a ULEB128 number.
+fn parse_attributes(data: &[u8], is_big_endian: bool) -> Result<Attributes, ElfError> {
+    if data.first() != Some(&b'A') {
+        return Err(ElfError::InvalidAttributes("unknown format version"));
+    }
+    let reader = Reader {
+        data,
+        is_big_endian,
+    };
+    let truncated = || ElfError::InvalidAttributes("truncated subsection");
+
+    let mut attributes = Attributes::default();
+    let mut offset = 1;
+    while offset < data.len() {
+        let length = reader.u32(offset as u64).map_err(|_| truncated())? as usize;
+        let end = offset + length;
+        if length < 4 || end > data.len() {
+            return Err(truncated());
+        }
+        let vendor = reader
+            .string(offset as u64 + 4)
+            .map_err(|_| ElfError::InvalidAttributes("invalid vendor name"))?;
+        if vendor == "riscv" {
+            let mut position = offset + 4 + vendor.len() + 1;
+            while position < end {
+                let (tag, next) = uleb128(&data[..end], position)?;
+                let size = reader.u32(next as u64).map_err(|_| truncated())? as usize;
+                if size < 5 || position + size > end || next + 4 > position + size {
+                    return Err(truncated());
+                }
+                if tag == TAG_FILE {
+                    parse_file_attributes(&data[next + 4..position + size], &mut attributes)?;
+                }
+                position += size;
+            }
+        }
+        offset = end;
+    }
+    Ok(attributes)
+}
+
+fn parse_file_attributes(data: &[u8], attributes: &mut Attributes) -> Result<(), ElfError> {
+    let mut priv_spec = (None, None, None);
+    let mut offset = 0;
+    while offset < data.len() {
+        let (tag, next) = uleb128(data, offset)?;
+        if tag % 2 == 1 {
+            let end = data[next..]
+                .iter()
+                .position(|&b| b == 0)
+                .ok_or(ElfError::InvalidAttributes("unterminated string"))?;
+            let value = std::str::from_utf8(&data[next..next + end])
+                .map_err(|_| ElfError::InvalidAttributes("invalid string"))?;
+            if tag == TAG_RISCV_ARCH {
+                attributes.arch = Some(value.to_string());
+            }
+            offset = next + end + 1;
+        } else {
+            let (value, next) = uleb128(data, next)?;
+            match tag {
+                TAG_RISCV_STACK_ALIGN => attributes.stack_align = Some(value),
+                TAG_RISCV_UNALIGNED_ACCESS => attributes.unaligned_access = Some(value != 0),
+                TAG_RISCV_PRIV_SPEC => priv_spec.0 = Some(value),
+                TAG_RISCV_PRIV_SPEC_MINOR => priv_spec.1 = Some(value),
+                TAG_RISCV_PRIV_SPEC_REVISION => priv_spec.2 = Some(value),
+                _ => {}
+            }
+            offset = next;
+        }
+    }
+    if let (Some(major), minor, revision) = priv_spec {
+        attributes.priv_spec = Some((major, minor.unwrap_or(0), revision.unwrap_or(0)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elf_flags() {
+        assert_eq!(
+            ElfFlags::from(EF_RISCV_RVC | 0x4 | EF_RISCV_TSO),
+            ElfFlags {
+                rvc: true,
+                float_abi: FloatAbi::Double,
+                rve: false,
+                tso: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_attributes() {
+        let mut data = b"A\x00\x00\x00\x00riscv\x00\x01\x00\x00\x00\x00".to_vec();
+        data.extend(b"\x04\x10\x05rv32i2p1_c2p0\x00\x06\x01\x08\x01\x0a\x0b");
+        let subsection = data.len() as u32 - 1;
+        let file = data.len() as u32 - 11;
+        data[1..5].copy_from_slice(&subsection.to_le_bytes());
+        data[12..16].copy_from_slice(&file.to_le_bytes());
+
+        assert_eq!(
+            parse_attributes(&data, false),
+            Ok(Attributes {
+                arch: Some("rv32i2p1_c2p0".to_string()),
+                stack_align: Some(16),
+                unaligned_access: Some(true),
+                priv_spec: Some((1, 11, 0)),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_attributes_other_vendor() {
+        let data = b"A\x0a\x00\x00\x00gnu\x00\x01\x02";
+        assert_eq!(parse_attributes(data, false), Ok(Attributes::default()));
+    }
+
+    #[test]
+    fn test_parse_attributes_invalid() {
+        assert_eq!(
+            parse_attributes(b"B", false),
+            Err(ElfError::InvalidAttributes("unknown format version"))
+        );
+        assert_eq!(
+            parse_attributes(b"A\x20\x00\x00\x00riscv\x00", false),
+            Err(ElfError::InvalidAttributes("truncated subsection"))
+        );
+        // Tag_File encoded as the non-minimal ULEB128 0x81 0x00, with a size of 5 that ends
+        // before its own size field.
+        assert_eq!(
+            parse_attributes(b"A\x10\x00\x00\x00riscv\x00\x81\x00\x05\x00\x00\x00", false),
+            Err(ElfError::InvalidAttributes("truncated subsection"))
+        );
+    }
+
+    #[test]
+    fn test_parse_overflowing_offsets() {
+        let mut header = [0u8; 64];
+        header[..4].copy_from_slice(b"\x7fELF");
+        header[4] = 2;
+        header[5] = 1;
+        header[18] = EM_RISCV as u8;
+        header[40..48].copy_from_slice(&u64::MAX.to_le_bytes());
+        header[58] = 64;
+        header[60] = 2;
+        assert_eq!(
+            ElfFile::parse(&header).err(),
+            Some(ElfError::Truncated {
+                offset: u64::MAX,
+                size: 128
+            })
+        );
+    }
+
+    #[test]
+    fn test_uleb128() {
+        assert_eq!(uleb128(&[0xe5, 0x8e, 0x26], 0), Ok((624485, 3)));
+        assert!(uleb128(&[0x80], 0).is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            ElfFile::parse(b"not an elf file").err(),
+            Some(ElfError::InvalidMagic)
+        );
+        let mut header = [0u8; 52];
+        header[..4].copy_from_slice(b"\x7fELF");
+        header[4] = 1;
+        header[5] = 1;
+        header[18] = 62;
+        assert_eq!(
+            ElfFile::parse(&header).err(),
+            Some(ElfError::UnsupportedMachine(62))
+        );
+        header[18] = EM_RISCV as u8;
+        header[32] = 0x40;
+        header[46] = 40;
+        header[48] = 1;
+        assert_eq!(
+            ElfFile::parse(&header).err(),
+            Some(ElfError::Truncated {
+                offset: 0x40,
+                size: 40
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_entry_sizes() {
+        let mut header = [0u8; 92];
+        header[..4].copy_from_slice(b"\x7fELF");
+        header[4] = 1;
+        header[5] = 1;
+        header[18] = EM_RISCV as u8;
+        // A zero section header size, with the section count of 0xffffffff taken from the first
+        // section header at offset 52.
+        header[32] = 52;
+        header[72..76].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(
+            ElfFile::parse(&header).err(),
+            Some(ElfError::InvalidEntrySize {
+                table: "section",
+                size: 0
+            })
+        );
+        header[46] = 40;
+        assert_eq!(
+            ElfFile::parse(&header).err(),
+            Some(ElfError::Truncated {
+                offset: 52,
+                size: 40 * u32::MAX as u64
+            })
+        );
+        // 0xffff program headers, whose count is held by the first section header.
+        header[72..76].copy_from_slice(&1u32.to_le_bytes());
+        header[44..46].copy_from_slice(&0xffffu16.to_le_bytes());
+        header[80..84].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(
+            ElfFile::parse(&header).err(),
+            Some(ElfError::InvalidEntrySize {
+                table: "program",
+                size: 0
+            })
+        );
+        header[42] = 32;
+        assert_eq!(
+            ElfFile::parse(&header).err(),
+            Some(ElfError::Truncated {
+                offset: 0,
+                size: 32 * u32::MAX as u64
+            })
+        );
+    }
+}
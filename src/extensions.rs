@@ -0,0 +1,466 @@
+use crate::DisassemblerError;
+
+/// Optional ISA extensions recognised on top of the RV32I base.
+///
+/// Every extension is disabled by default, so `Extensions::default()` decodes plain RV32I.
+///
+/// Some extensions reuse encodings that are already valid in the base ISA, such as the Zicbop
+/// prefetch hints, which are `ori` instructions with rd = x0. When such an extension is selected
+/// its instruction takes precedence; otherwise the base-ISA instruction is returned.
+///
+/// ### Example
+///
+/// ```
+/// use risc_v_disassembler::{parse_with_extensions, Extensions};
+///
+/// let extensions = Extensions {
+///     zfinx: true,
+///     ..Default::default()
+/// };
+///
+/// // fadd.s a0, a1, a2
+/// let bytes = [0x53, 0xf5, 0xc5, 0x00];
+/// let parsed_instruction = parse_with_extensions(&bytes, false, true, &extensions).unwrap();
+/// assert_eq!(parsed_instruction.to_string(), "fadd.s a0, a1, a2");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Extensions {
+    /// E: the RV32E base instead of RV32I, whose integer registers are `x0` to `x15`.
+    /// Instructions naming `x16` to `x31` are rejected with
+    /// [`InvalidRegister`](DisassemblerError::InvalidRegister).
+    pub e: bool,
+    /// Zfinx: single precision floating point operating on the integer registers.
+    pub zfinx: bool,
+    /// Zdinx: double precision floating point operating on the integer registers. On RV32 every
+    /// double precision operand occupies an even/odd register pair. Implies Zfinx.
+    pub zdinx: bool,
+    /// Zhinx: half precision floating point operating on the integer registers. Implies Zfinx.
+    pub zhinx: bool,
+    /// Svinval: fine-grained address-translation cache invalidation (`sinval.vma`,
+    /// `sfence.w.inval` and `sfence.inval.ir`).
+    pub svinval: bool,
+    /// Smrnmi: resumable non-maskable interrupts (`mnret`).
+    pub smrnmi: bool,
+    /// H: hypervisor fences and virtual-machine loads and stores. The `hinval.*` instructions
+    /// additionally require Svinval. Only the RV32 instructions are decoded: `hlv.d`, `hlv.wu`
+    /// and `hsv.d`, which only exist on RV64, are rejected like the other RV64 instructions.
+    pub h: bool,
+    /// Zicbom: cache-block management (`cbo.clean`, `cbo.flush` and `cbo.inval`).
+    pub zicbom: bool,
+    /// Zicboz: cache-block zeroing (`cbo.zero`).
+    pub zicboz: bool,
+    /// Zicbop: cache-block prefetch hints (`prefetch.i`, `prefetch.r` and `prefetch.w`). These
+    /// take precedence over the `ori` instructions they are encoded as.
+    pub zicbop: bool,
+    /// Zicond: integer conditional operations (`czero.eqz` and `czero.nez`).
+    pub zicond: bool,
+    /// Zawrs: wait-on-reservation-set (`wrs.nto` and `wrs.sto`).
+    pub zawrs: bool,
+    /// Zalrsc: load-reserved and store-conditional (`lr.w` and `sc.w`). Selecting both Zalrsc
+    /// and Zaamo gives the A extension.
+    pub zalrsc: bool,
+    /// Zaamo: word atomic memory operations (`amoswap.w`, `amoadd.w`, ...).
+    pub zaamo: bool,
+    /// Zabha: byte and halfword atomic memory operations. Requires Zaamo, and additionally
+    /// provides `amocas.b` and `amocas.h` when Zacas is selected.
+    pub zabha: bool,
+    /// Zacas: atomic compare-and-swap. On RV32 `amocas.d` operates on even/odd register pairs,
+    /// and `amocas.q`, which only exists on RV64, is rejected.
+    pub zacas: bool,
+    /// Zcb: simple 16-bit code-size saving instructions (`c.lbu`, `c.sext.b`, `c.mul`, ...).
+    pub zcb: bool,
+    /// Zcmp: 16-bit push, pop and register move sequences (`cm.push`, `cm.popret`, ...).
+    pub zcmp: bool,
+    /// Zcmt: 16-bit table jumps (`cm.jt` and `cm.jalt`).
+    pub zcmt: bool,
+    /// Zimop: may-be-operations (`mop.r.N` and `mop.rr.N`).
+    pub zimop: bool,
+    /// Zcmop: 16-bit may-be-operations (`c.mop.N`).
+    pub zcmop: bool,
+    /// Not an extension: classifies the base-ISA HINT encodings, such as `addi x0, a0, 1` or
+    /// `c.nop 5`, as [`hint`](crate::parsed_instructions::hint) instead of the instruction they
+    /// are encoded as. `addi x0, x0, 0`, the canonical `nop`, is not a HINT.
+    pub hints: bool,
+    /// Zvbb: vector basic bit-manipulation (`vandn`, `vbrev8`, `vrol`, `vwsll`, ...), which
+    /// includes Zvkb.
+    pub zvbb: bool,
+    /// Zvbc: vector carry-less multiplication (`vclmul` and `vclmulh`).
+    pub zvbc: bool,
+    /// Zvkned: vector AES block cipher (`vaesef`, `vaesdm`, `vaeskf1`, ...).
+    pub zvkned: bool,
+    /// Zvknha: vector SHA-256 secure hash (`vsha2ms`, `vsha2ch` and `vsha2cl`), which only
+    /// supports an SEW of 32.
+    pub zvknha: bool,
+    /// Zvknhb: vector SHA-256 and SHA-512 secure hash, the instructions of Zvknha with an SEW of
+    /// 32 or 64.
+    pub zvknhb: bool,
+    /// Zvksed: vector SM4 block cipher (`vsm4k` and `vsm4r`).
+    pub zvksed: bool,
+    /// Zvksh: vector SM3 secure hash (`vsm3me` and `vsm3c`).
+    pub zvksh: bool,
+    /// Zvkg: vector GCM/GMAC (`vghsh` and `vgmul`).
+    pub zvkg: bool,
+    /// Zfbfmin: scalar conversions between bfloat16 and single precision (`fcvt.bf16.s` and
+    /// `fcvt.s.bf16`). Unlike the Zfinx family its operands are floating point registers, as
+    /// Zfbfmin builds on F.
+    pub zfbfmin: bool,
+    /// Zvfbfmin: vector conversions between bfloat16 and single precision
+    /// (`vfncvtbf16.f.f.w` and `vfwcvtbf16.f.f.v`).
+    pub zvfbfmin: bool,
+    /// Zvfbfwma: vector widening bfloat16 multiply-accumulate (`vfwmaccbf16`). Its scalar
+    /// operand is a floating point register.
+    pub zvfbfwma: bool,
+    /// Not an extension: the LMUL the vector instructions are assumed to execute with. Vector
+    /// register groups must be aligned to it, so operands that are not are rejected. Defaults to
+    /// [`Lmul::M1`], where every vector register is valid.
+    pub lmul: Lmul,
+    /// Not an extension: the SEW the vector instructions are assumed to execute with. The
+    /// element group instructions of the vector cryptography extensions are rejected with an
+    /// SEW they do not support, which is any but 32 bits except for the SHA-512 forms of
+    /// Zvknhb. Defaults to [`Sew::E32`].
+    pub sew: Sew,
+    /// Not an extension: the VLEN of the vector registers in bits, when known. Element group
+    /// instructions whose element group of EGS × SEW bits is wider than a register group of
+    /// VLEN × LMUL bits are rejected. Defaults to `None`, which leaves the width unchecked.
+    pub vlen: Option<u32>,
+}
+
+/// Vector register group multiplier, as set by `vsetvli` in `vtype.vlmul`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Lmul {
+    Mf8,
+    Mf4,
+    Mf2,
+    #[default]
+    M1,
+    M2,
+    M4,
+    M8,
+}
+
+impl Lmul {
+    /// Number of registers in a register group. Fractional multipliers still occupy one
+    /// register.
+    pub(crate) fn registers(&self) -> u8 {
+        match self {
+            Lmul::Mf8 | Lmul::Mf4 | Lmul::Mf2 | Lmul::M1 => 1,
+            Lmul::M2 => 2,
+            Lmul::M4 => 4,
+            Lmul::M8 => 8,
+        }
+    }
+
+    /// Number of bits in a register group of `vlen` bit registers, VLEN × LMUL.
+    pub(crate) fn group_bits(&self, vlen: u32) -> u32 {
+        match self {
+            Lmul::Mf8 => vlen / 8,
+            Lmul::Mf4 => vlen / 4,
+            Lmul::Mf2 => vlen / 2,
+            _ => vlen * self.registers() as u32,
+        }
+    }
+}
+
+/// Selected element width, as set by `vsetvli` in `vtype.vsew`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Sew {
+    E8,
+    E16,
+    #[default]
+    E32,
+    E64,
+}
+
+impl Sew {
+    /// Number of bits in an element.
+    pub(crate) fn bits(&self) -> u32 {
+        match self {
+            Sew::E8 => 8,
+            Sew::E16 => 16,
+            Sew::E32 => 32,
+            Sew::E64 => 64,
+        }
+    }
+}
+
+impl Extensions {
+    pub(crate) fn has_zfinx(&self) -> bool {
+        self.zfinx || self.zdinx || self.zhinx
+    }
+
+    pub(crate) fn has_atomics(&self) -> bool {
+        self.zalrsc || self.zaamo || self.zacas
+    }
+
+    pub(crate) fn has_vector(&self) -> bool {
+        self.zvfbfmin
+            || self.zvfbfwma
+            || self.zvbb
+            || self.zvbc
+            || self.zvkned
+            || self.zvknha
+            || self.zvknhb
+            || self.zvksed
+            || self.zvksh
+            || self.zvkg
+    }
+}
+
+impl Extensions {
+    /// Selects the extensions named in an ISA string, such as `rv32imac_zicsr_zcb` or the
+    /// versioned `rv32i2p1_a2p1_c2p0_zcb1p0` found in ELF attributes and mapping symbols.
+    /// Extensions that are not decoded by this crate, such as M or C, are accepted and ignored.
+    /// A selects Zalrsc and Zaamo, and the vector cryptography shorthands such as Zvkn select
+    /// the extensions they group.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use risc_v_disassembler::Extensions;
+    ///
+    /// let extensions = Extensions::from_isa_string("rv32imac_zicond_zcb").unwrap();
+    /// assert!(extensions.zaamo && extensions.zicond && extensions.zcb);
+    /// ```
+    pub fn from_isa_string(isa: &str) -> Result<Extensions, DisassemblerError> {
+        let mut extensions = Extensions::default();
+        for name in isa_string_extensions(isa)? {
+            extensions.select(&name);
+        }
+        Ok(extensions)
+    }
+
+    /// Returns whether an ISA string allows 16 bit instructions, through the C extension or
+    /// one of its Zc* subsets, as selected with [`Instructions::compressed`].
+    ///
+    /// [`Instructions::compressed`]: crate::Instructions::compressed
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use risc_v_disassembler::Extensions;
+    ///
+    /// assert!(Extensions::compressed_from_isa_string("rv32imac").unwrap());
+    /// assert!(!Extensions::compressed_from_isa_string("rv32im").unwrap());
+    /// ```
+    pub fn compressed_from_isa_string(isa: &str) -> Result<bool, DisassemblerError> {
+        isa_string_compressed(isa)
+    }
+
+    /// Returns the lowercase names of the vendor extensions of an ISA string, those starting
+    /// with `x`. They are not selected by [`from_isa_string`](Extensions::from_isa_string) but
+    /// decoded by registering [`CustomDecoders`](crate::CustomDecoders), such as those of the
+    /// `xthead` and `xcv` features.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use risc_v_disassembler::Extensions;
+    ///
+    /// let names = Extensions::vendor_extensions_from_isa_string("rv32imc_zcb_xtheadba1p0");
+    /// assert_eq!(names.unwrap(), ["xtheadba"]);
+    /// ```
+    pub fn vendor_extensions_from_isa_string(isa: &str) -> Result<Vec<String>, DisassemblerError> {
+        Ok(isa_string_extensions(isa)?
+            .into_iter()
+            .filter(|name| name.starts_with('x'))
+            .collect())
+    }
+
+    fn select(&mut self, name: &str) {
+        match name {
+            "a" => {
+                self.zalrsc = true;
+                self.zaamo = true;
+            }
+            "e" => self.e = true,
+            "h" => self.h = true,
+            "zfinx" => self.zfinx = true,
+            "zdinx" => self.zdinx = true,
+            "zhinx" => self.zhinx = true,
+            "svinval" => self.svinval = true,
+            "smrnmi" => self.smrnmi = true,
+            "zicbom" => self.zicbom = true,
+            "zicboz" => self.zicboz = true,
+            "zicbop" => self.zicbop = true,
+            "zicond" => self.zicond = true,
+            "zawrs" => self.zawrs = true,
+            "zalrsc" => self.zalrsc = true,
+            "zaamo" => self.zaamo = true,
+            "zabha" => self.zabha = true,
+            "zacas" => self.zacas = true,
+            "zcb" => self.zcb = true,
+            "zcmp" => self.zcmp = true,
+            "zcmt" => self.zcmt = true,
+            "zimop" => self.zimop = true,
+            "zcmop" => self.zcmop = true,
+            // Zvkb is the subset of Zvbb used by the vector cryptography extensions.
+            "zvbb" | "zvkb" => self.zvbb = true,
+            "zvbc" => self.zvbc = true,
+            "zvkned" => self.zvkned = true,
+            "zvknha" => self.zvknha = true,
+            "zvknhb" => self.zvknhb = true,
+            "zvksed" => self.zvksed = true,
+            "zvksh" => self.zvksh = true,
+            "zvkg" => self.zvkg = true,
+            "zvkn" | "zvknc" | "zvkng" => {
+                self.select("zvkned");
+                self.select("zvknhb");
+                self.select("zvkb");
+                match name {
+                    "zvknc" => self.select("zvbc"),
+                    "zvkng" => self.select("zvkg"),
+                    _ => {}
+                }
+            }
+            "zvks" | "zvksc" | "zvksg" => {
+                self.select("zvksed");
+                self.select("zvksh");
+                self.select("zvkb");
+                match name {
+                    "zvksc" => self.select("zvbc"),
+                    "zvksg" => self.select("zvkg"),
+                    _ => {}
+                }
+            }
+            "zfbfmin" => self.zfbfmin = true,
+            "zvfbfmin" => self.zvfbfmin = true,
+            "zvfbfwma" => self.zvfbfwma = true,
+            _ => {}
+        }
+    }
+}
+
+/// Splits an ISA string into the lowercase names of its extensions, without their versions.
+/// The base ISA is given as `i`, `e` or the extensions of `g`. RV64 ISA strings are rejected, as
+/// only the RV32 instructions are decoded.
+pub(crate) fn isa_string_extensions(isa: &str) -> Result<Vec<String>, DisassemblerError> {
+    let isa = isa.to_ascii_lowercase();
+    let invalid = || DisassemblerError::InvalidIsaString(isa.clone());
+    if isa.starts_with("rv64") {
+        return Err(DisassemblerError::UnsupportedXlen(64));
+    }
+    let rest = isa.strip_prefix("rv32").ok_or_else(invalid)?;
+
+    let mut tokens = rest.split('_');
+    let single_letter = tokens.next().ok_or_else(invalid)?;
+    if !single_letter.starts_with(['i', 'e', 'g']) {
+        return Err(invalid());
+    }
+
+    let mut names = Vec::new();
+    let mut chars = single_letter.chars().peekable();
+    while let Some(letter) = chars.next() {
+        if !letter.is_ascii_lowercase() {
+            return Err(invalid());
+        }
+        match letter {
+            'g' => names.extend(["i", "m", "a", "f", "d", "zicsr", "zifencei"].map(String::from)),
+            _ => names.push(letter.to_string()),
+        }
+        // Skip the version, such as 2p1, where a `p` not following a digit is the P extension.
+        let mut version = false;
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_digit() || (c == 'p' && version) {
+                version = c.is_ascii_digit();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+    }
+
+    for token in tokens.filter(|token| !token.is_empty()) {
+        names.push(strip_version(token).to_string());
+    }
+    Ok(names)
+}
+
+/// Returns whether an ISA string allows 16 bit instructions, through the C extension or one of
+/// its Zc* subsets.
+pub(crate) fn isa_string_compressed(isa: &str) -> Result<bool, DisassemblerError> {
+    Ok(isa_string_extensions(isa)?
+        .iter()
+        .any(|name| name == "c" || name.starts_with("zc")))
+}
+
+/// Strips a trailing version, such as the `1p0` of `zcb1p0`, from a multi-letter extension.
+fn strip_version(name: &str) -> &str {
+    let without_minor = match name.rfind('p') {
+        Some(p)
+            if p + 1 < name.len()
+                && name[p + 1..].bytes().all(|b| b.is_ascii_digit())
+                && name[..p].ends_with(|c: char| c.is_ascii_digit()) =>
+        {
+            &name[..p]
+        }
+        _ => name,
+    };
+    let stripped = without_minor.trim_end_matches(|c: char| c.is_ascii_digit());
+    // Names such as zve32x end in a letter and keep their digits.
+    if stripped.len() < without_minor.len() && !stripped.is_empty() {
+        stripped
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_isa_string() {
+        let extensions = Extensions::from_isa_string("rv32imac_zicsr_zicond_zcb").unwrap();
+        assert_eq!(
+            extensions,
+            Extensions {
+                zalrsc: true,
+                zaamo: true,
+                zicond: true,
+                zcb: true,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_isa_string_versions() {
+        let extensions =
+            Extensions::from_isa_string("rv32i2p1_a2p1_c2p0_zfinx1p0_zcb1p0_zcmp1p0").unwrap();
+        assert!(extensions.zaamo && extensions.zfinx && extensions.zcb && extensions.zcmp);
+        assert!(!extensions.zcmt);
+    }
+
+    #[test]
+    fn test_from_isa_string_shorthands() {
+        let extensions = Extensions::from_isa_string("RV32GCV_Zvkng").unwrap();
+        assert!(extensions.zalrsc && extensions.zvkned && extensions.zvknhb);
+        assert!(extensions.zvbb && extensions.zvkg && !extensions.zvbc);
+    }
+
+    #[test]
+    fn test_from_isa_string_invalid() {
+        assert_eq!(
+            Extensions::from_isa_string("x86_64"),
+            Err(DisassemblerError::InvalidIsaString("x86_64".to_string()))
+        );
+        assert_eq!(
+            Extensions::from_isa_string("rv32zicsr"),
+            Err(DisassemblerError::InvalidIsaString("rv32zicsr".to_string()))
+        );
+        assert!(Extensions::from_isa_string("rv32e2p0_c").unwrap().e);
+        assert_eq!(
+            Extensions::from_isa_string("rv64gc"),
+            Err(DisassemblerError::UnsupportedXlen(64))
+        );
+    }
+
+    #[test]
+    fn test_isa_string_extensions() {
+        assert_eq!(
+            isa_string_extensions("rv32i2p1_m2p0_zve32x1p0_zvl128b1p0_zicsr").unwrap(),
+            vec!["i", "m", "zve32x", "zvl128b", "zicsr"]
+        );
+        assert_eq!(isa_string_extensions("rv32ip").unwrap(), vec!["i", "p"]);
+    }
+}
@@ -0,0 +1,914 @@
+//! Formatters, which write decoded instructions in the syntax of another disassembler.
+//!
+//! A [`Formatter`] is given the instructions of a listing in order, each with its address, and
+//! returns the text of the instruction and the address it targets. The `Display` output of an
+//! instruction is the crate's own syntax, while [`GnuFormatter`](crate::gnu::GnuFormatter)
+//! follows GNU objdump and [`LlvmFormatter`](crate::llvm::LlvmFormatter) follows llvm-objdump.
+//! The latter takes [`FormatOptions`], which select the radix of immediates, uppercase
+//! mnemonics, the separator between operands and the names of registers.
+//!
+//! ### Example
+//!
+//! ```
+//! use risc_v_disassembler::format::{FormatOptions, Formatter, Radix, RegisterNames};
+//! use risc_v_disassembler::llvm::LlvmFormatter;
+//! use risc_v_disassembler::parse;
+//!
+//! // addi a0, a1, -16
+//! let addi = parse(&[0x13, 0x85, 0x05, 0xff], false, true).unwrap();
+//!
+//! assert_eq!(LlvmFormatter::new().format(&addi, 0).text, "addi\ta0, a1, -16");
+//! let mut formatter = LlvmFormatter::with_options(FormatOptions {
+//!     radix: Radix::Hex,
+//!     uppercase: true,
+//!     separator: ",".to_string(),
+//!     registers: RegisterNames::Numeric,
+//! });
+//! assert_eq!(formatter.format(&addi, 0).text, "ADDI\tx10,x11,-0x10");
+//! ```
+//!
+//! Besides its text, a formatted instruction holds its [`Token`]s, which tell its mnemonic,
+//! registers, immediates, memory operands, separators and addresses apart for syntax
+//! highlighting. [`tokenize`] splits the `Display` output of an instruction likewise.
+
+use crate::parsed_instructions::*;
+use crate::registers::{
+    is_register, ABIRegister, NumberedRegister, FLOAT_ABI_REGISTERS, FLOAT_REGISTERS,
+};
+use crate::{ParsedInstruction32, Register};
+use std::fmt;
+
+/// Writes instructions in the syntax of a disassembler.
+pub trait Formatter: fmt::Debug {
+    /// Formats `instruction`, found at `address`. Formatters that follow the addresses formed
+    /// by several instructions expect the instructions of a section in order.
+    fn format(&mut self, instruction: &ParsedInstruction32, address: u64) -> FormattedInstruction;
+}
+
+/// An instruction written by a [`Formatter`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FormattedInstruction {
+    /// The instruction, without the `<symbol>` of its target.
+    pub text: String,
+    /// The tokens of `text`, which concatenate to it.
+    pub tokens: Vec<Token>,
+    /// Address of a branch or jump, or annotated after the instruction, whose symbol the
+    /// disassemblers append as `<symbol>`.
+    pub target: Option<u64>,
+}
+
+/// Kind of a [`Token`], by which it is highlighted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Mnemonic,
+    /// An integer, floating point or vector register, or a named CSR.
+    Register,
+    Immediate,
+    /// The `(` before the base register of a memory operand.
+    MemoryOpen,
+    /// The `)` after the base register of a memory operand.
+    MemoryClose,
+    /// The space or tab after the mnemonic, or the separator between operands.
+    Separator,
+    /// A branch or jump target, an annotated address, or the address of a listed line.
+    Address,
+    /// The name of a symbol, with its offset, as in `main+0x1c`.
+    Symbol,
+    /// Anything else, such as a fence set, a rounding mode, a register list or punctuation.
+    Text,
+}
+
+/// A piece of the text of an instruction or listed line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+}
+
+impl Token {
+    pub fn new(kind: TokenKind, text: impl Into<String>) -> Self {
+        Token {
+            kind,
+            text: text.into(),
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Splits the `Display` output of an instruction, or a pseudo-instruction of a listing, into
+/// tokens.
+///
+/// ### Example
+///
+/// ```
+/// use risc_v_disassembler::format::{tokenize, TokenKind};
+///
+/// let kinds: Vec<TokenKind> = tokenize("lw a0, -4(a1)").iter().map(|t| t.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     [
+///         TokenKind::Mnemonic,
+///         TokenKind::Separator,
+///         TokenKind::Register,
+///         TokenKind::Separator,
+///         TokenKind::Immediate,
+///         TokenKind::MemoryOpen,
+///         TokenKind::Register,
+///         TokenKind::MemoryClose,
+///     ]
+/// );
+/// ```
+pub fn tokenize(text: &str) -> Vec<Token> {
+    let Some((mnemonic, operands)) = text.split_once(' ') else {
+        return vec![Token::new(TokenKind::Mnemonic, text)];
+    };
+    let mut tokens = vec![
+        Token::new(TokenKind::Mnemonic, mnemonic),
+        Token::new(TokenKind::Separator, " "),
+    ];
+    for (i, operand) in split_operands(operands).into_iter().enumerate() {
+        if i > 0 {
+            tokens.push(Token::new(TokenKind::Separator, ", "));
+        }
+        let memory = operand
+            .strip_suffix(')')
+            .and_then(|operand| operand.rsplit_once('('))
+            .filter(|(_, base)| is_register_name(base));
+        match memory {
+            _ if parse_immediate(operand).is_some() => {
+                tokens.push(Token::new(TokenKind::Immediate, operand))
+            }
+            _ if is_register_name(operand) => tokens.push(Token::new(TokenKind::Register, operand)),
+            Some((offset, base)) => {
+                // The offset may be relocated, as in `%lo(message)(a0)`.
+                match parse_immediate(offset) {
+                    Some(_) => tokens.push(Token::new(TokenKind::Immediate, offset)),
+                    None if offset.is_empty() => {}
+                    None => tokens.push(Token::new(TokenKind::Text, offset)),
+                }
+                tokens.extend(memory_tokens(base.to_string()));
+            }
+            None => tokens.push(Token::new(TokenKind::Text, operand)),
+        }
+    }
+    tokens
+}
+
+/// Returns the tokens of `(base)`.
+fn memory_tokens(base: String) -> [Token; 3] {
+    [
+        Token::new(TokenKind::MemoryOpen, "("),
+        Token::new(TokenKind::Register, base),
+        Token::new(TokenKind::MemoryClose, ")"),
+    ]
+}
+
+/// Splits operands separated by `", "`, leaving whole the register lists, as in `{ra, s0-s1}`,
+/// that hold the separator themselves.
+fn split_operands(operands: &str) -> Vec<&str> {
+    let mut split = Vec::new();
+    let mut start = 0;
+    let mut depth = 0;
+    for (i, c) in operands.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                split.push(operands[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    split.push(operands[start..].trim());
+    split
+}
+
+/// Options of a [`Formatter`] that are not fixed by the syntax it follows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Radix of the immediates. Addresses are always written in hex.
+    pub radix: Radix,
+    /// Whether mnemonics are written in uppercase.
+    pub uppercase: bool,
+    /// Separator between operands, `", "` by default.
+    pub separator: String,
+    /// Names of integer and floating point registers.
+    pub registers: RegisterNames,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            radix: Radix::Decimal,
+            uppercase: false,
+            separator: ", ".to_string(),
+            registers: RegisterNames::Decoded,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Radix {
+    #[default]
+    Decimal,
+    /// Hex with a `0x` prefix, after the sign of negative immediates, as in `-0x10`.
+    Hex,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RegisterNames {
+    /// The names the instruction was decoded with.
+    #[default]
+    Decoded,
+    /// ABI names, such as `a0` and `fa0`.
+    Abi,
+    /// Numbered names, such as `x10` and `f10`.
+    Numeric,
+}
+
+/// Returns the name of a CSR, if it has one.
+pub fn csr_name(csr: u16) -> Option<String> {
+    let name = match csr {
+        0x001 => "fflags",
+        0x002 => "frm",
+        0x003 => "fcsr",
+        0x008 => "vstart",
+        0x009 => "vxsat",
+        0x00a => "vxrm",
+        0x00f => "vcsr",
+        0x015 => "seed",
+        0xc00 => "cycle",
+        0xc01 => "time",
+        0xc02 => "instret",
+        0xc03..=0xc1f => return Some(format!("hpmcounter{}", csr - 0xc00)),
+        0xc20 => "vl",
+        0xc21 => "vtype",
+        0xc22 => "vlenb",
+        0xc80 => "cycleh",
+        0xc81 => "timeh",
+        0xc82 => "instreth",
+        0xc83..=0xc9f => return Some(format!("hpmcounter{}h", csr - 0xc80)),
+        0x100 => "sstatus",
+        0x104 => "sie",
+        0x105 => "stvec",
+        0x106 => "scounteren",
+        0x10a => "senvcfg",
+        0x140 => "sscratch",
+        0x141 => "sepc",
+        0x142 => "scause",
+        0x143 => "stval",
+        0x144 => "sip",
+        0x180 => "satp",
+        0x5a8 => "scontext",
+        0xda0 => "scountovf",
+        0x200 => "vsstatus",
+        0x204 => "vsie",
+        0x205 => "vstvec",
+        0x240 => "vsscratch",
+        0x241 => "vsepc",
+        0x242 => "vscause",
+        0x243 => "vstval",
+        0x244 => "vsip",
+        0x280 => "vsatp",
+        0x600 => "hstatus",
+        0x602 => "hedeleg",
+        0x603 => "hideleg",
+        0x604 => "hie",
+        0x606 => "hcounteren",
+        0x607 => "hgeie",
+        0x60a => "henvcfg",
+        0x61a => "henvcfgh",
+        0x643 => "htval",
+        0x644 => "hip",
+        0x645 => "hvip",
+        0x64a => "htinst",
+        0x680 => "hgatp",
+        0xe12 => "hgeip",
+        0xf11 => "mvendorid",
+        0xf12 => "marchid",
+        0xf13 => "mimpid",
+        0xf14 => "mhartid",
+        0xf15 => "mconfigptr",
+        0x300 => "mstatus",
+        0x301 => "misa",
+        0x302 => "medeleg",
+        0x303 => "mideleg",
+        0x304 => "mie",
+        0x305 => "mtvec",
+        0x306 => "mcounteren",
+        0x30a => "menvcfg",
+        0x310 => "mstatush",
+        0x31a => "menvcfgh",
+        0x320 => "mcountinhibit",
+        0x323..=0x33f => return Some(format!("mhpmevent{}", csr - 0x320)),
+        0x340 => "mscratch",
+        0x341 => "mepc",
+        0x342 => "mcause",
+        0x343 => "mtval",
+        0x344 => "mip",
+        0x34a => "mtinst",
+        0x34b => "mtval2",
+        0x3a0..=0x3af => return Some(format!("pmpcfg{}", csr - 0x3a0)),
+        0x3b0..=0x3ef => return Some(format!("pmpaddr{}", csr - 0x3b0)),
+        0x747 => "mseccfg",
+        0x757 => "mseccfgh",
+        0x7a0 => "tselect",
+        0x7a1 => "tdata1",
+        0x7a2 => "tdata2",
+        0x7a3 => "tdata3",
+        0x7a4 => "tinfo",
+        0x7b0 => "dcsr",
+        0x7b1 => "dpc",
+        0x7b2 => "dscratch0",
+        0x7b3 => "dscratch1",
+        0xb00 => "mcycle",
+        0xb02 => "minstret",
+        0xb03..=0xb1f => return Some(format!("mhpmcounter{}", csr - 0xb00)),
+        0xb80 => "mcycleh",
+        0xb82 => "minstreth",
+        0xb83..=0xb9f => return Some(format!("mhpmcounter{}h", csr - 0xb80)),
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+/// The syntax conventions that [`FormatOptions`] leave to each formatter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Style {
+    /// GNU objdump: addresses and unnamed CSRs in hex, with addresses missing the `0x` prefix.
+    Gnu,
+    /// llvm-objdump: addresses with a `0x` prefix, and unnamed CSRs as immediates.
+    Llvm,
+}
+
+/// An operand of a formatted instruction.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) enum Operand {
+    Register(String),
+    /// An immediate, written in the radix of the options.
+    Immediate(i64),
+    /// An immediate that the syntax writes in hex whatever the radix, as GNU objdump does the
+    /// immediate of `lui`.
+    HexImmediate(u64),
+    /// `offset(base)`, or `(base)` without an offset.
+    Memory {
+        offset: Option<i64>,
+        base: String,
+    },
+    Csr(u16),
+    /// A branch or jump target.
+    Address(u64),
+    /// Any other operand, such as a fence set or a rounding mode, written as it is.
+    Text(String),
+}
+
+/// An instruction split into its mnemonic and operands.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) struct Parts {
+    pub mnemonic: String,
+    pub operands: Vec<Operand>,
+    /// Whether the tab after the mnemonic is written without operands.
+    pub tab_without_operands: bool,
+    /// Address written as a comment after the operands.
+    pub annotation: Option<u64>,
+}
+
+impl Parts {
+    pub fn new(mnemonic: &str, operands: Vec<Operand>) -> Self {
+        Parts {
+            mnemonic: mnemonic.to_string(),
+            operands,
+            tab_without_operands: false,
+            annotation: None,
+        }
+    }
+
+    /// Splits the `Display` output of an instruction, as in `lw a0, -4(a1)`, into its mnemonic
+    /// and operands.
+    pub fn from_display(text: &str) -> Self {
+        let Some((mnemonic, operands)) = text.split_once(' ') else {
+            return Parts::new(text, Vec::new());
+        };
+        let operands = split_operands(operands).into_iter().map(Operand::parse);
+        Parts::new(mnemonic, operands.collect())
+    }
+
+    /// Returns the formatted instruction, with the operands written in `style` and `options`.
+    pub fn render(&self, options: &FormatOptions, style: Style) -> FormattedInstruction {
+        let mnemonic = match options.uppercase {
+            true => self.mnemonic.to_uppercase(),
+            false => self.mnemonic.clone(),
+        };
+        let mut tokens = vec![Token::new(TokenKind::Mnemonic, mnemonic)];
+        if !self.operands.is_empty() || self.tab_without_operands {
+            tokens.push(Token::new(TokenKind::Separator, "\t"));
+        }
+        for (i, operand) in self.operands.iter().enumerate() {
+            if i > 0 {
+                tokens.push(Token::new(TokenKind::Separator, options.separator.as_str()));
+            }
+            tokens.extend(operand.render(options, style));
+        }
+        if let Some(address) = self.annotation {
+            tokens.push(Token::new(TokenKind::Text, " # "));
+            tokens.push(Token::new(TokenKind::Address, format!("{:x}", address)));
+        }
+        let text = tokens.iter().map(|token| token.text.as_str()).collect();
+        let target = self.annotation.or_else(|| {
+            self.operands.iter().find_map(|operand| match operand {
+                Operand::Address(address) => Some(*address),
+                _ => None,
+            })
+        });
+        FormattedInstruction {
+            text,
+            tokens,
+            target,
+        }
+    }
+}
+
+impl Operand {
+    fn parse(text: &str) -> Operand {
+        if let Some(value) = parse_immediate(text) {
+            return Operand::Immediate(value);
+        }
+        if let Some((offset, base)) = text.strip_suffix(')').and_then(|t| t.split_once('(')) {
+            let offset = match offset {
+                "" => Some(None),
+                offset => parse_immediate(offset).map(Some),
+            };
+            if let Some(offset) = offset {
+                return Operand::Memory {
+                    offset,
+                    base: base.to_string(),
+                };
+            }
+        }
+        match is_register_name(text) {
+            true => Operand::Register(text.to_string()),
+            false => Operand::Text(text.to_string()),
+        }
+    }
+
+    fn render(&self, options: &FormatOptions, style: Style) -> Vec<Token> {
+        let token = match self {
+            Operand::Register(name) => Token::new(
+                TokenKind::Register,
+                rename_register(name, options.registers),
+            ),
+            Operand::Immediate(value) => {
+                Token::new(TokenKind::Immediate, immediate(*value, options.radix))
+            }
+            Operand::HexImmediate(value) => {
+                Token::new(TokenKind::Immediate, format!("{:#x}", value))
+            }
+            Operand::Memory { offset, base } => {
+                let mut tokens = Vec::new();
+                if let Some(offset) = offset {
+                    tokens.push(Token::new(
+                        TokenKind::Immediate,
+                        immediate(*offset, options.radix),
+                    ));
+                }
+                tokens.extend(memory_tokens(rename_register(base, options.registers)));
+                return tokens;
+            }
+            Operand::Csr(csr) => match (csr_name(*csr), style) {
+                // llvm-objdump 14 predates `tinfo`.
+                (Some(_), Style::Llvm) if *csr == 0x7a4 => {
+                    Token::new(TokenKind::Immediate, immediate(*csr as i64, options.radix))
+                }
+                (Some(name), _) => Token::new(TokenKind::Register, name),
+                (None, Style::Gnu) => Token::new(TokenKind::Immediate, format!("{:#x}", csr)),
+                (None, Style::Llvm) => {
+                    Token::new(TokenKind::Immediate, immediate(*csr as i64, options.radix))
+                }
+            },
+            Operand::Address(address) => match style {
+                Style::Gnu => Token::new(TokenKind::Address, format!("{:x}", address)),
+                Style::Llvm => Token::new(TokenKind::Address, format!("{:#x}", address)),
+            },
+            // Register lists, as in `{ra, s0-s1}`, take the separator of the operands.
+            Operand::Text(text) => {
+                Token::new(TokenKind::Text, text.replace(", ", &options.separator))
+            }
+        };
+        vec![token]
+    }
+}
+
+fn parse_immediate(text: &str) -> Option<i64> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text),
+    };
+    let value = match digits.strip_prefix("0x") {
+        Some(hex) => i64::from_str_radix(hex, 16).ok()?,
+        None if digits.starts_with(|c: char| c.is_ascii_digit()) => digits.parse().ok()?,
+        None => return None,
+    };
+    Some(if negative { -value } else { value })
+}
+
+fn immediate(value: i64, radix: Radix) -> String {
+    match radix {
+        Radix::Decimal => value.to_string(),
+        Radix::Hex if value < 0 => format!("-{:#x}", value.unsigned_abs()),
+        Radix::Hex => format!("{:#x}", value),
+    }
+}
+
+/// Returns the index of an integer register named `name`, and whether it is a floating point
+/// register.
+fn register_index(name: &str) -> Option<(u8, bool)> {
+    if let Some(index) = (0..32).find(|index| is_register(name, *index)) {
+        return Some((index, false));
+    }
+    FLOAT_REGISTERS
+        .iter()
+        .chain(FLOAT_ABI_REGISTERS.iter())
+        .position(|float| *float == name)
+        .map(|index| ((index % 32) as u8, true))
+}
+
+fn is_register_name(name: &str) -> bool {
+    let vector = name
+        .strip_prefix('v')
+        .and_then(|index| index.parse::<u8>().ok())
+        .is_some_and(|index| index < 32);
+    vector || register_index(name).is_some()
+}
+
+/// Returns the name of register `name` in the naming style `names`.
+fn rename_register(name: &str, names: RegisterNames) -> String {
+    let renamed = match (register_index(name), names) {
+        (None, _) | (_, RegisterNames::Decoded) => None,
+        (Some((index, false)), RegisterNames::Abi) => {
+            ABIRegister::try_from(index).ok().map(|r| r.as_str())
+        }
+        (Some((index, false)), RegisterNames::Numeric) => {
+            NumberedRegister::try_from(index).ok().map(|r| r.as_str())
+        }
+        (Some((index, true)), RegisterNames::Abi) => Some(FLOAT_ABI_REGISTERS[index as usize]),
+        (Some((index, true)), RegisterNames::Numeric) => Some(FLOAT_REGISTERS[index as usize]),
+    };
+    renamed.unwrap_or(name).to_string()
+}
+
+/// Returns the fence set given by its IORW bits, or `empty` when the set is empty.
+fn fence_set(set: u8, empty: &str) -> String {
+    let names: String = [(0b1000, 'i'), (0b0100, 'o'), (0b0010, 'r'), (0b0001, 'w')]
+        .into_iter()
+        .filter(|(bit, _)| set & bit != 0)
+        .map(|(_, name)| name)
+        .collect();
+    match names.is_empty() {
+        true => empty.to_string(),
+        false => names,
+    }
+}
+
+/// Splits `instruction`, found at `address`, into the mnemonic and operands that GNU objdump
+/// and llvm-objdump agree on: the alias of the instruction where they have one, and its
+/// `Display` output otherwise, with branch and jump targets as addresses. `empty_fence_set`
+/// names an empty fence set.
+pub(crate) fn parts(
+    instruction: &ParsedInstruction32,
+    address: u64,
+    empty_fence_set: &str,
+) -> Parts {
+    let register = |name: &str| Operand::Register(name.to_string());
+    // The instructions decoded are those of RV32, whose addresses wrap at 32 bits.
+    let target =
+        |offset: i32| Operand::Address(address.wrapping_add_signed(offset as i64) as u32 as u64);
+    let zero = |name: &str| is_register(name, 0);
+
+    match instruction {
+        ParsedInstruction32::addi(addi { rd, rs1, imm }) => {
+            if zero(rd) && zero(rs1) && *imm == 0 {
+                Parts::new("nop", vec![])
+            } else if zero(rs1) {
+                Parts::new("li", vec![register(rd), Operand::Immediate(*imm as i64)])
+            } else if *imm == 0 {
+                Parts::new("mv", vec![register(rd), register(rs1)])
+            } else {
+                Parts::from_display(&instruction.to_string())
+            }
+        }
+        ParsedInstruction32::xori(xori { rd, rs1, imm: -1 }) => {
+            Parts::new("not", vec![register(rd), register(rs1)])
+        }
+        ParsedInstruction32::sltiu(sltiu { rd, rs1, imm: 1 }) => {
+            Parts::new("seqz", vec![register(rd), register(rs1)])
+        }
+        ParsedInstruction32::sub(sub { rd, rs1, rs2 }) if zero(rs1) => {
+            Parts::new("neg", vec![register(rd), register(rs2)])
+        }
+        ParsedInstruction32::sltu(sltu { rd, rs1, rs2 }) if zero(rs1) => {
+            Parts::new("snez", vec![register(rd), register(rs2)])
+        }
+        ParsedInstruction32::slt(slt { rd, rs1, rs2 }) if zero(rs2) => {
+            Parts::new("sltz", vec![register(rd), register(rs1)])
+        }
+        ParsedInstruction32::slt(slt { rd, rs1, rs2 }) if zero(rs1) => {
+            Parts::new("sgtz", vec![register(rd), register(rs2)])
+        }
+
+        ParsedInstruction32::jal(jal { rd, imm }) => match *rd {
+            rd if zero(rd) => Parts::new("j", vec![target(*imm)]),
+            rd if is_register(rd, 1) => Parts::new("jal", vec![target(*imm)]),
+            rd => Parts::new("jal", vec![register(rd), target(*imm)]),
+        },
+        ParsedInstruction32::jalr(jalr { rd, imm, rs1 }) => {
+            let base = match *imm {
+                0 => register(rs1),
+                imm => Operand::Memory {
+                    offset: Some(imm as i64),
+                    base: rs1.to_string(),
+                },
+            };
+            match *rd {
+                rd if zero(rd) && is_register(rs1, 1) && *imm == 0 => Parts::new("ret", vec![]),
+                rd if zero(rd) => Parts::new("jr", vec![base]),
+                rd if is_register(rd, 1) => Parts::new("jalr", vec![base]),
+                rd => Parts::new("jalr", vec![register(rd), base]),
+            }
+        }
+        ParsedInstruction32::beq(beq { rs1, rs2, imm }) => match zero(rs2) {
+            true => Parts::new("beqz", vec![register(rs1), target(*imm)]),
+            false => Parts::new("beq", vec![register(rs1), register(rs2), target(*imm)]),
+        },
+        ParsedInstruction32::bne(bne { rs1, rs2, imm }) => match zero(rs2) {
+            true => Parts::new("bnez", vec![register(rs1), target(*imm)]),
+            false => Parts::new("bne", vec![register(rs1), register(rs2), target(*imm)]),
+        },
+        ParsedInstruction32::bge(bge { rs1, rs2, imm }) => {
+            if zero(rs1) {
+                Parts::new("blez", vec![register(rs2), target(*imm)])
+            } else if zero(rs2) {
+                Parts::new("bgez", vec![register(rs1), target(*imm)])
+            } else {
+                Parts::new("bge", vec![register(rs1), register(rs2), target(*imm)])
+            }
+        }
+        ParsedInstruction32::blt(blt { rs1, rs2, imm }) => {
+            if zero(rs2) {
+                Parts::new("bltz", vec![register(rs1), target(*imm)])
+            } else if zero(rs1) {
+                Parts::new("bgtz", vec![register(rs2), target(*imm)])
+            } else {
+                Parts::new("blt", vec![register(rs1), register(rs2), target(*imm)])
+            }
+        }
+        ParsedInstruction32::bltu(bltu { rs1, rs2, imm }) => {
+            Parts::new("bltu", vec![register(rs1), register(rs2), target(*imm)])
+        }
+        ParsedInstruction32::bgeu(bgeu { rs1, rs2, imm }) => {
+            Parts::new("bgeu", vec![register(rs1), register(rs2), target(*imm)])
+        }
+
+        ParsedInstruction32::csrrs(csrrs { rd, csr, rs1 }) if zero(rs1) => match csr {
+            0xc00 => Parts::new("rdcycle", vec![register(rd)]),
+            0xc01 => Parts::new("rdtime", vec![register(rd)]),
+            0xc02 => Parts::new("rdinstret", vec![register(rd)]),
+            0xc80 => Parts::new("rdcycleh", vec![register(rd)]),
+            0xc81 => Parts::new("rdtimeh", vec![register(rd)]),
+            0xc82 => Parts::new("rdinstreth", vec![register(rd)]),
+            csr => Parts::new("csrr", vec![register(rd), Operand::Csr(*csr)]),
+        },
+        ParsedInstruction32::csrrs(csrrs { rd, csr, rs1 }) => match zero(rd) {
+            true => Parts::new("csrs", vec![Operand::Csr(*csr), register(rs1)]),
+            false => Parts::new(
+                "csrrs",
+                vec![register(rd), Operand::Csr(*csr), register(rs1)],
+            ),
+        },
+        ParsedInstruction32::csrrw(csrrw {
+            rd,
+            csr: 0xc00,
+            rs1,
+        }) if zero(rd) && zero(rs1) => Parts::new("unimp", vec![]),
+        ParsedInstruction32::csrrw(csrrw { rd, csr, rs1 }) => match zero(rd) {
+            true => Parts::new("csrw", vec![Operand::Csr(*csr), register(rs1)]),
+            false => Parts::new(
+                "csrrw",
+                vec![register(rd), Operand::Csr(*csr), register(rs1)],
+            ),
+        },
+        ParsedInstruction32::csrrc(csrrc { rd, csr, rs1 }) => match zero(rd) {
+            true => Parts::new("csrc", vec![Operand::Csr(*csr), register(rs1)]),
+            false => Parts::new(
+                "csrrc",
+                vec![register(rd), Operand::Csr(*csr), register(rs1)],
+            ),
+        },
+        ParsedInstruction32::csrrwi(csrrwi { rd, csr, uimm })
+        | ParsedInstruction32::csrrsi(csrrsi { rd, csr, uimm })
+        | ParsedInstruction32::csrrci(csrrci { rd, csr, uimm }) => {
+            let mnemonic = instruction.to_string();
+            let mnemonic = mnemonic.split(' ').next().unwrap_or_default();
+            let uimm = Operand::Immediate(*uimm as i64);
+            match zero(rd) {
+                // `csrrwi` becomes `csrwi`, and likewise for the others.
+                true => Parts::new(
+                    &mnemonic.replacen("csrr", "csr", 1),
+                    vec![Operand::Csr(*csr), uimm],
+                ),
+                false => Parts::new(mnemonic, vec![register(rd), Operand::Csr(*csr), uimm]),
+            }
+        }
+
+        ParsedInstruction32::fence(fence {
+            pred: 0b1111,
+            succ: 0b1111,
+        }) => Parts::new("fence", vec![]),
+        ParsedInstruction32::fence(fence { pred, succ }) => Parts::new(
+            "fence",
+            vec![
+                Operand::Text(fence_set(*pred, empty_fence_set)),
+                Operand::Text(fence_set(*succ, empty_fence_set)),
+            ],
+        ),
+        ParsedInstruction32::sfence_vma(sfence_vma { rs1, rs2 }) => match (zero(rs1), zero(rs2)) {
+            (true, true) => Parts::new("sfence.vma", vec![]),
+            (false, true) => Parts::new("sfence.vma", vec![register(rs1)]),
+            _ => Parts::new("sfence.vma", vec![register(rs1), register(rs2)]),
+        },
+
+        ParsedInstruction32::c_nop(c_nop { imm: 0 }) => Parts::new("nop", vec![]),
+
+        // Neither disassembler has a notion of HINTs, and both list them as the instruction
+        // they encode.
+        ParsedInstruction32::hint(hint { instruction }) => {
+            parts(instruction, address, empty_fence_set)
+        }
+        _ => Parts::from_display(&instruction.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_display() {
+        assert_eq!(
+            Parts::from_display("lw a0, -4(a1)").operands,
+            [
+                Operand::Register("a0".to_string()),
+                Operand::Memory {
+                    offset: Some(-4),
+                    base: "a1".to_string()
+                }
+            ]
+        );
+        assert_eq!(
+            Parts::from_display("cm.push {ra, s0-s1}, -16").operands,
+            [
+                Operand::Text("{ra, s0-s1}".to_string()),
+                Operand::Immediate(-16)
+            ]
+        );
+        assert_eq!(
+            Parts::from_display("lr.w.aq a0, (a1)").operands[1],
+            Operand::Memory {
+                offset: None,
+                base: "a1".to_string()
+            }
+        );
+        assert_eq!(
+            Parts::from_display("vadd.vv v8, v16, v24, v0.t").operands[3],
+            Operand::Text("v0.t".to_string())
+        );
+        assert_eq!(Parts::from_display("ecall"), Parts::new("ecall", vec![]));
+    }
+
+    #[test]
+    fn test_render_options() {
+        let parts = Parts::from_display("sw fa0, -8(sp)");
+        let options = FormatOptions {
+            radix: Radix::Hex,
+            uppercase: true,
+            separator: ",".to_string(),
+            registers: RegisterNames::Numeric,
+        };
+        assert_eq!(parts.render(&options, Style::Llvm).text, "SW\tf10,-0x8(x2)");
+        let options = FormatOptions {
+            registers: RegisterNames::Abi,
+            ..Default::default()
+        };
+        assert_eq!(
+            Parts::from_display("add x10, x11, x0")
+                .render(&options, Style::Llvm)
+                .text,
+            "add\ta0, a1, zero"
+        );
+    }
+
+    fn kinds(tokens: &[Token]) -> Vec<(TokenKind, &str)> {
+        tokens
+            .iter()
+            .map(|token| (token.kind, token.text.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        use TokenKind::*;
+        assert_eq!(kinds(&tokenize("ecall")), [(Mnemonic, "ecall")]);
+        assert_eq!(
+            kinds(&tokenize("lw a0, %lo(message)(a0)")),
+            [
+                (Mnemonic, "lw"),
+                (Separator, " "),
+                (Register, "a0"),
+                (Separator, ", "),
+                (Text, "%lo(message)"),
+                (MemoryOpen, "("),
+                (Register, "a0"),
+                (MemoryClose, ")"),
+            ]
+        );
+        assert_eq!(
+            kinds(&tokenize("cm.push {ra, s0-s1}, -16")),
+            [
+                (Mnemonic, "cm.push"),
+                (Separator, " "),
+                (Text, "{ra, s0-s1}"),
+                (Separator, ", "),
+                (Immediate, "-16"),
+            ]
+        );
+        assert_eq!(
+            kinds(&tokenize("vle32.v v8, (a0), v0.t"))[4..],
+            [
+                (MemoryOpen, "("),
+                (Register, "a0"),
+                (MemoryClose, ")"),
+                (Separator, ", "),
+                (Text, "v0.t"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_tokens() {
+        use TokenKind::*;
+        let mut parts = Parts::new(
+            "jalr",
+            vec![Operand::Memory {
+                offset: Some(8),
+                base: "ra".to_string(),
+            }],
+        );
+        parts.annotation = Some(0x1014c);
+        let formatted = parts.render(&FormatOptions::default(), Style::Gnu);
+        assert_eq!(
+            kinds(&formatted.tokens),
+            [
+                (Mnemonic, "jalr"),
+                (Separator, "\t"),
+                (Immediate, "8"),
+                (MemoryOpen, "("),
+                (Register, "ra"),
+                (MemoryClose, ")"),
+                (Text, " # "),
+                (Address, "1014c"),
+            ]
+        );
+        assert_eq!(formatted.text, "jalr\t8(ra) # 1014c");
+        let csr = Parts::new("csrr", vec![Operand::Csr(0x300), Operand::Csr(0x7c0)]);
+        assert_eq!(
+            kinds(&csr.render(&FormatOptions::default(), Style::Gnu).tokens)[2..],
+            [
+                (Register, "mstatus"),
+                (Separator, ", "),
+                (Immediate, "0x7c0")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_csr_names() {
+        assert_eq!(csr_name(0x300).as_deref(), Some("mstatus"));
+        assert_eq!(csr_name(0xc83).as_deref(), Some("hpmcounter3h"));
+        assert_eq!(csr_name(0x3ef).as_deref(), Some("pmpaddr63"));
+        assert_eq!(csr_name(0x7c0), None);
+        let csr = Parts::new("csrr", vec![Operand::Csr(0x7c0)]);
+        assert_eq!(
+            csr.render(&FormatOptions::default(), Style::Gnu).text,
+            "csrr\t0x7c0"
+        );
+        assert_eq!(
+            csr.render(&FormatOptions::default(), Style::Llvm).text,
+            "csrr\t1984"
+        );
+    }
+}
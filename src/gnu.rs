@@ -0,0 +1,362 @@
+//! Formatting of instructions in the syntax of GNU `objdump -d`, for listings that are compared
+//! byte for byte with those of the GNU toolchain.
+//!
+//! objdump separates the mnemonic from its operands with a tab and the operands with bare
+//! commas, prints the immediates of `lui`, `auipc` and the shifts in hex, names CSRs, and gives
+//! branch and jump targets as addresses. Where the binutils opcode table has an alias for an
+//! instruction, such as `li`, `mv`, `ret`, `beqz` or `csrr`, the alias is printed instead. An
+//! `addi`, `jalr`, load or store based on a register set by `lui` or `auipc` is annotated with
+//! the address they form, as in `addi a0,a0,-316 # 12000`, as are those based on `zero` or `tp`.
+//! The conventions followed are those of binutils 2.40 and later, without `-M` options.
+//!
+//! ### Example
+//!
+//! ```
+//! use risc_v_disassembler::format::Formatter;
+//! use risc_v_disassembler::gnu::GnuFormatter;
+//! use risc_v_disassembler::parse;
+//!
+//! let mut formatter = GnuFormatter::new();
+//! // auipc a0, 8192; addi a0, a0, -316
+//! let auipc = parse(&[0x17, 0x25, 0x00, 0x00], false, true).unwrap();
+//! let addi = parse(&[0x13, 0x05, 0x45, 0xec], false, true).unwrap();
+//!
+//! assert_eq!(formatter.format(&auipc, 0x1013c).text, "auipc\ta0,0x2");
+//! let formatted = formatter.format(&addi, 0x10140);
+//! assert_eq!(formatted.text, "addi\ta0,a0,-316 # 12000");
+//! assert_eq!(formatted.target, Some(0x12000));
+//! ```
+
+use crate::format::{parts, FormatOptions, FormattedInstruction, Formatter, Operand, Parts, Style};
+use crate::parsed_instructions::*;
+use crate::registers::is_register;
+use crate::ParsedInstruction32;
+
+/// Formats instructions as GNU objdump does.
+///
+/// The formatter remembers the addresses that `lui` and `auipc` write to each register, until
+/// an instruction based on the register annotates them. Instructions should therefore be
+/// formatted in order, with a formatter for each section.
+#[derive(Debug, Clone)]
+pub struct GnuFormatter {
+    options: FormatOptions,
+    /// Registers written by `lui` or `auipc`, with the address they hold.
+    upper: Vec<(&'static str, u64)>,
+}
+
+impl Default for GnuFormatter {
+    fn default() -> Self {
+        GnuFormatter {
+            options: FormatOptions {
+                separator: ",".to_string(),
+                ..Default::default()
+            },
+            upper: Vec::new(),
+        }
+    }
+}
+
+impl Formatter for GnuFormatter {
+    fn format(&mut self, instruction: &ParsedInstruction32, address: u64) -> FormattedInstruction {
+        self.parts(instruction, address)
+            .render(&self.options, Style::Gnu)
+    }
+}
+
+impl GnuFormatter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn parts(&mut self, instruction: &ParsedInstruction32, address: u64) -> Parts {
+        let register = |name: &str| Operand::Register(name.to_string());
+        let zero = |name: &str| is_register(name, 0);
+
+        let mut parts = match instruction {
+            ParsedInstruction32::lui(lui { rd, imm }) => {
+                self.set_upper(rd, *imm as i64 as u64);
+                let imm = Operand::HexImmediate((*imm as u32 >> 12) as u64);
+                Parts::new("lui", vec![register(rd), imm])
+            }
+            ParsedInstruction32::auipc(auipc { rd, imm }) => {
+                self.set_upper(rd, address.wrapping_add_signed(*imm as i64));
+                let imm = Operand::HexImmediate((*imm as u32 >> 12) as u64);
+                Parts::new("auipc", vec![register(rd), imm])
+            }
+            ParsedInstruction32::slli(slli { rd, rs1, shamt })
+            | ParsedInstruction32::srli(srli { rd, rs1, shamt })
+            | ParsedInstruction32::srai(srai { rd, rs1, shamt }) => {
+                let mut parts = Parts::from_display(&instruction.to_string());
+                parts.operands = vec![
+                    register(rd),
+                    register(rs1),
+                    Operand::HexImmediate(*shamt as u64),
+                ];
+                parts
+            }
+
+            ParsedInstruction32::csrrs(csrrs { rd, csr, rs1 }) if zero(rs1) => match csr {
+                0x003 => Parts::new("frcsr", vec![register(rd)]),
+                0x002 => Parts::new("frrm", vec![register(rd)]),
+                0x001 => Parts::new("frflags", vec![register(rd)]),
+                _ => parts(instruction, address, "0"),
+            },
+            ParsedInstruction32::csrrw(csrrw { rd, csr, rs1 }) if *csr <= 0x003 && *csr != 0 => {
+                let mnemonic = ["fsflags", "fsrm", "fscsr"][*csr as usize - 1];
+                match zero(rd) {
+                    true => Parts::new(mnemonic, vec![register(rs1)]),
+                    false => Parts::new(mnemonic, vec![register(rd), register(rs1)]),
+                }
+            }
+            ParsedInstruction32::csrrwi(csrrwi { rd, csr, uimm })
+                if matches!(csr, 0x001 | 0x002) =>
+            {
+                let mnemonic = ["fsflagsi", "fsrmi"][*csr as usize - 1];
+                let uimm = Operand::Immediate(*uimm as i64);
+                match zero(rd) {
+                    true => Parts::new(mnemonic, vec![uimm]),
+                    false => Parts::new(mnemonic, vec![register(rd), uimm]),
+                }
+            }
+
+            ParsedInstruction32::hfence_vvma(hfence_vvma { rs1, rs2 }) => {
+                address_fence("hfence.vvma", rs1, rs2)
+            }
+            ParsedInstruction32::hfence_gvma(hfence_gvma { rs1, rs2 }) => {
+                address_fence("hfence.gvma", rs1, rs2)
+            }
+            ParsedInstruction32::cbo_clean(cbo_clean { rs1 })
+            | ParsedInstruction32::cbo_flush(cbo_flush { rs1 })
+            | ParsedInstruction32::cbo_inval(cbo_inval { rs1 })
+            | ParsedInstruction32::cbo_zero(cbo_zero { rs1 }) => {
+                let mut parts = Parts::from_display(&instruction.to_string());
+                parts.operands = vec![Operand::Memory {
+                    offset: Some(0),
+                    base: rs1.to_string(),
+                }];
+                parts
+            }
+
+            // Zcb instructions, which binutils lists as the instructions they expand to.
+            ParsedInstruction32::c_lbu(_)
+            | ParsedInstruction32::c_lhu(_)
+            | ParsedInstruction32::c_lh(_)
+            | ParsedInstruction32::c_sb(_)
+            | ParsedInstruction32::c_sh(_) => {
+                let text = instruction.to_string();
+                Parts::from_display(text.strip_prefix("c.").unwrap_or(&text))
+            }
+            ParsedInstruction32::c_zext_b(c_zext_b { rd }) => {
+                Parts::new("zext.b", vec![register(rd), register(rd)])
+            }
+            ParsedInstruction32::c_sext_b(c_sext_b { rd }) => {
+                Parts::new("sext.b", vec![register(rd), register(rd)])
+            }
+            ParsedInstruction32::c_zext_h(c_zext_h { rd }) => {
+                Parts::new("zext.h", vec![register(rd), register(rd)])
+            }
+            ParsedInstruction32::c_sext_h(c_sext_h { rd }) => {
+                Parts::new("sext.h", vec![register(rd), register(rd)])
+            }
+            ParsedInstruction32::c_not(c_not { rd }) => {
+                Parts::new("not", vec![register(rd), register(rd)])
+            }
+            ParsedInstruction32::c_mul(c_mul { rd, rs2 }) => {
+                Parts::new("mul", vec![register(rd), register(rd), register(rs2)])
+            }
+
+            // objdump has no notion of HINTs, and lists them as the instruction they encode.
+            ParsedInstruction32::hint(hint { instruction }) => {
+                return self.parts(instruction, address)
+            }
+            _ => parts(instruction, address, "0"),
+        };
+
+        parts.annotation = match instruction {
+            ParsedInstruction32::addi(addi { rs1, imm, .. }) if parts.mnemonic == "addi" => {
+                self.address(rs1, *imm)
+            }
+            ParsedInstruction32::jalr(jalr { rs1, imm, .. }) if parts.mnemonic != "ret" => {
+                self.address(rs1, *imm)
+            }
+            ParsedInstruction32::lb(lb { imm, rs1, .. })
+            | ParsedInstruction32::lh(lh { imm, rs1, .. })
+            | ParsedInstruction32::lw(lw { imm, rs1, .. })
+            | ParsedInstruction32::lbu(lbu { imm, rs1, .. })
+            | ParsedInstruction32::lhu(lhu { imm, rs1, .. })
+            | ParsedInstruction32::sb(sb { imm, rs1, .. })
+            | ParsedInstruction32::sh(sh { imm, rs1, .. })
+            | ParsedInstruction32::sw(sw { imm, rs1, .. }) => self.address(rs1, *imm),
+            _ => None,
+        };
+        parts
+    }
+
+    fn set_upper(&mut self, register: &'static str, address: u64) {
+        self.upper.retain(|(name, _)| *name != register);
+        self.upper.push((register, address));
+    }
+
+    /// Returns the address formed by `offset` from `base`, when objdump annotates it. Registers
+    /// set by `lui` or `auipc` are forgotten once used.
+    fn address(&mut self, base: &str, offset: i32) -> Option<u64> {
+        let upper = match self.upper.iter().position(|(name, _)| *name == base) {
+            Some(index) => self.upper.remove(index).1,
+            None if is_register(base, 0) || is_register(base, 4) => 0,
+            None => return None,
+        };
+        // The instructions decoded are those of RV32, whose addresses wrap at 32 bits.
+        Some(upper.wrapping_add_signed(offset as i64) as u32 as u64)
+    }
+}
+
+/// Splits an address translation fence, whose operands objdump leaves out when they are
+/// `zero`.
+fn address_fence(mnemonic: &str, rs1: &str, rs2: &str) -> Parts {
+    let register = |name: &str| Operand::Register(name.to_string());
+    match (is_register(rs1, 0), is_register(rs2, 0)) {
+        (true, true) => Parts::new(mnemonic, vec![]),
+        (false, true) => Parts::new(mnemonic, vec![register(rs1)]),
+        _ => Parts::new(mnemonic, vec![register(rs1), register(rs2)]),
+    }
+}
+
+/// Returns the `.insn` directive objdump lists for bytes that do not decode, holding their
+/// value without padding, as in `.insn\t0x7f`.
+pub fn insn_directive(bytes: &[u8], is_big_endian: bool) -> String {
+    let value = bytes.iter().enumerate().fold(0u64, |value, (i, byte)| {
+        let shift = match is_big_endian {
+            true => 8 * (bytes.len() - 1 - i),
+            false => 8 * i,
+        };
+        value | (*byte as u64) << shift
+    });
+    format!(".insn\t{:#x}", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn gnu(words: &[u32], address: u64) -> Vec<FormattedInstruction> {
+        let mut formatter = GnuFormatter::new();
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                let instruction = parse(&word.to_le_bytes(), false, true).unwrap();
+                formatter.format(&instruction, address + 4 * i as u64)
+            })
+            .collect()
+    }
+
+    fn text(word: u32) -> String {
+        gnu(&[word], 0).remove(0).text
+    }
+
+    #[test]
+    fn test_operands() {
+        assert_eq!(text(0x12345537), "lui\ta0,0x12345");
+        assert_eq!(text(0x80000537), "lui\ta0,0x80000");
+        assert_eq!(text(0x00000517), "auipc\ta0,0x0");
+        assert_eq!(text(0x00c58533), "add\ta0,a1,a2");
+        assert_eq!(text(0x00359513), "slli\ta0,a1,0x3");
+        assert_eq!(text(0x41f5d513), "srai\ta0,a1,0x1f");
+        assert_eq!(text(0xffc5a503), "lw\ta0,-4(a1)");
+        assert_eq!(text(0x00a5a223), "sw\ta0,4(a1)");
+        assert_eq!(text(0x00c5c533), "xor\ta0,a1,a2");
+        assert_eq!(text(0x00000073), "ecall");
+        assert_eq!(text(0x0330000f), "fence\trw,rw");
+        let cbo = ParsedInstruction32::cbo_clean(cbo_clean { rs1: "a0" });
+        assert_eq!(GnuFormatter::new().format(&cbo, 0).text, "cbo.clean\t0(a0)");
+    }
+
+    #[test]
+    fn test_aliases() {
+        assert_eq!(text(0x00000013), "nop");
+        assert_eq!(text(0x00500513), "li\ta0,5");
+        assert_eq!(text(0x00058513), "mv\ta0,a1");
+        assert_eq!(text(0xfff5c513), "not\ta0,a1");
+        assert_eq!(text(0x40b00533), "neg\ta0,a1");
+        assert_eq!(text(0x0015b513), "seqz\ta0,a1");
+        assert_eq!(text(0x00b03533), "snez\ta0,a1");
+        assert_eq!(text(0x0005a533), "sltz\ta0,a1");
+        assert_eq!(text(0x00b02533), "sgtz\ta0,a1");
+        assert_eq!(text(0x00008067), "ret");
+        assert_eq!(text(0x00058067), "jr\ta1");
+        assert_eq!(text(0x000580e7), "jalr\ta1");
+        assert_eq!(text(0x008580e7), "jalr\t8(a1)");
+        assert_eq!(text(0x00058567), "jalr\ta0,a1");
+        assert_eq!(text(0x0ff0000f), "fence");
+        assert_eq!(text(0x12000073), "sfence.vma");
+        assert_eq!(text(0x12050073), "sfence.vma\ta0");
+        assert_eq!(text(0xc0001073), "unimp");
+    }
+
+    #[test]
+    fn test_csrs() {
+        assert_eq!(text(0x30002573), "csrr\ta0,mstatus");
+        assert_eq!(text(0x30059073), "csrw\tmstatus,a1");
+        assert_eq!(text(0x30059573), "csrrw\ta0,mstatus,a1");
+        assert_eq!(text(0x3005a073), "csrs\tmstatus,a1");
+        assert_eq!(text(0x3005b073), "csrc\tmstatus,a1");
+        assert_eq!(text(0x30046073), "csrsi\tmstatus,8");
+        assert_eq!(text(0xc0002573), "rdcycle\ta0");
+        assert_eq!(text(0x00302573), "frcsr\ta0");
+        assert_eq!(text(0x7c002573), "csrr\ta0,0x7c0");
+        assert_eq!(text(0x3a102573), "csrr\ta0,pmpcfg1");
+    }
+
+    #[test]
+    fn test_targets() {
+        let formatted = gnu(
+            &[0x00b50463, 0x00050463, 0x00a05463, 0xff9ff06f, 0x008000ef],
+            0x1000,
+        );
+        let texts: Vec<&str> = formatted.iter().map(|f| f.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "beq\ta0,a1,1008",
+                "beqz\ta0,100c",
+                "blez\ta0,1010",
+                "j\t1004",
+                "jal\t1018",
+            ]
+        );
+        assert_eq!(formatted[3].target, Some(0x1004));
+        // Backward from address zero, wrapping at 32 bits.
+        let formatted = gnu(&[0xfe000ee3], 0);
+        assert_eq!(formatted[0].text, "beqz\tzero,fffffffc");
+        assert_eq!(formatted[0].target, Some(0xffff_fffc));
+    }
+
+    #[test]
+    fn test_upper_annotations() {
+        // auipc a0, 0x2; addi a0, a0, -316; lui a1, 0x12; lw a2, 52(a1); auipc ra, 0x0;
+        // jalr ra; lw a3, 8(a1)
+        let formatted = gnu(
+            &[
+                0x00002517, 0xec450513, 0x000125b7, 0x0345a603, 0x00000097, 0x000080e7, 0x0085a683,
+            ],
+            0x1013c,
+        );
+        let texts: Vec<&str> = formatted.iter().map(|f| f.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "auipc\ta0,0x2",
+                "addi\ta0,a0,-316 # 12000",
+                "lui\ta1,0x12",
+                "lw\ta2,52(a1) # 12034",
+                "auipc\tra,0x0",
+                "jalr\tra # 1014c",
+                "lw\ta3,8(a1)",
+            ]
+        );
+        assert_eq!(formatted[1].target, Some(0x12000));
+        assert_eq!(formatted[6].target, None);
+        assert_eq!(text(0x01002503), "lw\ta0,16(zero) # 10");
+    }
+}
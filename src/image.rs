@@ -0,0 +1,439 @@
+//! Loading of firmware images given as Intel HEX, Motorola S-record or flat binary files.
+//!
+//! An [`Image`] holds the memory regions written by the file, each a run of contiguous bytes
+//! tagged with the address it starts at, and the entry address when the file records one. Gaps
+//! between records start a new region, so every region is disassembled with the streaming
+//! decoder at its own addresses. Extended address records of Intel HEX files and the 16, 24 and
+//! 32 bit addresses of S-records are applied, and the checksum of every record is verified.
+//!
+//! ### Example
+//!
+//! ```
+//! use risc_v_disassembler::image::Image;
+//! use risc_v_disassembler::Extensions;
+//!
+//! let hex = ":0200000480007A\n:0400000013050500DF\n:00000001FF\n";
+//! let image = Image::from_intel_hex(hex).unwrap();
+//! for region in &image.regions {
+//!     for disassembled in region.disassemble(false, true, &Extensions::default()) {
+//!         println!("{:8x}: {}", disassembled.address, disassembled.instruction.unwrap());
+//!     }
+//! }
+//! assert_eq!(image.regions[0].address, 0x8000_0000);
+//! ```
+
+use crate::{disassemble, Extensions, Instructions};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ImageError {
+    #[error("Invalid record on line {line}: {reason}.")]
+    InvalidRecord { line: usize, reason: &'static str },
+
+    #[error(
+        "Invalid checksum on line {line}: the record has {found:#04x}, but its bytes give {expected:#04x}."
+    )]
+    Checksum {
+        line: usize,
+        expected: u8,
+        found: u8,
+    },
+
+    #[error("Unsupported record type {record_type} on line {line}.")]
+    UnsupportedRecordType { line: usize, record_type: u8 },
+
+    #[error(
+        "Invalid record count on line {line}: the record gives {found}, but {expected} data records precede it."
+    )]
+    RecordCount {
+        line: usize,
+        expected: u32,
+        found: u32,
+    },
+
+    #[error("Missing end of file record. The Intel HEX file is truncated.")]
+    MissingEndOfFile,
+
+    #[error("Overlapping data at address {0:#x}. The address is written by more than one record.")]
+    Overlap(u64),
+}
+
+/// Contiguous bytes of an image, starting at `address`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub address: u64,
+    pub data: Vec<u8>,
+}
+
+impl Region {
+    /// Returns the address following the last byte of the region, which wraps at 32 bits.
+    pub fn end(&self) -> u64 {
+        self.address.wrapping_add(self.data.len() as u64) as u32 as u64
+    }
+
+    /// Returns an iterator over the instructions of the region, as [`disassemble`] does for a
+    /// buffer.
+    pub fn disassemble(
+        &self,
+        is_big_endian: bool,
+        use_abi_register_names: bool,
+        extensions: &Extensions,
+    ) -> Instructions<'_> {
+        disassemble(
+            &self.data,
+            self.address,
+            is_big_endian,
+            use_abi_register_names,
+            extensions,
+        )
+    }
+}
+
+/// The memory regions of a firmware image, in order of address.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Image {
+    pub regions: Vec<Region>,
+    /// The start address recorded by the file, if any.
+    pub entry: Option<u64>,
+}
+
+impl Image {
+    /// Returns the image of a flat binary file loaded at `address`.
+    pub fn from_binary(data: &[u8], address: u64) -> Self {
+        Image {
+            regions: vec![Region {
+                address,
+                data: data.to_vec(),
+            }],
+            entry: None,
+        }
+    }
+
+    /// Parses an Intel HEX file. Extended segment (`02`) and extended linear (`04`) address
+    /// records set the base of the data records following them, start address records (`03`
+    /// and `05`) give the entry address, and the end of file record (`01`) is required.
+    pub fn from_intel_hex(text: &str) -> Result<Self, ImageError> {
+        let mut chunks = Vec::new();
+        let mut base = 0u64;
+        let mut entry = None;
+
+        for (line, record) in records(text) {
+            let invalid = |reason| ImageError::InvalidRecord { line, reason };
+            let digits = record
+                .strip_prefix(':')
+                .ok_or_else(|| invalid("missing start code `:`"))?;
+            let bytes = hex_bytes(digits).map_err(invalid)?;
+            if bytes.len() < 5 {
+                return Err(invalid("record too short"));
+            }
+            let count = bytes[0] as usize;
+            if bytes.len() != count + 5 {
+                return Err(invalid("byte count does not match the record length"));
+            }
+            let (body, checksum) = bytes.split_at(bytes.len() - 1);
+            let expected = body
+                .iter()
+                .fold(0u8, |sum, b| sum.wrapping_add(*b))
+                .wrapping_neg();
+            if expected != checksum[0] {
+                return Err(ImageError::Checksum {
+                    line,
+                    expected,
+                    found: checksum[0],
+                });
+            }
+
+            let offset = u16::from_be_bytes([bytes[1], bytes[2]]) as u64;
+            let data = &body[4..];
+            let value = || data.iter().fold(0u64, |value, b| value << 8 | *b as u64);
+            match (bytes[3], count) {
+                (0x00, _) => chunks.push((base + offset, data.to_vec())),
+                (0x01, 0) => {
+                    return Image::from_chunks(chunks, entry);
+                }
+                (0x02, 2) => base = value() << 4,
+                (0x03, 4) => entry = Some((value() >> 16 << 4) + (value() & 0xffff)),
+                (0x04, 2) => base = value() << 16,
+                (0x05, 4) => entry = Some(value()),
+                (0x01..=0x05, _) => return Err(invalid("invalid length for the record type")),
+                (record_type, _) => {
+                    return Err(ImageError::UnsupportedRecordType { line, record_type })
+                }
+            }
+        }
+        Err(ImageError::MissingEndOfFile)
+    }
+
+    /// Parses a Motorola S-record file. Data records `S1`, `S2` and `S3` hold 16, 24 and 32 bit
+    /// addresses, the count records `S5` and `S6` are checked against the data records before
+    /// them, and the termination records `S7`, `S8` and `S9` give the entry address. The `S0`
+    /// header is ignored.
+    pub fn from_srecord(text: &str) -> Result<Self, ImageError> {
+        let mut chunks = Vec::new();
+        let mut entry = None;
+        let mut data_records = 0u32;
+
+        for (line, record) in records(text) {
+            let invalid = |reason| ImageError::InvalidRecord { line, reason };
+            let rest = record
+                .strip_prefix('S')
+                .ok_or_else(|| invalid("missing start code `S`"))?;
+            let record_type = rest
+                .chars()
+                .next()
+                .and_then(|c| c.to_digit(10))
+                .ok_or_else(|| invalid("missing record type"))? as u8;
+            let bytes = hex_bytes(&rest[1..]).map_err(invalid)?;
+            if bytes.len() < 3 {
+                return Err(invalid("record too short"));
+            }
+            if bytes.len() != bytes[0] as usize + 1 {
+                return Err(invalid("byte count does not match the record length"));
+            }
+            let (body, checksum) = bytes.split_at(bytes.len() - 1);
+            let expected = !body.iter().fold(0u8, |sum, b| sum.wrapping_add(*b));
+            if expected != checksum[0] {
+                return Err(ImageError::Checksum {
+                    line,
+                    expected,
+                    found: checksum[0],
+                });
+            }
+
+            let address_size = match record_type {
+                0 | 1 | 5 | 9 => 2,
+                2 | 6 | 8 => 3,
+                3 | 7 => 4,
+                _ => return Err(ImageError::UnsupportedRecordType { line, record_type }),
+            };
+            if body.len() < 1 + address_size {
+                return Err(invalid("record too short for its address"));
+            }
+            let (address, data) = body[1..].split_at(address_size);
+            let address = address.iter().fold(0u64, |value, b| value << 8 | *b as u64);
+            match record_type {
+                1..=3 => {
+                    chunks.push((address, data.to_vec()));
+                    data_records += 1;
+                }
+                5 | 6 if address as u32 != data_records => {
+                    return Err(ImageError::RecordCount {
+                        line,
+                        expected: data_records,
+                        found: address as u32,
+                    })
+                }
+                7..=9 => entry = Some(address),
+                _ => {}
+            }
+        }
+        Image::from_chunks(chunks, entry)
+    }
+
+    /// Joins the data of the records into regions, starting a new region at every gap.
+    fn from_chunks(
+        mut chunks: Vec<(u64, Vec<u8>)>,
+        entry: Option<u64>,
+    ) -> Result<Self, ImageError> {
+        chunks.retain(|(_, data)| !data.is_empty());
+        chunks.sort_by_key(|(address, _)| *address);
+
+        // The end of the last region is compared without wrapping, so that a region ending at
+        // 2^32 is not joined with one at address zero.
+        let mut regions: Vec<Region> = Vec::new();
+        for (address, data) in chunks {
+            let last = regions
+                .last_mut()
+                .map(|region| (region.address + region.data.len() as u64, region));
+            match last {
+                Some((end, _)) if address < end => return Err(ImageError::Overlap(address)),
+                Some((end, region)) if address == end => region.data.extend(data),
+                _ => regions.push(Region { address, data }),
+            }
+        }
+        Ok(Image { regions, entry })
+    }
+}
+
+/// Returns the non-empty lines of a file with their line numbers, counted from one.
+fn records(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+}
+
+fn hex_bytes(digits: &str) -> Result<Vec<u8>, &'static str> {
+    if !digits.len().is_multiple_of(2) {
+        return Err("odd number of hex digits");
+    }
+    digits
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or("invalid hex digit")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intel_hex_extended_linear_address() {
+        let hex = "\
+:0200000480007A
+:100000003715008083250500938515002322B50050
+:040010006780000005
+:0410000078563412D8
+:040000058000000077
+:00000001FF
+";
+        let image = Image::from_intel_hex(hex).unwrap();
+        assert_eq!(image.entry, Some(0x8000_0000));
+        assert_eq!(image.regions.len(), 2);
+        assert_eq!(image.regions[0].address, 0x8000_0000);
+        assert_eq!(image.regions[0].data.len(), 0x14);
+        assert_eq!(
+            image.regions[1],
+            Region {
+                address: 0x8000_1000,
+                data: vec![0x78, 0x56, 0x34, 0x12],
+            }
+        );
+    }
+
+    #[test]
+    fn test_intel_hex_segment_address() {
+        // Base 0x1000 << 4, with the entry at CS:IP 0x1000:0x0010.
+        let hex = ":020000021000EC\n:020000001305E6\n:0400000310000010D9\n:00000001FF\n";
+        let image = Image::from_intel_hex(hex).unwrap();
+        assert_eq!(image.regions[0].address, 0x10000);
+        assert_eq!(image.entry, Some(0x10010));
+    }
+
+    #[test]
+    fn test_intel_hex_errors() {
+        let error = |hex| Image::from_intel_hex(hex).unwrap_err();
+        assert_eq!(
+            error(":0400000013050500A0\n:00000001FF\n"),
+            ImageError::Checksum {
+                line: 1,
+                expected: 0xdf,
+                found: 0xa0
+            }
+        );
+        assert_eq!(error(":0400000013050500DF\n"), ImageError::MissingEndOfFile);
+        assert_eq!(
+            error("\n0400000013050500A0\n"),
+            ImageError::InvalidRecord {
+                line: 2,
+                reason: "missing start code `:`"
+            }
+        );
+        assert_eq!(
+            error(":0500000013050500A0\n"),
+            ImageError::InvalidRecord {
+                line: 1,
+                reason: "byte count does not match the record length"
+            }
+        );
+        assert_eq!(
+            error(":00000006FA\n"),
+            ImageError::UnsupportedRecordType {
+                line: 1,
+                record_type: 6
+            }
+        );
+        assert_eq!(
+            error(":0400000013050500DF\n:020002001305E4\n:00000001FF\n"),
+            ImageError::Overlap(2)
+        );
+    }
+
+    #[test]
+    fn test_srecord() {
+        let srec = "\
+S01000006669726D776172652E73726563B7
+S315800000003715008083250500938515002322B500CA
+S30980000010678000007F
+S309800010007856341252
+S5030003F9
+S705800000007A
+";
+        let image = Image::from_srecord(srec).unwrap();
+        assert_eq!(image.entry, Some(0x8000_0000));
+        assert_eq!(image.regions.len(), 2);
+        assert_eq!(image.regions[0].address, 0x8000_0000);
+        assert_eq!(image.regions[0].end(), 0x8000_0014);
+        assert_eq!(image.regions[1].data, [0x78, 0x56, 0x34, 0x12]);
+
+        // 16 and 24 bit addresses.
+        let image = Image::from_srecord("S10510001305D2\nS2060200001305DF\n").unwrap();
+        assert_eq!(image.regions[0].address, 0x1000);
+        assert_eq!(image.regions[1].address, 0x20000);
+    }
+
+    #[test]
+    fn test_srecord_errors() {
+        let error = |srec| Image::from_srecord(srec).unwrap_err();
+        assert_eq!(
+            error("S10510001305D3\n"),
+            ImageError::Checksum {
+                line: 1,
+                expected: 0xd2,
+                found: 0xd3
+            }
+        );
+        assert_eq!(
+            error("S10510001305D2\nS5030002FA\n"),
+            ImageError::RecordCount {
+                line: 2,
+                expected: 1,
+                found: 2
+            }
+        );
+        assert_eq!(
+            error("S4030000FC\n"),
+            ImageError::UnsupportedRecordType {
+                line: 1,
+                record_type: 4
+            }
+        );
+        assert_eq!(
+            error("S1051000130GD2\n"),
+            ImageError::InvalidRecord {
+                line: 1,
+                reason: "invalid hex digit"
+            }
+        );
+        assert_eq!(
+            error("S3030000FC\n"),
+            ImageError::InvalidRecord {
+                line: 1,
+                reason: "record too short for its address"
+            }
+        );
+    }
+
+    #[test]
+    fn test_region_end_wraps() {
+        let image = Image::from_binary(&[0x13, 0x00, 0x00, 0x00, 0x13, 0x00], 0xffff_fffc);
+        assert_eq!(image.regions[0].end(), 2);
+    }
+
+    #[test]
+    fn test_binary() {
+        let image = Image::from_binary(&[0x13, 0x05, 0x05, 0x00], 0x1000);
+        let addresses: Vec<u64> = image.regions[0]
+            .disassemble(false, true, &Extensions::default())
+            .map(|disassembled| disassembled.address)
+            .collect();
+        assert_eq!(addresses, [0x1000]);
+        assert_eq!(image.entry, None);
+    }
+}
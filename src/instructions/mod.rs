@@ -1,10 +1,12 @@
 pub mod parsed_instructions;
 
-use crate::{DisassemblerError, Register};
+use crate::{DisassemblerError, Extensions, Register};
 use parsed_instructions::*;
 use std::fmt;
 
 pub type Instruction32 = u32;
+pub type Instruction16 = u16;
+#[allow(clippy::enum_variant_names)]
 pub(crate) enum DecodedInstruction32 {
     RType {
         opcode: u8,
@@ -14,133 +16,520 @@ pub(crate) enum DecodedInstruction32 {
         rs2: u8,
         funct7: u8,
     },
-    IType {
+    R4Type {
         opcode: u8,
         rd: u8,
         funct3: u8,
         rs1: u8,
-        imm: i32,
-    },
-    SType {
-        opcode: u8,
-        imm: i32,
-        funct3: u8,
-        rs1: u8,
         rs2: u8,
+        funct2: u8,
+        rs3: u8,
     },
-    BType {
+}
+
+/// Formats of the supported 16-bit instructions. Register fields hold the three bit `rd'`,
+/// `rs1'` and `rs2'` encodings, which are mapped to registers by the parser.
+#[allow(clippy::enum_variant_names)]
+pub(crate) enum DecodedInstruction16 {
+    /// Shared by the arithmetic, byte and halfword load/store, and `cm.mv*` formats, which all
+    /// place a register in bits 9-7, a funct2 or immediate in bits 6-5 and a register in bits 4-2.
+    CAType {
         opcode: u8,
-        imm: i32,
-        funct3: u8,
+        funct6: u8,
         rs1: u8,
+        funct2: u8,
         rs2: u8,
     },
-    UType {
-        opcode: u8,
-        rd: u8,
-        imm: i32,
+    CMPPType {
+        funct6: u8,
+        funct2: u8,
+        rlist: u8,
+        spimm: u8,
     },
-    JType {
-        opcode: u8,
+    CMJTType {
+        funct6: u8,
+        index: u8,
+    },
+    CIType {
+        funct3: u8,
         rd: u8,
         imm: i32,
     },
 }
 
-#[derive(Debug, PartialEq)]
-#[allow(non_camel_case_types)]
-pub enum ParsedInstruction32 {
-    add(add),
-    sub(sub),
-    xor(xor),
-    or(or),
-    and(and),
-    sll(sll),
-    srl(srl),
-    sra(sra),
-    slt(slt),
-    sltu(sltu),
-    addi(addi),
-    xori(xori),
-    ori(ori),
-    andi(andi),
-    slli(slli),
-    srli(srli),
-    srai(srai),
-    slti(slti),
-    sltiu(sltiu),
-    lb(lb),
-    lh(lh),
-    lw(lw),
-    lbu(lbu),
-    lhu(lhu),
-    sb(sb),
-    sh(sh),
-    sw(sw),
-    beq(beq),
-    bne(bne),
-    blt(blt),
-    bge(bge),
-    bltu(bltu),
-    bgeu(bgeu),
-    jal(jal),
-    jalr(jalr),
-    lui(lui),
-    auipc(auipc),
-    ecall(ecall),
-    ebreak(ebreak),
+/// Defines `ParsedInstruction32`, with one variant wrapping each instruction struct, and its
+/// `Display`. Each variant is given with its mnemonic, under which the `serde` feature
+/// serializes it, in `mnemonic`, with the fields of its struct under `operands`.
+macro_rules! parsed_instruction32 {
+    ($($name:ident = $mnemonic:tt),* $(,)?) => {
+        #[derive(Debug, PartialEq)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(tag = "mnemonic", content = "operands")
+        )]
+        #[allow(non_camel_case_types)]
+        pub enum ParsedInstruction32 {
+            $(
+                #[cfg_attr(feature = "serde", serde(rename = $mnemonic))]
+                $name($name),
+            )*
+        }
+
+        impl fmt::Display for ParsedInstruction32 {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(ParsedInstruction32::$name(inner) => inner.fmt(f),)*
+                }
+            }
+        }
+    };
 }
 
+// Lists the instructions of the decode tables in `opcodes/`, generated by `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/opcode_variants.rs"));
+
+with_opcode_instructions!(parsed_instruction32! {
+    fadd_s = "fadd.s",
+    fsub_s = "fsub.s",
+    fmul_s = "fmul.s",
+    fdiv_s = "fdiv.s",
+    fsqrt_s = "fsqrt.s",
+    fsgnj_s = "fsgnj.s",
+    fsgnjn_s = "fsgnjn.s",
+    fsgnjx_s = "fsgnjx.s",
+    fmin_s = "fmin.s",
+    fmax_s = "fmax.s",
+    feq_s = "feq.s",
+    flt_s = "flt.s",
+    fle_s = "fle.s",
+    fclass_s = "fclass.s",
+    fcvt_w_s = "fcvt.w.s",
+    fcvt_wu_s = "fcvt.wu.s",
+    fcvt_s_w = "fcvt.s.w",
+    fcvt_s_wu = "fcvt.s.wu",
+    fmadd_s = "fmadd.s",
+    fmsub_s = "fmsub.s",
+    fnmsub_s = "fnmsub.s",
+    fnmadd_s = "fnmadd.s",
+    fadd_d = "fadd.d",
+    fsub_d = "fsub.d",
+    fmul_d = "fmul.d",
+    fdiv_d = "fdiv.d",
+    fsqrt_d = "fsqrt.d",
+    fsgnj_d = "fsgnj.d",
+    fsgnjn_d = "fsgnjn.d",
+    fsgnjx_d = "fsgnjx.d",
+    fmin_d = "fmin.d",
+    fmax_d = "fmax.d",
+    feq_d = "feq.d",
+    flt_d = "flt.d",
+    fle_d = "fle.d",
+    fclass_d = "fclass.d",
+    fcvt_w_d = "fcvt.w.d",
+    fcvt_wu_d = "fcvt.wu.d",
+    fcvt_d_w = "fcvt.d.w",
+    fcvt_d_wu = "fcvt.d.wu",
+    fmadd_d = "fmadd.d",
+    fmsub_d = "fmsub.d",
+    fnmsub_d = "fnmsub.d",
+    fnmadd_d = "fnmadd.d",
+    fcvt_s_d = "fcvt.s.d",
+    fcvt_d_s = "fcvt.d.s",
+    fadd_h = "fadd.h",
+    fsub_h = "fsub.h",
+    fmul_h = "fmul.h",
+    fdiv_h = "fdiv.h",
+    fsqrt_h = "fsqrt.h",
+    fsgnj_h = "fsgnj.h",
+    fsgnjn_h = "fsgnjn.h",
+    fsgnjx_h = "fsgnjx.h",
+    fmin_h = "fmin.h",
+    fmax_h = "fmax.h",
+    feq_h = "feq.h",
+    flt_h = "flt.h",
+    fle_h = "fle.h",
+    fclass_h = "fclass.h",
+    fcvt_w_h = "fcvt.w.h",
+    fcvt_wu_h = "fcvt.wu.h",
+    fcvt_h_w = "fcvt.h.w",
+    fcvt_h_wu = "fcvt.h.wu",
+    fmadd_h = "fmadd.h",
+    fmsub_h = "fmsub.h",
+    fnmsub_h = "fnmsub.h",
+    fnmadd_h = "fnmadd.h",
+    fcvt_s_h = "fcvt.s.h",
+    fcvt_h_s = "fcvt.h.s",
+    fcvt_d_h = "fcvt.d.h",
+    fcvt_h_d = "fcvt.h.d",
+    lr_w = "lr.w",
+    sc_w = "sc.w",
+    amoswap_w = "amoswap.w",
+    amoadd_w = "amoadd.w",
+    amoxor_w = "amoxor.w",
+    amoand_w = "amoand.w",
+    amoor_w = "amoor.w",
+    amomin_w = "amomin.w",
+    amomax_w = "amomax.w",
+    amominu_w = "amominu.w",
+    amomaxu_w = "amomaxu.w",
+    amoswap_b = "amoswap.b",
+    amoadd_b = "amoadd.b",
+    amoxor_b = "amoxor.b",
+    amoand_b = "amoand.b",
+    amoor_b = "amoor.b",
+    amomin_b = "amomin.b",
+    amomax_b = "amomax.b",
+    amominu_b = "amominu.b",
+    amomaxu_b = "amomaxu.b",
+    amoswap_h = "amoswap.h",
+    amoadd_h = "amoadd.h",
+    amoxor_h = "amoxor.h",
+    amoand_h = "amoand.h",
+    amoor_h = "amoor.h",
+    amomin_h = "amomin.h",
+    amomax_h = "amomax.h",
+    amominu_h = "amominu.h",
+    amomaxu_h = "amomaxu.h",
+    amocas_w = "amocas.w",
+    amocas_d = "amocas.d",
+    amocas_b = "amocas.b",
+    amocas_h = "amocas.h",
+    c_lbu = "c.lbu",
+    c_lhu = "c.lhu",
+    c_lh = "c.lh",
+    c_sb = "c.sb",
+    c_sh = "c.sh",
+    c_zext_b = "c.zext.b",
+    c_sext_b = "c.sext.b",
+    c_zext_h = "c.zext.h",
+    c_sext_h = "c.sext.h",
+    c_not = "c.not",
+    c_mul = "c.mul",
+    cm_push = "cm.push",
+    cm_pop = "cm.pop",
+    cm_popret = "cm.popret",
+    cm_popretz = "cm.popretz",
+    cm_mvsa01 = "cm.mvsa01",
+    cm_mva01s = "cm.mva01s",
+    cm_jt = "cm.jt",
+    cm_jalt = "cm.jalt",
+    hint = "hint",
+    c_nop = "c.nop",
+    c_mop = "c.mop",
+    custom = "custom",
+    vandn_vv = "vandn.vv",
+    vandn_vx = "vandn.vx",
+    vbrev_v = "vbrev.v",
+    vbrev8_v = "vbrev8.v",
+    vrev8_v = "vrev8.v",
+    vclz_v = "vclz.v",
+    vctz_v = "vctz.v",
+    vcpop_v = "vcpop.v",
+    vrol_vv = "vrol.vv",
+    vrol_vx = "vrol.vx",
+    vror_vv = "vror.vv",
+    vror_vx = "vror.vx",
+    vror_vi = "vror.vi",
+    vwsll_vv = "vwsll.vv",
+    vwsll_vx = "vwsll.vx",
+    vwsll_vi = "vwsll.vi",
+    vclmul_vv = "vclmul.vv",
+    vclmul_vx = "vclmul.vx",
+    vclmulh_vv = "vclmulh.vv",
+    vclmulh_vx = "vclmulh.vx",
+    vaesef_vv = "vaesef.vv",
+    vaesef_vs = "vaesef.vs",
+    vaesem_vv = "vaesem.vv",
+    vaesem_vs = "vaesem.vs",
+    vaesdf_vv = "vaesdf.vv",
+    vaesdf_vs = "vaesdf.vs",
+    vaesdm_vv = "vaesdm.vv",
+    vaesdm_vs = "vaesdm.vs",
+    vaesz_vs = "vaesz.vs",
+    vaeskf1_vi = "vaeskf1.vi",
+    vaeskf2_vi = "vaeskf2.vi",
+    vsha2ms_vv = "vsha2ms.vv",
+    vsha2ch_vv = "vsha2ch.vv",
+    vsha2cl_vv = "vsha2cl.vv",
+    vsm4k_vi = "vsm4k.vi",
+    vsm4r_vv = "vsm4r.vv",
+    vsm4r_vs = "vsm4r.vs",
+    vsm3me_vv = "vsm3me.vv",
+    vsm3c_vi = "vsm3c.vi",
+    vghsh_vv = "vghsh.vv",
+    vgmul_vv = "vgmul.vv",
+    fcvt_bf16_s = "fcvt.bf16.s",
+    fcvt_s_bf16 = "fcvt.s.bf16",
+    vfncvtbf16_f_f_w = "vfncvtbf16.f.f.w",
+    vfwcvtbf16_f_f_v = "vfwcvtbf16.f.f.v",
+    vfwmaccbf16_vv = "vfwmaccbf16.vv",
+    vfwmaccbf16_vf = "vfwmaccbf16.vf",
+});
+
 pub(crate) trait DecodeInstruction32 {
     fn decode_instruction32(&self) -> Result<DecodedInstruction32, DisassemblerError>;
 }
 
 pub(crate) trait ParseInstruction32 {
-    fn parse_instruction32<T: Register>(&self) -> Result<ParsedInstruction32, DisassemblerError>;
+    fn parse_instruction32<T: Register>(
+        &self,
+        extensions: &Extensions,
+    ) -> Result<ParsedInstruction32, DisassemblerError>;
 }
 
-impl fmt::Display for ParsedInstruction32 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+pub(crate) trait DecodeInstruction16 {
+    fn decode_instruction16(&self) -> Result<DecodedInstruction16, DisassemblerError>;
+}
+
+pub(crate) trait ParseInstruction16 {
+    fn parse_instruction16<T: Register>(
+        &self,
+        extensions: &Extensions,
+    ) -> Result<ParsedInstruction32, DisassemblerError>;
+}
+
+/// Floating point format an instruction operates on, as selected by its `fmt` field.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FloatFormat {
+    /// IEEE 754 binary32.
+    Single,
+    /// IEEE 754 binary64.
+    Double,
+    /// IEEE 754 binary16.
+    Half,
+    /// bfloat16: the upper half of a binary32, with its 8 bit exponent and a 7 bit fraction.
+    BFloat16,
+}
+
+/// Least privileged mode an instruction can be executed from.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum PrivilegeLevel {
+    User,
+    Supervisor,
+    /// HS-mode: supervisor mode with virtualization disabled.
+    Hypervisor,
+    Machine,
+}
+
+/// Memory access performed by a load, store or atomic instruction, with its size in bytes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MemoryAccess {
+    Load {
+        size: u8,
+    },
+    Store {
+        size: u8,
+    },
+    /// Atomic read-modify-write, such as an AMO or `amocas`.
+    Atomic {
+        size: u8,
+    },
+}
+
+impl ParsedInstruction32 {
+    /// Returns the minimum privilege level required to execute the instruction. Status bits
+    /// that can trap an instruction from a level it would otherwise be allowed in, such as
+    /// `mstatus.TVM` for `sfence.vma`, are not taken into account. `wfi` is reported as
+    /// requiring supervisor mode, since it may trap in user mode whenever supervisor mode exists.
+    /// Likewise the hypervisor loads and stores, which `hstatus.HU` can also allow in user mode,
+    /// are reported as requiring HS-mode. The CSR instructions report the level encoded in bits
+    /// 9-8 of the CSR address.
+    pub fn privilege_level(&self) -> PrivilegeLevel {
+        match self {
+            ParsedInstruction32::csrrw(csrrw { csr, .. })
+            | ParsedInstruction32::csrrs(csrrs { csr, .. })
+            | ParsedInstruction32::csrrc(csrrc { csr, .. })
+            | ParsedInstruction32::csrrwi(csrrwi { csr, .. })
+            | ParsedInstruction32::csrrsi(csrrsi { csr, .. })
+            | ParsedInstruction32::csrrci(csrrci { csr, .. }) => match csr >> 8 & 0b11 {
+                0b00 => PrivilegeLevel::User,
+                0b01 => PrivilegeLevel::Supervisor,
+                0b10 => PrivilegeLevel::Hypervisor,
+                _ => PrivilegeLevel::Machine,
+            },
+            ParsedInstruction32::sret(_)
+            | ParsedInstruction32::wfi(_)
+            | ParsedInstruction32::sfence_vma(_)
+            | ParsedInstruction32::sinval_vma(_)
+            | ParsedInstruction32::sfence_w_inval(_)
+            | ParsedInstruction32::sfence_inval_ir(_) => PrivilegeLevel::Supervisor,
+            ParsedInstruction32::hfence_vvma(_)
+            | ParsedInstruction32::hfence_gvma(_)
+            | ParsedInstruction32::hinval_vvma(_)
+            | ParsedInstruction32::hinval_gvma(_)
+            | ParsedInstruction32::hlv_b(_)
+            | ParsedInstruction32::hlv_bu(_)
+            | ParsedInstruction32::hlv_h(_)
+            | ParsedInstruction32::hlv_hu(_)
+            | ParsedInstruction32::hlvx_hu(_)
+            | ParsedInstruction32::hlv_w(_)
+            | ParsedInstruction32::hlvx_wu(_)
+            | ParsedInstruction32::hsv_b(_)
+            | ParsedInstruction32::hsv_h(_)
+            | ParsedInstruction32::hsv_w(_) => PrivilegeLevel::Hypervisor,
+            ParsedInstruction32::mret(_) | ParsedInstruction32::mnret(_) => PrivilegeLevel::Machine,
+            ParsedInstruction32::custom(inner) => inner.instruction.privilege_level(),
+            _ => PrivilegeLevel::User,
+        }
+    }
+
+    /// Returns the floating point format of the instruction, or `None` for non floating point
+    /// instructions. Conversions report the format held in their `fmt` field: the destination
+    /// format when converting to floating point, including from another floating point format,
+    /// and the source format when converting to an integer. The BF16 instructions, which convert to, from or multiply bfloat16 values, all
+    /// report [`FloatFormat::BFloat16`].
+    pub fn float_format(&self) -> Option<FloatFormat> {
+        match self {
+            ParsedInstruction32::fadd_s(_)
+            | ParsedInstruction32::fsub_s(_)
+            | ParsedInstruction32::fmul_s(_)
+            | ParsedInstruction32::fdiv_s(_)
+            | ParsedInstruction32::fsqrt_s(_)
+            | ParsedInstruction32::fsgnj_s(_)
+            | ParsedInstruction32::fsgnjn_s(_)
+            | ParsedInstruction32::fsgnjx_s(_)
+            | ParsedInstruction32::fmin_s(_)
+            | ParsedInstruction32::fmax_s(_)
+            | ParsedInstruction32::feq_s(_)
+            | ParsedInstruction32::flt_s(_)
+            | ParsedInstruction32::fle_s(_)
+            | ParsedInstruction32::fclass_s(_)
+            | ParsedInstruction32::fcvt_w_s(_)
+            | ParsedInstruction32::fcvt_wu_s(_)
+            | ParsedInstruction32::fcvt_s_w(_)
+            | ParsedInstruction32::fcvt_s_wu(_)
+            | ParsedInstruction32::fmadd_s(_)
+            | ParsedInstruction32::fmsub_s(_)
+            | ParsedInstruction32::fnmsub_s(_)
+            | ParsedInstruction32::fnmadd_s(_)
+            | ParsedInstruction32::fcvt_s_d(_)
+            | ParsedInstruction32::fcvt_s_h(_) => Some(FloatFormat::Single),
+            ParsedInstruction32::fadd_d(_)
+            | ParsedInstruction32::fsub_d(_)
+            | ParsedInstruction32::fmul_d(_)
+            | ParsedInstruction32::fdiv_d(_)
+            | ParsedInstruction32::fsqrt_d(_)
+            | ParsedInstruction32::fsgnj_d(_)
+            | ParsedInstruction32::fsgnjn_d(_)
+            | ParsedInstruction32::fsgnjx_d(_)
+            | ParsedInstruction32::fmin_d(_)
+            | ParsedInstruction32::fmax_d(_)
+            | ParsedInstruction32::feq_d(_)
+            | ParsedInstruction32::flt_d(_)
+            | ParsedInstruction32::fle_d(_)
+            | ParsedInstruction32::fclass_d(_)
+            | ParsedInstruction32::fcvt_w_d(_)
+            | ParsedInstruction32::fcvt_wu_d(_)
+            | ParsedInstruction32::fcvt_d_w(_)
+            | ParsedInstruction32::fcvt_d_wu(_)
+            | ParsedInstruction32::fmadd_d(_)
+            | ParsedInstruction32::fmsub_d(_)
+            | ParsedInstruction32::fnmsub_d(_)
+            | ParsedInstruction32::fnmadd_d(_)
+            | ParsedInstruction32::fcvt_d_s(_)
+            | ParsedInstruction32::fcvt_d_h(_) => Some(FloatFormat::Double),
+            ParsedInstruction32::fadd_h(_)
+            | ParsedInstruction32::fsub_h(_)
+            | ParsedInstruction32::fmul_h(_)
+            | ParsedInstruction32::fdiv_h(_)
+            | ParsedInstruction32::fsqrt_h(_)
+            | ParsedInstruction32::fsgnj_h(_)
+            | ParsedInstruction32::fsgnjn_h(_)
+            | ParsedInstruction32::fsgnjx_h(_)
+            | ParsedInstruction32::fmin_h(_)
+            | ParsedInstruction32::fmax_h(_)
+            | ParsedInstruction32::feq_h(_)
+            | ParsedInstruction32::flt_h(_)
+            | ParsedInstruction32::fle_h(_)
+            | ParsedInstruction32::fclass_h(_)
+            | ParsedInstruction32::fcvt_w_h(_)
+            | ParsedInstruction32::fcvt_wu_h(_)
+            | ParsedInstruction32::fcvt_h_w(_)
+            | ParsedInstruction32::fcvt_h_wu(_)
+            | ParsedInstruction32::fmadd_h(_)
+            | ParsedInstruction32::fmsub_h(_)
+            | ParsedInstruction32::fnmsub_h(_)
+            | ParsedInstruction32::fnmadd_h(_)
+            | ParsedInstruction32::fcvt_h_s(_)
+            | ParsedInstruction32::fcvt_h_d(_) => Some(FloatFormat::Half),
+            ParsedInstruction32::custom(inner) => inner.instruction.float_format(),
+            ParsedInstruction32::fcvt_bf16_s(_)
+            | ParsedInstruction32::fcvt_s_bf16(_)
+            | ParsedInstruction32::vfncvtbf16_f_f_w(_)
+            | ParsedInstruction32::vfwcvtbf16_f_f_v(_)
+            | ParsedInstruction32::vfwmaccbf16_vv(_)
+            | ParsedInstruction32::vfwmaccbf16_vf(_) => Some(FloatFormat::BFloat16),
+            _ => None,
+        }
+    }
+
+    /// Returns the memory access performed by the instruction, or `None` if it does not access
+    /// memory.
+    pub fn memory_access(&self) -> Option<MemoryAccess> {
         match self {
-            ParsedInstruction32::add(inner) => inner.fmt(f),
-            ParsedInstruction32::sub(inner) => inner.fmt(f),
-            ParsedInstruction32::xor(inner) => inner.fmt(f),
-            ParsedInstruction32::or(inner) => inner.fmt(f),
-            ParsedInstruction32::and(inner) => inner.fmt(f),
-            ParsedInstruction32::sll(inner) => inner.fmt(f),
-            ParsedInstruction32::srl(inner) => inner.fmt(f),
-            ParsedInstruction32::sra(inner) => inner.fmt(f),
-            ParsedInstruction32::slt(inner) => inner.fmt(f),
-            ParsedInstruction32::sltu(inner) => inner.fmt(f),
-            ParsedInstruction32::addi(inner) => inner.fmt(f),
-            ParsedInstruction32::xori(inner) => inner.fmt(f),
-            ParsedInstruction32::ori(inner) => inner.fmt(f),
-            ParsedInstruction32::andi(inner) => inner.fmt(f),
-            ParsedInstruction32::slli(inner) => inner.fmt(f),
-            ParsedInstruction32::srli(inner) => inner.fmt(f),
-            ParsedInstruction32::srai(inner) => inner.fmt(f),
-            ParsedInstruction32::slti(inner) => inner.fmt(f),
-            ParsedInstruction32::sltiu(inner) => inner.fmt(f),
-            ParsedInstruction32::lb(inner) => inner.fmt(f),
-            ParsedInstruction32::lh(inner) => inner.fmt(f),
-            ParsedInstruction32::lw(inner) => inner.fmt(f),
-            ParsedInstruction32::lbu(inner) => inner.fmt(f),
-            ParsedInstruction32::lhu(inner) => inner.fmt(f),
-            ParsedInstruction32::sb(inner) => inner.fmt(f),
-            ParsedInstruction32::sh(inner) => inner.fmt(f),
-            ParsedInstruction32::sw(inner) => inner.fmt(f),
-            ParsedInstruction32::beq(inner) => inner.fmt(f),
-            ParsedInstruction32::bne(inner) => inner.fmt(f),
-            ParsedInstruction32::blt(inner) => inner.fmt(f),
-            ParsedInstruction32::bge(inner) => inner.fmt(f),
-            ParsedInstruction32::bltu(inner) => inner.fmt(f),
-            ParsedInstruction32::bgeu(inner) => inner.fmt(f),
-            ParsedInstruction32::jal(inner) => inner.fmt(f),
-            ParsedInstruction32::jalr(inner) => inner.fmt(f),
-            ParsedInstruction32::lui(inner) => inner.fmt(f),
-            ParsedInstruction32::auipc(inner) => inner.fmt(f),
-            ParsedInstruction32::ecall(inner) => inner.fmt(f),
-            ParsedInstruction32::ebreak(inner) => inner.fmt(f),
+            ParsedInstruction32::lb(_)
+            | ParsedInstruction32::lbu(_)
+            | ParsedInstruction32::hlv_b(_)
+            | ParsedInstruction32::hlv_bu(_) => Some(MemoryAccess::Load { size: 1 }),
+            ParsedInstruction32::lh(_)
+            | ParsedInstruction32::lhu(_)
+            | ParsedInstruction32::hlv_h(_)
+            | ParsedInstruction32::hlv_hu(_)
+            | ParsedInstruction32::hlvx_hu(_) => Some(MemoryAccess::Load { size: 2 }),
+            ParsedInstruction32::lw(_)
+            | ParsedInstruction32::hlv_w(_)
+            | ParsedInstruction32::hlvx_wu(_) => Some(MemoryAccess::Load { size: 4 }),
+            ParsedInstruction32::sb(_) | ParsedInstruction32::hsv_b(_) => {
+                Some(MemoryAccess::Store { size: 1 })
+            }
+            ParsedInstruction32::sh(_) | ParsedInstruction32::hsv_h(_) => {
+                Some(MemoryAccess::Store { size: 2 })
+            }
+            ParsedInstruction32::sw(_) | ParsedInstruction32::hsv_w(_) => {
+                Some(MemoryAccess::Store { size: 4 })
+            }
+            ParsedInstruction32::c_lbu(_) => Some(MemoryAccess::Load { size: 1 }),
+            ParsedInstruction32::c_lhu(_) | ParsedInstruction32::c_lh(_) => {
+                Some(MemoryAccess::Load { size: 2 })
+            }
+            ParsedInstruction32::c_sb(_) => Some(MemoryAccess::Store { size: 1 }),
+            ParsedInstruction32::c_sh(_) => Some(MemoryAccess::Store { size: 2 }),
+            ParsedInstruction32::lr_w(_) => Some(MemoryAccess::Load { size: 4 }),
+            ParsedInstruction32::sc_w(_) => Some(MemoryAccess::Store { size: 4 }),
+            ParsedInstruction32::amoswap_b(_)
+            | ParsedInstruction32::amoadd_b(_)
+            | ParsedInstruction32::amoxor_b(_)
+            | ParsedInstruction32::amoand_b(_)
+            | ParsedInstruction32::amoor_b(_)
+            | ParsedInstruction32::amomin_b(_)
+            | ParsedInstruction32::amomax_b(_)
+            | ParsedInstruction32::amominu_b(_)
+            | ParsedInstruction32::amomaxu_b(_)
+            | ParsedInstruction32::amocas_b(_) => Some(MemoryAccess::Atomic { size: 1 }),
+            ParsedInstruction32::amoswap_h(_)
+            | ParsedInstruction32::amoadd_h(_)
+            | ParsedInstruction32::amoxor_h(_)
+            | ParsedInstruction32::amoand_h(_)
+            | ParsedInstruction32::amoor_h(_)
+            | ParsedInstruction32::amomin_h(_)
+            | ParsedInstruction32::amomax_h(_)
+            | ParsedInstruction32::amominu_h(_)
+            | ParsedInstruction32::amomaxu_h(_)
+            | ParsedInstruction32::amocas_h(_) => Some(MemoryAccess::Atomic { size: 2 }),
+            ParsedInstruction32::amoswap_w(_)
+            | ParsedInstruction32::amoadd_w(_)
+            | ParsedInstruction32::amoxor_w(_)
+            | ParsedInstruction32::amoand_w(_)
+            | ParsedInstruction32::amoor_w(_)
+            | ParsedInstruction32::amomin_w(_)
+            | ParsedInstruction32::amomax_w(_)
+            | ParsedInstruction32::amominu_w(_)
+            | ParsedInstruction32::amomaxu_w(_)
+            | ParsedInstruction32::amocas_w(_) => Some(MemoryAccess::Atomic { size: 4 }),
+            ParsedInstruction32::amocas_d(_) => Some(MemoryAccess::Atomic { size: 8 }),
+            ParsedInstruction32::custom(inner) => inner.instruction.memory_access(),
+            _ => None,
         }
     }
 }
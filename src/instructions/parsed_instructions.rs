@@ -1,499 +1,3373 @@
 #![allow(non_camel_case_types)]
 
+use super::ParsedInstruction32;
+use crate::custom::CustomInstruction;
+use crate::registers::RegisterName;
+#[cfg(feature = "serde")]
+use crate::serialize::{register, register_ranges};
+use crate::DisassemblerError;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 
+/// Rounding mode held in the `rm` field of floating point instructions.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum RoundingMode {
+    /// Round to nearest, ties to even.
+    Rne,
+    /// Round towards zero.
+    Rtz,
+    /// Round down, towards negative infinity.
+    Rdn,
+    /// Round up, towards positive infinity.
+    Rup,
+    /// Round to nearest, ties to max magnitude.
+    Rmm,
+    /// Use the dynamic rounding mode held in `frm`.
+    Dyn,
+}
+
+impl TryFrom<u8> for RoundingMode {
+    type Error = DisassemblerError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0b000 => Ok(RoundingMode::Rne),
+            0b001 => Ok(RoundingMode::Rtz),
+            0b010 => Ok(RoundingMode::Rdn),
+            0b011 => Ok(RoundingMode::Rup),
+            0b100 => Ok(RoundingMode::Rmm),
+            0b111 => Ok(RoundingMode::Dyn),
+            _ => Err(DisassemblerError::InvalidFunct3(value)),
+        }
+    }
+}
+
+impl fmt::Display for RoundingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RoundingMode::Rne => write!(f, "rne"),
+            RoundingMode::Rtz => write!(f, "rtz"),
+            RoundingMode::Rdn => write!(f, "rdn"),
+            RoundingMode::Rup => write!(f, "rup"),
+            RoundingMode::Rmm => write!(f, "rmm"),
+            RoundingMode::Dyn => write!(f, "dyn"),
+        }
+    }
+}
+
+/// Appends the rounding mode operand, which is omitted when it is the dynamic default.
+fn write_rounding_mode(f: &mut fmt::Formatter, rm: RoundingMode) -> fmt::Result {
+    match rm {
+        RoundingMode::Dyn => Ok(()),
+        _ => write!(f, ", {}", rm),
+    }
+}
+
+/// Writes the predecessor or successor set of a `fence`, given by its IORW bits, or `0` when the
+/// set is empty.
+fn write_fence_set(f: &mut fmt::Formatter, set: u8) -> fmt::Result {
+    if set == 0 {
+        return write!(f, "0");
+    }
+    for (bit, name) in [(0b1000, 'i'), (0b0100, 'o'), (0b0010, 'r'), (0b0001, 'w')] {
+        if set & bit != 0 {
+            write!(f, "{}", name)?;
+        }
+    }
+    Ok(())
+}
+
+// Instructions of the decode tables in `opcodes/`, generated by `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/opcode_instructions.rs"));
+
+/// Memory ordering of an atomic instruction, given by its `aq` and `rl` bits.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum MemoryOrdering {
+    /// Neither `aq` nor `rl` is set.
+    Relaxed,
+    /// Only `aq` is set.
+    Acquire,
+    /// Only `rl` is set.
+    Release,
+    /// Both `aq` and `rl` are set, making the access sequentially consistent.
+    AcquireRelease,
+}
+
+impl MemoryOrdering {
+    /// Builds the ordering from the two lowest bits of funct7, `aq` being bit 1 and `rl` bit 0.
+    pub(crate) fn from_aq_rl(bits: u8) -> Self {
+        match bits & 0b11 {
+            0b00 => MemoryOrdering::Relaxed,
+            0b10 => MemoryOrdering::Acquire,
+            0b01 => MemoryOrdering::Release,
+            _ => MemoryOrdering::AcquireRelease,
+        }
+    }
+}
+
+/// Displays the ordering as the mnemonic suffix, which is empty for relaxed accesses.
+impl fmt::Display for MemoryOrdering {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MemoryOrdering::Relaxed => Ok(()),
+            MemoryOrdering::Acquire => write!(f, ".aq"),
+            MemoryOrdering::Release => write!(f, ".rl"),
+            MemoryOrdering::AcquireRelease => write!(f, ".aqrl"),
+        }
+    }
+}
+
+/// Masking of a vector instruction, selected by its `vm` bit.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum VectorMask {
+    /// `vm` is set and every element is updated.
+    Unmasked,
+    /// `vm` is clear and only the elements whose bit in `v0` is set are updated.
+    Masked,
+}
+
+impl VectorMask {
+    pub(crate) fn from_vm(vm: u8) -> Self {
+        if vm & 0b1 == 0 {
+            VectorMask::Masked
+        } else {
+            VectorMask::Unmasked
+        }
+    }
+}
+
+/// Displays the mask as the trailing `, v0.t` operand, which is empty for unmasked instructions.
+impl fmt::Display for VectorMask {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VectorMask::Unmasked => Ok(()),
+            VectorMask::Masked => write!(f, ", v0.t"),
+        }
+    }
+}
+
+/// Register list of the Zcmp push and pop instructions: `ra` followed by the saved registers
+/// `s0` to `sN`. The saved registers are kept as inclusive ranges of consecutively named
+/// registers, so with ABI names `s0` to `s3` form a single range, while with numbered names
+/// they are split into `x8` to `x9` and `x18` to `x19`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RegisterList {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub ra: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register_ranges"))]
+    pub ranges: Vec<(RegisterName, RegisterName)>,
+}
+
+/// Displays the list in the `{ra, s0-sN}` syntax.
+impl fmt::Display for RegisterList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{{}", self.ra)?;
+        for (first, last) in &self.ranges {
+            if first == last {
+                write!(f, ", {}", first)?;
+            } else {
+                write!(f, ", {}-{}", first, last)?;
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Instruction decoded by a registered [`CustomDecoder`](crate::CustomDecoder).
+#[derive(Debug)]
+pub struct custom {
+    pub instruction: Box<dyn CustomInstruction>,
+}
+
+impl PartialEq for custom {
+    fn eq(&self, other: &Self) -> bool {
+        self.instruction.eq_custom(other.instruction.as_ref())
+    }
+}
+
+/// Serializes the instruction as `{"instruction": text}`, `text` being its `Display` output,
+/// since the decoder that built it is not known.
+#[cfg(feature = "serde")]
+impl Serialize for custom {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("custom", 1)?;
+        state.serialize_field("instruction", &self.instruction.to_string())?;
+        state.end()
+    }
+}
+
+/// Custom instructions cannot be deserialized, as only their text is serialized.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for custom {
+    fn deserialize<D: Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+        Err(serde::de::Error::custom(
+            "custom instructions cannot be deserialized",
+        ))
+    }
+}
+
 #[derive(Debug, PartialEq)]
-pub struct add {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub rs2: &'static str,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fadd_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct sub {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub rs2: &'static str,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsub_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct xor {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub rs2: &'static str,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmul_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct or {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub rs2: &'static str,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fdiv_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct and {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub rs2: &'static str,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsqrt_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct sll {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub rs2: &'static str,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsgnj_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct srl {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub rs2: &'static str,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsgnjn_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct sra {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub rs2: &'static str,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsgnjx_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct slt {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub rs2: &'static str,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmin_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct sltu {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub rs2: &'static str,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmax_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct addi {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct feq_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct xori {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct flt_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct ori {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fle_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct andi {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fclass_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct slli {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub shamt: u8,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_w_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct srli {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub shamt: u8,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_wu_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct srai {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub shamt: u8,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_s_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct slti {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_s_wu {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct sltiu {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmadd_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs3: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct lb {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmsub_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs3: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct lh {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fnmsub_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs3: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct lw {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fnmadd_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs3: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct lbu {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fadd_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct lhu {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsub_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct sb {
-    pub rs1: &'static str,
-    pub rs2: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmul_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct sh {
-    pub rs1: &'static str,
-    pub rs2: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fdiv_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct sw {
-    pub rs1: &'static str,
-    pub rs2: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsqrt_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct beq {
-    pub rs1: &'static str,
-    pub rs2: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsgnj_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct bne {
-    pub rs1: &'static str,
-    pub rs2: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsgnjn_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct blt {
-    pub rs1: &'static str,
-    pub rs2: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsgnjx_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct bge {
-    pub rs1: &'static str,
-    pub rs2: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmin_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct bltu {
-    pub rs1: &'static str,
-    pub rs2: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmax_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct bgeu {
-    pub rs1: &'static str,
-    pub rs2: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct feq_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct jal {
-    pub rd: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct flt_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct jalr {
-    pub rd: &'static str,
-    pub rs1: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fle_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct lui {
-    pub rd: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fclass_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct auipc {
-    pub rd: &'static str,
-    pub imm: i32,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_w_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_wu_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_d_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_d_wu {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmadd_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs3: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmsub_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs3: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fnmsub_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs3: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fnmadd_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs3: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_s_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_d_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fadd_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsub_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmul_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fdiv_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsqrt_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsgnj_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsgnjn_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fsgnjx_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmin_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmax_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct feq_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct ecall {}
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct flt_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fle_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fclass_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_w_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_wu_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_h_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_h_wu {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmadd_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs3: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fmsub_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs3: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fnmsub_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs3: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fnmadd_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs3: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_s_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_h_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_d_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_h_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct lr_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct sc_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoswap_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoadd_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoxor_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoand_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoor_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
 
 #[derive(Debug, PartialEq)]
-pub struct ebreak {}
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amomin_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amomax_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amominu_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amomaxu_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoswap_b {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoadd_b {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoxor_b {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoand_b {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoor_b {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amomin_b {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amomax_b {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amominu_b {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amomaxu_b {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoswap_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoadd_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoxor_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoand_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amoor_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amomin_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amomax_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amominu_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amomaxu_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amocas_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amocas_d {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amocas_b {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct amocas_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub ordering: MemoryOrdering,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_lbu {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub imm: i32,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_lhu {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub imm: i32,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_lh {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub imm: i32,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_sb {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub imm: i32,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_sh {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+    pub imm: i32,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_zext_b {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_sext_b {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_zext_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_sext_h {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_not {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_mul {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct cm_push {
+    pub rlist: RegisterList,
+    pub stack_adj: i32,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct cm_pop {
+    pub rlist: RegisterList,
+    pub stack_adj: i32,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct cm_popret {
+    pub rlist: RegisterList,
+    pub stack_adj: i32,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct cm_popretz {
+    pub rlist: RegisterList,
+    pub stack_adj: i32,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct cm_mvsa01 {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub r1s: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub r2s: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct cm_mva01s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub r1s: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub r2s: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct cm_jt {
+    pub index: u8,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct cm_jalt {
+    pub index: u8,
+}
+
+/// A HINT encoding: a base instruction writing to x0 that has no architectural effect. The
+/// instruction is kept with its fields, and is displayed as it would be without classification.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct hint {
+    pub instruction: Box<ParsedInstruction32>,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_nop {
+    pub imm: i32,
+}
+
+/// Zcmop may-be-operation `c.mop.N`, with N odd from 1 to 15. Unless redefined by a later
+/// extension, it does not write any register.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct c_mop {
+    pub n: u8,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vandn_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs1: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vandn_vx {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vbrev_v {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vbrev8_v {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vrev8_v {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vclz_v {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vctz_v {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vcpop_v {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vrol_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs1: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vrol_vx {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vror_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs1: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vror_vx {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vror_vi {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub uimm: u8,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vwsll_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs1: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vwsll_vx {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vwsll_vi {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub uimm: u8,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vclmul_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs1: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vclmul_vx {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vclmulh_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs1: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vclmulh_vx {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vaesef_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vaesef_vs {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vaesem_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vaesem_vs {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vaesdf_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vaesdf_vs {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vaesdm_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vaesdm_vs {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vaesz_vs {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vaeskf1_vi {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub uimm: u8,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vaeskf2_vi {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub uimm: u8,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vsha2ms_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs1: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vsha2ch_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs1: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vsha2cl_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs1: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vsm4k_vi {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub uimm: u8,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vsm4r_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vsm4r_vs {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vsm3me_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs1: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vsm3c_vi {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub uimm: u8,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vghsh_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs1: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vgmul_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_bf16_s {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct fcvt_s_bf16 {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    pub rm: RoundingMode,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vfncvtbf16_f_f_w {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vfwcvtbf16_f_f_v {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vfwmaccbf16_vv {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub vm: VectorMask,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct vfwmaccbf16_vf {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vd: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub rs1: RegisterName,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "register"))]
+    pub vs2: RegisterName,
+    pub vm: VectorMask,
+}
+
+impl fmt::Display for fadd_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fadd.s {}, {}, {}", self.rd, self.rs1, self.rs2)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fsub_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsub.s {}, {}, {}", self.rd, self.rs1, self.rs2)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fmul_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fmul.s {}, {}, {}", self.rd, self.rs1, self.rs2)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fdiv_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fdiv.s {}, {}, {}", self.rd, self.rs1, self.rs2)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fsqrt_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsqrt.s {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fsgnj_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsgnj.s {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fsgnjn_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsgnjn.s {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fsgnjx_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsgnjx.s {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fmin_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fmin.s {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fmax_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fmax.s {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for feq_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "feq.s {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for flt_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "flt.s {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fle_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fle.s {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fclass_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fclass.s {}, {}", self.rd, self.rs1)
+    }
+}
+
+impl fmt::Display for fcvt_w_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.w.s {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_wu_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.wu.s {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_s_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.s.w {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_s_wu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.s.wu {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fmadd_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fmadd.s {}, {}, {}, {}",
+            self.rd, self.rs1, self.rs2, self.rs3
+        )?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fmsub_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fmsub.s {}, {}, {}, {}",
+            self.rd, self.rs1, self.rs2, self.rs3
+        )?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fnmsub_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fnmsub.s {}, {}, {}, {}",
+            self.rd, self.rs1, self.rs2, self.rs3
+        )?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fnmadd_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fnmadd.s {}, {}, {}, {}",
+            self.rd, self.rs1, self.rs2, self.rs3
+        )?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fadd_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fadd.d {}, {}, {}", self.rd, self.rs1, self.rs2)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fsub_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsub.d {}, {}, {}", self.rd, self.rs1, self.rs2)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fmul_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fmul.d {}, {}, {}", self.rd, self.rs1, self.rs2)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fdiv_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fdiv.d {}, {}, {}", self.rd, self.rs1, self.rs2)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fsqrt_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsqrt.d {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fsgnj_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsgnj.d {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fsgnjn_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsgnjn.d {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fsgnjx_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsgnjx.d {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fmin_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fmin.d {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fmax_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fmax.d {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for feq_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "feq.d {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for flt_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "flt.d {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fle_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fle.d {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fclass_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fclass.d {}, {}", self.rd, self.rs1)
+    }
+}
+
+impl fmt::Display for fcvt_w_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.w.d {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_wu_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.wu.d {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_d_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.d.w {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_d_wu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.d.wu {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fmadd_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fmadd.d {}, {}, {}, {}",
+            self.rd, self.rs1, self.rs2, self.rs3
+        )?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fmsub_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fmsub.d {}, {}, {}, {}",
+            self.rd, self.rs1, self.rs2, self.rs3
+        )?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fnmsub_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fnmsub.d {}, {}, {}, {}",
+            self.rd, self.rs1, self.rs2, self.rs3
+        )?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fnmadd_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fnmadd.d {}, {}, {}, {}",
+            self.rd, self.rs1, self.rs2, self.rs3
+        )?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_s_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.s.d {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_d_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.d.s {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fadd_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fadd.h {}, {}, {}", self.rd, self.rs1, self.rs2)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fsub_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsub.h {}, {}, {}", self.rd, self.rs1, self.rs2)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fmul_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fmul.h {}, {}, {}", self.rd, self.rs1, self.rs2)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fdiv_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fdiv.h {}, {}, {}", self.rd, self.rs1, self.rs2)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fsqrt_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsqrt.h {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fsgnj_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsgnj.h {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fsgnjn_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsgnjn.h {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fsgnjx_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fsgnjx.h {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fmin_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fmin.h {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fmax_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fmax.h {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for feq_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "feq.h {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for flt_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "flt.h {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fle_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fle.h {}, {}, {}", self.rd, self.rs1, self.rs2)
+    }
+}
+
+impl fmt::Display for fclass_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fclass.h {}, {}", self.rd, self.rs1)
+    }
+}
+
+impl fmt::Display for fcvt_w_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.w.h {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_wu_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.wu.h {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_h_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.h.w {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_h_wu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.h.wu {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fmadd_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fmadd.h {}, {}, {}, {}",
+            self.rd, self.rs1, self.rs2, self.rs3
+        )?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fmsub_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fmsub.h {}, {}, {}, {}",
+            self.rd, self.rs1, self.rs2, self.rs3
+        )?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fnmsub_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fnmsub.h {}, {}, {}, {}",
+            self.rd, self.rs1, self.rs2, self.rs3
+        )?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fnmadd_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fnmadd.h {}, {}, {}, {}",
+            self.rd, self.rs1, self.rs2, self.rs3
+        )?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_s_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.s.h {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_h_s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.h.s {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_d_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.d.h {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for fcvt_h_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fcvt.h.d {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
+    }
+}
+
+impl fmt::Display for lr_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "lr.w{} {}, ({})", self.ordering, self.rd, self.rs1)
+    }
+}
+
+impl fmt::Display for sc_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sc.w{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoswap_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoswap.w{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoadd_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoadd.w{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoxor_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoxor.w{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoand_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoand.w{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoor_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoor.w{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amomin_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amomin.w{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amomax_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amomax.w{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amominu_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amominu.w{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amomaxu_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amomaxu.w{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoswap_b {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoswap.b{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoadd_b {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoadd.b{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoxor_b {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoxor.b{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoand_b {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoand.b{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoor_b {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoor.b{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amomin_b {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amomin.b{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amomax_b {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amomax.b{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amominu_b {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amominu.b{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amomaxu_b {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amomaxu.b{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoswap_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoswap.h{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoadd_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoadd.h{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoxor_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoxor.h{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoand_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoand.h{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amoor_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amoor.h{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amomin_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amomin.h{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amomax_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amomax.h{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amominu_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amominu.h{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amomaxu_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amomaxu.h{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amocas_w {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amocas.w{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amocas_d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amocas.d{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amocas_b {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amocas.b{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for amocas_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "amocas.h{} {}, {}, ({})",
+            self.ordering, self.rd, self.rs2, self.rs1
+        )
+    }
+}
+
+impl fmt::Display for c_lbu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.lbu {}, {}({})", self.rd, self.imm, self.rs1)
+    }
+}
+
+impl fmt::Display for c_lhu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.lhu {}, {}({})", self.rd, self.imm, self.rs1)
+    }
+}
+
+impl fmt::Display for c_lh {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.lh {}, {}({})", self.rd, self.imm, self.rs1)
+    }
+}
+
+impl fmt::Display for c_sb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.sb {}, {}({})", self.rs2, self.imm, self.rs1)
+    }
+}
+
+impl fmt::Display for c_sh {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.sh {}, {}({})", self.rs2, self.imm, self.rs1)
+    }
+}
+
+impl fmt::Display for c_zext_b {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.zext.b {}", self.rd)
+    }
+}
+
+impl fmt::Display for c_sext_b {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.sext.b {}", self.rd)
+    }
+}
+
+impl fmt::Display for c_zext_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.zext.h {}", self.rd)
+    }
+}
+
+impl fmt::Display for c_sext_h {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.sext.h {}", self.rd)
+    }
+}
+
+impl fmt::Display for c_not {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.not {}", self.rd)
+    }
+}
+
+impl fmt::Display for c_mul {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.mul {}, {}", self.rd, self.rs2)
+    }
+}
+
+impl fmt::Display for cm_push {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cm.push {}, {}", self.rlist, self.stack_adj)
+    }
+}
+
+impl fmt::Display for cm_pop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cm.pop {}, {}", self.rlist, self.stack_adj)
+    }
+}
+
+impl fmt::Display for cm_popret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cm.popret {}, {}", self.rlist, self.stack_adj)
+    }
+}
+
+impl fmt::Display for cm_popretz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cm.popretz {}, {}", self.rlist, self.stack_adj)
+    }
+}
+
+impl fmt::Display for cm_mvsa01 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cm.mvsa01 {}, {}", self.r1s, self.r2s)
+    }
+}
+
+impl fmt::Display for cm_mva01s {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cm.mva01s {}, {}", self.r1s, self.r2s)
+    }
+}
+
+impl fmt::Display for cm_jt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cm.jt {}", self.index)
+    }
+}
+
+impl fmt::Display for cm_jalt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cm.jalt {}", self.index)
+    }
+}
+
+impl fmt::Display for hint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.instruction)
+    }
+}
+
+impl fmt::Display for c_nop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.nop {}", self.imm)
+    }
+}
+
+impl fmt::Display for c_mop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "c.mop.{}", self.n)
+    }
+}
+
+impl fmt::Display for custom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.instruction.fmt(f)
+    }
+}
+
+impl fmt::Display for vandn_vv {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "vandn.vv {}, {}, {}{}", self.vd, self.vs2, self.vs1, self.vm)
+    }
+}
+
+impl fmt::Display for vandn_vx {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "vandn.vx {}, {}, {}{}", self.vd, self.vs2, self.rs1, self.vm)
+    }
+}
+
+impl fmt::Display for vbrev_v {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "vbrev.v {}, {}{}", self.vd, self.vs2, self.vm)
+    }
+}
+
+impl fmt::Display for vbrev8_v {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "vbrev8.v {}, {}{}", self.vd, self.vs2, self.vm)
+    }
+}
+
+impl fmt::Display for vrev8_v {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "vrev8.v {}, {}{}", self.vd, self.vs2, self.vm)
+    }
+}
+
+impl fmt::Display for vclz_v {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "vclz.v {}, {}{}", self.vd, self.vs2, self.vm)
+    }
+}
+
+impl fmt::Display for vctz_v {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "vctz.v {}, {}{}", self.vd, self.vs2, self.vm)
+    }
+}
+
+impl fmt::Display for vcpop_v {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "vcpop.v {}, {}{}", self.vd, self.vs2, self.vm)
+    }
+}
 
-impl fmt::Display for add {
+impl fmt::Display for vrol_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "add {}, {}, {}", self.rd, self.rs1, self.rs2)
+        write!(f, "vrol.vv {}, {}, {}{}", self.vd, self.vs2, self.vs1, self.vm)
     }
 }
 
-impl fmt::Display for sub {
+impl fmt::Display for vrol_vx {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "sub {}, {}, {}", self.rd, self.rs1, self.rs2)
+        write!(f, "vrol.vx {}, {}, {}{}", self.vd, self.vs2, self.rs1, self.vm)
     }
 }
 
-impl fmt::Display for xor {
+impl fmt::Display for vror_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "xor {}, {}, {}", self.rd, self.rs1, self.rs2)
+        write!(f, "vror.vv {}, {}, {}{}", self.vd, self.vs2, self.vs1, self.vm)
     }
 }
 
-impl fmt::Display for or {
+impl fmt::Display for vror_vx {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "or {}, {}, {}", self.rd, self.rs1, self.rs2)
+        write!(f, "vror.vx {}, {}, {}{}", self.vd, self.vs2, self.rs1, self.vm)
     }
 }
 
-impl fmt::Display for and {
+impl fmt::Display for vror_vi {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "and {}, {}, {}", self.rd, self.rs1, self.rs2)
+        write!(f, "vror.vi {}, {}, {}{}", self.vd, self.vs2, self.uimm, self.vm)
     }
 }
 
-impl fmt::Display for sll {
+impl fmt::Display for vwsll_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "sll {}, {}, {}", self.rd, self.rs1, self.rs2)
+        write!(f, "vwsll.vv {}, {}, {}{}", self.vd, self.vs2, self.vs1, self.vm)
     }
 }
 
-impl fmt::Display for srl {
+impl fmt::Display for vwsll_vx {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "srl {}, {}, {}", self.rd, self.rs1, self.rs2)
+        write!(f, "vwsll.vx {}, {}, {}{}", self.vd, self.vs2, self.rs1, self.vm)
     }
 }
 
-impl fmt::Display for sra {
+impl fmt::Display for vwsll_vi {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "sra {}, {}, {}", self.rd, self.rs1, self.rs2)
+        write!(f, "vwsll.vi {}, {}, {}{}", self.vd, self.vs2, self.uimm, self.vm)
     }
 }
 
-impl fmt::Display for slt {
+impl fmt::Display for vclmul_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "slt {}, {}, {}", self.rd, self.rs1, self.rs2)
+        write!(f, "vclmul.vv {}, {}, {}{}", self.vd, self.vs2, self.vs1, self.vm)
     }
 }
 
-impl fmt::Display for sltu {
+impl fmt::Display for vclmul_vx {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "sltu {}, {}, {}", self.rd, self.rs1, self.rs2)
+        write!(f, "vclmul.vx {}, {}, {}{}", self.vd, self.vs2, self.rs1, self.vm)
     }
 }
 
-impl fmt::Display for addi {
+impl fmt::Display for vclmulh_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "addi {}, {}, {}", self.rd, self.rs1, self.imm)
+        write!(f, "vclmulh.vv {}, {}, {}{}", self.vd, self.vs2, self.vs1, self.vm)
     }
 }
 
-impl fmt::Display for xori {
+impl fmt::Display for vclmulh_vx {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "xori {}, {}, {}", self.rd, self.rs1, self.imm)
+        write!(f, "vclmulh.vx {}, {}, {}{}", self.vd, self.vs2, self.rs1, self.vm)
     }
 }
 
-impl fmt::Display for ori {
+impl fmt::Display for vaesef_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ori {}, {}, {}", self.rd, self.rs1, self.imm)
+        write!(f, "vaesef.vv {}, {}", self.vd, self.vs2)
     }
 }
 
-impl fmt::Display for andi {
+impl fmt::Display for vaesef_vs {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "andi {}, {}, {}", self.rd, self.rs1, self.imm)
+        write!(f, "vaesef.vs {}, {}", self.vd, self.vs2)
     }
 }
 
-impl fmt::Display for slli {
+impl fmt::Display for vaesem_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "slli {}, {}, {}", self.rd, self.rs1, self.shamt)
+        write!(f, "vaesem.vv {}, {}", self.vd, self.vs2)
     }
 }
 
-impl fmt::Display for srli {
+impl fmt::Display for vaesem_vs {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "srli {}, {}, {}", self.rd, self.rs1, self.shamt)
+        write!(f, "vaesem.vs {}, {}", self.vd, self.vs2)
     }
 }
 
-impl fmt::Display for srai {
+impl fmt::Display for vaesdf_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "srai {}, {}, {}", self.rd, self.rs1, self.shamt)
+        write!(f, "vaesdf.vv {}, {}", self.vd, self.vs2)
     }
 }
 
-impl fmt::Display for slti {
+impl fmt::Display for vaesdf_vs {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "slti {}, {}, {}", self.rd, self.rs1, self.imm)
+        write!(f, "vaesdf.vs {}, {}", self.vd, self.vs2)
     }
 }
 
-impl fmt::Display for sltiu {
+impl fmt::Display for vaesdm_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "sltiu {}, {}, {}", self.rd, self.rs1, self.imm)
+        write!(f, "vaesdm.vv {}, {}", self.vd, self.vs2)
     }
 }
 
-impl fmt::Display for lb {
+impl fmt::Display for vaesdm_vs {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "lb {}, {}({})", self.rd, self.imm, self.rs1)
+        write!(f, "vaesdm.vs {}, {}", self.vd, self.vs2)
     }
 }
 
-impl fmt::Display for lh {
+impl fmt::Display for vaesz_vs {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "lh {}, {}({})", self.rd, self.imm, self.rs1)
+        write!(f, "vaesz.vs {}, {}", self.vd, self.vs2)
     }
 }
 
-impl fmt::Display for lw {
+impl fmt::Display for vaeskf1_vi {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "lw {}, {}({})", self.rd, self.imm, self.rs1)
+        write!(f, "vaeskf1.vi {}, {}, {}", self.vd, self.vs2, self.uimm)
     }
 }
 
-impl fmt::Display for lbu {
+impl fmt::Display for vaeskf2_vi {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "lbu {}, {}({})", self.rd, self.imm, self.rs1)
+        write!(f, "vaeskf2.vi {}, {}, {}", self.vd, self.vs2, self.uimm)
     }
 }
 
-impl fmt::Display for lhu {
+impl fmt::Display for vsha2ms_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "lhu {}, {}({})", self.rd, self.imm, self.rs1)
+        write!(f, "vsha2ms.vv {}, {}, {}", self.vd, self.vs2, self.vs1)
     }
 }
 
-impl fmt::Display for sb {
+impl fmt::Display for vsha2ch_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "sb {}, {}({})", self.rs2, self.imm, self.rs1)
+        write!(f, "vsha2ch.vv {}, {}, {}", self.vd, self.vs2, self.vs1)
     }
 }
 
-impl fmt::Display for sh {
+impl fmt::Display for vsha2cl_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "sh {}, {}({})", self.rs2, self.imm, self.rs1)
+        write!(f, "vsha2cl.vv {}, {}, {}", self.vd, self.vs2, self.vs1)
     }
 }
 
-impl fmt::Display for sw {
+impl fmt::Display for vsm4k_vi {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "sw {}, {}({})", self.rs2, self.imm, self.rs1)
+        write!(f, "vsm4k.vi {}, {}, {}", self.vd, self.vs2, self.uimm)
     }
 }
 
-impl fmt::Display for beq {
+impl fmt::Display for vsm4r_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "beq {}, {}, {}", self.rs1, self.rs2, self.imm)
+        write!(f, "vsm4r.vv {}, {}", self.vd, self.vs2)
     }
 }
 
-impl fmt::Display for bne {
+impl fmt::Display for vsm4r_vs {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "bne {}, {}, {}", self.rs1, self.rs2, self.imm)
+        write!(f, "vsm4r.vs {}, {}", self.vd, self.vs2)
     }
 }
 
-impl fmt::Display for blt {
+impl fmt::Display for vsm3me_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "blt {}, {}, {}", self.rs1, self.rs2, self.imm)
+        write!(f, "vsm3me.vv {}, {}, {}", self.vd, self.vs2, self.vs1)
     }
 }
 
-impl fmt::Display for bge {
+impl fmt::Display for vsm3c_vi {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "bge {}, {}, {}", self.rs1, self.rs2, self.imm)
+        write!(f, "vsm3c.vi {}, {}, {}", self.vd, self.vs2, self.uimm)
     }
 }
 
-impl fmt::Display for bltu {
+impl fmt::Display for vghsh_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "bltu {}, {}, {}", self.rs1, self.rs2, self.imm)
+        write!(f, "vghsh.vv {}, {}, {}", self.vd, self.vs2, self.vs1)
     }
 }
 
-impl fmt::Display for bgeu {
+impl fmt::Display for vgmul_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "bgeu {}, {}, {}", self.rs1, self.rs2, self.imm)
+        write!(f, "vgmul.vv {}, {}", self.vd, self.vs2)
     }
 }
 
-impl fmt::Display for jal {
+impl fmt::Display for fcvt_bf16_s {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "jal {}, {}", self.rd, self.imm)
+        write!(f, "fcvt.bf16.s {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
     }
 }
 
-impl fmt::Display for jalr {
+impl fmt::Display for fcvt_s_bf16 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "jalr {}, {}({})", self.rd, self.imm, self.rs1)
+        write!(f, "fcvt.s.bf16 {}, {}", self.rd, self.rs1)?;
+        write_rounding_mode(f, self.rm)
     }
 }
 
-impl fmt::Display for lui {
+impl fmt::Display for vfncvtbf16_f_f_w {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "lui {}, {}", self.rd, self.imm)
+        write!(f, "vfncvtbf16.f.f.w {}, {}{}", self.vd, self.vs2, self.vm)
     }
 }
 
-impl fmt::Display for auipc {
+impl fmt::Display for vfwcvtbf16_f_f_v {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "auipc {}, {}", self.rd, self.imm)
+        write!(f, "vfwcvtbf16.f.f.v {}, {}{}", self.vd, self.vs2, self.vm)
     }
 }
 
-impl fmt::Display for ecall {
+impl fmt::Display for vfwmaccbf16_vv {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ecall")
+        write!(f, "vfwmaccbf16.vv {}, {}, {}{}", self.vd, self.vs1, self.vs2, self.vm)
     }
 }
 
-impl fmt::Display for ebreak {
+impl fmt::Display for vfwmaccbf16_vf {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ebreak")
+        write!(f, "vfwmaccbf16.vf {}, {}, {}{}", self.vd, self.rs1, self.vs2, self.vm)
     }
 }
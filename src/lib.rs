@@ -1,14 +1,42 @@
 //! A simple disassembler for the RISC-V instruction set architecture.
-//! It currently only supports 32 bit RV32I instructions.
+//! It currently supports 32 bit RV32I instructions, and the 16 bit instructions of the Zcb,
+//! Zcmp and Zcmt extensions.
 //!
 //! ### Supported Instruction Sets
-//!  - RV32I
+//!  - RV32I, with Zicsr and Zifencei
+//!  - Zfinx, Zdinx and Zhinx, selected through [`Extensions`]
+//!  - Privileged instructions, including Svinval and Smrnmi
+//!  - H hypervisor extension
+//!  - Zicbom, Zicboz and Zicbop cache-block operations, Zicond and Zawrs
+//!  - Zalrsc and Zaamo atomics, together forming A, with Zabha and Zacas
+//!  - Zcb, Zcmp and Zcmt code-size reduction, given as two byte slices
+//!  - Zimop and Zcmop may-be-operations, and optional classification of HINT encodings
+//!  - Zvbb, Zvbc and the Zvkned, Zvknha, Zvknhb, Zvksed, Zvksh and Zvkg vector cryptography
+//!    extensions
+//!  - Zfbfmin, Zvfbfmin and Zvfbfwma bfloat16 conversions and multiply-accumulate
+//!  - Custom opcodes, through the decoders registered in [`CustomDecoders`]
+//!  - T-Head vendor extensions (XThead*), with the `xthead` cargo feature
+//!  - CORE-V vendor extensions (XCV*) of the CV32E40P, with the `xcv` cargo feature
+//!
+//! The base, Zicsr, Zifencei, privileged, H, Svinval, cache-block, Zicond, Zawrs and Zimop
+//! instructions are decoded from the opcode tables in `opcodes/`, from which `build.rs`
+//! generates their instruction structs, decode masks and `Display` formats. The atomic, OP-FP,
+//! fused multiply-add, vector and 16 bit formats are still decoded by the hand-written parsers
+//! of `src/parser/`, and are yet to be moved into the tables.
+//!
+//! Sequences of instructions are decoded with [`disassemble`], and with the `elf` cargo feature
+//! the executable sections of RISC-V ELF files are disassembled through `elf::ElfFile`. Intel
+//! HEX, Motorola S-record and flat binary firmware images are loaded into address-tagged
+//! regions by [`image::Image`]. Listings can be written in the syntax of GNU objdump with
+//! [`gnu::GnuFormatter`] and in that of llvm-objdump with [`llvm::LlvmFormatter`], or with any
+//! other implementation of [`format::Formatter`].
 //!
 //! Parses a byte array slice into a `ParsedInstruction32` enum representing a RISC-V instruction.
 //!
 //! ### Arguments
 //!
-//! * `bytes` - A slice of bytes representing the encoded instruction to be parsed.
+//! * `bytes` - A slice of bytes representing the encoded instruction to be parsed: four bytes for
+//!   a 32 bit instruction, or two bytes for a 16 bit instruction.
 //! * `is_big_endian` - A boolean indicating whether the bytes are in big endian format.
 //!
 //! ### Returns
@@ -57,15 +85,44 @@
 //! }));
 //! ``` `
 
+mod custom;
 mod decoder;
+#[cfg(feature = "elf")]
+pub mod elf;
+mod extensions;
+pub mod format;
+pub mod gnu;
+pub mod image;
 mod instructions;
+mod listing;
+pub mod llvm;
 mod macros;
 mod parser;
 mod registers;
+pub mod relocations;
+#[cfg(feature = "serde")]
+mod serialize;
+mod stream;
+mod symbols;
+#[cfg(feature = "serde")]
+pub mod trace;
+#[cfg(feature = "xcv")]
+pub mod xcv;
+#[cfg(feature = "xthead")]
+pub mod xthead;
 
-pub use instructions::{parsed_instructions, ParsedInstruction32};
-use instructions::{DecodeInstruction32, Instruction32, ParseInstruction32};
-pub use registers::Register;
+pub use custom::{CustomDecoder, CustomDecoders, CustomInstruction, CUSTOM_OPCODES};
+pub use extensions::{Extensions, Lmul, Sew};
+pub use instructions::{
+    parsed_instructions, FloatFormat, MemoryAccess, ParsedInstruction32, PrivilegeLevel,
+};
+use instructions::{DecodeInstruction16, Instruction16, Instruction32, ParseInstruction16};
+use registers::{ABIRegister, EmbeddedRegister, NumberedRegister};
+pub use registers::{Register, RegisterName};
+pub use relocations::Relocation;
+pub use listing::{Directive, Line, ListedData, ListedInstruction, Listing};
+pub use stream::{disassemble, Disassembled, Instructions};
+pub use symbols::{Mapping, MappingSymbol, Symbol, SymbolMap, SymbolReference};
 use thiserror::Error;
 
 pub fn parse(
@@ -73,6 +130,44 @@ pub fn parse(
     is_big_endian: bool,
     use_abi_register_names: bool,
 ) -> Result<ParsedInstruction32, DisassemblerError> {
+    parse_with_extensions(
+        bytes,
+        is_big_endian,
+        use_abi_register_names,
+        &Extensions::default(),
+    )
+}
+
+/// Parses an instruction like [`parse`], additionally recognising the instructions of the
+/// selected `extensions`.
+pub fn parse_with_extensions(
+    bytes: &[u8],
+    is_big_endian: bool,
+    use_abi_register_names: bool,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    parse_with_custom_decoders(
+        bytes,
+        is_big_endian,
+        use_abi_register_names,
+        extensions,
+        &CustomDecoders::new(),
+    )
+}
+
+/// Parses an instruction like [`parse_with_extensions`], additionally passing the instructions
+/// of the custom opcodes to the `decoders` registered for them. Their results are returned as
+/// the [`custom`](parsed_instructions::custom) variant.
+pub fn parse_with_custom_decoders(
+    bytes: &[u8],
+    is_big_endian: bool,
+    use_abi_register_names: bool,
+    extensions: &Extensions,
+    decoders: &CustomDecoders,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    if bytes.len() == 2 {
+        return parse_compressed(bytes, is_big_endian, use_abi_register_names, extensions);
+    }
     if bytes.len() != 4 {
         return Err(DisassemblerError::UnsupportedInstructionLength(bytes.len()));
     }
@@ -83,18 +178,79 @@ pub fn parse(
         Instruction32::from_le_bytes(bytes.try_into().unwrap())
     };
 
-    let decoded_instruction = instruction.decode_instruction32()?;
+    if let Some(decoder) = decoders.get((instruction & 0x7f) as u8) {
+        let instruction = match (use_abi_register_names, extensions.e) {
+            (true, false) => decode_custom::<ABIRegister>(decoder, instruction)?,
+            (false, false) => decode_custom::<NumberedRegister>(decoder, instruction)?,
+            (true, true) => decode_custom::<EmbeddedRegister<ABIRegister>>(decoder, instruction)?,
+            (false, true) => {
+                decode_custom::<EmbeddedRegister<NumberedRegister>>(decoder, instruction)?
+            }
+        };
+        return Ok(ParsedInstruction32::custom(parsed_instructions::custom {
+            instruction,
+        }));
+    }
+
+    match (use_abi_register_names, extensions.e) {
+        (true, false) => parser::parse_instruction32::<ABIRegister>(instruction, extensions),
+        (false, false) => parser::parse_instruction32::<NumberedRegister>(instruction, extensions),
+        (true, true) => {
+            parser::parse_instruction32::<EmbeddedRegister<ABIRegister>>(instruction, extensions)
+        }
+        (false, true) => parser::parse_instruction32::<EmbeddedRegister<NumberedRegister>>(
+            instruction,
+            extensions,
+        ),
+    }
+}
+
+fn register_name<T: Register>(register: u8) -> Result<&'static str, DisassemblerError> {
+    Ok(T::try_from_u8(register)?.as_str())
+}
+
+/// Decodes a custom instruction with the integer and floating point registers named like `T`.
+fn decode_custom<T: Register>(
+    decoder: &dyn CustomDecoder,
+    instruction: Instruction32,
+) -> Result<Box<dyn CustomInstruction>, DisassemblerError> {
+    decoder.decode(
+        instruction,
+        register_name::<T>,
+        registers::float_register_name::<T>,
+    )
+}
 
-    let parsed_instruction = if use_abi_register_names {
-        decoded_instruction.parse_instruction32::<registers::ABIRegister>()?
+fn parse_compressed(
+    bytes: &[u8],
+    is_big_endian: bool,
+    use_abi_register_names: bool,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let instruction = if is_big_endian {
+        Instruction16::from_be_bytes(bytes.try_into().unwrap())
     } else {
-        decoded_instruction.parse_instruction32::<registers::NumberedRegister>()?
+        Instruction16::from_le_bytes(bytes.try_into().unwrap())
     };
 
-    Ok(parsed_instruction)
+    let decoded_instruction = instruction.decode_instruction16()?;
+
+    match (use_abi_register_names, extensions.e) {
+        (true, false) => decoded_instruction.parse_instruction16::<ABIRegister>(extensions),
+        (false, false) => decoded_instruction.parse_instruction16::<NumberedRegister>(extensions),
+        (true, true) => {
+            decoded_instruction.parse_instruction16::<EmbeddedRegister<ABIRegister>>(extensions)
+        }
+        (false, true) => decoded_instruction
+            .parse_instruction16::<EmbeddedRegister<NumberedRegister>>(extensions),
+    }
 }
 
+/// Static message of an error, named so that `serde` does not deserialize it by borrowing.
+type Message = &'static str;
+
 #[derive(Debug, Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DisassemblerError {
     #[error(
         "Unsupported instruction length: {0}. The length of the instruction is not supported."
@@ -124,9 +280,75 @@ pub enum DisassemblerError {
     #[error("Invalid register: {0:?}. The register index is out of bounds.")]
     InvalidRegister(u8),
 
+    #[error("Invalid register pair: {0:?}. Register pairs must start at an even register index.")]
+    InvalidRegisterPair(u8),
+
+    #[error(
+        "Invalid funct6 field with value {0:b}. The value is not valid for the given instruction."
+    )]
+    InvalidFunct6(u8),
+
+    #[error(
+        "Invalid funct2 field with value {0:b}. The value is not valid for the given instruction."
+    )]
+    InvalidFunct2(u8),
+
+    #[error("Invalid register list: {0:b}. The value is reserved for the given instruction.")]
+    InvalidRegisterList(u8),
+
+    #[error("Unsupported compressed instruction: {0:#06x}. The encoding is not supported.")]
+    UnsupportedCompressedInstruction(u16),
+
+    #[error(
+        "Invalid rd field with value {0:b}. The value is not valid for the given instruction."
+    )]
+    InvalidRdField(u8),
+
+    #[error(
+        "Invalid rs1 field with value {0:b}. The value is not valid for the given instruction."
+    )]
+    InvalidRs1Field(u8),
+
+    #[error(
+        "Invalid rs2 field with value {0:b}. The value is not valid for the given instruction."
+    )]
+    InvalidRs2Field(u8),
+
+    #[error(
+        "Invalid vector register group: v{0}. The register is not aligned to the register group size."
+    )]
+    InvalidVectorRegisterGroup(u8),
+
+    #[error(
+        "Invalid element group operand: v{0}. The register group overlaps another operand of the element group instruction."
+    )]
+    InvalidElementGroup(u8),
+
+    #[error(
+        "Unsupported element width: {0} bits. The element group instruction is reserved for the selected SEW."
+    )]
+    UnsupportedElementWidth(u32),
+
+    #[error(
+        "Element group too wide: {0} bits. The element group does not fit in a register group of VLEN × LMUL bits."
+    )]
+    ElementGroupTooWide(u32),
+
+    #[error("Invalid ISA string: {0:?}. ISA strings start with rv32 or rv64 and a base ISA.")]
+    InvalidIsaString(String),
+
+    #[error("Unsupported XLEN: {0}. Only the RV32 instructions are decoded.")]
+    UnsupportedXlen(u32),
+
     #[error("Bit extraction error: {0}.")]
-    BitExtractionError(&'static str),
+    BitExtractionError(
+        #[cfg_attr(feature = "serde", serde(deserialize_with = "serialize::message"))]
+        Message,
+    ),
 
     #[error("Bit extension error: {0}.")]
-    BitExtensionError(&'static str),
+    BitExtensionError(
+        #[cfg_attr(feature = "serde", serde(deserialize_with = "serialize::message"))]
+        Message,
+    ),
 }
@@ -0,0 +1,992 @@
+//! Listings of decoded instructions, labelled and annotated with the symbols of a [`SymbolMap`].
+
+use crate::format::{tokenize, Formatter, Token, TokenKind};
+use crate::parsed_instructions::{addi, auipc, jalr};
+use crate::registers::is_register;
+use crate::relocations::{
+    Relocation, R_RISCV_CALL, R_RISCV_CALL_PLT, R_RISCV_GOT_HI20, R_RISCV_PCREL_HI20,
+};
+use crate::symbols::{Mapping, Symbol};
+use crate::{
+    Disassembled, DisassemblerError, Instructions, ParsedInstruction32, SymbolMap, SymbolReference,
+};
+use std::fmt;
+use std::iter::Peekable;
+
+/// A line of a [`Listing`].
+#[derive(Debug, PartialEq)]
+pub enum Line<'a> {
+    /// A symbol at the address of the next instruction.
+    Label(&'a Symbol),
+    Instruction(ListedInstruction<'a>),
+    /// Data marked by a `$d` mapping symbol.
+    Data(ListedData<'a>),
+}
+
+/// An instruction of a [`Listing`], or a pseudo-instruction formed by two instructions.
+#[derive(Debug, PartialEq)]
+pub struct ListedInstruction<'a> {
+    pub address: u64,
+    pub bytes: &'a [u8],
+    /// The `Display` output of the instruction, or the pseudo-instruction with its symbol, such
+    /// as `call printf` or `la a0, message`. With a [`formatter`](Listing::formatter), the
+    /// instruction as it writes it.
+    pub instruction: Result<String, DisassemblerError>,
+    /// The tokens of `instruction`, which concatenate to it, or none when it failed to decode.
+    pub tokens: Vec<Token>,
+    /// Address branched or jumped to, or computed by `auipc` or a pseudo-instruction.
+    pub target: Option<u64>,
+    /// Symbol of the target, annotated after the instruction as `<func+0x1c>`. Pseudo-instructions
+    /// name their symbol as an operand instead.
+    pub symbol: Option<SymbolReference<'a>>,
+    /// Relocation of the instruction that could not be written as an operand, annotated after
+    /// the instruction as `# R_RISCV_CALL_PLT printf`.
+    pub relocation: Option<&'a Relocation>,
+}
+
+/// Data of a [`Listing`], listed as an assembler directive.
+#[derive(Debug, PartialEq)]
+pub struct ListedData<'a> {
+    pub address: u64,
+    pub bytes: &'a [u8],
+    pub directive: Directive,
+}
+
+/// A data directive, holding a value read in the byte order of the listing.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Directive {
+    Word(u32),
+    Byte(u8),
+}
+
+impl fmt::Display for Directive {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Directive::Word(value) => write!(f, ".word {:#010x}", value),
+            Directive::Byte(value) => write!(f, ".byte {:#04x}", value),
+        }
+    }
+}
+
+impl Line<'_> {
+    /// Returns the tokens of the line, which concatenate to its `Display` output.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use risc_v_disassembler::format::TokenKind;
+    /// use risc_v_disassembler::{disassemble, Extensions, Symbol, SymbolMap};
+    ///
+    /// // beq zero, zero, 0
+    /// let bytes = [0x63, 0x00, 0x00, 0x00];
+    /// let symbols: SymbolMap = [Symbol::function("main", 0x1000, 4)].into_iter().collect();
+    ///
+    /// let lines: Vec<_> = disassemble(&bytes, 0x1000, false, true, &Extensions::default())
+    ///     .listing(&symbols)
+    ///     .collect();
+    /// let symbols: Vec<String> = lines[1]
+    ///     .tokens()
+    ///     .into_iter()
+    ///     .filter(|token| token.kind == TokenKind::Symbol)
+    ///     .map(|token| token.text)
+    ///     .collect();
+    /// assert_eq!(lines[1].to_string(), "    1000:\tbeq zero, zero, 0 <main>");
+    /// assert_eq!(symbols, ["main"]);
+    /// ```
+    pub fn tokens(&self) -> Vec<Token> {
+        let text = |text: &str| Token::new(TokenKind::Text, text);
+        let address = |address: u64| {
+            let digits = format!("{:x}", address);
+            [
+                text(&" ".repeat(8usize.saturating_sub(digits.len()))),
+                Token::new(TokenKind::Address, digits),
+                text(":\t"),
+            ]
+        };
+        match self {
+            Line::Label(symbol) => vec![
+                Token::new(TokenKind::Address, format!("{:08x}", symbol.address)),
+                text(" <"),
+                Token::new(TokenKind::Symbol, symbol.name.as_str()),
+                text(">:"),
+            ],
+            Line::Instruction(instruction) => {
+                let mut tokens = address(instruction.address).to_vec();
+                match &instruction.instruction {
+                    Ok(_) => tokens.extend(instruction.tokens.iter().cloned()),
+                    Err(error) => tokens.push(text(&error.to_string())),
+                }
+                if let Some(symbol) = &instruction.symbol {
+                    tokens.push(text(" <"));
+                    tokens.push(Token::new(TokenKind::Symbol, symbol.to_string()));
+                    tokens.push(text(">"));
+                }
+                if let Some(relocation) = instruction.relocation {
+                    tokens.push(text(" # "));
+                    tokens.push(text(&relocation.to_string()));
+                }
+                tokens
+            }
+            Line::Data(data) => {
+                let mut tokens = address(data.address).to_vec();
+                let (directive, value) = match data.directive {
+                    Directive::Word(value) => (".word", format!("{:#010x}", value)),
+                    Directive::Byte(value) => (".byte", format!("{:#04x}", value)),
+                };
+                tokens.push(Token::new(TokenKind::Mnemonic, directive));
+                tokens.push(Token::new(TokenKind::Separator, " "));
+                tokens.push(Token::new(TokenKind::Immediate, value));
+                tokens
+            }
+        }
+    }
+}
+
+impl fmt::Display for Line<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.tokens()
+            .iter()
+            .try_for_each(|token| write!(f, "{}", token))
+    }
+}
+
+/// Iterator over the lines of a listing, created by [`Instructions::listing`].
+///
+/// Each instruction is preceded by a label for every symbol at its address. Branches, jumps and
+/// `auipc` are annotated with the symbol of their target, while an `auipc` followed by the
+/// `addi` or `jalr` completing its address is listed as the `la`, `call` or `tail`
+/// pseudo-instruction when the address has a symbol. A label between the two instructions
+/// keeps them apart.
+///
+/// The mapping symbols of the map split the listing into code and data. Data, such as literal
+/// pools and jump tables, is listed as `.word` directives, or `.byte` where a word would be
+/// unaligned or cross the end of the data. Code after `$x<isa>` is decoded with the extensions
+/// of the ISA string, or of the buffer when the string is invalid, and code after `$x` with
+/// those of the buffer.
+///
+/// In object files, whose immediates are left for the linker to fill in, the
+/// [`relocations`](Listing::relocations) of an instruction replace the immediate with the
+/// operand they stand for, such as `%hi(message)` or the symbol branched to, and `auipc` pairs
+/// relocated by `R_RISCV_CALL`, `R_RISCV_CALL_PLT`, `R_RISCV_PCREL_HI20` or `R_RISCV_GOT_HI20`
+/// are listed as `call`, `tail` or `la` of the relocated symbol.
+#[derive(Debug)]
+pub struct Listing<'a> {
+    buffer: Instructions<'a>,
+    symbols: &'a SymbolMap,
+    relocations: &'a [Relocation],
+    pseudo_instructions: bool,
+    formatter: Option<Box<dyn Formatter + 'a>>,
+    region: Region<'a>,
+    /// End of the current region, and of the listing.
+    region_end: u64,
+    end: u64,
+    /// Address of the next instruction and the number of its labels already listed.
+    labels: (u64, usize),
+}
+
+/// Code or data between two mapping symbols.
+#[derive(Debug)]
+enum Region<'a> {
+    Code(Peekable<Instructions<'a>>),
+    /// Data, holding the address of the next directive.
+    Data(u64),
+}
+
+impl<'a> Instructions<'a> {
+    /// Returns a listing of the instructions, labelled and annotated with `symbols`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use risc_v_disassembler::{disassemble, Extensions, Symbol, SymbolMap};
+    ///
+    /// // auipc ra, 0; jalr ra, 12(ra); ret; ret
+    /// let bytes = [
+    ///     0x97, 0x00, 0x00, 0x00, 0xe7, 0x80, 0xc0, 0x00, 0x67, 0x80, 0x00, 0x00, 0x67, 0x80,
+    ///     0x00, 0x00,
+    /// ];
+    /// let symbols: SymbolMap = [
+    ///     Symbol::function("main", 0x1000, 12),
+    ///     Symbol::function("helper", 0x100c, 4),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let listing: Vec<String> = disassemble(&bytes, 0x1000, false, true, &Extensions::default())
+    ///     .listing(&symbols)
+    ///     .map(|line| line.to_string())
+    ///     .collect();
+    /// assert_eq!(
+    ///     listing,
+    ///     [
+    ///         "00001000 <main>:",
+    ///         "    1000:\tcall helper",
+    ///         "    1008:\tjalr zero, 0(ra)",
+    ///         "0000100c <helper>:",
+    ///         "    100c:\tjalr zero, 0(ra)",
+    ///     ]
+    /// );
+    /// ```
+    pub fn listing(self, symbols: &'a SymbolMap) -> Listing<'a> {
+        let (start, end) = self.range();
+        let mut listing = Listing {
+            buffer: self,
+            symbols,
+            relocations: &[],
+            pseudo_instructions: true,
+            formatter: None,
+            region: Region::Data(start),
+            region_end: start,
+            end,
+            labels: (0, 0),
+        };
+        listing.enter_region(start);
+        listing
+    }
+}
+
+impl<'a> Listing<'a> {
+    /// Applies `relocations`, given in order of address, to the instructions of the listing.
+    pub fn relocations(mut self, relocations: &'a [Relocation]) -> Self {
+        self.relocations = relocations;
+        self
+    }
+
+    /// Selects whether `auipc` pairs are listed as the `la`, `call` and `tail`
+    /// pseudo-instructions, which they are by default, or as two instructions.
+    pub fn pseudo_instructions(mut self, pseudo_instructions: bool) -> Self {
+        self.pseudo_instructions = pseudo_instructions;
+        self
+    }
+
+    /// Writes the instructions with `formatter`, instead of their `Display` output. As in the
+    /// disassemblers the formatters follow, `auipc` pairs are listed as two instructions and
+    /// relocations are not applied.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use risc_v_disassembler::gnu::GnuFormatter;
+    /// use risc_v_disassembler::{disassemble, Extensions, Symbol, SymbolMap};
+    ///
+    /// // lui a0, 0x12345; jal 0x1000
+    /// let bytes = [0x37, 0x55, 0x34, 0x12, 0xef, 0xf0, 0xdf, 0xff];
+    /// let symbols: SymbolMap = [Symbol::function("main", 0x1000, 8)].into_iter().collect();
+    ///
+    /// let listing: Vec<String> = disassemble(&bytes, 0x1000, false, true, &Extensions::default())
+    ///     .listing(&symbols)
+    ///     .formatter(GnuFormatter::new())
+    ///     .map(|line| line.to_string())
+    ///     .collect();
+    /// assert_eq!(
+    ///     listing,
+    ///     [
+    ///         "00001000 <main>:",
+    ///         "    1000:\tlui\ta0,0x12345",
+    ///         "    1004:\tjal\t1000 <main>",
+    ///     ]
+    /// );
+    /// ```
+    pub fn formatter(mut self, formatter: impl Formatter + 'a) -> Self {
+        self.formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Starts the region at `start`, which ends at the next mapping symbol, looked up past the
+    /// wrap of addresses at 32 bits too.
+    fn enter_region(&mut self, start: u64) {
+        let wrapped = start as u32 as u64;
+        let base = start - wrapped;
+        let next = match self.symbols.next_mapping(wrapped) {
+            Some(next) => Some(base + next),
+            None => match self.symbols.mapping(0) {
+                Some(first) if first.address == 0 => Some(0),
+                _ => self.symbols.next_mapping(0),
+            }
+            .map(|next| base + (1 << 32) + next),
+        };
+        let end = next.map_or(self.end, |next| next.min(self.end));
+        let mapping = self.symbols.mapping(wrapped);
+        self.region = match mapping.map(|m| &m.mapping) {
+            Some(Mapping::Data) => Region::Data(start),
+            Some(Mapping::Code { isa }) => {
+                Region::Code(self.buffer.region(start, end, isa.as_deref()).peekable())
+            }
+            None => Region::Code(self.buffer.region(start, end, None).peekable()),
+        };
+        self.region_end = end;
+    }
+
+    /// Returns the address of the next instruction or directive, entering the following
+    /// regions until one of them is not empty.
+    fn peek_address(&mut self) -> Option<u64> {
+        loop {
+            let address = match &mut self.region {
+                Region::Code(instructions) => instructions.peek().map(|i| i.address),
+                Region::Data(address) => Some(*address).filter(|a| *a < self.region_end),
+            };
+            if address.is_some() || self.region_end >= self.end {
+                return address;
+            }
+            self.enter_region(self.region_end);
+        }
+    }
+
+    /// Lists an instruction on its own, writing the operand of its relocation or annotating the
+    /// target of a branch, jump or `auipc`.
+    fn single(&mut self, disassembled: Disassembled<'a>) -> ListedInstruction<'a> {
+        if let Some(formatter) = &mut self.formatter {
+            let formatted = match disassembled.instruction {
+                Ok(instruction) => Ok(formatter.format(&instruction, disassembled.address)),
+                Err(error) => Err(error),
+            };
+            let target = formatted
+                .as_ref()
+                .ok()
+                .and_then(|formatted| formatted.target);
+            let (instruction, tokens) = match formatted {
+                Ok(formatted) => (Ok(formatted.text), formatted.tokens),
+                Err(error) => (Err(error), Vec::new()),
+            };
+            return ListedInstruction {
+                address: disassembled.address,
+                bytes: disassembled.bytes,
+                instruction,
+                tokens,
+                target,
+                symbol: target.and_then(|target| self.symbols.lookup(target)),
+                relocation: None,
+            };
+        }
+        if let Some(relocation) = relocation(self.relocations, disassembled.address) {
+            let instruction = disassembled.instruction.map(|i| i.to_string());
+            let substituted = match (&instruction, relocation.operand()) {
+                (Ok(text), Some(operand)) => substitute_immediate(text, &operand),
+                _ => None,
+            };
+            let relocation = substituted.is_none().then_some(relocation);
+            let instruction = substituted.map_or(instruction, Ok);
+            return ListedInstruction {
+                address: disassembled.address,
+                bytes: disassembled.bytes,
+                relocation,
+                tokens: instruction.as_deref().map_or(Vec::new(), tokenize),
+                instruction,
+                target: None,
+                symbol: None,
+            };
+        }
+
+        let offset = match &disassembled.instruction {
+            Ok(ParsedInstruction32::jal(jal)) => Some(jal.imm),
+            Ok(ParsedInstruction32::beq(branch)) => Some(branch.imm),
+            Ok(ParsedInstruction32::bne(branch)) => Some(branch.imm),
+            Ok(ParsedInstruction32::blt(branch)) => Some(branch.imm),
+            Ok(ParsedInstruction32::bge(branch)) => Some(branch.imm),
+            Ok(ParsedInstruction32::bltu(branch)) => Some(branch.imm),
+            Ok(ParsedInstruction32::bgeu(branch)) => Some(branch.imm),
+            Ok(ParsedInstruction32::auipc(auipc)) => Some(auipc.imm),
+            _ => None,
+        };
+        // The instructions decoded are those of RV32, whose addresses wrap at 32 bits.
+        let target = offset
+            .map(|offset| disassembled.address.wrapping_add_signed(offset as i64) as u32 as u64);
+        let instruction = disassembled.instruction.map(|i| i.to_string());
+        ListedInstruction {
+            address: disassembled.address,
+            bytes: disassembled.bytes,
+            tokens: instruction.as_deref().map_or(Vec::new(), tokenize),
+            instruction,
+            target,
+            symbol: target.and_then(|target| self.symbols.lookup(target)),
+            relocation: None,
+        }
+    }
+}
+
+/// Lists the data at `address` as a word when it is aligned and fits before `end`, or as a
+/// byte.
+fn data<'a>(buffer: &Instructions<'a>, address: u64, end: u64) -> ListedData<'a> {
+    let word = address.is_multiple_of(4) && end - address >= 4;
+    let bytes = buffer.bytes_at(address, if word { 4 } else { 1 });
+    let directive = match bytes.try_into() {
+        Ok(word) if buffer.is_big_endian() => Directive::Word(u32::from_be_bytes(word)),
+        Ok(word) => Directive::Word(u32::from_le_bytes(word)),
+        Err(_) => Directive::Byte(bytes[0]),
+    };
+    ListedData {
+        address: address as u32 as u64,
+        bytes,
+        directive,
+    }
+}
+
+/// Returns the relocation at `address` that changes the operands of its instruction.
+fn relocation(relocations: &[Relocation], address: u64) -> Option<&Relocation> {
+    let start = relocations.partition_point(|r| r.address < address);
+    relocations[start..]
+        .iter()
+        .take_while(|r| r.address == address)
+        .find(|r| !r.is_marker())
+}
+
+/// Replaces the immediate ending the `Display` output of an instruction, as in `lui a0, 0` or
+/// `lw a0, 0(a1)`, with `operand`.
+fn substitute_immediate(text: &str, operand: &str) -> Option<String> {
+    let (head, last) = text.rsplit_once(", ")?;
+    match last.find('(') {
+        Some(base) => Some(format!("{}, {}{}", head, operand, &last[base..])),
+        None => Some(format!("{}, {}", head, operand)),
+    }
+}
+
+/// Lists `auipc` and the instruction after it as a pseudo-instruction, if they form one
+/// whose address has a symbol or is relocated to one.
+fn pseudo<'a>(
+    symbols: &'a SymbolMap,
+    relocations: &'a [Relocation],
+    buffer: &Instructions<'a>,
+    first: &Disassembled<'a>,
+    second: &Disassembled,
+) -> Option<ListedInstruction<'a>> {
+    let Ok(ParsedInstruction32::auipc(auipc { rd, imm: upper })) = first.instruction else {
+        return None;
+    };
+    let (mut tokens, lower) = match &second.instruction {
+        Ok(ParsedInstruction32::addi(addi {
+            rd: addi_rd,
+            rs1,
+            imm,
+        })) if *addi_rd == rd && *rs1 == rd => (
+            vec![
+                Token::new(TokenKind::Mnemonic, "la"),
+                Token::new(TokenKind::Separator, " "),
+                Token::new(TokenKind::Register, rd),
+                Token::new(TokenKind::Separator, ", "),
+            ],
+            *imm,
+        ),
+        Ok(ParsedInstruction32::jalr(jalr { rd: link, imm, rs1 }))
+            if *rs1 == rd && is_register(link, 1) && is_register(rd, 1) =>
+        {
+            (
+                vec![
+                    Token::new(TokenKind::Mnemonic, "call"),
+                    Token::new(TokenKind::Separator, " "),
+                ],
+                *imm,
+            )
+        }
+        Ok(ParsedInstruction32::jalr(jalr { rd: link, imm, rs1 }))
+            if *rs1 == rd && is_register(link, 0) && is_register(rd, 6) =>
+        {
+            (
+                vec![
+                    Token::new(TokenKind::Mnemonic, "tail"),
+                    Token::new(TokenKind::Separator, " "),
+                ],
+                *imm,
+            )
+        }
+        _ => return None,
+    };
+
+    let (operand, target) = match relocation(relocations, first.address) {
+        Some(relocation) => {
+            let relocation_types: &[u32] = match second.instruction {
+                Ok(ParsedInstruction32::jalr(_)) => &[R_RISCV_CALL, R_RISCV_CALL_PLT],
+                _ => &[R_RISCV_PCREL_HI20, R_RISCV_GOT_HI20],
+            };
+            if !relocation_types.contains(&relocation.relocation_type) {
+                return None;
+            }
+            (relocation.target(), None)
+        }
+        None => {
+            let address = first
+                .address
+                .wrapping_add_signed(upper as i64 + lower as i64) as u32
+                as u64;
+            (symbols.lookup(address)?.to_string(), Some(address))
+        }
+    };
+    tokens.push(Token::new(TokenKind::Symbol, operand));
+    Some(ListedInstruction {
+        address: first.address,
+        bytes: buffer.bytes_at(first.address, first.bytes.len() + second.bytes.len()),
+        instruction: Ok(tokens.iter().map(|token| token.text.as_str()).collect()),
+        tokens,
+        target,
+        symbol: None,
+        relocation: None,
+    })
+}
+
+impl<'a> Iterator for Listing<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let address = self.peek_address()?;
+        if self.labels.0 != address {
+            self.labels = (address, 0);
+        }
+        if let Some(symbol) = self.symbols.at(address as u32 as u64).get(self.labels.1) {
+            self.labels.1 += 1;
+            return Some(Line::Label(symbol));
+        }
+
+        let instructions = match &mut self.region {
+            Region::Code(instructions) => instructions,
+            Region::Data(next) => {
+                let data = data(&self.buffer, address, self.region_end);
+                *next += data.bytes.len() as u64;
+                return Some(Line::Data(data));
+            }
+        };
+        let first = instructions.next()?;
+        let next_address = first.address.wrapping_add(first.bytes.len() as u64) as u32 as u64;
+        let pseudo = match instructions.peek() {
+            Some(second)
+                if self.pseudo_instructions
+                    && self.formatter.is_none()
+                    && second.address == next_address
+                    && self.symbols.at(next_address).is_empty() =>
+            {
+                pseudo(self.symbols, self.relocations, &self.buffer, &first, second)
+            }
+            _ => None,
+        };
+        if let Some(pseudo) = pseudo {
+            instructions.next();
+            return Some(Line::Instruction(pseudo));
+        }
+        Some(Line::Instruction(self.single(first)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gnu::GnuFormatter;
+    use crate::llvm::LlvmFormatter;
+    use crate::{disassemble, Extensions};
+
+    fn listing(words: &[u32], symbols: &SymbolMap, abi: bool) -> Vec<String> {
+        let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+        disassemble(&bytes, 0x8000_0000, false, abi, &Extensions::default())
+            .listing(symbols)
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    fn symbols() -> SymbolMap {
+        [
+            Symbol::function("_start", 0x8000_0000, 0x10),
+            Symbol::function("loop", 0x8000_0010, 0x8),
+            Symbol::new("message", 0x8000_1000),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_branch_annotations() {
+        let words = [
+            0x00000013, // addi zero, zero, 0
+            0x00b50863, // beq a0, a1, 16
+            0x00000013, // addi zero, zero, 0
+            0x00000013, // addi zero, zero, 0
+            0xfe000ee3, // beq zero, zero, -4
+            0xffdff0ef, // jal ra, -4
+        ];
+        assert_eq!(
+            listing(&words, &symbols(), true),
+            [
+                "80000000 <_start>:",
+                "80000000:\taddi zero, zero, 0",
+                "80000004:\tbeq a0, a1, 16 <loop+0x4>",
+                "80000008:\taddi zero, zero, 0",
+                "8000000c:\taddi zero, zero, 0",
+                "80000010 <loop>:",
+                "80000010:\tbeq zero, zero, -4 <_start+0xc>",
+                "80000014:\tjal ra, -4 <loop>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wrapping_branch_annotations() {
+        // jal ra, -4 at address zero, which targets the last word of the 32 bit address space.
+        let symbols: SymbolMap = [Symbol::function("top", 0xffff_fffc, 4)]
+            .into_iter()
+            .collect();
+        let lines: Vec<String> = disassemble(
+            &0xffdff0efu32.to_le_bytes(),
+            0,
+            false,
+            true,
+            &Extensions::default(),
+        )
+        .listing(&symbols)
+        .map(|line| line.to_string())
+        .collect();
+        assert_eq!(lines, ["       0:\tjal ra, -4 <top>"]);
+    }
+
+    #[test]
+    fn test_pseudo_instructions() {
+        let words = [
+            0x00001517, // auipc a0, 4096
+            0x00050513, // addi a0, a0, 0
+            0x00000097, // auipc ra, 0
+            0x008080e7, // jalr ra, 8(ra)
+            0x00000317, // auipc t1, 0
+            0xff830067, // jalr zero, -8(t1)
+        ];
+        let expected = [
+            "80000000 <_start>:",
+            "80000000:\tla a0, message",
+            "80000008:\tcall loop",
+            "80000010 <loop>:",
+            "80000010:\ttail _start+0x8",
+        ];
+        assert_eq!(listing(&words, &symbols(), true), expected);
+        assert_eq!(
+            listing(&words, &symbols(), false)[2],
+            "80000008:\tcall loop"
+        );
+    }
+
+    #[test]
+    fn test_pseudo_instruction_bytes() {
+        let bytes = [0x97, 0x00, 0x00, 0x00, 0xe7, 0x80, 0x80, 0x00];
+        let symbols: SymbolMap = [Symbol::function("f", 0x8, 4)].into_iter().collect();
+        let lines: Vec<Line> = disassemble(&bytes, 0, false, true, &Extensions::default())
+            .listing(&symbols)
+            .collect();
+        assert_eq!(
+            lines,
+            [Line::Instruction(ListedInstruction {
+                address: 0,
+                bytes: &bytes,
+                instruction: Ok("call f".to_string()),
+                tokens: vec![
+                    Token::new(TokenKind::Mnemonic, "call"),
+                    Token::new(TokenKind::Separator, " "),
+                    Token::new(TokenKind::Symbol, "f"),
+                ],
+                target: Some(8),
+                symbol: None,
+                relocation: None,
+            })]
+        );
+    }
+
+    #[test]
+    fn test_unsymbolised_pairs() {
+        // Without a symbol, or with a label on the second instruction, the pair is listed as
+        // two instructions.
+        let words = [0x00000097, 0x008080e7, 0x00000097, 0x008080e7];
+        let symbols: SymbolMap = [Symbol::new("second", 0x8000_000c)].into_iter().collect();
+        assert_eq!(
+            listing(&words, &symbols, true),
+            [
+                "80000000:\tauipc ra, 0",
+                "80000004:\tjalr ra, 8(ra)",
+                "80000008:\tauipc ra, 0",
+                "8000000c <second>:",
+                "8000000c:\tjalr ra, 8(ra)",
+            ]
+        );
+        assert_eq!(
+            listing(&words, &SymbolMap::new(), true)[..2],
+            ["80000000:\tauipc ra, 0", "80000004:\tjalr ra, 8(ra)"]
+        );
+    }
+
+    #[test]
+    fn test_without_pseudo_instructions() {
+        let bytes = [0x97, 0x00, 0x00, 0x00, 0xe7, 0x80, 0x80, 0x00];
+        let symbols: SymbolMap = [Symbol::function("f", 0x8, 4)].into_iter().collect();
+        let listing: Vec<String> = disassemble(&bytes, 0, false, true, &Extensions::default())
+            .listing(&symbols)
+            .pseudo_instructions(false)
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(
+            listing,
+            ["       0:\tauipc ra, 0", "       4:\tjalr ra, 8(ra)"]
+        );
+    }
+
+    #[test]
+    fn test_formatter() {
+        let words: [u32; 5] = [
+            0x00001517, // auipc a0, 4096
+            0x00050513, // addi a0, a0, 0
+            0x00000097, // auipc ra, 0
+            0x008080e7, // jalr ra, 8(ra)
+            0xfe000ee3, // beq zero, zero, -4
+        ];
+        let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+        let lines: Vec<String> =
+            disassemble(&bytes, 0x8000_0000, false, true, &Extensions::default())
+                .listing(&symbols())
+                .formatter(GnuFormatter::new())
+                .map(|line| line.to_string())
+                .collect();
+        assert_eq!(
+            lines,
+            [
+                "80000000 <_start>:",
+                "80000000:\tauipc\ta0,0x1",
+                "80000004:\tmv\ta0,a0",
+                "80000008:\tauipc\tra,0x0",
+                "8000000c:\tjalr\t8(ra) # 80000010 <loop>",
+                "80000010 <loop>:",
+                "80000010:\tbeqz\tzero,8000000c <_start+0xc>",
+            ]
+        );
+        let lines: Vec<String> =
+            disassemble(&bytes, 0x8000_0000, false, true, &Extensions::default())
+                .listing(&symbols())
+                .formatter(LlvmFormatter::new())
+                .map(|line| line.to_string())
+                .collect();
+        assert_eq!(lines[1], "80000000:\tauipc\ta0, 1");
+        assert_eq!(lines[4], "8000000c:\tjalr\t8(ra)");
+        assert_eq!(lines[6], "80000010:\tbeqz\tzero, 0x8000000c <_start+0xc>");
+    }
+
+    #[test]
+    fn test_errors_are_listed() {
+        // The reserved parcels 0xffff are skipped one at a time.
+        let lines = listing(&[0xffffffff], &symbols(), true);
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("80000000:\t"));
+        assert!(lines[2].starts_with("80000002:\t"));
+    }
+
+    fn listing_bytes(bytes: &[u8], is_big_endian: bool, symbols: &SymbolMap) -> Vec<String> {
+        disassemble(bytes, 0, is_big_endian, true, &Extensions::default())
+            .listing(symbols)
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_data_regions() {
+        let bytes = [
+            0x13, 0x00, 0x00, 0x00, // addi zero, zero, 0
+            0x78, 0x56, 0x34, 0x12, // .word 0x12345678
+            0xff, 0xff, 0xff, 0xff, // .word 0xffffffff
+            0xaa, 0xbb, // .byte 0xaa, 0xbb
+            0x13, 0x00, 0x00, 0x00, // addi zero, zero, 0
+        ];
+        let symbols: SymbolMap = [
+            Symbol::new("$x", 0),
+            Symbol::new("$d", 4),
+            Symbol::new("table", 4),
+            Symbol::new("$x", 0xe),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            listing_bytes(&bytes, false, &symbols),
+            [
+                "       0:\taddi zero, zero, 0",
+                "00000004 <table>:",
+                "       4:\t.word 0x12345678",
+                "       8:\t.word 0xffffffff",
+                "       c:\t.byte 0xaa",
+                "       d:\t.byte 0xbb",
+                "       e:\taddi zero, zero, 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wrapping_addresses() {
+        // la a0, message across the end of the address space, followed by its data.
+        let bytes = [
+            0x17, 0x05, 0x00, 0x00, // auipc a0, 0
+            0x13, 0x05, 0x85, 0x00, // addi a0, a0, 8
+            0x78, 0x56, 0x34, 0x12, // .word 0x12345678
+        ];
+        let mut symbols: SymbolMap = [Symbol::new("message", 4)].into_iter().collect();
+        symbols.insert_mapping(0xffff_fffc, Mapping::Code { isa: None });
+        symbols.insert_mapping(4, Mapping::Data);
+        let lines: Vec<String> =
+            disassemble(&bytes, 0xffff_fffc, false, true, &Extensions::default())
+                .listing(&symbols)
+                .map(|line| line.to_string())
+                .collect();
+        assert_eq!(
+            lines,
+            [
+                "fffffffc:\tla a0, message",
+                "00000004 <message>:",
+                "       4:\t.word 0x12345678",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_data_regions_big_endian() {
+        let bytes = [0x12, 0x34, 0x56, 0x78, 0x00, 0x00, 0x00, 0x13];
+        let mut symbols = SymbolMap::new();
+        symbols.insert_mapping(0, Mapping::Data);
+        symbols.insert_mapping(4, Mapping::Code { isa: None });
+        assert_eq!(
+            listing_bytes(&bytes, true, &symbols),
+            [
+                "       0:\t.word 0x12345678",
+                "       4:\taddi zero, zero, 0"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unaligned_data_region() {
+        // Data starting between words is listed in bytes up to the next word.
+        let bytes = [0x01, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let mut symbols = SymbolMap::new();
+        symbols.insert_mapping(0, Mapping::Code { isa: None });
+        symbols.insert_mapping(2, Mapping::Data);
+        let lines: Vec<Line> = disassemble(&bytes, 0, false, true, &Extensions::default())
+            .listing(&symbols)
+            .collect();
+        let data: Vec<(u64, Directive)> = lines
+            .iter()
+            .filter_map(|line| match line {
+                Line::Data(data) => Some((data.address, data.directive)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            data,
+            [
+                (2, Directive::Byte(0)),
+                (3, Directive::Byte(0)),
+                (4, Directive::Byte(0)),
+                (5, Directive::Byte(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_isa_switches() {
+        // c.zext.b a0 needs Zcb, which `$xrv32i_zcb` enables until the next mapping symbol.
+        let bytes = [0x61, 0x9d, 0x61, 0x9d, 0x61, 0x9d, 0x61, 0x9d];
+        let symbols: SymbolMap = [
+            Symbol::new("$x", 0),
+            Symbol::new("$xrv32i2p1_zcb1p0", 2),
+            Symbol::new("$x", 4),
+            Symbol::new("$xrv32q", 6),
+        ]
+        .into_iter()
+        .collect();
+        let decoded: Vec<bool> = disassemble(&bytes, 0, false, true, &Extensions::default())
+            .listing(&symbols)
+            .map(|line| match line {
+                Line::Instruction(instruction) => instruction.instruction.is_ok(),
+                line => panic!("unexpected line {}", line),
+            })
+            .collect();
+        assert_eq!(decoded, [false, true, false, false]);
+    }
+
+    #[test]
+    fn test_isa_switch_without_compressed_instructions() {
+        // Without C or Zc*, the parcels of a 16 bit encoding are decoded as a whole word.
+        let bytes = [0x61, 0x9d, 0x61, 0x9d];
+        let symbols: SymbolMap = [Symbol::new("$xrv32i", 0)].into_iter().collect();
+        let lines: Vec<Line> = disassemble(&bytes, 0, false, true, &Extensions::default())
+            .listing(&symbols)
+            .collect();
+        assert_eq!(lines.len(), 1);
+        assert!(matches!(&lines[0], Line::Instruction(i) if i.bytes.len() == 4));
+    }
+
+    fn relocation(address: u64, relocation_type: u32, symbol: &str) -> Relocation {
+        Relocation {
+            address,
+            relocation_type,
+            symbol: symbol.to_string(),
+            addend: 0,
+        }
+    }
+
+    fn relocated_listing(words: &[u32], relocations: &[Relocation]) -> Vec<String> {
+        let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+        let symbols: SymbolMap = [Symbol::function("main", 0, 0x24)].into_iter().collect();
+        disassemble(&bytes, 0, false, true, &Extensions::default())
+            .listing(&symbols)
+            .relocations(relocations)
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_relocated_operands() {
+        use crate::relocations::*;
+        let words = [
+            0x00000097, // auipc ra, 0
+            0x000080e7, // jalr ra, 0(ra)
+            0x00000517, // auipc a0, 0
+            0x00050513, // addi a0, a0, 0
+            0x000005b7, // lui a1, 0
+            0x0005a603, // lw a2, 0(a1)
+            0x00b50063, // beq a0, a1, 0
+            0x0000006f, // jal zero, 0
+            0x00000317, // auipc t1, 0
+            0x00030067, // jalr zero, 0(t1)
+        ];
+        let relocations = [
+            relocation(0x0, R_RISCV_CALL_PLT, "printf"),
+            relocation(0x0, R_RISCV_RELAX, ""),
+            relocation(0x8, R_RISCV_PCREL_HI20, "message"),
+            relocation(0xc, R_RISCV_PCREL_LO12_I, ".Lpcrel_hi0"),
+            relocation(0x10, R_RISCV_HI20, "message"),
+            relocation(0x14, R_RISCV_LO12_I, "message"),
+            relocation(0x18, R_RISCV_BRANCH, "done"),
+            relocation(0x1c, R_RISCV_JAL, "exit"),
+            relocation(0x20, R_RISCV_CALL, "puts"),
+        ];
+        assert_eq!(
+            relocated_listing(&words, &relocations),
+            [
+                "00000000 <main>:",
+                "       0:\tcall printf",
+                "       8:\tla a0, message",
+                "      10:\tlui a1, %hi(message)",
+                "      14:\tlw a2, %lo(message)(a1)",
+                "      18:\tbeq a0, a1, done",
+                "      1c:\tjal zero, exit",
+                "      20:\ttail puts",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_annotated_relocations() {
+        use crate::relocations::*;
+        // A call relocation without its jalr, and a relocation with no operand of its own.
+        let words = [
+            0x00000097, // auipc ra, 0
+            0x00000013, // addi zero, zero, 0
+        ];
+        let relocations = [
+            relocation(0x0, R_RISCV_CALL_PLT, "printf"),
+            relocation(0x4, R_RISCV_TPREL_ADD, "counter"),
+        ];
+        assert_eq!(
+            relocated_listing(&words, &relocations),
+            [
+                "00000000 <main>:",
+                "       0:\tauipc ra, 0 # R_RISCV_CALL_PLT printf",
+                "       4:\taddi zero, zero, 0 # R_RISCV_TPREL_ADD counter",
+            ]
+        );
+    }
+}
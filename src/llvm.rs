@@ -0,0 +1,138 @@
+//! Formatting of instructions in the syntax of `llvm-objdump -d`, for listings that are compared
+//! with those of the LLVM toolchain.
+//!
+//! llvm-objdump separates the mnemonic from its operands with a tab and the operands with `", "`,
+//! prints every immediate in decimal, including those of `lui` and `auipc`, and gives branch
+//! and jump targets as addresses with a `0x` prefix. It prints the same aliases as GNU objdump,
+//! except for those of the floating point CSRs, and leaves a tab after the mnemonic of an
+//! instruction without operands unless it is an alias, as in `ecall\t` but `ret`. The
+//! conventions followed are those of llvm-objdump 14, without `--mattr` options.
+//!
+//! [`FormatOptions`] change the radix of immediates, the case of mnemonics, the separator between
+//! operands and the names of registers.
+//!
+//! ### Example
+//!
+//! ```
+//! use risc_v_disassembler::format::Formatter;
+//! use risc_v_disassembler::llvm::LlvmFormatter;
+//! use risc_v_disassembler::parse;
+//!
+//! let mut formatter = LlvmFormatter::new();
+//! // lui a0, 0x12345; beqz a0, -4
+//! let lui = parse(&[0x37, 0x55, 0x34, 0x12], false, true).unwrap();
+//! let beqz = parse(&[0xe3, 0x0e, 0x05, 0xfe], false, true).unwrap();
+//!
+//! assert_eq!(formatter.format(&lui, 0x100).text, "lui\ta0, 74565");
+//! let formatted = formatter.format(&beqz, 0x104);
+//! assert_eq!(formatted.text, "beqz\ta0, 0x100");
+//! assert_eq!(formatted.target, Some(0x100));
+//! ```
+
+use crate::format::{parts, FormatOptions, FormattedInstruction, Formatter, Operand, Parts, Style};
+use crate::parsed_instructions::*;
+use crate::ParsedInstruction32;
+
+/// Formats instructions as llvm-objdump does.
+#[derive(Debug, Default, Clone)]
+pub struct LlvmFormatter {
+    options: FormatOptions,
+}
+
+impl Formatter for LlvmFormatter {
+    fn format(&mut self, instruction: &ParsedInstruction32, address: u64) -> FormattedInstruction {
+        let mut parts = match instruction {
+            ParsedInstruction32::lui(lui { rd, imm })
+            | ParsedInstruction32::auipc(auipc { rd, imm }) => {
+                let mut parts = Parts::from_display(&instruction.to_string());
+                parts.operands = vec![
+                    Operand::Register(rd.to_string()),
+                    Operand::Immediate((*imm as u32 >> 12) as i64),
+                ];
+                parts
+            }
+            _ => parts(instruction, address, "unknown"),
+        };
+        // Aliases are printed without the tab of the instructions they stand for.
+        parts.tab_without_operands = !matches!(
+            parts.mnemonic.as_str(),
+            "ret" | "nop" | "fence" | "sfence.vma"
+        );
+        parts.render(&self.options, Style::Llvm)
+    }
+}
+
+impl LlvmFormatter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_options(options: FormatOptions) -> Self {
+        LlvmFormatter { options }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::{Radix, RegisterNames};
+    use crate::parse;
+
+    fn text(word: u32) -> String {
+        let instruction = parse(&word.to_le_bytes(), false, true).unwrap();
+        LlvmFormatter::new().format(&instruction, 0).text
+    }
+
+    #[test]
+    fn test_operands() {
+        assert_eq!(text(0x12345537), "lui\ta0, 74565");
+        assert_eq!(text(0x80000517), "auipc\ta0, 524288");
+        assert_eq!(text(0x00359513), "slli\ta0, a1, 3");
+        assert_eq!(text(0xffc5a503), "lw\ta0, -4(a1)");
+        assert_eq!(text(0x00000073), "ecall\t");
+        assert_eq!(text(0x0000100f), "fence.i\t");
+        assert_eq!(text(0x0010000f), "fence\tunknown, w");
+        assert_eq!(text(0x0330000f), "fence\trw, rw");
+    }
+
+    #[test]
+    fn test_targets() {
+        // j -8; bne a0, a1, 8
+        let mut formatter = LlvmFormatter::new();
+        let j = parse(&0xff9ff06fu32.to_le_bytes(), false, true).unwrap();
+        let bne = parse(&0x00b51463u32.to_le_bytes(), false, true).unwrap();
+        let formatted = formatter.format(&j, 0x1000);
+        assert_eq!(formatted.text, "j\t0xff8");
+        assert_eq!(formatted.target, Some(0xff8));
+        assert_eq!(formatter.format(&bne, 0x1004).text, "bne\ta0, a1, 0x100c");
+        // Backward from address zero, wrapping at 32 bits.
+        let formatted = formatter.format(&j, 0);
+        assert_eq!(formatted.text, "j\t0xfffffff8");
+        assert_eq!(formatted.target, Some(0xffff_fff8));
+    }
+
+    #[test]
+    fn test_aliases() {
+        assert_eq!(text(0x00000013), "nop");
+        assert_eq!(text(0x00008067), "ret");
+        assert_eq!(text(0x0ff0000f), "fence");
+        assert_eq!(text(0xc0001073), "unimp\t");
+        assert_eq!(text(0x00302573), "csrr\ta0, fcsr");
+        assert_eq!(text(0x00359073), "csrw\tfcsr, a1");
+        assert_eq!(text(0x7c002573), "csrr\ta0, 1984");
+        assert_eq!(text(0xc0002573), "rdcycle\ta0");
+        assert_eq!(text(0x0ff5f513), "andi\ta0, a1, 255");
+    }
+
+    #[test]
+    fn test_options() {
+        let instruction = parse(&0x12345537u32.to_le_bytes(), false, false).unwrap();
+        let mut formatter = LlvmFormatter::with_options(FormatOptions {
+            radix: Radix::Hex,
+            uppercase: true,
+            separator: ",".to_string(),
+            registers: RegisterNames::Abi,
+        });
+        assert_eq!(formatter.format(&instruction, 0).text, "LUI\ta0,0x12345");
+    }
+}
@@ -0,0 +1,479 @@
+use crate::instructions::{parsed_instructions::*, ParsedInstruction32};
+use crate::registers::Register;
+use crate::{DisassemblerError, Extensions};
+
+/// Parses the AMO opcode. funct7 holds funct5 in its upper bits followed by the `aq` and `rl`
+/// bits, and funct3 selects the access width. The doubleword forms of the base A extension and
+/// `amocas.q` only exist on RV64 and are rejected.
+pub(crate) fn parse_atomic32<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    funct7: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    if !extensions.has_atomics() {
+        return Err(DisassemblerError::InvalidOpcode(0b0101111));
+    }
+
+    let funct5 = *funct7 >> 2;
+    let ordering = MemoryOrdering::from_aq_rl(*funct7);
+
+    match funct5 {
+        0b00010 | 0b00011 if extensions.zalrsc => {
+            parse_atomic32_lrsc::<T>(rd, funct3, rs1, rs2, funct5, ordering)
+        }
+        0b00101 if extensions.zacas => {
+            parse_atomic32_cas::<T>(rd, funct3, rs1, rs2, ordering, extensions)
+        }
+        0b00010 | 0b00011 | 0b00101 => Err(DisassemblerError::InvalidFunct7(*funct7)),
+        _ if extensions.zaamo => {
+            parse_atomic32_amo::<T>(rd, funct3, rs1, rs2, funct7, ordering, extensions)
+        }
+        _ => Err(DisassemblerError::InvalidFunct7(*funct7)),
+    }
+}
+
+fn parse_atomic32_lrsc<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    funct5: u8,
+    ordering: MemoryOrdering,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    if *funct3 != 0b010 {
+        return Err(DisassemblerError::InvalidFunct3(*funct3));
+    }
+
+    let rd = T::try_from_u8(*rd)?.as_str();
+    let rs1 = T::try_from_u8(*rs1)?.as_str();
+
+    match funct5 {
+        0b00010 => {
+            if *rs2 != 0 {
+                return Err(DisassemblerError::InvalidRs2Field(*rs2));
+            }
+            Ok(ParsedInstruction32::lr_w(lr_w { rd, rs1, ordering }))
+        }
+        _ => Ok(ParsedInstruction32::sc_w(sc_w {
+            rd,
+            rs1,
+            rs2: T::try_from_u8(*rs2)?.as_str(),
+            ordering,
+        })),
+    }
+}
+
+/// Parses the Zacas compare-and-swap instructions. `amocas.d` reads and writes the even/odd
+/// register pairs starting at rd and rs2, so both must be even numbered.
+fn parse_atomic32_cas<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    ordering: MemoryOrdering,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    if *funct3 == 0b011 {
+        if !rd.is_multiple_of(2) {
+            return Err(DisassemblerError::InvalidRegisterPair(*rd));
+        }
+        if !rs2.is_multiple_of(2) {
+            return Err(DisassemblerError::InvalidRegisterPair(*rs2));
+        }
+    }
+
+    let rd = T::try_from_u8(*rd)?.as_str();
+    let rs1 = T::try_from_u8(*rs1)?.as_str();
+    let rs2 = T::try_from_u8(*rs2)?.as_str();
+
+    match funct3 {
+        0b010 => Ok(ParsedInstruction32::amocas_w(amocas_w {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        0b011 => Ok(ParsedInstruction32::amocas_d(amocas_d {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        0b000 if extensions.zabha => Ok(ParsedInstruction32::amocas_b(amocas_b {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        0b001 if extensions.zabha => Ok(ParsedInstruction32::amocas_h(amocas_h {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        _ => Err(DisassemblerError::InvalidFunct3(*funct3)),
+    }
+}
+
+/// Parses the Zaamo atomic memory operations, and their byte and halfword forms from Zabha.
+fn parse_atomic32_amo<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    funct7: &u8,
+    ordering: MemoryOrdering,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    match funct3 {
+        0b010 => {}
+        0b000 | 0b001 if extensions.zabha => {}
+        _ => return Err(DisassemblerError::InvalidFunct3(*funct3)),
+    }
+
+    let rd = T::try_from_u8(*rd)?.as_str();
+    let rs1 = T::try_from_u8(*rs1)?.as_str();
+    let rs2 = T::try_from_u8(*rs2)?.as_str();
+
+    match (*funct7 >> 2, *funct3) {
+        (0b00001, 0b010) => Ok(ParsedInstruction32::amoswap_w(amoswap_w {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b00000, 0b010) => Ok(ParsedInstruction32::amoadd_w(amoadd_w {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b00100, 0b010) => Ok(ParsedInstruction32::amoxor_w(amoxor_w {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b01100, 0b010) => Ok(ParsedInstruction32::amoand_w(amoand_w {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b01000, 0b010) => Ok(ParsedInstruction32::amoor_w(amoor_w {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b10000, 0b010) => Ok(ParsedInstruction32::amomin_w(amomin_w {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b10100, 0b010) => Ok(ParsedInstruction32::amomax_w(amomax_w {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b11000, 0b010) => Ok(ParsedInstruction32::amominu_w(amominu_w {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b11100, 0b010) => Ok(ParsedInstruction32::amomaxu_w(amomaxu_w {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b00001, 0b000) => Ok(ParsedInstruction32::amoswap_b(amoswap_b {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b00000, 0b000) => Ok(ParsedInstruction32::amoadd_b(amoadd_b {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b00100, 0b000) => Ok(ParsedInstruction32::amoxor_b(amoxor_b {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b01100, 0b000) => Ok(ParsedInstruction32::amoand_b(amoand_b {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b01000, 0b000) => Ok(ParsedInstruction32::amoor_b(amoor_b {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b10000, 0b000) => Ok(ParsedInstruction32::amomin_b(amomin_b {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b10100, 0b000) => Ok(ParsedInstruction32::amomax_b(amomax_b {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b11000, 0b000) => Ok(ParsedInstruction32::amominu_b(amominu_b {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b11100, 0b000) => Ok(ParsedInstruction32::amomaxu_b(amomaxu_b {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b00001, 0b001) => Ok(ParsedInstruction32::amoswap_h(amoswap_h {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b00000, 0b001) => Ok(ParsedInstruction32::amoadd_h(amoadd_h {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b00100, 0b001) => Ok(ParsedInstruction32::amoxor_h(amoxor_h {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b01100, 0b001) => Ok(ParsedInstruction32::amoand_h(amoand_h {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b01000, 0b001) => Ok(ParsedInstruction32::amoor_h(amoor_h {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b10000, 0b001) => Ok(ParsedInstruction32::amomin_h(amomin_h {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b10100, 0b001) => Ok(ParsedInstruction32::amomax_h(amomax_h {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b11000, 0b001) => Ok(ParsedInstruction32::amominu_h(amominu_h {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        (0b11100, 0b001) => Ok(ParsedInstruction32::amomaxu_h(amomaxu_h {
+            rd,
+            rs1,
+            rs2,
+            ordering,
+        })),
+        _ => Err(DisassemblerError::InvalidFunct7(*funct7)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registers::NumberedRegister;
+
+    fn atomics() -> Extensions {
+        Extensions {
+            zalrsc: true,
+            zaamo: true,
+            zabha: true,
+            zacas: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_atomic32_lr_w() {
+        let result =
+            parse_atomic32::<NumberedRegister>(&1, &0b010, &2, &0, &0b0001010, &atomics()).unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::lr_w(lr_w {
+                rd: "x1",
+                rs1: "x2",
+                ordering: MemoryOrdering::Acquire
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_atomic32_lr_w_invalid_rs2() {
+        let result = parse_atomic32::<NumberedRegister>(&1, &0b010, &2, &3, &0b0001000, &atomics());
+        assert_eq!(result.err(), Some(DisassemblerError::InvalidRs2Field(3)));
+    }
+
+    #[test]
+    fn test_parse_atomic32_sc_w() {
+        let result =
+            parse_atomic32::<NumberedRegister>(&1, &0b010, &2, &3, &0b0001101, &atomics()).unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::sc_w(sc_w {
+                rd: "x1",
+                rs1: "x2",
+                rs2: "x3",
+                ordering: MemoryOrdering::Release
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_atomic32_amoadd_w() {
+        let result =
+            parse_atomic32::<NumberedRegister>(&1, &0b010, &2, &3, &0b0000011, &atomics()).unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::amoadd_w(amoadd_w {
+                rd: "x1",
+                rs1: "x2",
+                rs2: "x3",
+                ordering: MemoryOrdering::AcquireRelease
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_atomic32_amomaxu_h() {
+        let result =
+            parse_atomic32::<NumberedRegister>(&1, &0b001, &2, &3, &0b1110000, &atomics()).unwrap();
+        assert!(matches!(result, ParsedInstruction32::amomaxu_h { .. }));
+    }
+
+    #[test]
+    fn test_parse_atomic32_amoswap_b_without_zabha() {
+        let zaamo = Extensions {
+            zaamo: true,
+            ..Default::default()
+        };
+        let result = parse_atomic32::<NumberedRegister>(&1, &0b000, &2, &3, &0b0000100, &zaamo);
+        assert_eq!(result.err(), Some(DisassemblerError::InvalidFunct3(0b000)));
+    }
+
+    #[test]
+    fn test_parse_atomic32_amoadd_d_rejected() {
+        let result = parse_atomic32::<NumberedRegister>(&1, &0b011, &2, &3, &0b0000000, &atomics());
+        assert_eq!(result.err(), Some(DisassemblerError::InvalidFunct3(0b011)));
+    }
+
+    #[test]
+    fn test_parse_atomic32_invalid_funct5() {
+        let result = parse_atomic32::<NumberedRegister>(&1, &0b010, &2, &3, &0b1111100, &atomics());
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidFunct7(0b1111100))
+        );
+    }
+
+    #[test]
+    fn test_parse_atomic32_amocas_d() {
+        let result =
+            parse_atomic32::<NumberedRegister>(&10, &0b011, &2, &12, &0b0010100, &atomics())
+                .unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::amocas_d(amocas_d {
+                rd: "x10",
+                rs1: "x2",
+                rs2: "x12",
+                ordering: MemoryOrdering::Relaxed
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_atomic32_amocas_d_odd_register_pair() {
+        let result =
+            parse_atomic32::<NumberedRegister>(&10, &0b011, &2, &13, &0b0010100, &atomics());
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidRegisterPair(13))
+        );
+    }
+
+    #[test]
+    fn test_parse_atomic32_amocas_q_rejected() {
+        let result =
+            parse_atomic32::<NumberedRegister>(&10, &0b100, &2, &12, &0b0010100, &atomics());
+        assert_eq!(result.err(), Some(DisassemblerError::InvalidFunct3(0b100)));
+    }
+
+    #[test]
+    fn test_parse_atomic32_zalrsc_only_rejects_amo() {
+        let zalrsc = Extensions {
+            zalrsc: true,
+            ..Default::default()
+        };
+        let result = parse_atomic32::<NumberedRegister>(&1, &0b010, &2, &3, &0b0000000, &zalrsc);
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidFunct7(0b0000000))
+        );
+    }
+
+    #[test]
+    fn test_parse_atomic32_zaamo_only_rejects_lr() {
+        let zaamo = Extensions {
+            zaamo: true,
+            ..Default::default()
+        };
+        let result = parse_atomic32::<NumberedRegister>(&1, &0b010, &2, &0, &0b0001000, &zaamo);
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidFunct7(0b0001000))
+        );
+    }
+
+    #[test]
+    fn test_parse_atomic32_without_extension() {
+        let result = parse_atomic32::<NumberedRegister>(
+            &1,
+            &0b010,
+            &2,
+            &3,
+            &0b0000000,
+            &Extensions::default(),
+        );
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidOpcode(0b0101111))
+        );
+    }
+}
@@ -0,0 +1,455 @@
+use crate::instructions::{parsed_instructions::*, ParsedInstruction32};
+use crate::parser::hint::hint;
+use crate::registers::Register;
+use crate::{DisassemblerError, Extensions};
+
+/// Maps the three bit `rd'`, `rs1'` and `rs2'` fields to the registers `x8` to `x15`.
+fn compressed_register<T: Register>(register: u8) -> Result<&'static str, DisassemblerError> {
+    Ok(T::try_from_u8(register + 8)?.as_str())
+}
+
+/// Maps the three bit `r1s'` and `r2s'` fields of `cm.mvsa01` and `cm.mva01s` to the saved
+/// registers `s0` to `s7`.
+fn saved_register<T: Register>(register: u8) -> Result<&'static str, DisassemblerError> {
+    Ok(T::try_from_u8(saved_register_number(register))?.as_str())
+}
+
+/// Parses the instructions sharing the CA layout: the Zcb byte and halfword loads and stores,
+/// the Zcb arithmetic instructions, and the Zcmp register moves.
+pub(crate) fn parse_catype16<T: Register>(
+    opcode: &u8,
+    funct6: &u8,
+    rs1: &u8,
+    funct2: &u8,
+    rs2: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    match (opcode, funct6) {
+        (0b00, _) if extensions.zcb => parse_catype16_memory::<T>(funct6, rs1, funct2, rs2),
+        (0b01, 0b100111) if extensions.zcb => parse_catype16_arithmetic::<T>(rs1, funct2, rs2),
+        (0b10, 0b101011) if extensions.zcmp => parse_catype16_move::<T>(rs1, funct2, rs2),
+        _ => Err(DisassemblerError::InvalidFunct6(*funct6)),
+    }
+}
+
+/// Parses the Zcb loads and stores. Bits 6-5 hold the offset, except for the halfword loads,
+/// where bit 6 selects between `c.lhu` and `c.lh`, and `c.sh`, where it must be zero.
+fn parse_catype16_memory<T: Register>(
+    funct6: &u8,
+    rs1: &u8,
+    funct2: &u8,
+    rs2: &u8,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let rs1 = compressed_register::<T>(*rs1)?;
+    let byte_offset = ((funct2 & 0b01) << 1 | funct2 >> 1) as i32;
+    let halfword_offset = ((funct2 & 0b01) << 1) as i32;
+
+    match (funct6, funct2 >> 1) {
+        (0b100000, _) => Ok(ParsedInstruction32::c_lbu(c_lbu {
+            rd: compressed_register::<T>(*rs2)?,
+            rs1,
+            imm: byte_offset,
+        })),
+        (0b100001, 0) => Ok(ParsedInstruction32::c_lhu(c_lhu {
+            rd: compressed_register::<T>(*rs2)?,
+            rs1,
+            imm: halfword_offset,
+        })),
+        (0b100001, _) => Ok(ParsedInstruction32::c_lh(c_lh {
+            rd: compressed_register::<T>(*rs2)?,
+            rs1,
+            imm: halfword_offset,
+        })),
+        (0b100010, _) => Ok(ParsedInstruction32::c_sb(c_sb {
+            rs1,
+            rs2: compressed_register::<T>(*rs2)?,
+            imm: byte_offset,
+        })),
+        (0b100011, 0) => Ok(ParsedInstruction32::c_sh(c_sh {
+            rs1,
+            rs2: compressed_register::<T>(*rs2)?,
+            imm: halfword_offset,
+        })),
+        (0b100011, _) => Err(DisassemblerError::InvalidFunct2(*funct2)),
+        _ => Err(DisassemblerError::InvalidFunct6(*funct6)),
+    }
+}
+
+/// Parses the Zcb arithmetic instructions. Funct2 `11` selects the unary instructions, which
+/// use the rs2 field as a further selector. `c.zext.w` only exists on RV64 and is rejected.
+fn parse_catype16_arithmetic<T: Register>(
+    rs1: &u8,
+    funct2: &u8,
+    rs2: &u8,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let rd = compressed_register::<T>(*rs1)?;
+
+    match (funct2, rs2) {
+        (0b10, _) => Ok(ParsedInstruction32::c_mul(c_mul {
+            rd,
+            rs2: compressed_register::<T>(*rs2)?,
+        })),
+        (0b11, 0b000) => Ok(ParsedInstruction32::c_zext_b(c_zext_b { rd })),
+        (0b11, 0b001) => Ok(ParsedInstruction32::c_sext_b(c_sext_b { rd })),
+        (0b11, 0b010) => Ok(ParsedInstruction32::c_zext_h(c_zext_h { rd })),
+        (0b11, 0b011) => Ok(ParsedInstruction32::c_sext_h(c_sext_h { rd })),
+        (0b11, 0b101) => Ok(ParsedInstruction32::c_not(c_not { rd })),
+        (0b11, _) => Err(DisassemblerError::InvalidRs2Field(*rs2)),
+        _ => Err(DisassemblerError::InvalidFunct2(*funct2)),
+    }
+}
+
+/// Parses `cm.mvsa01` and `cm.mva01s`. The two saved registers of `cm.mvsa01` must differ.
+fn parse_catype16_move<T: Register>(
+    r1s: &u8,
+    funct2: &u8,
+    r2s: &u8,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    match funct2 {
+        0b01 => {
+            if r1s == r2s {
+                return Err(DisassemblerError::InvalidRs2Field(*r2s));
+            }
+            Ok(ParsedInstruction32::cm_mvsa01(cm_mvsa01 {
+                r1s: saved_register::<T>(*r1s)?,
+                r2s: saved_register::<T>(*r2s)?,
+            }))
+        }
+        0b11 => Ok(ParsedInstruction32::cm_mva01s(cm_mva01s {
+            r1s: saved_register::<T>(*r1s)?,
+            r2s: saved_register::<T>(*r2s)?,
+        })),
+        _ => Err(DisassemblerError::InvalidFunct2(*funct2)),
+    }
+}
+
+/// Builds the register list of a push or pop. Encodings 4 to 15 select `ra` followed by zero
+/// to twelve saved registers, with 15 covering `s0` to `s11` since `s10` cannot be saved
+/// without `s11`. Encodings 0 to 3 are reserved.
+fn register_list<T: Register>(rlist: u8) -> Result<RegisterList, DisassemblerError> {
+    let saved = match rlist {
+        4..=14 => rlist - 4,
+        15 => 12,
+        _ => return Err(DisassemblerError::InvalidRegisterList(rlist)),
+    };
+
+    let mut ranges = Vec::new();
+    if saved > 0 {
+        // s0 and s1 are x8 and x9, while s2 to s11 are x18 to x27. With ABI names the saved
+        // registers are consecutively named and form a single range.
+        let s0 = T::try_from_u8(8)?.as_str();
+        let s1 = T::try_from_u8(9)?.as_str();
+        let last = saved_register_number(saved - 1);
+        if saved <= 2 || T::ABI {
+            ranges.push((s0, T::try_from_u8(last)?.as_str()));
+        } else {
+            ranges.push((s0, s1));
+            ranges.push((T::try_from_u8(18)?.as_str(), T::try_from_u8(last)?.as_str()));
+        }
+    }
+
+    Ok(RegisterList {
+        ra: T::try_from_u8(1)?.as_str(),
+        ranges,
+    })
+}
+
+/// Returns the register number of saved register `sN`.
+fn saved_register_number(n: u8) -> u8 {
+    match n {
+        0 | 1 => n + 8,
+        _ => n + 16,
+    }
+}
+
+/// Parses the Zcmp push and pop instructions. The stack adjustment is the space needed to
+/// save the register list, rounded up to 16 bytes, plus `spimm` additional 16 byte blocks.
+pub(crate) fn parse_cmpptype16<T: Register>(
+    funct6: &u8,
+    funct2: &u8,
+    rlist: &u8,
+    spimm: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    if !extensions.zcmp {
+        return Err(DisassemblerError::InvalidFunct6(*funct6));
+    }
+
+    let registers = match rlist {
+        15 => 13,
+        _ => *rlist as i32 - 3,
+    };
+    let stack_adj = (registers * 4 + 15) / 16 * 16 + *spimm as i32 * 16;
+    let rlist = register_list::<T>(*rlist)?;
+
+    match (funct6, funct2) {
+        (0b101110, 0b00) => Ok(ParsedInstruction32::cm_push(cm_push {
+            rlist,
+            stack_adj: -stack_adj,
+        })),
+        (0b101110, 0b10) => Ok(ParsedInstruction32::cm_pop(cm_pop { rlist, stack_adj })),
+        (0b101111, 0b00) => Ok(ParsedInstruction32::cm_popretz(cm_popretz {
+            rlist,
+            stack_adj,
+        })),
+        (0b101111, 0b10) => Ok(ParsedInstruction32::cm_popret(cm_popret {
+            rlist,
+            stack_adj,
+        })),
+        _ => Err(DisassemblerError::InvalidFunct2(*funct2)),
+    }
+}
+
+/// Parses the Zcmt table jumps. Indices below 32 select `cm.jt`, and the remaining indices
+/// select `cm.jalt`.
+pub(crate) fn parse_cmjttype16(
+    funct6: &u8,
+    index: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    if !extensions.zcmt {
+        return Err(DisassemblerError::InvalidFunct6(*funct6));
+    }
+
+    match index {
+        0..=31 => Ok(ParsedInstruction32::cm_jt(cm_jt { index: *index })),
+        _ => Ok(ParsedInstruction32::cm_jalt(cm_jalt { index: *index })),
+    }
+}
+
+/// Parses the CI format instructions without a register operand: the `c.nop` HINTs, which
+/// have a nonzero immediate, and the Zcmop `c.mop.N`, which are `c.lui` with an odd rd below
+/// x16 and a zero immediate.
+pub(crate) fn parse_citype16(
+    funct3: &u8,
+    rd: &u8,
+    imm: &i32,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    match funct3 {
+        0b000 if extensions.hints && *rd == 0 && *imm != 0 => {
+            Ok(hint(ParsedInstruction32::c_nop(c_nop { imm: *imm })))
+        }
+        0b011 if extensions.zcmop && *imm == 0 && *rd % 2 == 1 && *rd < 16 => {
+            Ok(ParsedInstruction32::c_mop(c_mop { n: *rd }))
+        }
+        _ => Err(DisassemblerError::InvalidFunct3(*funct3)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registers::{ABIRegister, NumberedRegister};
+
+    fn zc() -> Extensions {
+        Extensions {
+            zcb: true,
+            zcmp: true,
+            zcmt: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_catype16_c_lbu() {
+        let result =
+            parse_catype16::<NumberedRegister>(&0b00, &0b100000, &0b011, &0b10, &0b010, &zc())
+                .unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::c_lbu(c_lbu {
+                rd: "x10",
+                rs1: "x11",
+                imm: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_catype16_c_lh() {
+        let result =
+            parse_catype16::<NumberedRegister>(&0b00, &0b100001, &0b011, &0b11, &0b010, &zc())
+                .unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::c_lh(c_lh {
+                rd: "x10",
+                rs1: "x11",
+                imm: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_catype16_c_sh_reserved() {
+        let result =
+            parse_catype16::<NumberedRegister>(&0b00, &0b100011, &0b011, &0b10, &0b010, &zc());
+        assert_eq!(result.err(), Some(DisassemblerError::InvalidFunct2(0b10)));
+    }
+
+    #[test]
+    fn test_parse_catype16_c_sext_b() {
+        let result =
+            parse_catype16::<NumberedRegister>(&0b01, &0b100111, &0b010, &0b11, &0b001, &zc())
+                .unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::c_sext_b(c_sext_b { rd: "x10" })
+        );
+    }
+
+    #[test]
+    fn test_parse_catype16_c_zext_w_rejected() {
+        let result =
+            parse_catype16::<NumberedRegister>(&0b01, &0b100111, &0b010, &0b11, &0b100, &zc());
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidRs2Field(0b100))
+        );
+    }
+
+    #[test]
+    fn test_parse_catype16_c_mul() {
+        let result =
+            parse_catype16::<NumberedRegister>(&0b01, &0b100111, &0b010, &0b10, &0b011, &zc())
+                .unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::c_mul(c_mul {
+                rd: "x10",
+                rs2: "x11"
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_catype16_cm_mva01s() {
+        let result =
+            parse_catype16::<ABIRegister>(&0b10, &0b101011, &0b001, &0b11, &0b101, &zc()).unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::cm_mva01s(cm_mva01s {
+                r1s: "s1",
+                r2s: "s5"
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_catype16_cm_mvsa01_same_registers() {
+        let result = parse_catype16::<ABIRegister>(&0b10, &0b101011, &0b001, &0b01, &0b001, &zc());
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidRs2Field(0b001))
+        );
+    }
+
+    #[test]
+    fn test_parse_catype16_without_zcb() {
+        let result = parse_catype16::<NumberedRegister>(
+            &0b00,
+            &0b100000,
+            &0b011,
+            &0b10,
+            &0b010,
+            &Extensions::default(),
+        );
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidFunct6(0b100000))
+        );
+    }
+
+    #[test]
+    fn test_parse_cmpptype16_cm_push() {
+        let result = parse_cmpptype16::<ABIRegister>(&0b101110, &0b00, &8, &0, &zc()).unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::cm_push(cm_push {
+                rlist: RegisterList {
+                    ra: "ra",
+                    ranges: vec![("s0", "s3")]
+                },
+                stack_adj: -32
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_cmpptype16_cm_popret_numbered() {
+        let result =
+            parse_cmpptype16::<NumberedRegister>(&0b101111, &0b10, &15, &1, &zc()).unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::cm_popret(cm_popret {
+                rlist: RegisterList {
+                    ra: "x1",
+                    ranges: vec![("x8", "x9"), ("x18", "x27")]
+                },
+                stack_adj: 80
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_cmpptype16_cm_pop_ra_only() {
+        let result = parse_cmpptype16::<ABIRegister>(&0b101110, &0b10, &4, &0, &zc()).unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::cm_pop(cm_pop {
+                rlist: RegisterList {
+                    ra: "ra",
+                    ranges: vec![]
+                },
+                stack_adj: 16
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_cmpptype16_reserved_rlist() {
+        let result = parse_cmpptype16::<ABIRegister>(&0b101110, &0b00, &3, &0, &zc());
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidRegisterList(3))
+        );
+    }
+
+    #[test]
+    fn test_parse_cmjttype16() {
+        let result = parse_cmjttype16(&0b101000, &5, &zc()).unwrap();
+        assert_eq!(result, ParsedInstruction32::cm_jt(cm_jt { index: 5 }));
+
+        let result = parse_cmjttype16(&0b101000, &32, &zc()).unwrap();
+        assert_eq!(result, ParsedInstruction32::cm_jalt(cm_jalt { index: 32 }));
+    }
+
+    #[test]
+    fn test_parse_citype16_c_nop_hint() {
+        let hints = Extensions {
+            hints: true,
+            ..Default::default()
+        };
+        let result = parse_citype16(&0b000, &0, &-1, &hints).unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::hint(hint {
+                instruction: Box::new(ParsedInstruction32::c_nop(c_nop { imm: -1 }))
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_citype16_c_mop() {
+        let zcmop = Extensions {
+            zcmop: true,
+            ..Default::default()
+        };
+        let result = parse_citype16(&0b011, &7, &0, &zcmop).unwrap();
+        assert_eq!(result, ParsedInstruction32::c_mop(c_mop { n: 7 }));
+
+        let result = parse_citype16(&0b011, &6, &0, &zcmop);
+        assert_eq!(result.err(), Some(DisassemblerError::InvalidFunct3(0b011)));
+    }
+}
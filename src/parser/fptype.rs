@@ -0,0 +1,692 @@
+use crate::instructions::{parsed_instructions::*, FloatFormat, ParsedInstruction32};
+use crate::registers::{float_register_name, Register};
+use crate::{DisassemblerError, Extensions};
+
+/// Parses the OP-FP instructions of the Zfinx, Zdinx and Zhinx extensions, where floating point
+/// values are held in the integer registers.
+pub(crate) fn parse_fptype32<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    funct7: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    if extensions.zfbfmin && matches!((*funct7, *rs2), (0b0100010, 0b01000) | (0b0100000, 0b00110))
+    {
+        return parse_fptype32_bf16::<T>(rd, funct3, rs1, rs2);
+    }
+    if !extensions.has_zfinx() {
+        return Err(DisassemblerError::InvalidOpcode(0b1010011));
+    }
+
+    let funct5 = funct7 >> 2;
+    let format =
+        float_format(funct7 & 0b11, extensions).ok_or(DisassemblerError::InvalidFunct7(*funct7))?;
+
+    match funct5 {
+        0b00000..=0b00011 => {
+            parse_fptype32_arithmetic::<T>(rd, funct3, rs1, rs2, funct7, format)
+        }
+        0b01011 => {
+            if *rs2 != 0 {
+                return Err(DisassemblerError::InvalidRs2Field(*rs2));
+            }
+            let rd = float_register::<T>(*rd, format)?;
+            let rs1 = float_register::<T>(*rs1, format)?;
+            let rm = RoundingMode::try_from(*funct3)?;
+            Ok(match format {
+                FloatFormat::Single => ParsedInstruction32::fsqrt_s(fsqrt_s { rd, rs1, rm }),
+                FloatFormat::Double => ParsedInstruction32::fsqrt_d(fsqrt_d { rd, rs1, rm }),
+                FloatFormat::Half => ParsedInstruction32::fsqrt_h(fsqrt_h { rd, rs1, rm }),
+                FloatFormat::BFloat16 => return Err(DisassemblerError::InvalidFunct7(*funct7)),
+            })
+        }
+        0b00100 => parse_fptype32_sign_injection::<T>(rd, funct3, rs1, rs2, format),
+        0b00101 => parse_fptype32_min_max::<T>(rd, funct3, rs1, rs2, format),
+        0b10100 => parse_fptype32_compare::<T>(rd, funct3, rs1, rs2, format),
+        0b11100 => {
+            if *funct3 != 0b001 {
+                return Err(DisassemblerError::InvalidFunct3(*funct3));
+            }
+            if *rs2 != 0 {
+                return Err(DisassemblerError::InvalidRs2Field(*rs2));
+            }
+            let rd = T::try_from_u8(*rd)?.as_str();
+            let rs1 = float_register::<T>(*rs1, format)?;
+            Ok(match format {
+                FloatFormat::Single => ParsedInstruction32::fclass_s(fclass_s { rd, rs1 }),
+                FloatFormat::Double => ParsedInstruction32::fclass_d(fclass_d { rd, rs1 }),
+                FloatFormat::Half => ParsedInstruction32::fclass_h(fclass_h { rd, rs1 }),
+                FloatFormat::BFloat16 => return Err(DisassemblerError::InvalidFunct7(*funct7)),
+            })
+        }
+        0b01000 => parse_fptype32_convert_float::<T>(rd, funct3, rs1, rs2, format, extensions),
+        0b11000 => parse_fptype32_convert_to_int::<T>(rd, funct3, rs1, rs2, format),
+        0b11010 => parse_fptype32_convert_from_int::<T>(rd, funct3, rs1, rs2, format),
+        _ => Err(DisassemblerError::InvalidFunct7(*funct7)),
+    }
+}
+
+/// Maps a two bit `fmt` field to its floating point format, if the matching extension is enabled.
+pub(crate) fn float_format(fmt: u8, extensions: &Extensions) -> Option<FloatFormat> {
+    match fmt {
+        0b00 if extensions.has_zfinx() => Some(FloatFormat::Single),
+        0b01 if extensions.zdinx => Some(FloatFormat::Double),
+        0b10 if extensions.zhinx => Some(FloatFormat::Half),
+        _ => None,
+    }
+}
+
+/// Returns the name of an integer register holding a floating point value of the given format.
+/// Zdinx keeps double precision values in even/odd register pairs on RV32, which are named by
+/// their even register.
+pub(crate) fn float_register<T: Register>(
+    register: u8,
+    format: FloatFormat,
+) -> Result<&'static str, DisassemblerError> {
+    if format == FloatFormat::Double && !register.is_multiple_of(2) {
+        return Err(DisassemblerError::InvalidRegisterPair(register));
+    }
+    Ok(T::try_from_u8(register)?.as_str())
+}
+
+fn parse_fptype32_arithmetic<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    funct7: &u8,
+    format: FloatFormat,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let rd = float_register::<T>(*rd, format)?;
+    let rs1 = float_register::<T>(*rs1, format)?;
+    let rs2 = float_register::<T>(*rs2, format)?;
+    let rm = RoundingMode::try_from(*funct3)?;
+
+    match (funct7 >> 2, format) {
+        (0b00000, FloatFormat::Single) => {
+            Ok(ParsedInstruction32::fadd_s(fadd_s { rd, rs1, rs2, rm }))
+        }
+        (0b00001, FloatFormat::Single) => {
+            Ok(ParsedInstruction32::fsub_s(fsub_s { rd, rs1, rs2, rm }))
+        }
+        (0b00010, FloatFormat::Single) => {
+            Ok(ParsedInstruction32::fmul_s(fmul_s { rd, rs1, rs2, rm }))
+        }
+        (0b00011, FloatFormat::Single) => {
+            Ok(ParsedInstruction32::fdiv_s(fdiv_s { rd, rs1, rs2, rm }))
+        }
+        (0b00000, FloatFormat::Double) => {
+            Ok(ParsedInstruction32::fadd_d(fadd_d { rd, rs1, rs2, rm }))
+        }
+        (0b00001, FloatFormat::Double) => {
+            Ok(ParsedInstruction32::fsub_d(fsub_d { rd, rs1, rs2, rm }))
+        }
+        (0b00010, FloatFormat::Double) => {
+            Ok(ParsedInstruction32::fmul_d(fmul_d { rd, rs1, rs2, rm }))
+        }
+        (0b00011, FloatFormat::Double) => {
+            Ok(ParsedInstruction32::fdiv_d(fdiv_d { rd, rs1, rs2, rm }))
+        }
+        (0b00000, FloatFormat::Half) => {
+            Ok(ParsedInstruction32::fadd_h(fadd_h { rd, rs1, rs2, rm }))
+        }
+        (0b00001, FloatFormat::Half) => {
+            Ok(ParsedInstruction32::fsub_h(fsub_h { rd, rs1, rs2, rm }))
+        }
+        (0b00010, FloatFormat::Half) => {
+            Ok(ParsedInstruction32::fmul_h(fmul_h { rd, rs1, rs2, rm }))
+        }
+        (0b00011, FloatFormat::Half) => {
+            Ok(ParsedInstruction32::fdiv_h(fdiv_h { rd, rs1, rs2, rm }))
+        }
+        _ => Err(DisassemblerError::InvalidFunct7(*funct7)),
+    }
+}
+
+fn parse_fptype32_sign_injection<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    format: FloatFormat,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let rd = float_register::<T>(*rd, format)?;
+    let rs1 = float_register::<T>(*rs1, format)?;
+    let rs2 = float_register::<T>(*rs2, format)?;
+
+    match (funct3, format) {
+        (0b000, FloatFormat::Single) => Ok(ParsedInstruction32::fsgnj_s(fsgnj_s { rd, rs1, rs2 })),
+        (0b001, FloatFormat::Single) => {
+            Ok(ParsedInstruction32::fsgnjn_s(fsgnjn_s { rd, rs1, rs2 }))
+        }
+        (0b010, FloatFormat::Single) => {
+            Ok(ParsedInstruction32::fsgnjx_s(fsgnjx_s { rd, rs1, rs2 }))
+        }
+        (0b000, FloatFormat::Double) => Ok(ParsedInstruction32::fsgnj_d(fsgnj_d { rd, rs1, rs2 })),
+        (0b001, FloatFormat::Double) => {
+            Ok(ParsedInstruction32::fsgnjn_d(fsgnjn_d { rd, rs1, rs2 }))
+        }
+        (0b010, FloatFormat::Double) => {
+            Ok(ParsedInstruction32::fsgnjx_d(fsgnjx_d { rd, rs1, rs2 }))
+        }
+        (0b000, FloatFormat::Half) => Ok(ParsedInstruction32::fsgnj_h(fsgnj_h { rd, rs1, rs2 })),
+        (0b001, FloatFormat::Half) => Ok(ParsedInstruction32::fsgnjn_h(fsgnjn_h { rd, rs1, rs2 })),
+        (0b010, FloatFormat::Half) => Ok(ParsedInstruction32::fsgnjx_h(fsgnjx_h { rd, rs1, rs2 })),
+        _ => Err(DisassemblerError::InvalidFunct3(*funct3)),
+    }
+}
+
+fn parse_fptype32_min_max<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    format: FloatFormat,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let rd = float_register::<T>(*rd, format)?;
+    let rs1 = float_register::<T>(*rs1, format)?;
+    let rs2 = float_register::<T>(*rs2, format)?;
+
+    match (funct3, format) {
+        (0b000, FloatFormat::Single) => Ok(ParsedInstruction32::fmin_s(fmin_s { rd, rs1, rs2 })),
+        (0b001, FloatFormat::Single) => Ok(ParsedInstruction32::fmax_s(fmax_s { rd, rs1, rs2 })),
+        (0b000, FloatFormat::Double) => Ok(ParsedInstruction32::fmin_d(fmin_d { rd, rs1, rs2 })),
+        (0b001, FloatFormat::Double) => Ok(ParsedInstruction32::fmax_d(fmax_d { rd, rs1, rs2 })),
+        (0b000, FloatFormat::Half) => Ok(ParsedInstruction32::fmin_h(fmin_h { rd, rs1, rs2 })),
+        (0b001, FloatFormat::Half) => Ok(ParsedInstruction32::fmax_h(fmax_h { rd, rs1, rs2 })),
+        _ => Err(DisassemblerError::InvalidFunct3(*funct3)),
+    }
+}
+
+fn parse_fptype32_compare<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    format: FloatFormat,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let rd = T::try_from_u8(*rd)?.as_str();
+    let rs1 = float_register::<T>(*rs1, format)?;
+    let rs2 = float_register::<T>(*rs2, format)?;
+
+    match (funct3, format) {
+        (0b010, FloatFormat::Single) => Ok(ParsedInstruction32::feq_s(feq_s { rd, rs1, rs2 })),
+        (0b001, FloatFormat::Single) => Ok(ParsedInstruction32::flt_s(flt_s { rd, rs1, rs2 })),
+        (0b000, FloatFormat::Single) => Ok(ParsedInstruction32::fle_s(fle_s { rd, rs1, rs2 })),
+        (0b010, FloatFormat::Double) => Ok(ParsedInstruction32::feq_d(feq_d { rd, rs1, rs2 })),
+        (0b001, FloatFormat::Double) => Ok(ParsedInstruction32::flt_d(flt_d { rd, rs1, rs2 })),
+        (0b000, FloatFormat::Double) => Ok(ParsedInstruction32::fle_d(fle_d { rd, rs1, rs2 })),
+        (0b010, FloatFormat::Half) => Ok(ParsedInstruction32::feq_h(feq_h { rd, rs1, rs2 })),
+        (0b001, FloatFormat::Half) => Ok(ParsedInstruction32::flt_h(flt_h { rd, rs1, rs2 })),
+        (0b000, FloatFormat::Half) => Ok(ParsedInstruction32::fle_h(fle_h { rd, rs1, rs2 })),
+        _ => Err(DisassemblerError::InvalidFunct3(*funct3)),
+    }
+}
+
+fn parse_fptype32_convert_float<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    format: FloatFormat,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let source = float_format(*rs2, extensions).ok_or(DisassemblerError::InvalidRs2Field(*rs2))?;
+    let rd = float_register::<T>(*rd, format)?;
+    let rs1 = float_register::<T>(*rs1, source)?;
+    let rm = RoundingMode::try_from(*funct3)?;
+
+    match (format, source) {
+        (FloatFormat::Single, FloatFormat::Double) => {
+            Ok(ParsedInstruction32::fcvt_s_d(fcvt_s_d { rd, rs1, rm }))
+        }
+        (FloatFormat::Double, FloatFormat::Single) => {
+            Ok(ParsedInstruction32::fcvt_d_s(fcvt_d_s { rd, rs1, rm }))
+        }
+        (FloatFormat::Single, FloatFormat::Half) => {
+            Ok(ParsedInstruction32::fcvt_s_h(fcvt_s_h { rd, rs1, rm }))
+        }
+        (FloatFormat::Half, FloatFormat::Single) => {
+            Ok(ParsedInstruction32::fcvt_h_s(fcvt_h_s { rd, rs1, rm }))
+        }
+        (FloatFormat::Double, FloatFormat::Half) => {
+            Ok(ParsedInstruction32::fcvt_d_h(fcvt_d_h { rd, rs1, rm }))
+        }
+        (FloatFormat::Half, FloatFormat::Double) => {
+            Ok(ParsedInstruction32::fcvt_h_d(fcvt_h_d { rd, rs1, rm }))
+        }
+        _ => Err(DisassemblerError::InvalidRs2Field(*rs2)),
+    }
+}
+
+/// Parses the Zfbfmin conversions, which are encoded as `fcvt.h.s` and `fcvt.s.h` with the
+/// otherwise unused source formats `01000` and `00110` in rs2.
+fn parse_fptype32_bf16<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let rd = float_register_name::<T>(*rd)?;
+    let rs1 = float_register_name::<T>(*rs1)?;
+    let rm = RoundingMode::try_from(*funct3)?;
+
+    match rs2 {
+        0b01000 => Ok(ParsedInstruction32::fcvt_bf16_s(fcvt_bf16_s {
+            rd,
+            rs1,
+            rm,
+        })),
+        _ => Ok(ParsedInstruction32::fcvt_s_bf16(fcvt_s_bf16 {
+            rd,
+            rs1,
+            rm,
+        })),
+    }
+}
+
+fn parse_fptype32_convert_to_int<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    format: FloatFormat,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let rd = T::try_from_u8(*rd)?.as_str();
+    let rs1 = float_register::<T>(*rs1, format)?;
+    let rm = RoundingMode::try_from(*funct3)?;
+
+    match (rs2, format) {
+        (0b00000, FloatFormat::Single) => {
+            Ok(ParsedInstruction32::fcvt_w_s(fcvt_w_s { rd, rs1, rm }))
+        }
+        (0b00001, FloatFormat::Single) => {
+            Ok(ParsedInstruction32::fcvt_wu_s(fcvt_wu_s { rd, rs1, rm }))
+        }
+        (0b00000, FloatFormat::Double) => {
+            Ok(ParsedInstruction32::fcvt_w_d(fcvt_w_d { rd, rs1, rm }))
+        }
+        (0b00001, FloatFormat::Double) => {
+            Ok(ParsedInstruction32::fcvt_wu_d(fcvt_wu_d { rd, rs1, rm }))
+        }
+        (0b00000, FloatFormat::Half) => Ok(ParsedInstruction32::fcvt_w_h(fcvt_w_h { rd, rs1, rm })),
+        (0b00001, FloatFormat::Half) => {
+            Ok(ParsedInstruction32::fcvt_wu_h(fcvt_wu_h { rd, rs1, rm }))
+        }
+        _ => Err(DisassemblerError::InvalidRs2Field(*rs2)),
+    }
+}
+
+fn parse_fptype32_convert_from_int<T: Register>(
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    format: FloatFormat,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let rd = float_register::<T>(*rd, format)?;
+    let rs1 = T::try_from_u8(*rs1)?.as_str();
+    let rm = RoundingMode::try_from(*funct3)?;
+
+    match (rs2, format) {
+        (0b00000, FloatFormat::Single) => {
+            Ok(ParsedInstruction32::fcvt_s_w(fcvt_s_w { rd, rs1, rm }))
+        }
+        (0b00001, FloatFormat::Single) => {
+            Ok(ParsedInstruction32::fcvt_s_wu(fcvt_s_wu { rd, rs1, rm }))
+        }
+        (0b00000, FloatFormat::Double) => {
+            Ok(ParsedInstruction32::fcvt_d_w(fcvt_d_w { rd, rs1, rm }))
+        }
+        (0b00001, FloatFormat::Double) => {
+            Ok(ParsedInstruction32::fcvt_d_wu(fcvt_d_wu { rd, rs1, rm }))
+        }
+        (0b00000, FloatFormat::Half) => Ok(ParsedInstruction32::fcvt_h_w(fcvt_h_w { rd, rs1, rm })),
+        (0b00001, FloatFormat::Half) => {
+            Ok(ParsedInstruction32::fcvt_h_wu(fcvt_h_wu { rd, rs1, rm }))
+        }
+        _ => Err(DisassemblerError::InvalidRs2Field(*rs2)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registers::NumberedRegister;
+
+    fn zfinx() -> Extensions {
+        Extensions {
+            zfinx: true,
+            ..Default::default()
+        }
+    }
+
+    fn all_inx() -> Extensions {
+        Extensions {
+            zfinx: true,
+            zdinx: true,
+            zhinx: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_fptype32_fadd_s() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00001,
+            &0b111,
+            &0b00010,
+            &0b00011,
+            &0b0000000,
+            &zfinx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fadd_s { .. }));
+    }
+
+    #[test]
+    fn test_parse_fptype32_fdiv_d() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00010,
+            &0b000,
+            &0b00100,
+            &0b00110,
+            &0b0001101,
+            &all_inx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fdiv_d { .. }));
+    }
+
+    #[test]
+    fn test_parse_fptype32_fsqrt_h() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00001,
+            &0b111,
+            &0b00010,
+            &0b00000,
+            &0b0101110,
+            &all_inx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fsqrt_h { .. }));
+    }
+
+    #[test]
+    fn test_parse_fptype32_fsqrt_invalid_rs2() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00001,
+            &0b111,
+            &0b00010,
+            &0b00001,
+            &0b0101100,
+            &zfinx(),
+        );
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidRs2Field(0b00001))
+        );
+    }
+
+    #[test]
+    fn test_parse_fptype32_fsgnjx_s() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00001,
+            &0b010,
+            &0b00010,
+            &0b00011,
+            &0b0010000,
+            &zfinx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fsgnjx_s { .. }));
+    }
+
+    #[test]
+    fn test_parse_fptype32_fmax_h() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00001,
+            &0b001,
+            &0b00010,
+            &0b00011,
+            &0b0010110,
+            &all_inx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fmax_h { .. }));
+    }
+
+    #[test]
+    fn test_parse_fptype32_feq_d_odd_destination() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00001,
+            &0b010,
+            &0b00010,
+            &0b00100,
+            &0b1010001,
+            &all_inx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::feq_d { .. }));
+    }
+
+    #[test]
+    fn test_parse_fptype32_fclass_s() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00001,
+            &0b001,
+            &0b00010,
+            &0b00000,
+            &0b1110000,
+            &zfinx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fclass_s { .. }));
+    }
+
+    #[test]
+    fn test_parse_fptype32_fmv_x_w_reserved() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00001,
+            &0b000,
+            &0b00010,
+            &0b00000,
+            &0b1110000,
+            &zfinx(),
+        );
+        assert_eq!(result.err(), Some(DisassemblerError::InvalidFunct3(0b000)));
+    }
+
+    #[test]
+    fn test_parse_fptype32_fcvt_d_s() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00010,
+            &0b111,
+            &0b00011,
+            &0b00000,
+            &0b0100001,
+            &all_inx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fcvt_d_s { .. }));
+    }
+
+    #[test]
+    fn test_parse_fptype32_fcvt_h_d() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00011,
+            &0b111,
+            &0b00100,
+            &0b00001,
+            &0b0100010,
+            &all_inx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fcvt_h_d { .. }));
+    }
+
+    #[test]
+    fn test_parse_fptype32_fcvt_same_format() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00010,
+            &0b111,
+            &0b00100,
+            &0b00001,
+            &0b0100001,
+            &all_inx(),
+        );
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidRs2Field(0b00001))
+        );
+    }
+
+    #[test]
+    fn test_parse_fptype32_fcvt_wu_d() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00011,
+            &0b001,
+            &0b00100,
+            &0b00001,
+            &0b1100001,
+            &all_inx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fcvt_wu_d { .. }));
+    }
+
+    #[test]
+    fn test_parse_fptype32_fcvt_l_s_rv64_only() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00001,
+            &0b111,
+            &0b00010,
+            &0b00010,
+            &0b1100000,
+            &zfinx(),
+        );
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidRs2Field(0b00010))
+        );
+    }
+
+    #[test]
+    fn test_parse_fptype32_fcvt_s_w() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00001,
+            &0b111,
+            &0b00010,
+            &0b00000,
+            &0b1101000,
+            &zfinx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fcvt_s_w { .. }));
+    }
+
+    #[test]
+    fn test_parse_fptype32_odd_register_pair() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00010,
+            &0b111,
+            &0b00011,
+            &0b00100,
+            &0b0000001,
+            &all_inx(),
+        );
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidRegisterPair(0b00011))
+        );
+    }
+
+    #[test]
+    fn test_parse_fptype32_double_without_zdinx() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00010,
+            &0b111,
+            &0b00100,
+            &0b00110,
+            &0b0000001,
+            &zfinx(),
+        );
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidFunct7(0b0000001))
+        );
+    }
+
+    #[test]
+    fn test_parse_fptype32_invalid_rounding_mode() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00001,
+            &0b101,
+            &0b00010,
+            &0b00011,
+            &0b0000000,
+            &zfinx(),
+        );
+        assert_eq!(result.err(), Some(DisassemblerError::InvalidFunct3(0b101)));
+    }
+
+    #[test]
+    fn test_parse_fptype32_without_extension() {
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00001,
+            &0b111,
+            &0b00010,
+            &0b00011,
+            &0b0000000,
+            &Extensions::default(),
+        );
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidOpcode(0b1010011))
+        );
+    }
+
+    #[test]
+    fn test_parse_fptype32_fcvt_bf16_s() {
+        let extensions = Extensions {
+            zfbfmin: true,
+            ..Default::default()
+        };
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00010,
+            &0b001,
+            &0b00011,
+            &0b01000,
+            &0b0100010,
+            &extensions,
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::fcvt_bf16_s(fcvt_bf16_s {
+                rd: "f2",
+                rs1: "f3",
+                rm: RoundingMode::Rtz
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_fptype32_fcvt_bf16_s_zfbfmin_not_selected() {
+        let extensions = Extensions {
+            zhinx: true,
+            ..Default::default()
+        };
+        let result = parse_fptype32::<NumberedRegister>(
+            &0b00010,
+            &0b001,
+            &0b00011,
+            &0b01000,
+            &0b0100010,
+            &extensions,
+        );
+        assert_eq!(result.err(), Some(DisassemblerError::InvalidRs2Field(0b01000)));
+    }
+}
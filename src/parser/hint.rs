@@ -0,0 +1,102 @@
+use crate::instructions::{parsed_instructions::*, Instruction32, ParsedInstruction32};
+
+/// Encoding of `addi x0, x0, 0`, the canonical `nop`.
+const NOP: Instruction32 = 0x00000013;
+
+/// Returns whether the instruction is one of the RV32I HINT encodings: the integer computational
+/// instructions with rd = x0, except for `addi x0, x0, 0`, which is the canonical `nop`, and the
+/// fences with an empty predecessor or successor set, such as `pause`.
+pub(crate) fn is_hint32(instruction: Instruction32, parsed: &ParsedInstruction32) -> bool {
+    if let ParsedInstruction32::fence(fence) = parsed {
+        return fence.pred == 0 || fence.succ == 0;
+    }
+
+    if instruction >> 7 & 0x1f != 0 {
+        return false;
+    }
+
+    match parsed {
+        ParsedInstruction32::addi(_) => instruction != NOP,
+        ParsedInstruction32::add(_)
+        | ParsedInstruction32::sub(_)
+        | ParsedInstruction32::xor(_)
+        | ParsedInstruction32::or(_)
+        | ParsedInstruction32::and(_)
+        | ParsedInstruction32::sll(_)
+        | ParsedInstruction32::srl(_)
+        | ParsedInstruction32::sra(_)
+        | ParsedInstruction32::slt(_)
+        | ParsedInstruction32::sltu(_)
+        | ParsedInstruction32::xori(_)
+        | ParsedInstruction32::ori(_)
+        | ParsedInstruction32::andi(_)
+        | ParsedInstruction32::slli(_)
+        | ParsedInstruction32::srli(_)
+        | ParsedInstruction32::srai(_)
+        | ParsedInstruction32::slti(_)
+        | ParsedInstruction32::sltiu(_)
+        | ParsedInstruction32::lui(_)
+        | ParsedInstruction32::auipc(_) => true,
+        _ => false,
+    }
+}
+
+/// Wraps a HINT encoding in the `hint` variant.
+pub(crate) fn hint(instruction: ParsedInstruction32) -> ParsedInstruction32 {
+    ParsedInstruction32::hint(hint {
+        instruction: Box::new(instruction),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_hint32_addi() {
+        let parsed = ParsedInstruction32::addi(addi {
+            rd: "x0",
+            rs1: "x10",
+            imm: 1,
+        });
+        assert!(is_hint32(0x00150013, &parsed));
+    }
+
+    #[test]
+    fn test_is_hint32_nop() {
+        let parsed = ParsedInstruction32::addi(addi {
+            rd: "x0",
+            rs1: "x0",
+            imm: 0,
+        });
+        assert!(!is_hint32(NOP, &parsed));
+    }
+
+    #[test]
+    fn test_is_hint32_load_to_x0() {
+        let parsed = ParsedInstruction32::lw(lw {
+            rd: "x0",
+            imm: 0,
+            rs1: "x10",
+        });
+        assert!(!is_hint32(0x00052003, &parsed));
+    }
+
+    #[test]
+    fn test_is_hint32_other_rd() {
+        let parsed = ParsedInstruction32::lui(lui {
+            rd: "x1",
+            imm: 0x1000,
+        });
+        assert!(!is_hint32(0x000010B7, &parsed));
+    }
+
+    #[test]
+    fn test_is_hint32_pause() {
+        let parsed = ParsedInstruction32::fence(fence {
+            pred: 0b0001,
+            succ: 0b0000,
+        });
+        assert!(is_hint32(0x0100000F, &parsed));
+    }
+}
@@ -1,7 +1,11 @@
+#[allow(clippy::module_inception)]
 mod parser;
-mod rtype;
-mod itype;
-mod stype;
-mod btype;
-mod utype;
-mod jtype;
\ No newline at end of file
+mod opcodes;
+mod fptype;
+mod r4type;
+mod atomic;
+mod compressed;
+mod hint;
+mod vector;
+
+pub(crate) use parser::parse_instruction32;
@@ -0,0 +1,678 @@
+use crate::instructions::{parsed_instructions::*, Instruction32, ParsedInstruction32};
+use crate::registers::Register;
+use crate::{DisassemblerError, Extensions};
+
+/// Entry of the decode table generated by `build.rs` from the opcode descriptions in `opcodes/`.
+/// An instruction matches the entry when its bits selected by `mask` equal `value`. The fixed
+/// bits of `fields` are whole funct3, funct7, rd, rs1 or rs2 fields.
+pub(crate) struct Opcode {
+    pub(crate) mask: u32,
+    pub(crate) value: u32,
+    pub(crate) fields: u32,
+    pub(crate) enabled: fn(&Extensions) -> bool,
+}
+
+impl Opcode {
+    fn matches(&self, instruction: Instruction32) -> bool {
+        instruction & self.mask == self.value
+    }
+
+    /// Returns whether the entry leaves `field` free or fixes it to its value in `instruction`.
+    fn accepts(&self, instruction: Instruction32, field: u32) -> bool {
+        self.fields & field == 0 || (instruction ^ self.value) & field == 0
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/opcode_table.rs"));
+
+const FUNCT3: u32 = 0b111 << 12;
+const FUNCT7: u32 = 0x7f << 25;
+const RD: u32 = 0x1f << 7;
+const RS1: u32 = 0x1f << 15;
+const RS2: u32 = 0x1f << 20;
+
+/// Error reporting an invalid field of an instruction.
+type FieldError = fn(Instruction32) -> DisassemblerError;
+
+/// Fields an unmatched instruction is checked against, in order, with the error reporting them.
+const REPORTED_FIELDS: [(u32, FieldError); 5] = [
+    (FUNCT3, |i| {
+        DisassemblerError::InvalidFunct3(bits(i, 14, 12) as u8)
+    }),
+    (FUNCT7, |i| {
+        DisassemblerError::InvalidFunct7(bits(i, 31, 25) as u8)
+    }),
+    (RD, |i| DisassemblerError::InvalidRdField(rd(i))),
+    (RS1, |i| DisassemblerError::InvalidRs1Field(rs1(i))),
+    (RS2, |i| DisassemblerError::InvalidRs2Field(rs2(i))),
+];
+
+/// Parses an instruction through the generated decode table. Returns `None` when the table has
+/// no instructions for its major opcode, which is then left to the format based parsers.
+///
+/// An instruction matching none of the entries enabled by `extensions` is checked against them
+/// field by field, in the order of [`REPORTED_FIELDS`], keeping the entries that accept each
+/// field. It is rejected with an error for the field that no entry accepts, or with an invalid
+/// immediate when its other fixed bits differ.
+pub(crate) fn parse_opcode32<T: Register>(
+    instruction: Instruction32,
+    extensions: &Extensions,
+) -> Option<Result<ParsedInstruction32, DisassemblerError>> {
+    let opcode = instruction & 0x7f;
+    if !OPCODES.iter().any(|entry| entry.value & 0x7f == opcode) {
+        return None;
+    }
+
+    let mut candidates: Vec<(usize, &Opcode)> = OPCODES
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.value & 0x7f == opcode && (entry.enabled)(extensions))
+        .collect();
+
+    if let Some((index, _)) = candidates
+        .iter()
+        .find(|(_, entry)| entry.matches(instruction))
+    {
+        return Some(build_opcode32::<T>(*index, instruction));
+    }
+
+    for (field, error) in REPORTED_FIELDS {
+        candidates.retain(|(_, entry)| entry.accepts(instruction, field));
+        if candidates.is_empty() {
+            return Some(Err(error(instruction)));
+        }
+    }
+    Some(Err(DisassemblerError::InvalidImmediate(imm12(instruction))))
+}
+
+/// Returns bits `hi` down to `lo` of the instruction.
+fn bits(instruction: Instruction32, hi: u32, lo: u32) -> u32 {
+    instruction << (31 - hi) >> (31 - hi + lo)
+}
+
+fn rd(instruction: Instruction32) -> u8 {
+    bits(instruction, 11, 7) as u8
+}
+
+fn rs1(instruction: Instruction32) -> u8 {
+    bits(instruction, 19, 15) as u8
+}
+
+fn rs2(instruction: Instruction32) -> u8 {
+    bits(instruction, 24, 20) as u8
+}
+
+fn shamt(instruction: Instruction32) -> u8 {
+    bits(instruction, 24, 20) as u8
+}
+
+fn csr(instruction: Instruction32) -> u16 {
+    bits(instruction, 31, 20) as u16
+}
+
+fn zimm(instruction: Instruction32) -> u8 {
+    bits(instruction, 19, 15) as u8
+}
+
+fn pred(instruction: Instruction32) -> u8 {
+    bits(instruction, 27, 24) as u8
+}
+
+fn succ(instruction: Instruction32) -> u8 {
+    bits(instruction, 23, 20) as u8
+}
+
+/// Sign extended I-type immediate.
+fn imm12(instruction: Instruction32) -> i32 {
+    instruction as i32 >> 20
+}
+
+/// Sign extended S-type immediate.
+fn simm12(instruction: Instruction32) -> i32 {
+    (instruction as i32 >> 25 << 5) | bits(instruction, 11, 7) as i32
+}
+
+/// Sign extended B-type branch offset.
+fn bimm12(instruction: Instruction32) -> i32 {
+    (instruction as i32 >> 31 << 12)
+        | (bits(instruction, 7, 7) << 11
+            | bits(instruction, 30, 25) << 5
+            | bits(instruction, 11, 8) << 1) as i32
+}
+
+/// Sign extended J-type jump offset.
+fn jimm20(instruction: Instruction32) -> i32 {
+    (instruction as i32 >> 31 << 20)
+        | (bits(instruction, 19, 12) << 12
+            | bits(instruction, 20, 20) << 11
+            | bits(instruction, 30, 21) << 1) as i32
+}
+
+/// U-type immediate, already shifted into the upper 20 bits.
+fn imm20(instruction: Instruction32) -> i32 {
+    (instruction & 0xfffff000) as i32
+}
+
+/// Sign extended I-type immediate with the low five bits cleared, which the prefetch hints use
+/// as a 32-byte aligned offset.
+fn pimm12(instruction: Instruction32) -> i32 {
+    instruction as i32 >> 25 << 5
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registers::NumberedRegister;
+
+    fn parse(instruction: Instruction32) -> Result<ParsedInstruction32, DisassemblerError> {
+        parse_extensions(instruction, &Extensions::default())
+    }
+
+    fn parse_extensions(
+        instruction: Instruction32,
+        extensions: &Extensions,
+    ) -> Result<ParsedInstruction32, DisassemblerError> {
+        parse_opcode32::<NumberedRegister>(instruction, extensions).unwrap()
+    }
+
+    /// Asserts the listing of each instruction decoded with `extensions`, or its error.
+    fn assert_parsed(
+        extensions: &Extensions,
+        cases: &[(Instruction32, Result<&str, DisassemblerError>)],
+    ) {
+        for (instruction, expected) in cases {
+            let parsed =
+                parse_extensions(*instruction, extensions).map(|parsed| parsed.to_string());
+            assert_eq!(
+                parsed.as_deref(),
+                expected.as_ref().copied(),
+                "{:#010x}",
+                instruction
+            );
+        }
+    }
+
+    #[test]
+    fn test_opcodes_are_unambiguous() {
+        for (index, entry) in OPCODES.iter().enumerate() {
+            for other in &OPCODES[index + 1..] {
+                let common = entry.mask & other.mask;
+                assert!(
+                    entry.mask != other.mask || entry.value & common != other.value & common,
+                    "entries {:#010x} and {:#010x} have the same encoding",
+                    entry.value,
+                    other.value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_immediates() {
+        assert_eq!(imm12(0x02a0e013), 42);
+        assert_eq!(imm12(0xfd60e013), -42);
+        assert_eq!(simm12(0x003107a3), 15);
+        assert_eq!(simm12(0xfe3108a3), -15);
+        assert_eq!(bimm12(0x02619d63), 58);
+        assert_eq!(bimm12(0xfc6193e3), -58);
+        assert_eq!(imm20(0x0005b3b7), 91 << 12);
+        assert_eq!(imm20(0xfffa53b7), -91 << 12);
+        assert_eq!(jimm20(0x0360066f), 54);
+        assert_eq!(jimm20(0xfcbff66f), -54);
+    }
+
+    #[test]
+    fn test_parse_opcode32_unknown_opcode() {
+        assert!(parse_opcode32::<NumberedRegister>(0x0000_0053, &Extensions::default()).is_none());
+    }
+
+    #[test]
+    fn test_parse_opcode32_rtype() {
+        assert_eq!(
+            parse(0x00C58533),
+            Ok(ParsedInstruction32::add(add {
+                rd: "x10",
+                rs1: "x11",
+                rs2: "x12"
+            }))
+        );
+        assert_eq!(
+            parse(0x40C58533),
+            Ok(ParsedInstruction32::sub(sub {
+                rd: "x10",
+                rs1: "x11",
+                rs2: "x12"
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_opcode32_invalid_funct7() {
+        assert_eq!(
+            parse(0x02C59533),
+            Err(DisassemblerError::InvalidFunct7(0b0000001))
+        );
+    }
+
+    #[test]
+    fn test_parse_opcode32_itype() {
+        assert_eq!(
+            parse(0xFFF58513),
+            Ok(ParsedInstruction32::addi(addi {
+                rd: "x10",
+                rs1: "x11",
+                imm: -1
+            }))
+        );
+        assert_eq!(
+            parse(0x41F5D513),
+            Ok(ParsedInstruction32::srai(srai {
+                rd: "x10",
+                rs1: "x11",
+                shamt: 31
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_opcode32_invalid_shift_immediate() {
+        assert_eq!(
+            parse(0x40159513),
+            Err(DisassemblerError::InvalidImmediate(0x401))
+        );
+    }
+
+    #[test]
+    fn test_parse_opcode32_invalid_funct3() {
+        assert_eq!(
+            parse(0x0005E503),
+            Err(DisassemblerError::InvalidFunct3(0b110))
+        );
+        assert_eq!(
+            parse(0x000590E7),
+            Err(DisassemblerError::InvalidFunct3(0b001))
+        );
+    }
+
+    #[test]
+    fn test_parse_opcode32_load_store() {
+        assert_eq!(
+            parse(0xFFC5A503),
+            Ok(ParsedInstruction32::lw(lw {
+                rd: "x10",
+                imm: -4,
+                rs1: "x11"
+            }))
+        );
+        assert_eq!(
+            parse(0xFEA5AE23),
+            Ok(ParsedInstruction32::sw(sw {
+                rs2: "x10",
+                imm: -4,
+                rs1: "x11"
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_opcode32_branch_and_jump() {
+        assert_eq!(
+            parse(0xFEB50EE3),
+            Ok(ParsedInstruction32::beq(beq {
+                rs1: "x10",
+                rs2: "x11",
+                imm: -4
+            }))
+        );
+        assert_eq!(
+            parse(0xFFDFF0EF),
+            Ok(ParsedInstruction32::jal(jal { rd: "x1", imm: -4 }))
+        );
+        assert_eq!(
+            parse(0x800000B7),
+            Ok(ParsedInstruction32::lui(lui {
+                rd: "x1",
+                imm: i32::MIN
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_opcode32_fence() {
+        assert_eq!(
+            parse(0x0100000F),
+            Ok(ParsedInstruction32::fence(fence {
+                pred: 0b0001,
+                succ: 0b0000
+            }))
+        );
+        assert_eq!(
+            parse(0x8330000F),
+            Ok(ParsedInstruction32::fence_tso(fence_tso {}))
+        );
+        assert_eq!(
+            parse(0x8FF0000F),
+            Err(DisassemblerError::InvalidImmediate(-0x701))
+        );
+    }
+
+    #[test]
+    fn test_parse_opcode32_system() {
+        assert_eq!(
+            parse(0x00100073),
+            Ok(ParsedInstruction32::ebreak(ebreak {}))
+        );
+        assert_eq!(
+            parse(0x10200573),
+            Err(DisassemblerError::InvalidRdField(10))
+        );
+        assert_eq!(
+            parse(0x10208073),
+            Err(DisassemblerError::InvalidRs1Field(1))
+        );
+        assert_eq!(
+            parse(0x00200073),
+            Err(DisassemblerError::InvalidImmediate(2))
+        );
+    }
+
+    #[test]
+    fn test_parse_opcode32_numbered_mnemonic() {
+        let zimop = Extensions {
+            zimop: true,
+            ..Default::default()
+        };
+        let result = parse_extensions(0xCDC5C573, &zimop).unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::mop_r(mop_r {
+                n: 28,
+                rd: "x10",
+                rs1: "x11"
+            })
+        );
+        assert_eq!(result.to_string(), "mop.r.28 x10, x11");
+    }
+
+    #[test]
+    fn test_parse_opcode32_extension_not_selected() {
+        assert_eq!(
+            parse(0x0EC5D533),
+            Err(DisassemblerError::InvalidFunct7(0b0000111))
+        );
+        let zicond = Extensions {
+            zicond: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_extensions(0x0EC5D533, &zicond),
+            Ok(ParsedInstruction32::czero_eqz(czero_eqz {
+                rd: "x10",
+                rs1: "x11",
+                rs2: "x12"
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_opcode32_specific_encoding_first() {
+        let zicbop = Extensions {
+            zicbop: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_extensions(0x0215E013, &zicbop),
+            Ok(ParsedInstruction32::prefetch_r(prefetch_r {
+                imm: 32,
+                rs1: "x11"
+            }))
+        );
+        assert!(matches!(parse(0x0215E013), Ok(ParsedInstruction32::ori(_))));
+    }
+
+    #[test]
+    fn test_parse_branches_and_jumps() {
+        assert_parsed(
+            &Extensions::default(),
+            &[
+                (0x00310263, Ok("beq x2, x3, 4")),
+                (0x00311263, Ok("bne x2, x3, 4")),
+                (0x00312263, Err(DisassemblerError::InvalidFunct3(0b010))),
+                (0x00314263, Ok("blt x2, x3, 4")),
+                (0x00315263, Ok("bge x2, x3, 4")),
+                (0x00316263, Ok("bltu x2, x3, 4")),
+                (0x00317263, Ok("bgeu x2, x3, 4")),
+                (0x0020016F, Ok("jal x2, 2")),
+                (0x001100E7, Ok("jalr x1, 1(x2)")),
+                (0x001110E7, Err(DisassemblerError::InvalidFunct3(0b001))),
+                (0x00001137, Ok("lui x2, 4096")),
+                (0x00001117, Ok("auipc x2, 4096")),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_parse_loads_and_stores() {
+        assert_parsed(
+            &Extensions::default(),
+            &[
+                (0x00110083, Ok("lb x1, 1(x2)")),
+                (0x00111083, Ok("lh x1, 1(x2)")),
+                (0x00112083, Ok("lw x1, 1(x2)")),
+                (0x00114083, Ok("lbu x1, 1(x2)")),
+                (0x00115083, Ok("lhu x1, 1(x2)")),
+                (0x00116083, Err(DisassemblerError::InvalidFunct3(0b110))),
+                (0x003100A3, Ok("sb x3, 1(x2)")),
+                (0x003110A3, Ok("sh x3, 1(x2)")),
+                (0x003120A3, Ok("sw x3, 1(x2)")),
+                (0x003130A3, Err(DisassemblerError::InvalidFunct3(0b011))),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_parse_immediate_arithmetic() {
+        assert_parsed(
+            &Extensions::default(),
+            &[
+                (0x00110093, Ok("addi x1, x2, 1")),
+                (0x00111093, Ok("slli x1, x2, 1")),
+                (0x00112093, Ok("slti x1, x2, 1")),
+                (0x00113093, Ok("sltiu x1, x2, 1")),
+                (0x00114093, Ok("xori x1, x2, 1")),
+                (0x00115093, Ok("srli x1, x2, 1")),
+                (0x40115093, Ok("srai x1, x2, 1")),
+                (0x00116093, Ok("ori x1, x2, 1")),
+                (0x00117093, Ok("andi x1, x2, 1")),
+                // A shift immediate of -2047 and a slli immediate of 1025 select no shift.
+                (0x80115093, Err(DisassemblerError::InvalidImmediate(-2047))),
+                (0x40111093, Err(DisassemblerError::InvalidImmediate(1025))),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_parse_register_arithmetic() {
+        assert_parsed(
+            &Extensions::default(),
+            &[
+                (0x003100B3, Ok("add x1, x2, x3")),
+                (0x403100B3, Ok("sub x1, x2, x3")),
+                (0x003110B3, Ok("sll x1, x2, x3")),
+                (0x003120B3, Ok("slt x1, x2, x3")),
+                (0x003130B3, Ok("sltu x1, x2, x3")),
+                (0x003140B3, Ok("xor x1, x2, x3")),
+                (0x003150B3, Ok("srl x1, x2, x3")),
+                (0x403150B3, Ok("sra x1, x2, x3")),
+                (0x003160B3, Ok("or x1, x2, x3")),
+                (0x003170B3, Ok("and x1, x2, x3")),
+                (0x803100B3, Err(DisassemblerError::InvalidFunct7(0b1000000))),
+                (0x403110B3, Err(DisassemblerError::InvalidFunct7(0b0100000))),
+                (0x203150B3, Err(DisassemblerError::InvalidFunct7(0b0010000))),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_parse_zicond() {
+        let zicond = Extensions {
+            zicond: true,
+            ..Default::default()
+        };
+        assert_parsed(
+            &zicond,
+            &[
+                (0x0E3150B3, Ok("czero.eqz x1, x2, x3")),
+                (0x0E3170B3, Ok("czero.nez x1, x2, x3")),
+                // funct3 is matched first: 110 is `or`, whose funct7 differs.
+                (0x0E3160B3, Err(DisassemblerError::InvalidFunct7(0b0000111))),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_parse_zicbop() {
+        let zicbop = Extensions {
+            zicbop: true,
+            ..Default::default()
+        };
+        assert_parsed(
+            &zicbop,
+            &[
+                (0x0410E013, Ok("prefetch.r 64(x1)")),
+                (0xFE30E013, Ok("prefetch.w -32(x1)")),
+                // The reserved selector 00010 is left to ori, as are all prefetches without Zicbop.
+                (0x0020E013, Ok("ori x0, x1, 2")),
+            ],
+        );
+        assert_parsed(
+            &Extensions::default(),
+            &[(0x0410E013, Ok("ori x0, x1, 65"))],
+        );
+    }
+
+    #[test]
+    fn test_parse_misc_mem() {
+        assert_parsed(
+            &Extensions::default(),
+            &[
+                (0x0FF0000F, Ok("fence iorw, iorw")),
+                (0x8330000F, Ok("fence.tso")),
+                (
+                    0x8FF0000F,
+                    Err(DisassemblerError::InvalidImmediate(0x8FF - 0x1000)),
+                ),
+                (0x0000100F, Ok("fence.i")),
+                (0x0020A00F, Err(DisassemblerError::InvalidFunct3(0b010))),
+            ],
+        );
+        let cmo = Extensions {
+            zicbom: true,
+            zicboz: true,
+            ..Default::default()
+        };
+        assert_parsed(
+            &cmo,
+            &[
+                (0x0010A00F, Ok("cbo.clean (x1)")),
+                (0x0040A00F, Ok("cbo.zero (x1)")),
+                (0x0020A10F, Err(DisassemblerError::InvalidRdField(2))),
+            ],
+        );
+        let zicbom = Extensions {
+            zicbom: true,
+            ..Default::default()
+        };
+        assert_parsed(
+            &zicbom,
+            &[(0x0040A00F, Err(DisassemblerError::InvalidImmediate(0x004)))],
+        );
+    }
+
+    #[test]
+    fn test_parse_system_base() {
+        assert_parsed(
+            &Extensions::default(),
+            &[
+                (0x00000073, Ok("ecall")),
+                (0x00100073, Ok("ebreak")),
+                (0x00200073, Err(DisassemblerError::InvalidImmediate(2))),
+                (0xC0002573, Ok("csrrs x10, 0xc00, x0")),
+                (0x3002D073, Ok("csrrwi x0, 0x300, 5")),
+                // mnret, sinval.vma, hlv.hu and wrs.nto without their extensions
+                (0x70200073, Err(DisassemblerError::InvalidImmediate(0x702))),
+                (0x16418073, Err(DisassemblerError::InvalidRs1Field(3))),
+                (0x6410C1F3, Err(DisassemblerError::InvalidFunct3(0b100))),
+                (0x00D00073, Err(DisassemblerError::InvalidImmediate(0x00D))),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_parse_system_privileged() {
+        let privileged = Extensions {
+            svinval: true,
+            smrnmi: true,
+            h: true,
+            ..Default::default()
+        };
+        assert_parsed(
+            &privileged,
+            &[
+                (0x10200073, Ok("sret")),
+                (0x30200073, Ok("mret")),
+                (0x70200073, Ok("mnret")),
+                (0x10500073, Ok("wfi")),
+                (0x12208073, Ok("sfence.vma x1, x2")),
+                (0x16418073, Ok("sinval.vma x3, x4")),
+                (0x18000073, Ok("sfence.w.inval")),
+                (0x18100073, Ok("sfence.inval.ir")),
+                (0x302000F3, Err(DisassemblerError::InvalidRdField(1))),
+                (0x10210073, Err(DisassemblerError::InvalidRs1Field(2))),
+                (0x22208073, Ok("hfence.vvma x1, x2")),
+                (0x62208073, Ok("hfence.gvma x1, x2")),
+                (0x66208073, Ok("hinval.gvma x1, x2")),
+                (0x6410C1F3, Ok("hlv.hu x3, (x1)")),
+                (0x6830C1F3, Ok("hlvx.wu x3, (x1)")),
+                // hlv.wu and hlv.d only exist on RV64.
+                (0x6810C1F3, Err(DisassemblerError::InvalidRs2Field(0b00001))),
+                (0x6C00C1F3, Err(DisassemblerError::InvalidFunct7(0b0110110))),
+                (0x6A20C073, Ok("hsv.w x2, (x1)")),
+                (0x6220C273, Err(DisassemblerError::InvalidRdField(4))),
+            ],
+        );
+        let h = Extensions {
+            h: true,
+            ..Default::default()
+        };
+        // hinval.vvma without Svinval
+        assert_parsed(
+            &h,
+            &[(0x26208073, Err(DisassemblerError::InvalidRs1Field(1)))],
+        );
+    }
+
+    #[test]
+    fn test_parse_zawrs_and_zimop() {
+        let zawrs = Extensions {
+            zawrs: true,
+            ..Default::default()
+        };
+        assert_parsed(
+            &zawrs,
+            &[(0x00D00073, Ok("wrs.nto")), (0x01D00073, Ok("wrs.sto"))],
+        );
+        let zimop = Extensions {
+            zimop: true,
+            ..Default::default()
+        };
+        assert_parsed(
+            &zimop,
+            &[
+                (0xCDD140F3, Ok("mop.r.29 x1, x2")),
+                (0xC63140F3, Ok("mop.rr.5 x1, x2, x3")),
+                (0x900140F3, Err(DisassemblerError::InvalidImmediate(-0x700))),
+            ],
+        );
+    }
+}
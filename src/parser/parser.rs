@@ -1,46 +1,110 @@
-use crate::instructions::{DecodedInstruction32, ParseInstruction32, ParsedInstruction32};
-use crate::parser::btype::parse_btype32;
-use crate::parser::itype::parse_itype32;
-use crate::parser::jtype::parse_jtype32;
-use crate::parser::rtype::parse_rtype32;
-use crate::parser::stype::parse_stype32;
-use crate::parser::utype::parse_utype32;
-use crate::{DisassemblerError, Register};
+use crate::instructions::{
+    DecodeInstruction32, DecodedInstruction16, DecodedInstruction32, Instruction32,
+    ParseInstruction16, ParseInstruction32, ParsedInstruction32,
+};
+use crate::parser::atomic::parse_atomic32;
+use crate::parser::compressed::{
+    parse_catype16, parse_citype16, parse_cmjttype16, parse_cmpptype16,
+};
+use crate::parser::fptype::parse_fptype32;
+use crate::parser::hint::{hint, is_hint32};
+use crate::parser::opcodes::parse_opcode32;
+use crate::parser::r4type::parse_r4type32;
+use crate::parser::vector::parse_vector32;
+use crate::{DisassemblerError, Extensions, Register};
+
+/// Parses a 32-bit instruction, first through the generated opcode tables and then through the
+/// format based parsers of the remaining opcodes: AMO, OP-FP, the fused multiply-add opcodes and
+/// OP-V, which are not described in `opcodes/` yet. HINT encodings are wrapped in the `hint`
+/// variant when `extensions.hints` is set.
+pub(crate) fn parse_instruction32<T: Register>(
+    instruction: Instruction32,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let parsed = match parse_opcode32::<T>(instruction, extensions) {
+        Some(parsed) => parsed,
+        None => instruction
+            .decode_instruction32()?
+            .parse_instruction32::<T>(extensions),
+    }?;
+
+    if extensions.hints && is_hint32(instruction, &parsed) {
+        return Ok(hint(parsed));
+    }
+    Ok(parsed)
+}
 
 impl ParseInstruction32 for DecodedInstruction32 {
-    fn parse_instruction32<T: Register>(&self) -> Result<ParsedInstruction32, DisassemblerError> {
+    fn parse_instruction32<T: Register>(
+        &self,
+        extensions: &Extensions,
+    ) -> Result<ParsedInstruction32, DisassemblerError> {
         match self {
             DecodedInstruction32::RType {
-                opcode,
+                opcode: 0b1010011,
                 rd,
                 funct3,
                 rs1,
                 rs2,
                 funct7,
-            } => parse_rtype32::<T>(opcode, rd, funct3, rs1, rs2, funct7),
-            DecodedInstruction32::IType {
-                opcode,
+            } => parse_fptype32::<T>(rd, funct3, rs1, rs2, funct7, extensions),
+            DecodedInstruction32::RType {
+                opcode: 0b0101111,
                 rd,
                 funct3,
                 rs1,
-                imm,
-            } => parse_itype32::<T>(opcode, rd, funct3, rs1, imm),
-            DecodedInstruction32::SType {
-                opcode,
-                imm,
+                rs2,
+                funct7,
+            } => parse_atomic32::<T>(rd, funct3, rs1, rs2, funct7, extensions),
+            DecodedInstruction32::RType {
+                opcode: 0b1010111,
+                rd,
                 funct3,
                 rs1,
                 rs2,
-            } => parse_stype32::<T>(opcode, imm, funct3, rs1, rs2),
-            DecodedInstruction32::BType {
+                funct7,
+            } => parse_vector32::<T>(rd, funct3, rs1, rs2, funct7, extensions),
+            DecodedInstruction32::RType { opcode, .. } => {
+                Err(DisassemblerError::InvalidOpcode(*opcode))
+            }
+            DecodedInstruction32::R4Type {
                 opcode,
-                imm,
+                rd,
                 funct3,
                 rs1,
                 rs2,
-            } => parse_btype32::<T>(opcode, imm, funct3, rs1, rs2),
-            DecodedInstruction32::UType { opcode, rd, imm } => parse_utype32::<T>(opcode, rd, imm),
-            DecodedInstruction32::JType { opcode, rd, imm } => parse_jtype32::<T>(opcode, rd, imm),
+                funct2,
+                rs3,
+            } => parse_r4type32::<T>(opcode, rd, funct3, rs1, rs2, funct2, rs3, extensions),
+        }
+    }
+}
+
+impl ParseInstruction16 for DecodedInstruction16 {
+    fn parse_instruction16<T: Register>(
+        &self,
+        extensions: &Extensions,
+    ) -> Result<ParsedInstruction32, DisassemblerError> {
+        match self {
+            DecodedInstruction16::CAType {
+                opcode,
+                funct6,
+                rs1,
+                funct2,
+                rs2,
+            } => parse_catype16::<T>(opcode, funct6, rs1, funct2, rs2, extensions),
+            DecodedInstruction16::CMPPType {
+                funct6,
+                funct2,
+                rlist,
+                spimm,
+            } => parse_cmpptype16::<T>(funct6, funct2, rlist, spimm, extensions),
+            DecodedInstruction16::CMJTType { funct6, index } => {
+                parse_cmjttype16(funct6, index, extensions)
+            }
+            DecodedInstruction16::CIType { funct3, rd, imm } => {
+                parse_citype16(funct3, rd, imm, extensions)
+            }
         }
     }
 }
@@ -53,77 +117,40 @@ mod tests {
 
     #[test]
     fn test_parse_instruction32_rtype_add() {
-        let decoded = DecodedInstruction32::RType {
-            opcode: 0b0110011,
-            rd: 0b00001,
-            funct3: 0b000,
-            rs1: 0b00010,
-            rs2: 0b00011,
-            funct7: 0b0000000,
-        };
-        let result = decoded.parse_instruction32::<NumberedRegister>().unwrap();
+        let result =
+            parse_instruction32::<NumberedRegister>(0x003100B3, &Extensions::default()).unwrap();
         assert!(matches!(result, ParsedInstruction32::add { .. }));
     }
 
     #[test]
-    fn test_parse_instruction32_itype_addi() {
-        let decoded = DecodedInstruction32::IType {
-            opcode: 0b0010011,
+    fn test_parse_instruction32_rtype_fadd_s() {
+        let decoded = DecodedInstruction32::RType {
+            opcode: 0b1010011,
             rd: 0b00001,
             funct3: 0b000,
             rs1: 0b00010,
-            imm: 1,
-        };
-        let result = decoded.parse_instruction32::<NumberedRegister>().unwrap();
-        assert!(matches!(result, ParsedInstruction32::addi { .. }));
-    }
-
-    #[test]
-    fn test_parse_instruction32_stype_sb() {
-        let decoded = DecodedInstruction32::SType {
-            opcode: 0b0100011,
-            imm: 1,
-            funct3: 0b000,
-            rs1: 0b00010,
-            rs2: 0b00011,
-        };
-        let result = decoded.parse_instruction32::<NumberedRegister>().unwrap();
-        assert!(matches!(result, ParsedInstruction32::sb { .. }));
-    }
-
-    #[test]
-    fn test_parse_instruction32_btype_beq() {
-        let decoded = DecodedInstruction32::BType {
-            opcode: 0b1100011,
-            imm: 1,
-            funct3: 0b000,
-            rs1: 0b00010,
             rs2: 0b00011,
+            funct7: 0b0000000,
         };
-        let result = decoded.parse_instruction32::<NumberedRegister>().unwrap();
-        assert!(matches!(result, ParsedInstruction32::beq { .. }));
-    }
-
-    #[test]
-    fn test_parse_instruction32_utype_lui() {
-        let decoded = DecodedInstruction32::UType {
-            opcode: 0b0110111,
-            rd: 0b00001,
-            imm: (1 as i32) << 12,
+        let extensions = Extensions {
+            zfinx: true,
+            ..Default::default()
         };
-        let result = decoded.parse_instruction32::<NumberedRegister>().unwrap();
-        assert!(matches!(result, ParsedInstruction32::lui { .. }));
+        let result = decoded
+            .parse_instruction32::<NumberedRegister>(&extensions)
+            .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fadd_s { .. }));
     }
 
     #[test]
-    fn test_parse_instruction32_jtype_jal() {
-        let decoded = DecodedInstruction32::JType {
-            opcode: 0b1101111,
-            rd: 0b00001,
-            imm: 1,
+    fn test_parse_instruction32_hint() {
+        let extensions = Extensions {
+            hints: true,
+            ..Default::default()
         };
-        let result = decoded.parse_instruction32::<NumberedRegister>().unwrap();
-        assert!(matches!(result, ParsedInstruction32::jal { .. }));
+        let result = parse_instruction32::<NumberedRegister>(0x00100013, &extensions).unwrap();
+        assert!(matches!(result, ParsedInstruction32::hint { .. }));
+        let result = parse_instruction32::<NumberedRegister>(0x00000013, &extensions).unwrap();
+        assert!(matches!(result, ParsedInstruction32::addi { .. }));
     }
 }
-
@@ -0,0 +1,216 @@
+use crate::instructions::{parsed_instructions::*, FloatFormat, ParsedInstruction32};
+use crate::parser::fptype::{float_format, float_register};
+use crate::registers::Register;
+use crate::{DisassemblerError, Extensions};
+
+/// Parses the fused multiply-add instructions of the Zfinx, Zdinx and Zhinx extensions.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn parse_r4type32<T: Register>(
+    opcode: &u8,
+    rd: &u8,
+    funct3: &u8,
+    rs1: &u8,
+    rs2: &u8,
+    funct2: &u8,
+    rs3: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    if !extensions.has_zfinx() {
+        return Err(DisassemblerError::InvalidOpcode(*opcode));
+    }
+
+    let format = float_format(*funct2, extensions)
+        .ok_or(DisassemblerError::InvalidFunct7(*rs3 << 2 | *funct2))?;
+    let rd = float_register::<T>(*rd, format)?;
+    let rs1 = float_register::<T>(*rs1, format)?;
+    let rs2 = float_register::<T>(*rs2, format)?;
+    let rs3 = float_register::<T>(*rs3, format)?;
+    let rm = RoundingMode::try_from(*funct3)?;
+
+    match (opcode, format) {
+        (0b1000011, FloatFormat::Single) => Ok(ParsedInstruction32::fmadd_s(fmadd_s {
+            rd,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        })),
+        (0b1000111, FloatFormat::Single) => Ok(ParsedInstruction32::fmsub_s(fmsub_s {
+            rd,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        })),
+        (0b1001011, FloatFormat::Single) => Ok(ParsedInstruction32::fnmsub_s(fnmsub_s {
+            rd,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        })),
+        (0b1001111, FloatFormat::Single) => Ok(ParsedInstruction32::fnmadd_s(fnmadd_s {
+            rd,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        })),
+        (0b1000011, FloatFormat::Double) => Ok(ParsedInstruction32::fmadd_d(fmadd_d {
+            rd,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        })),
+        (0b1000111, FloatFormat::Double) => Ok(ParsedInstruction32::fmsub_d(fmsub_d {
+            rd,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        })),
+        (0b1001011, FloatFormat::Double) => Ok(ParsedInstruction32::fnmsub_d(fnmsub_d {
+            rd,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        })),
+        (0b1001111, FloatFormat::Double) => Ok(ParsedInstruction32::fnmadd_d(fnmadd_d {
+            rd,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        })),
+        (0b1000011, FloatFormat::Half) => Ok(ParsedInstruction32::fmadd_h(fmadd_h {
+            rd,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        })),
+        (0b1000111, FloatFormat::Half) => Ok(ParsedInstruction32::fmsub_h(fmsub_h {
+            rd,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        })),
+        (0b1001011, FloatFormat::Half) => Ok(ParsedInstruction32::fnmsub_h(fnmsub_h {
+            rd,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        })),
+        (0b1001111, FloatFormat::Half) => Ok(ParsedInstruction32::fnmadd_h(fnmadd_h {
+            rd,
+            rs1,
+            rs2,
+            rs3,
+            rm,
+        })),
+        _ => Err(DisassemblerError::InvalidOpcode(*opcode)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registers::NumberedRegister;
+
+    fn zdinx() -> Extensions {
+        Extensions {
+            zdinx: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_r4type32_fmadd_s() {
+        let result = parse_r4type32::<NumberedRegister>(
+            &0b1000011,
+            &0b00001,
+            &0b111,
+            &0b00010,
+            &0b00011,
+            &0b00,
+            &0b00100,
+            &zdinx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fmadd_s { .. }));
+    }
+
+    #[test]
+    fn test_parse_r4type32_fnmadd_d() {
+        let result = parse_r4type32::<NumberedRegister>(
+            &0b1001111,
+            &0b00010,
+            &0b111,
+            &0b00100,
+            &0b00110,
+            &0b01,
+            &0b01000,
+            &zdinx(),
+        )
+        .unwrap();
+        assert!(matches!(result, ParsedInstruction32::fnmadd_d { .. }));
+    }
+
+    #[test]
+    fn test_parse_r4type32_odd_register_pair() {
+        let result = parse_r4type32::<NumberedRegister>(
+            &0b1000011,
+            &0b00010,
+            &0b111,
+            &0b00100,
+            &0b00110,
+            &0b01,
+            &0b00111,
+            &zdinx(),
+        );
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidRegisterPair(0b00111))
+        );
+    }
+
+    #[test]
+    fn test_parse_r4type32_disabled_format() {
+        let result = parse_r4type32::<NumberedRegister>(
+            &0b1000011,
+            &0b00001,
+            &0b111,
+            &0b00010,
+            &0b00011,
+            &0b10,
+            &0b00100,
+            &zdinx(),
+        );
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidFunct7(0b0010010))
+        );
+    }
+
+    #[test]
+    fn test_parse_r4type32_without_extension() {
+        let result = parse_r4type32::<NumberedRegister>(
+            &0b1000011,
+            &0b00001,
+            &0b111,
+            &0b00010,
+            &0b00011,
+            &0b00,
+            &0b00100,
+            &Extensions::default(),
+        );
+        assert_eq!(
+            result.err(),
+            Some(DisassemblerError::InvalidOpcode(0b1000011))
+        );
+    }
+}
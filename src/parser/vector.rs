@@ -0,0 +1,746 @@
+use crate::instructions::{parsed_instructions::*, ParsedInstruction32};
+use crate::registers::{float_register_name, Register, VECTOR_REGISTERS};
+use crate::{DisassemblerError, Extensions};
+
+fn vector_register(register: u8) -> &'static str {
+    VECTOR_REGISTERS[register as usize]
+}
+
+/// Parses the OP-V opcode, of which only the vector BF16, bit-manipulation and cryptography
+/// extensions are supported. funct7 holds funct6 in its upper bits followed by the `vm` bit, and
+/// funct3 selects the operand kind: `000` vector-vector, `011` vector-immediate and `100`
+/// vector-scalar for the integer operations, `001` vector-vector and `101` vector-scalar for the
+/// floating point operations, and `010` vector-vector and `110` vector-scalar for the others.
+pub(crate) fn parse_vector32<T: Register>(
+    vd: &u8,
+    funct3: &u8,
+    vs1: &u8,
+    vs2: &u8,
+    funct7: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    if !extensions.has_vector() {
+        return Err(DisassemblerError::InvalidOpcode(0b1010111));
+    }
+
+    let funct6 = *funct7 >> 1;
+    match (funct6, funct3) {
+        (0b010010, 0b010) if extensions.zvbb => {
+            parse_vector32_unary(vd, vs1, vs2, funct7, extensions)
+        }
+        (0b001100 | 0b001101, 0b010 | 0b110) if extensions.zvbc => {
+            parse_vector32_clmul::<T>(vd, funct3, vs1, vs2, funct7, extensions)
+        }
+        (_, 0b000 | 0b011 | 0b100) if extensions.zvbb => {
+            parse_vector32_bitmanip::<T>(vd, funct3, vs1, vs2, funct7, extensions)
+        }
+        (0b010010, 0b001) if extensions.zvfbfmin => {
+            parse_vector32_bf16_convert(vd, vs1, vs2, funct7, extensions)
+        }
+        (0b111011, 0b001 | 0b101) if extensions.zvfbfwma => {
+            parse_vector32_bf16_macc::<T>(vd, funct3, vs1, vs2, funct7, extensions)
+        }
+        (_, 0b010) => parse_vector32_crypto(vd, vs1, vs2, funct7, extensions),
+        (_, 0b000 | 0b001 | 0b011 | 0b100 | 0b101 | 0b110) => {
+            Err(DisassemblerError::InvalidFunct6(funct6))
+        }
+        _ => Err(DisassemblerError::InvalidFunct3(*funct3)),
+    }
+}
+
+/// Checks that the destination and the vector sources start at a multiple of the register
+/// group size, the destination of a widening instruction occupying twice as many registers, and
+/// that a masked instruction does not write the mask register `v0`.
+fn check_register_groups(
+    vd: u8,
+    sources: &[u8],
+    widening: bool,
+    vm: VectorMask,
+    extensions: &Extensions,
+) -> Result<(), DisassemblerError> {
+    let registers = extensions.lmul.registers();
+    let destination_registers = if widening { registers * 2 } else { registers };
+
+    // A widening instruction with LMUL 8 would need a destination group of 16 registers.
+    if destination_registers > 8 || !vd.is_multiple_of(destination_registers) {
+        return Err(DisassemblerError::InvalidVectorRegisterGroup(vd));
+    }
+    if let Some(source) = sources
+        .iter()
+        .find(|source| !source.is_multiple_of(registers))
+    {
+        return Err(DisassemblerError::InvalidVectorRegisterGroup(*source));
+    }
+    if vm == VectorMask::Masked && vd == 0 {
+        return Err(DisassemblerError::InvalidVectorRegisterGroup(vd));
+    }
+    Ok(())
+}
+
+/// Checks that the wide source of a narrowing instruction, which occupies twice as many
+/// registers as LMUL, is aligned.
+fn check_wide_source(vs2: u8, extensions: &Extensions) -> Result<(), DisassemblerError> {
+    let registers = extensions.lmul.registers() * 2;
+    if registers > 8 || !vs2.is_multiple_of(registers) {
+        return Err(DisassemblerError::InvalidVectorRegisterGroup(vs2));
+    }
+    Ok(())
+}
+
+/// Checks that the destination group of an element group instruction does not overlap the
+/// register group starting at `source`, which is `source_registers` long.
+fn check_no_overlap(
+    vd: u8,
+    source: u8,
+    source_registers: u8,
+    extensions: &Extensions,
+) -> Result<(), DisassemblerError> {
+    let registers = extensions.lmul.registers();
+    if vd < source + source_registers && source < vd + registers {
+        return Err(DisassemblerError::InvalidElementGroup(source));
+    }
+    Ok(())
+}
+
+/// Parses the Zvbb integer operations: `vandn`, the rotates and `vwsll`. `vror.vi` takes a six
+/// bit immediate whose upper bit is the lowest bit of funct6.
+fn parse_vector32_bitmanip<T: Register>(
+    vd: &u8,
+    funct3: &u8,
+    vs1: &u8,
+    vs2: &u8,
+    funct7: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let funct6 = *funct7 >> 1;
+    let vm = VectorMask::from_vm(*funct7);
+    let sources: &[u8] = if *funct3 == 0b000 {
+        &[*vs2, *vs1]
+    } else {
+        &[*vs2]
+    };
+    check_register_groups(*vd, sources, funct6 == 0b110101, vm, extensions)?;
+
+    let vd = vector_register(*vd);
+    let vs2 = vector_register(*vs2);
+    match (funct6, funct3) {
+        (0b000001, 0b000) => Ok(ParsedInstruction32::vandn_vv(vandn_vv {
+            vd,
+            vs2,
+            vs1: vector_register(*vs1),
+            vm,
+        })),
+        (0b000001, 0b100) => Ok(ParsedInstruction32::vandn_vx(vandn_vx {
+            vd,
+            vs2,
+            rs1: T::try_from_u8(*vs1)?.as_str(),
+            vm,
+        })),
+        (0b010101, 0b000) => Ok(ParsedInstruction32::vrol_vv(vrol_vv {
+            vd,
+            vs2,
+            vs1: vector_register(*vs1),
+            vm,
+        })),
+        (0b010101, 0b100) => Ok(ParsedInstruction32::vrol_vx(vrol_vx {
+            vd,
+            vs2,
+            rs1: T::try_from_u8(*vs1)?.as_str(),
+            vm,
+        })),
+        (0b010100, 0b000) => Ok(ParsedInstruction32::vror_vv(vror_vv {
+            vd,
+            vs2,
+            vs1: vector_register(*vs1),
+            vm,
+        })),
+        (0b010100, 0b100) => Ok(ParsedInstruction32::vror_vx(vror_vx {
+            vd,
+            vs2,
+            rs1: T::try_from_u8(*vs1)?.as_str(),
+            vm,
+        })),
+        (0b010100 | 0b010101, 0b011) => Ok(ParsedInstruction32::vror_vi(vror_vi {
+            vd,
+            vs2,
+            uimm: (funct6 & 0b1) << 5 | *vs1,
+            vm,
+        })),
+        (0b110101, 0b000) => Ok(ParsedInstruction32::vwsll_vv(vwsll_vv {
+            vd,
+            vs2,
+            vs1: vector_register(*vs1),
+            vm,
+        })),
+        (0b110101, 0b100) => Ok(ParsedInstruction32::vwsll_vx(vwsll_vx {
+            vd,
+            vs2,
+            rs1: T::try_from_u8(*vs1)?.as_str(),
+            vm,
+        })),
+        (0b110101, 0b011) => Ok(ParsedInstruction32::vwsll_vi(vwsll_vi {
+            vd,
+            vs2,
+            uimm: *vs1,
+            vm,
+        })),
+        _ => Err(DisassemblerError::InvalidFunct6(funct6)),
+    }
+}
+
+/// Parses the Zvbb unary operations, which share funct6 `010010` with the integer extension
+/// instructions of the base V extension and are selected by the vs1 field.
+fn parse_vector32_unary(
+    vd: &u8,
+    vs1: &u8,
+    vs2: &u8,
+    funct7: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let vm = VectorMask::from_vm(*funct7);
+    check_register_groups(*vd, &[*vs2], false, vm, extensions)?;
+
+    let vd = vector_register(*vd);
+    let vs2 = vector_register(*vs2);
+    match vs1 {
+        0b01000 => Ok(ParsedInstruction32::vbrev8_v(vbrev8_v { vd, vs2, vm })),
+        0b01001 => Ok(ParsedInstruction32::vrev8_v(vrev8_v { vd, vs2, vm })),
+        0b01010 => Ok(ParsedInstruction32::vbrev_v(vbrev_v { vd, vs2, vm })),
+        0b01100 => Ok(ParsedInstruction32::vclz_v(vclz_v { vd, vs2, vm })),
+        0b01101 => Ok(ParsedInstruction32::vctz_v(vctz_v { vd, vs2, vm })),
+        0b01110 => Ok(ParsedInstruction32::vcpop_v(vcpop_v { vd, vs2, vm })),
+        _ => Err(DisassemblerError::InvalidRs1Field(*vs1)),
+    }
+}
+
+/// Parses the Zvbc carry-less multiplications.
+fn parse_vector32_clmul<T: Register>(
+    vd: &u8,
+    funct3: &u8,
+    vs1: &u8,
+    vs2: &u8,
+    funct7: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let funct6 = *funct7 >> 1;
+    let vm = VectorMask::from_vm(*funct7);
+    let sources: &[u8] = if *funct3 == 0b010 {
+        &[*vs2, *vs1]
+    } else {
+        &[*vs2]
+    };
+    check_register_groups(*vd, sources, false, vm, extensions)?;
+
+    let vd = vector_register(*vd);
+    let vs2 = vector_register(*vs2);
+    match (funct6, funct3) {
+        (0b001100, 0b010) => Ok(ParsedInstruction32::vclmul_vv(vclmul_vv {
+            vd,
+            vs2,
+            vs1: vector_register(*vs1),
+            vm,
+        })),
+        (0b001100, _) => Ok(ParsedInstruction32::vclmul_vx(vclmul_vx {
+            vd,
+            vs2,
+            rs1: T::try_from_u8(*vs1)?.as_str(),
+            vm,
+        })),
+        (_, 0b010) => Ok(ParsedInstruction32::vclmulh_vv(vclmulh_vv {
+            vd,
+            vs2,
+            vs1: vector_register(*vs1),
+            vm,
+        })),
+        _ => Ok(ParsedInstruction32::vclmulh_vx(vclmulh_vx {
+            vd,
+            vs2,
+            rs1: T::try_from_u8(*vs1)?.as_str(),
+            vm,
+        })),
+    }
+}
+
+/// Parses the Zvfbfmin conversions, which share funct6 `010010` with the floating point
+/// conversions of the base V extension and are selected by the vs1 field.
+fn parse_vector32_bf16_convert(
+    vd: &u8,
+    vs1: &u8,
+    vs2: &u8,
+    funct7: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let vm = VectorMask::from_vm(*funct7);
+    match vs1 {
+        0b11101 => {
+            check_register_groups(*vd, &[], false, vm, extensions)?;
+            check_wide_source(*vs2, extensions)?;
+            Ok(ParsedInstruction32::vfncvtbf16_f_f_w(vfncvtbf16_f_f_w {
+                vd: vector_register(*vd),
+                vs2: vector_register(*vs2),
+                vm,
+            }))
+        }
+        0b01101 => {
+            check_register_groups(*vd, &[*vs2], true, vm, extensions)?;
+            Ok(ParsedInstruction32::vfwcvtbf16_f_f_v(vfwcvtbf16_f_f_v {
+                vd: vector_register(*vd),
+                vs2: vector_register(*vs2),
+                vm,
+            }))
+        }
+        _ => Err(DisassemblerError::InvalidRs1Field(*vs1)),
+    }
+}
+
+/// Parses the Zvfbfwma widening multiply-accumulate, whose `.vf` form takes a floating point
+/// register as scalar operand.
+fn parse_vector32_bf16_macc<T: Register>(
+    vd: &u8,
+    funct3: &u8,
+    vs1: &u8,
+    vs2: &u8,
+    funct7: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let vm = VectorMask::from_vm(*funct7);
+    let sources: &[u8] = if *funct3 == 0b001 {
+        &[*vs2, *vs1]
+    } else {
+        &[*vs2]
+    };
+    check_register_groups(*vd, sources, true, vm, extensions)?;
+
+    let vd = vector_register(*vd);
+    let vs2 = vector_register(*vs2);
+    match funct3 {
+        0b001 => Ok(ParsedInstruction32::vfwmaccbf16_vv(vfwmaccbf16_vv {
+            vd,
+            vs1: vector_register(*vs1),
+            vs2,
+            vm,
+        })),
+        _ => Ok(ParsedInstruction32::vfwmaccbf16_vf(vfwmaccbf16_vf {
+            vd,
+            rs1: float_register_name::<T>(*vs1)?,
+            vs2,
+            vm,
+        })),
+    }
+}
+
+/// Parses the element group instructions of Zvkned, Zvknha, Zvknhb, Zvksed, Zvksh and Zvkg,
+/// which cannot be masked. The `.vs` forms read a single element group from vs2, which therefore does
+/// not have to be aligned to LMUL but must not overlap the destination group.
+fn parse_vector32_crypto(
+    vd: &u8,
+    vs1: &u8,
+    vs2: &u8,
+    funct7: &u8,
+    extensions: &Extensions,
+) -> Result<ParsedInstruction32, DisassemblerError> {
+    let funct6 = *funct7 >> 1;
+    let is_vs = funct6 == 0b101001;
+
+    let supported = match funct6 {
+        0b101000 | 0b101001 => match vs1 {
+            0b00000..=0b00011 => extensions.zvkned,
+            0b00111 => extensions.zvkned && is_vs,
+            0b10000 => extensions.zvksed,
+            0b10001 => extensions.zvkg && !is_vs,
+            _ => return Err(DisassemblerError::InvalidRs1Field(*vs1)),
+        },
+        0b100010 | 0b101010 => extensions.zvkned,
+        0b101101..=0b101111 => extensions.zvknha || extensions.zvknhb,
+        0b100001 => extensions.zvksed,
+        0b100000 | 0b101011 => extensions.zvksh,
+        0b101100 => extensions.zvkg,
+        _ => false,
+    };
+    if !supported {
+        return Err(DisassemblerError::InvalidFunct6(funct6));
+    }
+    if VectorMask::from_vm(*funct7) == VectorMask::Masked {
+        return Err(DisassemblerError::InvalidFunct7(*funct7));
+    }
+
+    // Element groups hold eight elements for SM3 and four otherwise, of 32 bits but for the
+    // SHA-512 forms of Zvknhb.
+    let sew = extensions.sew.bits();
+    if sew != 32 && !(sew == 64 && extensions.zvknhb && matches!(funct6, 0b101101..=0b101111)) {
+        return Err(DisassemblerError::UnsupportedElementWidth(sew));
+    }
+    let egw = match funct6 {
+        0b100000 | 0b101011 => 8 * sew,
+        _ => 4 * sew,
+    };
+    if let Some(vlen) = extensions.vlen {
+        if egw > extensions.lmul.group_bits(vlen) {
+            return Err(DisassemblerError::ElementGroupTooWide(egw));
+        }
+    }
+
+    // vs1 holds a register only for the instructions with three vector operands, and an
+    // immediate or a function selector otherwise.
+    let has_vs1 = matches!(funct6, 0b101101..=0b101111 | 0b100000 | 0b101100);
+    if is_vs {
+        check_register_groups(*vd, &[], false, VectorMask::Unmasked, extensions)?;
+        check_no_overlap(*vd, *vs2, 1, extensions)?;
+    } else if has_vs1 {
+        check_register_groups(*vd, &[*vs2, *vs1], false, VectorMask::Unmasked, extensions)?;
+    } else {
+        check_register_groups(*vd, &[*vs2], false, VectorMask::Unmasked, extensions)?;
+    }
+
+    // The SHA-2 destination must not overlap either source, and the SM3 destination must not
+    // overlap vs2.
+    let registers = extensions.lmul.registers();
+    if matches!(funct6, 0b101101..=0b101111) {
+        check_no_overlap(*vd, *vs1, registers, extensions)?;
+    }
+    if matches!(funct6, 0b101101..=0b101111 | 0b100000 | 0b101011) {
+        check_no_overlap(*vd, *vs2, registers, extensions)?;
+    }
+
+    let uimm = *vs1;
+    let vs1 = vector_register(*vs1);
+    let vd = vector_register(*vd);
+    let vs2 = vector_register(*vs2);
+    match (funct6, uimm) {
+        (0b101000, 0b00011) => Ok(ParsedInstruction32::vaesef_vv(vaesef_vv { vd, vs2 })),
+        (0b101001, 0b00011) => Ok(ParsedInstruction32::vaesef_vs(vaesef_vs { vd, vs2 })),
+        (0b101000, 0b00010) => Ok(ParsedInstruction32::vaesem_vv(vaesem_vv { vd, vs2 })),
+        (0b101001, 0b00010) => Ok(ParsedInstruction32::vaesem_vs(vaesem_vs { vd, vs2 })),
+        (0b101000, 0b00001) => Ok(ParsedInstruction32::vaesdf_vv(vaesdf_vv { vd, vs2 })),
+        (0b101001, 0b00001) => Ok(ParsedInstruction32::vaesdf_vs(vaesdf_vs { vd, vs2 })),
+        (0b101000, 0b00000) => Ok(ParsedInstruction32::vaesdm_vv(vaesdm_vv { vd, vs2 })),
+        (0b101001, 0b00000) => Ok(ParsedInstruction32::vaesdm_vs(vaesdm_vs { vd, vs2 })),
+        (0b101001, 0b00111) => Ok(ParsedInstruction32::vaesz_vs(vaesz_vs { vd, vs2 })),
+        (0b101000, 0b10000) => Ok(ParsedInstruction32::vsm4r_vv(vsm4r_vv { vd, vs2 })),
+        (0b101001, 0b10000) => Ok(ParsedInstruction32::vsm4r_vs(vsm4r_vs { vd, vs2 })),
+        (0b101000, _) => Ok(ParsedInstruction32::vgmul_vv(vgmul_vv { vd, vs2 })),
+        (0b100010, _) => Ok(ParsedInstruction32::vaeskf1_vi(vaeskf1_vi {
+            vd,
+            vs2,
+            uimm,
+        })),
+        (0b101010, _) => Ok(ParsedInstruction32::vaeskf2_vi(vaeskf2_vi {
+            vd,
+            vs2,
+            uimm,
+        })),
+        (0b101101, _) => Ok(ParsedInstruction32::vsha2ms_vv(vsha2ms_vv { vd, vs2, vs1 })),
+        (0b101110, _) => Ok(ParsedInstruction32::vsha2ch_vv(vsha2ch_vv { vd, vs2, vs1 })),
+        (0b101111, _) => Ok(ParsedInstruction32::vsha2cl_vv(vsha2cl_vv { vd, vs2, vs1 })),
+        (0b100001, _) => Ok(ParsedInstruction32::vsm4k_vi(vsm4k_vi { vd, vs2, uimm })),
+        (0b100000, _) => Ok(ParsedInstruction32::vsm3me_vv(vsm3me_vv { vd, vs2, vs1 })),
+        (0b101011, _) => Ok(ParsedInstruction32::vsm3c_vi(vsm3c_vi { vd, vs2, uimm })),
+        _ => Ok(ParsedInstruction32::vghsh_vv(vghsh_vv { vd, vs2, vs1 })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extensions::{Lmul, Sew};
+    use crate::registers::NumberedRegister;
+
+    fn vector_crypto(lmul: Lmul) -> Extensions {
+        Extensions {
+            zvbb: true,
+            zvbc: true,
+            zvkned: true,
+            zvknhb: true,
+            zvksed: true,
+            zvksh: true,
+            zvkg: true,
+            lmul,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_vector32_vandn_vx_masked() {
+        let result = parse_vector32::<NumberedRegister>(
+            &1,
+            &0b100,
+            &10,
+            &2,
+            &0b0000010,
+            &vector_crypto(Lmul::M1),
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::vandn_vx(vandn_vx {
+                vd: "v1",
+                vs2: "v2",
+                rs1: "x10",
+                vm: VectorMask::Masked
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_vector32_vror_vi_upper_immediate_bit() {
+        let result = parse_vector32::<NumberedRegister>(
+            &1,
+            &0b011,
+            &3,
+            &2,
+            &0b0101011,
+            &vector_crypto(Lmul::M1),
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::vror_vi(vror_vi {
+                vd: "v1",
+                vs2: "v2",
+                uimm: 35,
+                vm: VectorMask::Unmasked
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_vector32_masked_v0_destination() {
+        let result = parse_vector32::<NumberedRegister>(
+            &0,
+            &0b010,
+            &0b01000,
+            &2,
+            &0b0100100,
+            &vector_crypto(Lmul::M1),
+        );
+        assert_eq!(
+            result,
+            Err(DisassemblerError::InvalidVectorRegisterGroup(0))
+        );
+    }
+
+    #[test]
+    fn test_parse_vector32_vwsll_lmul() {
+        let result = parse_vector32::<NumberedRegister>(
+            &2,
+            &0b000,
+            &6,
+            &4,
+            &0b1101011,
+            &vector_crypto(Lmul::M2),
+        );
+        assert_eq!(
+            result,
+            Err(DisassemblerError::InvalidVectorRegisterGroup(2))
+        );
+
+        let result = parse_vector32::<NumberedRegister>(
+            &0,
+            &0b000,
+            &8,
+            &16,
+            &0b1101011,
+            &vector_crypto(Lmul::M8),
+        );
+        assert_eq!(
+            result,
+            Err(DisassemblerError::InvalidVectorRegisterGroup(0))
+        );
+    }
+
+    #[test]
+    fn test_parse_vector32_vaesef_vs_overlap() {
+        let result = parse_vector32::<NumberedRegister>(
+            &4,
+            &0b010,
+            &0b00011,
+            &6,
+            &0b1010011,
+            &vector_crypto(Lmul::M4),
+        );
+        assert_eq!(result, Err(DisassemblerError::InvalidElementGroup(6)));
+
+        let result = parse_vector32::<NumberedRegister>(
+            &4,
+            &0b010,
+            &0b00011,
+            &9,
+            &0b1010011,
+            &vector_crypto(Lmul::M4),
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::vaesef_vs(vaesef_vs {
+                vd: "v4",
+                vs2: "v9"
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_vector32_vsha2ms_overlap() {
+        let result = parse_vector32::<NumberedRegister>(
+            &4,
+            &0b010,
+            &4,
+            &8,
+            &0b1011011,
+            &vector_crypto(Lmul::M1),
+        );
+        assert_eq!(result, Err(DisassemblerError::InvalidElementGroup(4)));
+    }
+
+    #[test]
+    fn test_parse_vector32_vsha2ms_element_width() {
+        // vsha2ms.vv v4, v8, v12
+        let parse = |extensions: &Extensions| {
+            parse_vector32::<NumberedRegister>(&4, &0b010, &12, &8, &0b1011011, extensions)
+        };
+        let zvknha = Extensions {
+            zvknha: true,
+            sew: Sew::E64,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse(&zvknha),
+            Err(DisassemblerError::UnsupportedElementWidth(64))
+        );
+        let zvknhb = Extensions {
+            zvknhb: true,
+            ..zvknha
+        };
+        assert!(parse(&zvknhb).is_ok());
+        assert!(parse(&Extensions {
+            sew: Sew::E32,
+            ..zvknha
+        })
+        .is_ok());
+
+        // vaesef.vv v4, v8 only supports 32 bit elements.
+        let result = parse_vector32::<NumberedRegister>(
+            &4,
+            &0b010,
+            &0b00011,
+            &8,
+            &0b1010001,
+            &Extensions {
+                sew: Sew::E16,
+                ..vector_crypto(Lmul::M1)
+            },
+        );
+        assert_eq!(result, Err(DisassemblerError::UnsupportedElementWidth(16)));
+    }
+
+    #[test]
+    fn test_parse_vector32_element_group_width() {
+        // vsm3c.vi v4, v8, 5, whose element group of eight 32 bit elements needs 256 bits.
+        let parse = |vlen, lmul| {
+            let extensions = Extensions {
+                vlen: Some(vlen),
+                ..vector_crypto(lmul)
+            };
+            parse_vector32::<NumberedRegister>(&4, &0b010, &5, &8, &0b1010111, &extensions)
+        };
+        assert_eq!(
+            parse(128, Lmul::M1),
+            Err(DisassemblerError::ElementGroupTooWide(256))
+        );
+        assert!(parse(128, Lmul::M2).is_ok());
+        assert!(parse(256, Lmul::M1).is_ok());
+        assert_eq!(
+            parse(1024, Lmul::Mf8),
+            Err(DisassemblerError::ElementGroupTooWide(256))
+        );
+
+        // vaesef.vv v4, v8, whose element group of four 32 bit elements needs 128 bits.
+        let extensions = Extensions {
+            vlen: Some(128),
+            ..vector_crypto(Lmul::M1)
+        };
+        let result =
+            parse_vector32::<NumberedRegister>(&4, &0b010, &0b00011, &8, &0b1010001, &extensions);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_parse_vector32_crypto_masked() {
+        let result = parse_vector32::<NumberedRegister>(
+            &4,
+            &0b010,
+            &0b10001,
+            &8,
+            &0b1010000,
+            &vector_crypto(Lmul::M1),
+        );
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct7(0b1010000)));
+    }
+
+    #[test]
+    fn test_parse_vector32_vfncvtbf16_wide_source() {
+        let extensions = Extensions {
+            zvfbfmin: true,
+            lmul: Lmul::M2,
+            ..Default::default()
+        };
+        let result =
+            parse_vector32::<NumberedRegister>(&2, &0b001, &0b11101, &4, &0b0100101, &extensions)
+                .unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::vfncvtbf16_f_f_w(vfncvtbf16_f_f_w {
+                vd: "v2",
+                vs2: "v4",
+                vm: VectorMask::Unmasked
+            })
+        );
+
+        let result =
+            parse_vector32::<NumberedRegister>(&2, &0b001, &0b11101, &2, &0b0100101, &extensions);
+        assert_eq!(
+            result,
+            Err(DisassemblerError::InvalidVectorRegisterGroup(2))
+        );
+    }
+
+    #[test]
+    fn test_parse_vector32_vfwmaccbf16_vf() {
+        let extensions = Extensions {
+            zvfbfwma: true,
+            ..Default::default()
+        };
+        let result =
+            parse_vector32::<NumberedRegister>(&2, &0b101, &10, &4, &0b1110110, &extensions)
+                .unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::vfwmaccbf16_vf(vfwmaccbf16_vf {
+                vd: "v2",
+                rs1: "f10",
+                vs2: "v4",
+                vm: VectorMask::Masked
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_vector32_extension_not_selected() {
+        let extensions = Extensions {
+            zvkned: true,
+            ..Default::default()
+        };
+        let result =
+            parse_vector32::<NumberedRegister>(&4, &0b010, &0b10000, &8, &0b1010001, &extensions);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct6(0b101000)));
+
+        let result = parse_vector32::<NumberedRegister>(
+            &4,
+            &0b010,
+            &0b10000,
+            &8,
+            &0b1010001,
+            &Extensions::default(),
+        );
+        assert_eq!(result, Err(DisassemblerError::InvalidOpcode(0b1010111)));
+    }
+}
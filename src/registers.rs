@@ -2,6 +2,10 @@ use crate::DisassemblerError;
 use std::fmt;
 
 pub trait Register {
+    /// Whether the registers are named by their ABI names, which also selects the ABI names of
+    /// the floating point registers.
+    const ABI: bool;
+
     fn as_u8(&self) -> u8;
     fn try_from_u8(value: u8) -> Result<Self, DisassemblerError>
     where
@@ -9,7 +13,49 @@ pub trait Register {
     fn as_str(&self) -> &'static str;
 }
 
+/// Name of a register, as held by the register fields of the instruction structs.
+pub type RegisterName = &'static str;
+
+/// Floating point register names.
+pub(crate) const FLOAT_REGISTERS: [&str; 32] = [
+    "f0", "f1", "f2", "f3", "f4", "f5", "f6", "f7", "f8", "f9", "f10", "f11", "f12", "f13", "f14",
+    "f15", "f16", "f17", "f18", "f19", "f20", "f21", "f22", "f23", "f24", "f25", "f26", "f27",
+    "f28", "f29", "f30", "f31",
+];
+
+/// Floating point register ABI names.
+pub(crate) const FLOAT_ABI_REGISTERS: [&str; 32] = [
+    "ft0", "ft1", "ft2", "ft3", "ft4", "ft5", "ft6", "ft7", "fs0", "fs1", "fa0", "fa1", "fa2",
+    "fa3", "fa4", "fa5", "fa6", "fa7", "fs2", "fs3", "fs4", "fs5", "fs6", "fs7", "fs8", "fs9",
+    "fs10", "fs11", "ft8", "ft9", "ft10", "ft11",
+];
+
+/// Vector register names.
+pub(crate) const VECTOR_REGISTERS: [&str; 32] = [
+    "v0", "v1", "v2", "v3", "v4", "v5", "v6", "v7", "v8", "v9", "v10", "v11", "v12", "v13", "v14",
+    "v15", "v16", "v17", "v18", "v19", "v20", "v21", "v22", "v23", "v24", "v25", "v26", "v27",
+    "v28", "v29", "v30", "v31",
+];
+
+/// Returns the name of a floating point register, in the naming style of the integer registers
+/// `T`.
+pub(crate) fn float_register_name<T: Register>(
+    register: u8,
+) -> Result<&'static str, DisassemblerError> {
+    let names = if T::ABI {
+        &FLOAT_ABI_REGISTERS
+    } else {
+        &FLOAT_REGISTERS
+    };
+    names
+        .get(register as usize)
+        .copied()
+        .ok_or(DisassemblerError::InvalidRegister(register))
+}
+
 impl Register for NumberedRegister {
+    const ABI: bool = false;
+
     fn as_u8(&self) -> u8 {
         *self as u8
     }
@@ -57,6 +103,8 @@ impl Register for NumberedRegister {
 }
 
 impl Register for ABIRegister {
+    const ABI: bool = true;
+
     fn as_u8(&self) -> u8 {
         *self as u8
     }
@@ -103,7 +151,37 @@ impl Register for ABIRegister {
     }
 }
 
+/// Integer registers of the RV32E base, named like `T`, of which only `x0` to `x15` exist.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) struct EmbeddedRegister<T>(T);
+
+impl<T: Register> Register for EmbeddedRegister<T> {
+    const ABI: bool = T::ABI;
+
+    fn as_u8(&self) -> u8 {
+        self.0.as_u8()
+    }
+
+    fn try_from_u8(value: u8) -> Result<Self, DisassemblerError> {
+        if value >= 16 {
+            return Err(DisassemblerError::InvalidRegister(value));
+        }
+        T::try_from_u8(value).map(EmbeddedRegister)
+    }
+
+    fn as_str(&self) -> &'static str {
+        self.0.as_str()
+    }
+}
+
+/// Returns whether `name` is the numbered or ABI name of integer register `register`.
+pub(crate) fn is_register(name: &str, register: u8) -> bool {
+    NumberedRegister::try_from(register).map(|r| r.as_str()) == Ok(name)
+        || ABIRegister::try_from(register).map(|r| r.as_str()) == Ok(name)
+}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 #[allow(non_camel_case_types)]
 pub enum NumberedRegister {
@@ -142,6 +220,7 @@ pub enum NumberedRegister {
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 #[allow(non_camel_case_types)]
 pub enum ABIRegister {
@@ -0,0 +1,199 @@
+//! Relocations of the RISC-V ELF psABI, which name the symbols that the immediates of an object
+//! file are resolved to when it is linked.
+
+use std::fmt;
+
+pub const R_RISCV_NONE: u32 = 0;
+pub const R_RISCV_32: u32 = 1;
+pub const R_RISCV_64: u32 = 2;
+pub const R_RISCV_BRANCH: u32 = 16;
+pub const R_RISCV_JAL: u32 = 17;
+pub const R_RISCV_CALL: u32 = 18;
+pub const R_RISCV_CALL_PLT: u32 = 19;
+pub const R_RISCV_GOT_HI20: u32 = 20;
+pub const R_RISCV_TLS_GOT_HI20: u32 = 21;
+pub const R_RISCV_TLS_GD_HI20: u32 = 22;
+pub const R_RISCV_PCREL_HI20: u32 = 23;
+pub const R_RISCV_PCREL_LO12_I: u32 = 24;
+pub const R_RISCV_PCREL_LO12_S: u32 = 25;
+pub const R_RISCV_HI20: u32 = 26;
+pub const R_RISCV_LO12_I: u32 = 27;
+pub const R_RISCV_LO12_S: u32 = 28;
+pub const R_RISCV_TPREL_HI20: u32 = 29;
+pub const R_RISCV_TPREL_LO12_I: u32 = 30;
+pub const R_RISCV_TPREL_LO12_S: u32 = 31;
+pub const R_RISCV_TPREL_ADD: u32 = 32;
+pub const R_RISCV_ALIGN: u32 = 43;
+pub const R_RISCV_RVC_BRANCH: u32 = 44;
+pub const R_RISCV_RVC_JUMP: u32 = 45;
+pub const R_RISCV_RELAX: u32 = 51;
+
+/// A relocation of an instruction or data word.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Relocation {
+    /// Address of the instruction or data that is relocated.
+    pub address: u64,
+    /// The relocation type, such as [`R_RISCV_CALL_PLT`].
+    pub relocation_type: u32,
+    /// Name of the symbol the relocation refers to, or of its section for relocations against
+    /// a section.
+    pub symbol: String,
+    pub addend: i64,
+}
+
+impl Relocation {
+    /// Returns the psABI name of the relocation type, such as `R_RISCV_CALL_PLT`.
+    pub fn type_name(&self) -> Option<&'static str> {
+        let name = match self.relocation_type {
+            R_RISCV_NONE => "R_RISCV_NONE",
+            R_RISCV_32 => "R_RISCV_32",
+            R_RISCV_64 => "R_RISCV_64",
+            3 => "R_RISCV_RELATIVE",
+            4 => "R_RISCV_COPY",
+            5 => "R_RISCV_JUMP_SLOT",
+            6 => "R_RISCV_TLS_DTPMOD32",
+            7 => "R_RISCV_TLS_DTPMOD64",
+            8 => "R_RISCV_TLS_DTPREL32",
+            9 => "R_RISCV_TLS_DTPREL64",
+            10 => "R_RISCV_TLS_TPREL32",
+            11 => "R_RISCV_TLS_TPREL64",
+            12 => "R_RISCV_TLSDESC",
+            R_RISCV_BRANCH => "R_RISCV_BRANCH",
+            R_RISCV_JAL => "R_RISCV_JAL",
+            R_RISCV_CALL => "R_RISCV_CALL",
+            R_RISCV_CALL_PLT => "R_RISCV_CALL_PLT",
+            R_RISCV_GOT_HI20 => "R_RISCV_GOT_HI20",
+            R_RISCV_TLS_GOT_HI20 => "R_RISCV_TLS_GOT_HI20",
+            R_RISCV_TLS_GD_HI20 => "R_RISCV_TLS_GD_HI20",
+            R_RISCV_PCREL_HI20 => "R_RISCV_PCREL_HI20",
+            R_RISCV_PCREL_LO12_I => "R_RISCV_PCREL_LO12_I",
+            R_RISCV_PCREL_LO12_S => "R_RISCV_PCREL_LO12_S",
+            R_RISCV_HI20 => "R_RISCV_HI20",
+            R_RISCV_LO12_I => "R_RISCV_LO12_I",
+            R_RISCV_LO12_S => "R_RISCV_LO12_S",
+            R_RISCV_TPREL_HI20 => "R_RISCV_TPREL_HI20",
+            R_RISCV_TPREL_LO12_I => "R_RISCV_TPREL_LO12_I",
+            R_RISCV_TPREL_LO12_S => "R_RISCV_TPREL_LO12_S",
+            R_RISCV_TPREL_ADD => "R_RISCV_TPREL_ADD",
+            33 => "R_RISCV_ADD8",
+            34 => "R_RISCV_ADD16",
+            35 => "R_RISCV_ADD32",
+            36 => "R_RISCV_ADD64",
+            37 => "R_RISCV_SUB8",
+            38 => "R_RISCV_SUB16",
+            39 => "R_RISCV_SUB32",
+            40 => "R_RISCV_SUB64",
+            41 => "R_RISCV_GOT32_PCREL",
+            R_RISCV_ALIGN => "R_RISCV_ALIGN",
+            R_RISCV_RVC_BRANCH => "R_RISCV_RVC_BRANCH",
+            R_RISCV_RVC_JUMP => "R_RISCV_RVC_JUMP",
+            R_RISCV_RELAX => "R_RISCV_RELAX",
+            52 => "R_RISCV_SUB6",
+            53 => "R_RISCV_SET6",
+            54 => "R_RISCV_SET8",
+            55 => "R_RISCV_SET16",
+            56 => "R_RISCV_SET32",
+            57 => "R_RISCV_32_PCREL",
+            58 => "R_RISCV_IRELATIVE",
+            59 => "R_RISCV_PLT32",
+            60 => "R_RISCV_SET_ULEB128",
+            61 => "R_RISCV_SUB_ULEB128",
+            62 => "R_RISCV_TLSDESC_HI20",
+            63 => "R_RISCV_TLSDESC_LOAD_LO12",
+            64 => "R_RISCV_TLSDESC_ADD_LO12",
+            65 => "R_RISCV_TLSDESC_CALL",
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    /// Returns the symbol and addend, as `printf` or `message+0x4`.
+    pub fn target(&self) -> String {
+        match self.addend {
+            0 => self.symbol.clone(),
+            addend if addend < 0 => format!("{}-{:#x}", self.symbol, addend.unsigned_abs()),
+            addend => format!("{}+{:#x}", self.symbol, addend),
+        }
+    }
+
+    /// Returns the operand the relocation gives the immediate of its instruction, such as
+    /// `%hi(message)`, or `None` for relocations that do not stand for a single operand.
+    pub fn operand(&self) -> Option<String> {
+        let modifier = match self.relocation_type {
+            R_RISCV_BRANCH | R_RISCV_JAL | R_RISCV_RVC_BRANCH | R_RISCV_RVC_JUMP => {
+                return Some(self.target())
+            }
+            R_RISCV_HI20 => "hi",
+            R_RISCV_LO12_I | R_RISCV_LO12_S => "lo",
+            R_RISCV_PCREL_HI20 => "pcrel_hi",
+            R_RISCV_PCREL_LO12_I | R_RISCV_PCREL_LO12_S => "pcrel_lo",
+            R_RISCV_GOT_HI20 => "got_pcrel_hi",
+            R_RISCV_TLS_GOT_HI20 => "tls_ie_pcrel_hi",
+            R_RISCV_TLS_GD_HI20 => "tls_gd_pcrel_hi",
+            R_RISCV_TPREL_HI20 => "tprel_hi",
+            R_RISCV_TPREL_LO12_I | R_RISCV_TPREL_LO12_S => "tprel_lo",
+            _ => return None,
+        };
+        Some(format!("%{}({})", modifier, self.target()))
+    }
+
+    /// Returns whether the relocation only marks the instruction for the linker, rather than
+    /// changing its operands.
+    pub(crate) fn is_marker(&self) -> bool {
+        matches!(
+            self.relocation_type,
+            R_RISCV_NONE | R_RISCV_RELAX | R_RISCV_ALIGN
+        )
+    }
+}
+
+/// Displays the relocation as `R_RISCV_CALL_PLT printf`, with the number of types this crate
+/// does not name.
+impl fmt::Display for Relocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.type_name() {
+            Some(name) => write!(f, "{} {}", name, self.target()),
+            None => write!(f, "R_RISCV_{} {}", self.relocation_type, self.target()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn relocation(relocation_type: u32, symbol: &str, addend: i64) -> Relocation {
+        Relocation {
+            address: 0,
+            relocation_type,
+            symbol: symbol.to_string(),
+            addend,
+        }
+    }
+
+    #[test]
+    fn test_operand() {
+        let operand = |t, addend| relocation(t, "message", addend).operand();
+        assert_eq!(operand(R_RISCV_HI20, 0), Some("%hi(message)".to_string()));
+        assert_eq!(
+            operand(R_RISCV_LO12_S, 4),
+            Some("%lo(message+0x4)".to_string())
+        );
+        assert_eq!(
+            operand(R_RISCV_PCREL_HI20, -8),
+            Some("%pcrel_hi(message-0x8)".to_string())
+        );
+        assert_eq!(operand(R_RISCV_JAL, 0), Some("message".to_string()));
+        assert_eq!(operand(R_RISCV_CALL_PLT, 0), None);
+        assert_eq!(operand(R_RISCV_RELAX, 0), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            relocation(R_RISCV_CALL_PLT, "printf", 0).to_string(),
+            "R_RISCV_CALL_PLT printf"
+        );
+        assert_eq!(relocation(200, "x", 1).to_string(), "R_RISCV_200 x+0x1");
+    }
+}
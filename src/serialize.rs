@@ -0,0 +1,86 @@
+//! Helpers of the `serde` feature, deserializing register names and error messages into the
+//! static strings the decoder uses.
+
+use crate::registers::{
+    ABIRegister, NumberedRegister, Register, RegisterName, FLOAT_ABI_REGISTERS, FLOAT_REGISTERS,
+    VECTOR_REGISTERS,
+};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+
+/// Messages of [`DisassemblerError::BitExtractionError`](crate::DisassemblerError) and
+/// [`DisassemblerError::BitExtensionError`](crate::DisassemblerError).
+const MESSAGES: [&str; 3] = [
+    "Index out of bounds",
+    "Start index must be less than or equal to end index",
+    "Size exceeds 32 bits",
+];
+
+/// Returns the static name of the integer, floating point or vector register `name`.
+fn register_name(name: &str) -> Option<RegisterName> {
+    (0..32)
+        .flat_map(|index| {
+            [
+                NumberedRegister::try_from(index).map(|r| r.as_str()),
+                ABIRegister::try_from(index).map(|r| r.as_str()),
+            ]
+        })
+        .flatten()
+        .chain(FLOAT_REGISTERS)
+        .chain(FLOAT_ABI_REGISTERS)
+        .chain(VECTOR_REGISTERS)
+        .find(|register| *register == name)
+}
+
+/// Deserializes a register name.
+pub(crate) fn register<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<RegisterName, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    register_name(&name).ok_or_else(|| D::Error::custom(format!("unknown register {:?}", name)))
+}
+
+/// Deserializes the ranges of a [`RegisterList`](crate::parsed_instructions::RegisterList).
+pub(crate) fn register_ranges<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<(RegisterName, RegisterName)>, D::Error> {
+    Vec::<(String, String)>::deserialize(deserializer)?
+        .iter()
+        .map(
+            |(first, last)| match (register_name(first), register_name(last)) {
+                (Some(first), Some(last)) => Ok((first, last)),
+                _ => Err(D::Error::custom(format!(
+                    "unknown register range {:?}",
+                    (first, last)
+                ))),
+            },
+        )
+        .collect()
+}
+
+/// Deserializes the message of a bit extraction or extension error.
+pub(crate) fn message<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<&'static str, D::Error> {
+    let message = String::deserialize(deserializer)?;
+    MESSAGES
+        .into_iter()
+        .find(|known| *known == message)
+        .ok_or_else(|| D::Error::custom(format!("unknown error message {:?}", message)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_name() {
+        assert_eq!(register_name("x31"), Some("x31"));
+        assert_eq!(register_name("zero"), Some("zero"));
+        assert_eq!(register_name("fa0"), Some("fa0"));
+        assert_eq!(register_name("f31"), Some("f31"));
+        assert_eq!(register_name("v8"), Some("v8"));
+        assert_eq!(register_name("x32"), None);
+        assert_eq!(register_name("fp"), None);
+    }
+}
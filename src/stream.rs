@@ -0,0 +1,328 @@
+//! Streaming decoding of a buffer holding a sequence of instructions.
+
+use crate::extensions::isa_string_compressed;
+use crate::{
+    parse_with_custom_decoders, parse_with_extensions, CustomDecoders, DisassemblerError,
+    Extensions, ParsedInstruction32,
+};
+
+/// An instruction decoded by [`Instructions`].
+#[derive(Debug, PartialEq)]
+pub struct Disassembled<'a> {
+    /// Address of the first byte of the instruction, which wraps at 32 bits as those of RV32
+    /// do.
+    pub address: u64,
+    /// The encoded instruction, or the remaining bytes of the buffer when it ends within the
+    /// instruction.
+    pub bytes: &'a [u8],
+    pub instruction: Result<ParsedInstruction32, DisassemblerError>,
+}
+
+/// Iterator decoding the instructions of a buffer in order, created by [`disassemble`].
+///
+/// The length of each instruction is read from the low bits of its first 16 bit parcel. An
+/// instruction that cannot be decoded is returned as an error and skipped, so the iterator
+/// continues with the next one. Instructions longer than 32 bits are skipped in the same way.
+#[derive(Debug, Clone)]
+pub struct Instructions<'a> {
+    bytes: &'a [u8],
+    address: u64,
+    offset: usize,
+    is_big_endian: bool,
+    use_abi_register_names: bool,
+    extensions: Extensions,
+    compressed: bool,
+    decoders: Option<&'a CustomDecoders>,
+}
+
+/// Returns an iterator over the instructions in `bytes`, the first of which is at `address`.
+/// The addresses of RV32 instructions wrap at 32 bits, and so does `address`.
+///
+/// Big endian buffers store every instruction as a byte-swapped word, which hides the length of
+/// the instruction from its first parcel, so they are decoded as 32 bit instructions only.
+///
+/// ### Example
+///
+/// ```
+/// use risc_v_disassembler::{disassemble, Extensions};
+///
+/// let bytes = [0x93, 0x00, 0x51, 0x00, 0x61, 0x9d];
+/// let extensions = Extensions {
+///     zcb: true,
+///     ..Default::default()
+/// };
+///
+/// let listing: Vec<String> = disassemble(&bytes, 0x1000, false, true, &extensions)
+///     .map(|disassembled| {
+///         format!("{:x}: {}", disassembled.address, disassembled.instruction.unwrap())
+///     })
+///     .collect();
+/// assert_eq!(listing, ["1000: addi ra, sp, 5", "1004: c.zext.b a0"]);
+/// ```
+pub fn disassemble<'a>(
+    bytes: &'a [u8],
+    address: u64,
+    is_big_endian: bool,
+    use_abi_register_names: bool,
+    extensions: &Extensions,
+) -> Instructions<'a> {
+    Instructions {
+        bytes,
+        address: address as u32 as u64,
+        offset: 0,
+        is_big_endian,
+        use_abi_register_names,
+        extensions: *extensions,
+        compressed: !is_big_endian,
+        decoders: None,
+    }
+}
+
+impl<'a> Instructions<'a> {
+    /// Selects whether the buffer holds 16 bit instructions. Without the C extension or one of
+    /// its Zc* subsets every instruction is 32 bits long, so a word whose low bits mark a 16 bit
+    /// instruction is decoded, and rejected, as a whole word.
+    pub fn compressed(mut self, compressed: bool) -> Self {
+        self.compressed = compressed && !self.is_big_endian;
+        self
+    }
+
+    /// Passes the instructions of the custom opcodes to the `decoders` registered for them, as
+    /// [`parse_with_custom_decoders`] does, so that vendor extensions such as those of the
+    /// `xthead` and `xcv` features are decoded.
+    pub fn custom_decoders(mut self, decoders: &'a CustomDecoders) -> Self {
+        self.decoders = Some(decoders);
+        self
+    }
+
+    /// Returns the addresses of the next instruction and of the end of the buffer.
+    pub(crate) fn range(&self) -> (u64, u64) {
+        (
+            self.address.wrapping_add(self.offset as u64),
+            self.address.wrapping_add(self.bytes.len() as u64),
+        )
+    }
+
+    pub(crate) fn is_big_endian(&self) -> bool {
+        self.is_big_endian
+    }
+
+    /// Returns an iterator over the instructions from `start` to `end`, decoded with the
+    /// extensions of `isa` instead, when it is a valid ISA string.
+    pub(crate) fn region(&self, start: u64, end: u64, isa: Option<&str>) -> Instructions<'a> {
+        let mut region = Instructions {
+            bytes: self.bytes_at(start, (end - start) as usize),
+            address: start,
+            offset: 0,
+            ..self.clone()
+        };
+        if let Some(isa) = isa {
+            if let (Ok(extensions), Ok(compressed)) =
+                (Extensions::from_isa_string(isa), isa_string_compressed(isa))
+            {
+                region.extensions = extensions;
+                region = region.compressed(compressed);
+            }
+        }
+        region
+    }
+
+    /// Returns `length` bytes of the buffer, starting with the instruction at `address`.
+    pub(crate) fn bytes_at(&self, address: u64, length: usize) -> &'a [u8] {
+        let offset = address.wrapping_sub(self.address) as u32 as usize;
+        &self.bytes[offset..offset + length]
+    }
+
+    /// Returns the length in bytes of the instruction starting with `bytes`.
+    fn instruction_length(&self, bytes: &[u8]) -> usize {
+        if !self.compressed {
+            return 4;
+        }
+        let parcel = match bytes {
+            [low, high, ..] => u16::from_le_bytes([*low, *high]),
+            _ => return 2,
+        };
+        match parcel {
+            _ if parcel & 0b11 != 0b11 => 2,
+            _ if parcel & 0b11100 != 0b11100 => 4,
+            _ if parcel & 0b111111 == 0b011111 => 6,
+            _ if parcel & 0b1111111 == 0b0111111 => 8,
+            // The 80 to 176 bit formats, while the longer ones are reserved and skipped as a
+            // single parcel.
+            _ if parcel >> 12 & 0b111 != 0b111 => 10 + 2 * (parcel >> 12 & 0b111) as usize,
+            _ => 2,
+        }
+    }
+}
+
+impl<'a> Iterator for Instructions<'a> {
+    type Item = Disassembled<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = &self.bytes[self.offset..];
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let address = self.address.wrapping_add(self.offset as u64) as u32 as u64;
+        let length = self.instruction_length(remaining);
+        if remaining.len() < length {
+            self.offset = self.bytes.len();
+            return Some(Disassembled {
+                address,
+                bytes: remaining,
+                instruction: Err(DisassemblerError::UnsupportedInstructionLength(
+                    remaining.len(),
+                )),
+            });
+        }
+
+        let bytes = &remaining[..length];
+        self.offset += length;
+        let instruction = match (length, self.decoders) {
+            (4, Some(decoders)) => parse_with_custom_decoders(
+                bytes,
+                self.is_big_endian,
+                self.use_abi_register_names,
+                &self.extensions,
+                decoders,
+            ),
+            (2 | 4, _) => parse_with_extensions(
+                bytes,
+                self.is_big_endian,
+                self.use_abi_register_names,
+                &self.extensions,
+            ),
+            _ => Err(DisassemblerError::UnsupportedInstructionLength(length)),
+        };
+        Some(Disassembled {
+            address,
+            bytes,
+            instruction,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomDecoder, CustomInstruction};
+
+    #[test]
+    fn test_disassemble_mixed_lengths() {
+        let extensions = Extensions {
+            zcb: true,
+            ..Default::default()
+        };
+        let bytes = [0x61, 0x9d, 0x93, 0x00, 0x51, 0x00, 0x61, 0x9d];
+        let addresses: Vec<(u64, usize)> = disassemble(&bytes, 0x100, false, false, &extensions)
+            .map(|disassembled| (disassembled.address, disassembled.bytes.len()))
+            .collect();
+        assert_eq!(addresses, [(0x100, 2), (0x102, 4), (0x106, 2)]);
+    }
+
+    #[test]
+    fn test_disassemble_wrapping_addresses() {
+        let bytes = 0x00000013u32.to_le_bytes().repeat(3);
+        let addresses = |address| {
+            disassemble(&bytes, address, false, false, &Extensions::default())
+                .map(|disassembled| disassembled.address)
+                .collect::<Vec<u64>>()
+        };
+        assert_eq!(addresses(0xffff_fffc), [0xffff_fffc, 0, 4]);
+        assert_eq!(addresses(u64::MAX - 1), [0xffff_fffe, 2, 6]);
+    }
+
+    #[test]
+    fn test_disassemble_continues_after_error() {
+        let bytes = [0xff, 0xff, 0xff, 0xff, 0x93, 0x00, 0x51, 0x00];
+        let results: Vec<bool> = disassemble(&bytes, 0, false, false, &Extensions::default())
+            .compressed(false)
+            .map(|disassembled| disassembled.instruction.is_ok())
+            .collect();
+        assert_eq!(results, [false, true]);
+    }
+
+    #[test]
+    fn test_disassemble_truncated() {
+        let bytes = [0x93, 0x00, 0x51];
+        let disassembled: Vec<Disassembled> =
+            disassemble(&bytes, 0, false, false, &Extensions::default()).collect();
+        assert_eq!(
+            disassembled,
+            [Disassembled {
+                address: 0,
+                bytes: &bytes,
+                instruction: Err(DisassemblerError::UnsupportedInstructionLength(3)),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_disassemble_long_instruction() {
+        let bytes = [0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x93, 0x00, 0x51, 0x00];
+        let lengths: Vec<(usize, bool)> =
+            disassemble(&bytes, 0, false, false, &Extensions::default())
+                .map(|disassembled| (disassembled.bytes.len(), disassembled.instruction.is_ok()))
+                .collect();
+        assert_eq!(lengths, [(6, false), (4, true)]);
+    }
+
+    #[test]
+    fn test_disassemble_custom_decoders() {
+        #[derive(Debug, PartialEq)]
+        struct Custom(u32);
+
+        impl std::fmt::Display for Custom {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "custom {:#x}", self.0)
+            }
+        }
+
+        impl CustomInstruction for Custom {
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            fn eq_custom(&self, other: &dyn CustomInstruction) -> bool {
+                other.as_any().downcast_ref::<Custom>() == Some(self)
+            }
+        }
+
+        struct Decoder;
+
+        impl CustomDecoder for Decoder {
+            fn decode(
+                &self,
+                instruction: u32,
+                _register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+                _float_register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+            ) -> Result<Box<dyn CustomInstruction>, DisassemblerError> {
+                Ok(Box::new(Custom(instruction)))
+            }
+        }
+
+        let mut decoders = CustomDecoders::new();
+        decoders.register(0b0001011, Decoder).unwrap();
+        let bytes = [0x0b, 0x00, 0x00, 0x00, 0x93, 0x00, 0x51, 0x00];
+        let listing: Vec<String> = disassemble(&bytes, 0, false, true, &Extensions::default())
+            .custom_decoders(&decoders)
+            .map(|disassembled| disassembled.instruction.unwrap().to_string())
+            .collect();
+        assert_eq!(listing, ["custom 0xb", "addi ra, sp, 5"]);
+        assert!(disassemble(&bytes, 0, false, true, &Extensions::default())
+            .next()
+            .unwrap()
+            .instruction
+            .is_err());
+    }
+
+    #[test]
+    fn test_disassemble_big_endian() {
+        let bytes = [0x00, 0x51, 0x00, 0x93, 0x00, 0x51, 0x00, 0x93];
+        let addresses: Vec<u64> = disassemble(&bytes, 0, true, false, &Extensions::default())
+            .map(|disassembled| disassembled.address)
+            .collect();
+        assert_eq!(addresses, [0, 4]);
+    }
+}
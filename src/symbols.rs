@@ -0,0 +1,287 @@
+//! Symbols naming the addresses of a listing, read from an ELF symbol table or supplied by the
+//! user, and the mapping symbols separating its code from its data.
+
+use std::fmt;
+
+/// A named address, such as a function or a data object.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Symbol {
+    pub name: String,
+    pub address: u64,
+    /// Size in bytes, or zero when unknown.
+    pub size: u64,
+    pub is_function: bool,
+}
+
+impl Symbol {
+    /// Returns a symbol of unknown size that is not known to be a function.
+    pub fn new(name: impl Into<String>, address: u64) -> Self {
+        Symbol {
+            name: name.into(),
+            address,
+            size: 0,
+            is_function: false,
+        }
+    }
+
+    /// Returns a function symbol.
+    pub fn function(name: impl Into<String>, address: u64, size: u64) -> Self {
+        Symbol {
+            size,
+            is_function: true,
+            ..Symbol::new(name, address)
+        }
+    }
+}
+
+/// What the bytes from a mapping symbol up to the next one hold.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Mapping {
+    /// Instructions, named `$x`, or `$x<isa>` when they are decoded with the extensions of the
+    /// ISA string instead of those of the file, as in `$xrv32i2p1_c2p0`.
+    Code { isa: Option<String> },
+    /// Data, such as literal pools or jump tables, named `$d`.
+    Data,
+}
+
+impl Mapping {
+    /// Returns the mapping of a mapping symbol name, or `None` for other names. Suffixes such
+    /// as the `.1` of `$d.1`, which keep the names of local mapping symbols unique, are ignored.
+    pub fn from_name(name: &str) -> Option<Mapping> {
+        let (kind, rest) = (name.get(..2)?, &name[2..]);
+        let plain = rest.is_empty() || rest.starts_with('.');
+        match kind {
+            "$d" if plain => Some(Mapping::Data),
+            "$x" if plain => Some(Mapping::Code { isa: None }),
+            "$x" if rest.starts_with("rv") => Some(Mapping::Code {
+                isa: Some(rest.to_string()),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// A mapping symbol, marking the start of code or data.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MappingSymbol {
+    pub address: u64,
+    pub mapping: Mapping,
+}
+
+/// Symbols ordered by address, used to label a listing and to name the targets of its
+/// instructions, with the mapping symbols that tell its code and data apart.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SymbolMap {
+    symbols: Vec<Symbol>,
+    mappings: Vec<MappingSymbol>,
+}
+
+/// An address given as an offset from a symbol, displayed as `name` or `name+0x1c`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SymbolReference<'a> {
+    pub symbol: &'a Symbol,
+    pub offset: u64,
+}
+
+impl fmt::Display for SymbolReference<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.offset {
+            0 => write!(f, "{}", self.symbol.name),
+            offset => write!(f, "{}+{:#x}", self.symbol.name, offset),
+        }
+    }
+}
+
+impl SymbolMap {
+    pub fn new() -> Self {
+        SymbolMap::default()
+    }
+
+    /// Adds a symbol after the symbols already at its address, unless a symbol with the same
+    /// name is among them, as happens when `.symtab` and `.dynsym` both list a function.
+    /// Mapping symbols such as `$x` and `$d` are added as mappings instead of labels.
+    pub fn insert(&mut self, symbol: Symbol) {
+        if let Some(mapping) = Mapping::from_name(&symbol.name) {
+            self.insert_mapping(symbol.address, mapping);
+            return;
+        }
+        if self
+            .at(symbol.address)
+            .iter()
+            .any(|s| s.name == symbol.name)
+        {
+            return;
+        }
+        let index = self
+            .symbols
+            .partition_point(|s| s.address <= symbol.address);
+        self.symbols.insert(index, symbol);
+    }
+
+    /// Marks `address` as the start of code or data, replacing a mapping already there.
+    pub fn insert_mapping(&mut self, address: u64, mapping: Mapping) {
+        let index = self.mappings.partition_point(|m| m.address < address);
+        match self.mappings.get_mut(index) {
+            Some(existing) if existing.address == address => existing.mapping = mapping,
+            _ => self
+                .mappings
+                .insert(index, MappingSymbol { address, mapping }),
+        }
+    }
+
+    /// Returns the mapping symbols in order of address.
+    pub fn mappings(&self) -> &[MappingSymbol] {
+        &self.mappings
+    }
+
+    /// Returns the mapping symbol in effect at `address`, which is the last one at or below it.
+    pub fn mapping(&self, address: u64) -> Option<&MappingSymbol> {
+        let index = self.mappings.partition_point(|m| m.address <= address);
+        self.mappings[..index].last()
+    }
+
+    /// Returns the address of the first mapping symbol above `address`.
+    pub fn next_mapping(&self, address: u64) -> Option<u64> {
+        let index = self.mappings.partition_point(|m| m.address <= address);
+        self.mappings.get(index).map(|m| m.address)
+    }
+
+    /// Returns whether there are no symbols, not counting mapping symbols.
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Returns the number of symbols, not counting mapping symbols.
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Returns the symbols in order of address.
+    pub fn iter(&self) -> impl Iterator<Item = &Symbol> {
+        self.symbols.iter()
+    }
+
+    /// Returns the symbols at `address`, in the order they were added.
+    pub fn at(&self, address: u64) -> &[Symbol] {
+        let start = self.symbols.partition_point(|s| s.address < address);
+        let end = self.symbols.partition_point(|s| s.address <= address);
+        &self.symbols[start..end]
+    }
+
+    /// Returns `address` as an offset from the closest symbol at or below it, preferring a
+    /// function among symbols at the same address.
+    pub fn lookup(&self, address: u64) -> Option<SymbolReference<'_>> {
+        let index = self.symbols.partition_point(|s| s.address <= address);
+        let closest = self.symbols[..index].last()?;
+        let candidates = self.at(closest.address);
+        let symbol = candidates
+            .iter()
+            .find(|s| s.is_function)
+            .unwrap_or(&candidates[0]);
+        Some(SymbolReference {
+            symbol,
+            offset: address - symbol.address,
+        })
+    }
+}
+
+impl FromIterator<Symbol> for SymbolMap {
+    fn from_iter<I: IntoIterator<Item = Symbol>>(symbols: I) -> Self {
+        let mut map = SymbolMap::new();
+        map.extend(symbols);
+        map
+    }
+}
+
+impl Extend<Symbol> for SymbolMap {
+    fn extend<I: IntoIterator<Item = Symbol>>(&mut self, symbols: I) {
+        for symbol in symbols {
+            self.insert(symbol);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbols() -> SymbolMap {
+        [
+            Symbol::new("data", 0x2000),
+            Symbol::new("_start", 0x1000),
+            Symbol::function("main", 0x1000, 0x20),
+            Symbol::function("helper", 0x1020, 0x10),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_at() {
+        let symbols = symbols();
+        let names: Vec<&str> = symbols.at(0x1000).iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["_start", "main"]);
+        assert!(symbols.at(0x1004).is_empty());
+    }
+
+    #[test]
+    fn test_lookup() {
+        let symbols = symbols();
+        let lookup = |address| symbols.lookup(address).map(|r| r.to_string());
+        assert_eq!(lookup(0x1000), Some("main".to_string()));
+        assert_eq!(lookup(0x101c), Some("main+0x1c".to_string()));
+        assert_eq!(lookup(0x1024), Some("helper+0x4".to_string()));
+        assert_eq!(lookup(0x2008), Some("data+0x8".to_string()));
+        assert_eq!(lookup(0xfff), None);
+    }
+
+    #[test]
+    fn test_insert_duplicate() {
+        let mut symbols = symbols();
+        symbols.insert(Symbol::function("main", 0x1000, 0x20));
+        symbols.insert(Symbol::new("main", 0x1020));
+        assert_eq!(symbols.len(), 5);
+        assert_eq!(symbols.at(0x1020).len(), 2);
+    }
+
+    #[test]
+    fn test_mapping_from_name() {
+        assert_eq!(Mapping::from_name("$d"), Some(Mapping::Data));
+        assert_eq!(Mapping::from_name("$d.12"), Some(Mapping::Data));
+        assert_eq!(Mapping::from_name("$x"), Some(Mapping::Code { isa: None }));
+        assert_eq!(
+            Mapping::from_name("$xrv32i2p1_c2p0"),
+            Some(Mapping::Code {
+                isa: Some("rv32i2p1_c2p0".to_string())
+            })
+        );
+        assert_eq!(Mapping::from_name("$data"), None);
+        assert_eq!(Mapping::from_name("$"), None);
+        assert_eq!(Mapping::from_name("main"), None);
+    }
+
+    #[test]
+    fn test_mappings() {
+        let mut symbols = symbols();
+        symbols.extend([
+            Symbol::new("$x", 0x1000),
+            Symbol::new("$d", 0x1010),
+            Symbol::new("$xrv32ic", 0x1020),
+        ]);
+        symbols.insert_mapping(0x1010, Mapping::Data);
+        assert_eq!(symbols.len(), 4);
+        assert_eq!(symbols.mappings().len(), 3);
+
+        assert_eq!(symbols.mapping(0xfff), None);
+        assert_eq!(
+            symbols.mapping(0x100c).map(|m| &m.mapping),
+            Some(&Mapping::Code { isa: None })
+        );
+        assert_eq!(
+            symbols.mapping(0x1010).map(|m| &m.mapping),
+            Some(&Mapping::Data)
+        );
+        assert_eq!(symbols.next_mapping(0x1000), Some(0x1010));
+        assert_eq!(symbols.next_mapping(0x1020), None);
+    }
+}
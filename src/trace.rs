@@ -0,0 +1,109 @@
+//! Records of decoded instructions, for storing traces and exchanging them with other tools. The
+//! module requires the `serde` cargo feature, which also implements `Serialize` and
+//! `Deserialize` for [`ParsedInstruction32`], the structs of
+//! [`parsed_instructions`](crate::parsed_instructions) and [`DisassemblerError`].
+//!
+//! A [`TraceEntry`] is serialized with the fields:
+//!
+//!  - `address`: the address of the instruction.
+//!  - `bits`: the encoded instruction, as an unsigned integer.
+//!  - `length`: the length of the instruction in bytes, 2 or 4.
+//!  - `mnemonic`: the mnemonic of the instruction, as in `c.zext.b` for the `c_zext_b` struct,
+//!    without the number of the may-be-operations such as `c.mop.N`.
+//!  - `operands`: the fields of the instruction struct. Registers are given by name, rounding
+//!    modes, memory orderings and vector masks in snake case, as in `"rne"`, and the wrapped
+//!    instruction of a `hint` as a nested `mnemonic` and `operands` object.
+//!
+//! Instructions of custom decoders, including those of the `xthead` and `xcv` features, are
+//! serialized under the `custom` mnemonic with their text, as `{"instruction": "cv.abs a0, a1"}`,
+//! and cannot be deserialized. The schema is that of `tests/fixtures/trace.json`, and of
+//! `tests/fixtures/custom_trace.json` for custom instructions.
+//!
+//! ### Example
+//!
+//! ```
+//! use risc_v_disassembler::trace::TraceEntry;
+//! use risc_v_disassembler::{disassemble, Extensions};
+//!
+//! let bytes = [0x93, 0x00, 0x51, 0x00];
+//! let entries: Vec<TraceEntry> = disassemble(&bytes, 0x1000, false, true, &Extensions::default())
+//!     .map(|disassembled| TraceEntry::from_disassembled(disassembled, false).unwrap())
+//!     .collect();
+//!
+//! let json = serde_json::to_string(&entries[0]).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"address":4096,"bits":5308563,"length":4,"mnemonic":"addi","operands":{"rd":"ra","rs1":"sp","imm":5}}"#
+//! );
+//! assert_eq!(serde_json::from_str::<TraceEntry>(&json).unwrap(), entries[0]);
+//! ```
+
+use crate::{Disassembled, DisassemblerError, ParsedInstruction32};
+use serde::{Deserialize, Serialize};
+
+/// An instruction of a trace, with its address and encoding.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct TraceEntry {
+    pub address: u64,
+    pub bits: u32,
+    pub length: usize,
+    #[serde(flatten)]
+    pub instruction: ParsedInstruction32,
+}
+
+impl TraceEntry {
+    /// Builds the entry of an instruction decoded by [`disassemble`](crate::disassemble) from a
+    /// buffer of the given endianness, or returns the error it could not be decoded with.
+    pub fn from_disassembled(
+        disassembled: Disassembled,
+        is_big_endian: bool,
+    ) -> Result<Self, DisassemblerError> {
+        let instruction = disassembled.instruction?;
+        let bytes = disassembled.bytes;
+        let bits = match (bytes.len(), is_big_endian) {
+            (2, false) => u16::from_le_bytes([bytes[0], bytes[1]]) as u32,
+            (2, true) => u16::from_be_bytes([bytes[0], bytes[1]]) as u32,
+            (4, false) => u32::from_le_bytes(bytes.try_into().unwrap()),
+            (4, true) => u32::from_be_bytes(bytes.try_into().unwrap()),
+            (length, _) => return Err(DisassemblerError::UnsupportedInstructionLength(length)),
+        };
+        Ok(TraceEntry {
+            address: disassembled.address,
+            bits,
+            length: bytes.len(),
+            instruction,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsed_instructions::*;
+    use crate::{disassemble, Extensions};
+
+    #[test]
+    fn test_from_disassembled() {
+        // c.zext.b a0; addi ra, sp, 5, big endian
+        let extensions = Extensions {
+            zcb: true,
+            ..Default::default()
+        };
+        let entry = |bytes: &[u8], is_big_endian| {
+            let disassembled = disassemble(bytes, 0x100, is_big_endian, true, &extensions)
+                .next()
+                .unwrap();
+            TraceEntry::from_disassembled(disassembled, is_big_endian).unwrap()
+        };
+        assert_eq!(
+            entry(&[0x61, 0x9d], false),
+            TraceEntry {
+                address: 0x100,
+                bits: 0x9d61,
+                length: 2,
+                instruction: ParsedInstruction32::c_zext_b(c_zext_b { rd: "a0" }),
+            }
+        );
+        assert_eq!(entry(&[0x00, 0x51, 0x00, 0x93], true).bits, 0x00510093);
+    }
+}
@@ -0,0 +1,1985 @@
+//! Decoder for the CORE-V extensions implemented by the CV32E40P core: XCVmem post-increment
+//! and register-offset loads and stores, XCVelw, XCVhwlp hardware loops, XCVbitmanip, XCValu,
+//! XCVmac and the XCVsimd ALU, comparison and dot-product operations. They are spread over the
+//! four custom opcodes in [`XCV_OPCODES`], which the decoder has to be registered for.
+//!
+//! The `.div` variants of the SIMD additions and subtractions, the complex-number and shuffle
+//! operations, and the normalising and rounding forms of XCValu and XCVmac are not decoded.
+//!
+//! ### Example
+//!
+//! ```
+//! use risc_v_disassembler::xcv::{XcvDecoder, XCV_OPCODES};
+//! use risc_v_disassembler::{parse_with_custom_decoders, CustomDecoders, Extensions};
+//!
+//! let mut decoders = CustomDecoders::new();
+//! for opcode in XCV_OPCODES {
+//!     decoders.register(opcode, XcvDecoder).unwrap();
+//! }
+//!
+//! // cv.lw a0, (a1), 4
+//! let bytes = 0x0045a50bu32.to_le_bytes();
+//! let parsed_instruction =
+//!     parse_with_custom_decoders(&bytes, false, true, &Extensions::default(), &decoders).unwrap();
+//! assert_eq!(parsed_instruction.to_string(), "cv.lw a0, (a1), 4");
+//! ```
+
+use crate::custom::{CustomDecoder, CustomInstruction};
+use crate::instructions::{Instruction32, MemoryAccess};
+use crate::macros::{extract_bits, sign_extend32};
+use crate::DisassemblerError;
+use std::any::Any;
+use std::fmt;
+
+/// The custom-0 to custom-3 opcodes, which hold the CORE-V instructions.
+pub const XCV_OPCODES: [u8; 4] = [0b0001011, 0b0101011, 0b1011011, 0b1111011];
+
+/// Names of the extensions decoded by [`XcvDecoder`], as written in ISA strings.
+pub const XCV_EXTENSIONS: [&str; 7] = [
+    "xcvmem",
+    "xcvelw",
+    "xcvhwlp",
+    "xcvbitmanip",
+    "xcvalu",
+    "xcvmac",
+    "xcvsimd",
+];
+
+/// Address operand of the XCVmem loads and stores, relative to the base register rs1.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Addressing {
+    /// Accesses `(rs1)` and then adds the immediate to rs1, written `(rs1), imm`.
+    PostIncrementImmediate(i32),
+    /// Accesses `(rs1)` and then adds the register to rs1, written `(rs1), rs2`.
+    PostIncrementRegister(&'static str),
+    /// Accesses `rs1 + rs2` without updating rs1, written `rs2(rs1)`.
+    RegisterOffset(&'static str),
+}
+
+impl Addressing {
+    fn with_base(&self, rs1: &str) -> String {
+        match self {
+            Addressing::PostIncrementImmediate(imm) => format!("({}), {}", rs1, imm),
+            Addressing::PostIncrementRegister(rs2) => format!("({}), {}", rs1, rs2),
+            Addressing::RegisterOffset(rs2) => format!("{}({})", rs2, rs1),
+        }
+    }
+}
+
+/// Element width of a packed-SIMD instruction, written as the `.h` or `.b` suffix.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SimdWidth {
+    Halfword,
+    Byte,
+}
+
+impl fmt::Display for SimdWidth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SimdWidth::Halfword => write!(f, ".h"),
+            SimdWidth::Byte => write!(f, ".b"),
+        }
+    }
+}
+
+/// Second operand of a packed-SIMD instruction.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SimdOperand {
+    /// Operates element-wise on the elements of rs2.
+    Vector(&'static str),
+    /// Operates on every element with the lowest element of rs2, written with the `.sc` suffix.
+    Scalar(&'static str),
+    /// Operates on every element with a six bit immediate, written with the `.sci` suffix.
+    Immediate(i8),
+}
+
+impl SimdOperand {
+    fn suffix(&self) -> &'static str {
+        match self {
+            SimdOperand::Vector(_) => "",
+            SimdOperand::Scalar(_) => ".sc",
+            SimdOperand::Immediate(_) => ".sci",
+        }
+    }
+}
+
+impl fmt::Display for SimdOperand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SimdOperand::Vector(rs2) | SimdOperand::Scalar(rs2) => write!(f, "{}", rs2),
+            SimdOperand::Immediate(imm) => write!(f, "{}", imm),
+        }
+    }
+}
+
+/// [`CustomDecoder`] for the CORE-V instructions, to be registered for each of
+/// [`XCV_OPCODES`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct XcvDecoder;
+
+impl CustomDecoder for XcvDecoder {
+    fn decode(
+        &self,
+        instruction: Instruction32,
+        register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+        _float_register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+    ) -> Result<Box<dyn CustomInstruction>, DisassemblerError> {
+        let fields = Fields::new(instruction, register_name)?;
+        let decoded = match fields.opcode {
+            0b0001011 => decode_load_immediate(&fields)?,
+            0b0101011 => match fields.funct3 {
+                0b000..=0b010 => decode_store_immediate(&fields)?,
+                0b011 => decode_register(&fields)?,
+                0b100 => decode_hardware_loop(&fields)?,
+                _ => return Err(DisassemblerError::InvalidFunct3(fields.funct3)),
+            },
+            0b1011011 => decode_bit_manipulation(&fields)?,
+            0b1111011 => decode_simd(&fields)?,
+            _ => return Err(DisassemblerError::InvalidOpcode(fields.opcode)),
+        };
+        Ok(Box::new(decoded))
+    }
+}
+
+/// Fields of a CORE-V instruction, with the register fields kept as numbers.
+struct Fields {
+    instruction: Instruction32,
+    opcode: u8,
+    rd: u8,
+    funct3: u8,
+    rs1: u8,
+    rs2: u8,
+    funct7: u8,
+    register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+}
+
+impl Fields {
+    fn new(
+        instruction: Instruction32,
+        register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+    ) -> Result<Self, DisassemblerError> {
+        Ok(Fields {
+            instruction,
+            opcode: extract_bits!(instruction, 0, 6)? as u8,
+            rd: extract_bits!(instruction, 7, 11)? as u8,
+            funct3: extract_bits!(instruction, 12, 14)? as u8,
+            rs1: extract_bits!(instruction, 15, 19)? as u8,
+            rs2: extract_bits!(instruction, 20, 24)? as u8,
+            funct7: extract_bits!(instruction, 25, 31)? as u8,
+            register_name,
+        })
+    }
+
+    fn rd(&self) -> Result<&'static str, DisassemblerError> {
+        (self.register_name)(self.rd)
+    }
+
+    fn rs1(&self) -> Result<&'static str, DisassemblerError> {
+        (self.register_name)(self.rs1)
+    }
+
+    fn rs2(&self) -> Result<&'static str, DisassemblerError> {
+        (self.register_name)(self.rs2)
+    }
+
+    /// The sign extended immediate in bits 31-20, as in an I-type instruction.
+    fn itype_imm(&self) -> Result<i32, DisassemblerError> {
+        sign_extend32!(extract_bits!(self.instruction, 20, 31)?, 12)
+    }
+
+    /// The sign extended immediate in bits 31-25 and 11-7, as in an S-type instruction.
+    fn stype_imm(&self) -> Result<i32, DisassemblerError> {
+        sign_extend32!((self.funct7 as u32) << 5 | self.rd as u32, 12)
+    }
+
+    /// The unsigned immediate in bits 31-20 of the hardware loop instructions.
+    fn uimm(&self) -> Result<u16, DisassemblerError> {
+        Ok(extract_bits!(self.instruction, 20, 31)? as u16)
+    }
+
+    /// Fails unless the rs2 field of a single source instruction is zero.
+    fn no_rs2(&self) -> Result<(), DisassemblerError> {
+        if self.rs2 != 0 {
+            return Err(DisassemblerError::InvalidRs2Field(self.rs2));
+        }
+        Ok(())
+    }
+}
+
+/// Parses the custom-0 loads with a post-incremented immediate, and `cv.elw`.
+fn decode_load_immediate(fields: &Fields) -> Result<XcvInstruction, DisassemblerError> {
+    let rd = fields.rd()?;
+    let rs1 = fields.rs1()?;
+    let imm = fields.itype_imm()?;
+    let offset = Addressing::PostIncrementImmediate(imm);
+
+    match fields.funct3 {
+        0b000 => Ok(XcvInstruction::cv_lb { rd, rs1, offset }),
+        0b001 => Ok(XcvInstruction::cv_lh { rd, rs1, offset }),
+        0b010 => Ok(XcvInstruction::cv_lw { rd, rs1, offset }),
+        0b011 => Ok(XcvInstruction::cv_elw { rd, rs1, imm }),
+        0b100 => Ok(XcvInstruction::cv_lbu { rd, rs1, offset }),
+        0b101 => Ok(XcvInstruction::cv_lhu { rd, rs1, offset }),
+        _ => Err(DisassemblerError::InvalidFunct3(fields.funct3)),
+    }
+}
+
+/// Parses the custom-1 stores with a post-incremented immediate.
+fn decode_store_immediate(fields: &Fields) -> Result<XcvInstruction, DisassemblerError> {
+    let rs2 = fields.rs2()?;
+    let rs1 = fields.rs1()?;
+    let offset = Addressing::PostIncrementImmediate(fields.stype_imm()?);
+
+    match fields.funct3 {
+        0b000 => Ok(XcvInstruction::cv_sb { rs2, rs1, offset }),
+        0b001 => Ok(XcvInstruction::cv_sh { rs2, rs1, offset }),
+        _ => Ok(XcvInstruction::cv_sw { rs2, rs1, offset }),
+    }
+}
+
+/// Parses the register-register instructions in funct3 `011` of custom-1: the loads and stores
+/// with register offsets, and the register forms of XCVbitmanip, XCValu and XCVmac.
+fn decode_register(fields: &Fields) -> Result<XcvInstruction, DisassemblerError> {
+    match fields.funct7 {
+        0b0000000..=0b0001111 => decode_load_register(fields),
+        0b0010000..=0b0010111 => decode_store_register(fields),
+        _ => decode_alu(fields),
+    }
+}
+
+/// Parses the loads with a register post-increment (funct7 bit 2 clear) or a register offset
+/// (funct7 bit 2 set).
+fn decode_load_register(fields: &Fields) -> Result<XcvInstruction, DisassemblerError> {
+    let rd = fields.rd()?;
+    let rs1 = fields.rs1()?;
+    let rs2 = fields.rs2()?;
+    let offset = if fields.funct7 & 0b100 == 0 {
+        Addressing::PostIncrementRegister(rs2)
+    } else {
+        Addressing::RegisterOffset(rs2)
+    };
+
+    match fields.funct7 & !0b100 {
+        0b0000000 => Ok(XcvInstruction::cv_lb { rd, rs1, offset }),
+        0b0000001 => Ok(XcvInstruction::cv_lh { rd, rs1, offset }),
+        0b0000010 => Ok(XcvInstruction::cv_lw { rd, rs1, offset }),
+        0b0001000 => Ok(XcvInstruction::cv_lbu { rd, rs1, offset }),
+        0b0001001 => Ok(XcvInstruction::cv_lhu { rd, rs1, offset }),
+        _ => Err(DisassemblerError::InvalidFunct7(fields.funct7)),
+    }
+}
+
+/// Parses the stores with a register post-increment or a register offset, whose offset register
+/// (rs3) is held in the rd field.
+fn decode_store_register(fields: &Fields) -> Result<XcvInstruction, DisassemblerError> {
+    let rs2 = fields.rs2()?;
+    let rs1 = fields.rs1()?;
+    let rs3 = fields.rd()?;
+    let offset = if fields.funct7 & 0b100 == 0 {
+        Addressing::PostIncrementRegister(rs3)
+    } else {
+        Addressing::RegisterOffset(rs3)
+    };
+
+    match fields.funct7 & !0b100 {
+        0b0010000 => Ok(XcvInstruction::cv_sb { rs2, rs1, offset }),
+        0b0010001 => Ok(XcvInstruction::cv_sh { rs2, rs1, offset }),
+        0b0010010 => Ok(XcvInstruction::cv_sw { rs2, rs1, offset }),
+        _ => Err(DisassemblerError::InvalidFunct7(fields.funct7)),
+    }
+}
+
+/// Parses the register forms of XCVbitmanip, XCValu and XCVmac.
+fn decode_alu(fields: &Fields) -> Result<XcvInstruction, DisassemblerError> {
+    let rd = fields.rd()?;
+    let rs1 = fields.rs1()?;
+
+    match fields.funct7 {
+        0b0100001..=0b0100100 | 0b0101000 | 0b0110000..=0b0110011 => {
+            fields.no_rs2()?;
+            match fields.funct7 {
+                0b0100001 => Ok(XcvInstruction::cv_ff1 { rd, rs1 }),
+                0b0100010 => Ok(XcvInstruction::cv_fl1 { rd, rs1 }),
+                0b0100011 => Ok(XcvInstruction::cv_clb { rd, rs1 }),
+                0b0100100 => Ok(XcvInstruction::cv_cnt { rd, rs1 }),
+                0b0101000 => Ok(XcvInstruction::cv_abs { rd, rs1 }),
+                0b0110000 => Ok(XcvInstruction::cv_exths { rd, rs1 }),
+                0b0110001 => Ok(XcvInstruction::cv_exthz { rd, rs1 }),
+                0b0110010 => Ok(XcvInstruction::cv_extbs { rd, rs1 }),
+                _ => Ok(XcvInstruction::cv_extbz { rd, rs1 }),
+            }
+        }
+        0b0111000 => Ok(XcvInstruction::cv_clip {
+            rd,
+            rs1,
+            is2: fields.rs2,
+        }),
+        0b0111001 => Ok(XcvInstruction::cv_clipu {
+            rd,
+            rs1,
+            is2: fields.rs2,
+        }),
+        _ => {
+            let rs2 = fields.rs2()?;
+            match fields.funct7 {
+                0b0011000 => Ok(XcvInstruction::cv_extractr { rd, rs1, rs2 }),
+                0b0011001 => Ok(XcvInstruction::cv_extractur { rd, rs1, rs2 }),
+                0b0011010 => Ok(XcvInstruction::cv_insertr { rd, rs1, rs2 }),
+                0b0011100 => Ok(XcvInstruction::cv_bclrr { rd, rs1, rs2 }),
+                0b0011101 => Ok(XcvInstruction::cv_bsetr { rd, rs1, rs2 }),
+                0b0100000 => Ok(XcvInstruction::cv_ror { rd, rs1, rs2 }),
+                0b0101001 => Ok(XcvInstruction::cv_slet { rd, rs1, rs2 }),
+                0b0101010 => Ok(XcvInstruction::cv_sletu { rd, rs1, rs2 }),
+                0b0101011 => Ok(XcvInstruction::cv_min { rd, rs1, rs2 }),
+                0b0101100 => Ok(XcvInstruction::cv_minu { rd, rs1, rs2 }),
+                0b0101101 => Ok(XcvInstruction::cv_max { rd, rs1, rs2 }),
+                0b0101110 => Ok(XcvInstruction::cv_maxu { rd, rs1, rs2 }),
+                0b0111010 => Ok(XcvInstruction::cv_clipr { rd, rs1, rs2 }),
+                0b0111011 => Ok(XcvInstruction::cv_clipur { rd, rs1, rs2 }),
+                0b1001000 => Ok(XcvInstruction::cv_mac { rd, rs1, rs2 }),
+                0b1001001 => Ok(XcvInstruction::cv_msu { rd, rs1, rs2 }),
+                _ => Err(DisassemblerError::InvalidFunct7(fields.funct7)),
+            }
+        }
+    }
+}
+
+/// Parses the XCVhwlp instructions, which select the operation through bits 11-8 and the loop
+/// through bit 7.
+fn decode_hardware_loop(fields: &Fields) -> Result<XcvInstruction, DisassemblerError> {
+    let loop_index = fields.rd & 0b1;
+    let operation = fields.rd >> 1;
+
+    // The immediate forms, apart from cv.setupi, have no rs1, and the register forms other
+    // than cv.setup have no immediate.
+    match operation {
+        0b0000 | 0b0010 | 0b0100 if fields.rs1 != 0 => {
+            return Err(DisassemblerError::InvalidRs1Field(fields.rs1))
+        }
+        0b0001 | 0b0011 | 0b0101 if fields.uimm()? != 0 => {
+            return Err(DisassemblerError::InvalidImmediate(fields.uimm()? as i32))
+        }
+        _ => {}
+    }
+
+    match operation {
+        0b0000 => Ok(XcvInstruction::cv_starti {
+            loop_index,
+            uimm: fields.uimm()?,
+        }),
+        0b0001 => Ok(XcvInstruction::cv_start {
+            loop_index,
+            rs1: fields.rs1()?,
+        }),
+        0b0010 => Ok(XcvInstruction::cv_endi {
+            loop_index,
+            uimm: fields.uimm()?,
+        }),
+        0b0011 => Ok(XcvInstruction::cv_end {
+            loop_index,
+            rs1: fields.rs1()?,
+        }),
+        0b0100 => Ok(XcvInstruction::cv_counti {
+            loop_index,
+            uimm: fields.uimm()?,
+        }),
+        0b0101 => Ok(XcvInstruction::cv_count {
+            loop_index,
+            rs1: fields.rs1()?,
+        }),
+        0b0110 => Ok(XcvInstruction::cv_setupi {
+            loop_index,
+            uimm: fields.uimm()?,
+            uimms: fields.rs1,
+        }),
+        0b0111 => Ok(XcvInstruction::cv_setup {
+            loop_index,
+            rs1: fields.rs1()?,
+            uimm: fields.uimm()?,
+        }),
+        _ => Err(DisassemblerError::InvalidRdField(fields.rd)),
+    }
+}
+
+/// Parses the immediate forms of XCVbitmanip, which hold the bit count (Is3) in bits 29-25 and
+/// the bit position (Is2) in bits 24-20.
+fn decode_bit_manipulation(fields: &Fields) -> Result<XcvInstruction, DisassemblerError> {
+    let rd = fields.rd()?;
+    let rs1 = fields.rs1()?;
+    let is3 = fields.funct7 & 0b11111;
+    let is2 = fields.rs2;
+
+    match (fields.funct3, fields.funct7 >> 5) {
+        (0b000, 0b00) => Ok(XcvInstruction::cv_extract { rd, rs1, is3, is2 }),
+        (0b000, 0b01) => Ok(XcvInstruction::cv_extractu { rd, rs1, is3, is2 }),
+        (0b000, 0b10) => Ok(XcvInstruction::cv_insert { rd, rs1, is3, is2 }),
+        (0b001, 0b00) => Ok(XcvInstruction::cv_bclr { rd, rs1, is3, is2 }),
+        (0b001, 0b01) => Ok(XcvInstruction::cv_bset { rd, rs1, is3, is2 }),
+        (0b000, _) | (0b001, _) => Err(DisassemblerError::InvalidFunct7(fields.funct7)),
+        _ => Err(DisassemblerError::InvalidFunct3(fields.funct3)),
+    }
+}
+
+/// Parses the XCVsimd instructions, whose funct3 selects the element width and the kind of the
+/// second operand, and bits 31-26 the operation. Bit 25 is the lowest bit of the immediate in
+/// the `.sci` forms and zero otherwise.
+fn decode_simd(fields: &Fields) -> Result<XcvInstruction, DisassemblerError> {
+    let rd = fields.rd()?;
+    let rs1 = fields.rs1()?;
+    let funct6 = fields.funct7 >> 1;
+    let width = if fields.funct3 & 0b1 == 0 {
+        SimdWidth::Halfword
+    } else {
+        SimdWidth::Byte
+    };
+
+    if funct6 == 0b011100 {
+        if fields.funct3 > 0b001 {
+            return Err(DisassemblerError::InvalidFunct3(fields.funct3));
+        }
+        if fields.funct7 & 0b1 != 0 {
+            return Err(DisassemblerError::InvalidFunct7(fields.funct7));
+        }
+        fields.no_rs2()?;
+        return Ok(XcvInstruction::cv_abs_simd { rd, rs1, width });
+    }
+
+    // The immediate is zero extended for the unsigned operations and shift amounts.
+    let unsigned = matches!(
+        funct6,
+        0b000110
+            | 0b001010
+            | 0b001110
+            | 0b010000
+            | 0b010010
+            | 0b010100
+            | 0b100000
+            | 0b100110
+            | 0b001101
+            | 0b001111
+            | 0b010001
+            | 0b010011
+    );
+    let rs2 = match fields.funct3 {
+        0b000 | 0b001 | 0b100 | 0b101 if fields.funct7 & 0b1 != 0 => {
+            return Err(DisassemblerError::InvalidFunct7(fields.funct7))
+        }
+        0b000 | 0b001 => SimdOperand::Vector(fields.rs2()?),
+        0b100 | 0b101 => SimdOperand::Scalar(fields.rs2()?),
+        0b110 | 0b111 => {
+            let imm6 = (fields.rs2 << 1 | fields.funct7 & 0b1) as u32;
+            if unsigned {
+                SimdOperand::Immediate(imm6 as i8)
+            } else {
+                SimdOperand::Immediate(sign_extend32!(imm6, 6)? as i8)
+            }
+        }
+        _ => return Err(DisassemblerError::InvalidFunct3(fields.funct3)),
+    };
+
+    match funct6 {
+        0b000000 => Ok(XcvInstruction::cv_add_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b000010 => Ok(XcvInstruction::cv_sub_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b000100 => Ok(XcvInstruction::cv_avg_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b000110 => Ok(XcvInstruction::cv_avgu_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b001000 => Ok(XcvInstruction::cv_min_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b001010 => Ok(XcvInstruction::cv_minu_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b001100 => Ok(XcvInstruction::cv_max_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b001110 => Ok(XcvInstruction::cv_maxu_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b010000 => Ok(XcvInstruction::cv_srl_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b010010 => Ok(XcvInstruction::cv_sra_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b010100 => Ok(XcvInstruction::cv_sll_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b010110 => Ok(XcvInstruction::cv_or_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b011000 => Ok(XcvInstruction::cv_xor_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b011010 => Ok(XcvInstruction::cv_and_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b100000 => Ok(XcvInstruction::cv_dotup_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b100010 => Ok(XcvInstruction::cv_dotusp_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b100100 => Ok(XcvInstruction::cv_dotsp_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b100110 => Ok(XcvInstruction::cv_sdotup_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b101000 => Ok(XcvInstruction::cv_sdotusp_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b101010 => Ok(XcvInstruction::cv_sdotsp_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b000001 => Ok(XcvInstruction::cv_cmpeq_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b000011 => Ok(XcvInstruction::cv_cmpne_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b000101 => Ok(XcvInstruction::cv_cmpgt_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b000111 => Ok(XcvInstruction::cv_cmpge_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b001001 => Ok(XcvInstruction::cv_cmplt_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b001011 => Ok(XcvInstruction::cv_cmple_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b001101 => Ok(XcvInstruction::cv_cmpgtu_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b001111 => Ok(XcvInstruction::cv_cmpgeu_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b010001 => Ok(XcvInstruction::cv_cmpltu_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        0b010011 => Ok(XcvInstruction::cv_cmpleu_simd {
+            rd,
+            rs1,
+            rs2,
+            width,
+        }),
+        _ => Err(DisassemblerError::InvalidFunct7(fields.funct7)),
+    }
+}
+
+/// Instruction of the CORE-V extensions, as decoded by [`XcvDecoder`].
+#[derive(Debug, PartialEq, Clone)]
+#[allow(non_camel_case_types)]
+pub enum XcvInstruction {
+    cv_lb {
+        rd: &'static str,
+        rs1: &'static str,
+        offset: Addressing,
+    },
+    cv_lbu {
+        rd: &'static str,
+        rs1: &'static str,
+        offset: Addressing,
+    },
+    cv_lh {
+        rd: &'static str,
+        rs1: &'static str,
+        offset: Addressing,
+    },
+    cv_lhu {
+        rd: &'static str,
+        rs1: &'static str,
+        offset: Addressing,
+    },
+    cv_lw {
+        rd: &'static str,
+        rs1: &'static str,
+        offset: Addressing,
+    },
+    cv_elw {
+        rd: &'static str,
+        rs1: &'static str,
+        imm: i32,
+    },
+    cv_sb {
+        rs2: &'static str,
+        rs1: &'static str,
+        offset: Addressing,
+    },
+    cv_sh {
+        rs2: &'static str,
+        rs1: &'static str,
+        offset: Addressing,
+    },
+    cv_sw {
+        rs2: &'static str,
+        rs1: &'static str,
+        offset: Addressing,
+    },
+    cv_starti {
+        loop_index: u8,
+        uimm: u16,
+    },
+    cv_start {
+        loop_index: u8,
+        rs1: &'static str,
+    },
+    cv_endi {
+        loop_index: u8,
+        uimm: u16,
+    },
+    cv_end {
+        loop_index: u8,
+        rs1: &'static str,
+    },
+    cv_counti {
+        loop_index: u8,
+        uimm: u16,
+    },
+    cv_count {
+        loop_index: u8,
+        rs1: &'static str,
+    },
+    cv_setupi {
+        loop_index: u8,
+        uimm: u16,
+        uimms: u8,
+    },
+    cv_setup {
+        loop_index: u8,
+        rs1: &'static str,
+        uimm: u16,
+    },
+    cv_extract {
+        rd: &'static str,
+        rs1: &'static str,
+        is3: u8,
+        is2: u8,
+    },
+    cv_extractu {
+        rd: &'static str,
+        rs1: &'static str,
+        is3: u8,
+        is2: u8,
+    },
+    cv_insert {
+        rd: &'static str,
+        rs1: &'static str,
+        is3: u8,
+        is2: u8,
+    },
+    cv_bclr {
+        rd: &'static str,
+        rs1: &'static str,
+        is3: u8,
+        is2: u8,
+    },
+    cv_bset {
+        rd: &'static str,
+        rs1: &'static str,
+        is3: u8,
+        is2: u8,
+    },
+    cv_extractr {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_extractur {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_insertr {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_bclrr {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_bsetr {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_ror {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_ff1 {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    cv_fl1 {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    cv_clb {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    cv_cnt {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    cv_abs {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    cv_exths {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    cv_exthz {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    cv_extbs {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    cv_extbz {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    cv_slet {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_sletu {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_min {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_minu {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_max {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_maxu {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_clipr {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_clipur {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_mac {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_msu {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    cv_clip {
+        rd: &'static str,
+        rs1: &'static str,
+        is2: u8,
+    },
+    cv_clipu {
+        rd: &'static str,
+        rs1: &'static str,
+        is2: u8,
+    },
+    cv_abs_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        width: SimdWidth,
+    },
+    cv_add_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_sub_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_avg_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_avgu_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_min_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_minu_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_max_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_maxu_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_srl_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_sra_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_sll_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_or_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_xor_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_and_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_dotup_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_dotusp_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_dotsp_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_sdotup_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_sdotusp_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_sdotsp_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_cmpeq_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_cmpne_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_cmpgt_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_cmpge_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_cmplt_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_cmple_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_cmpgtu_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_cmpgeu_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_cmpltu_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+    cv_cmpleu_simd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: SimdOperand,
+        width: SimdWidth,
+    },
+}
+
+impl fmt::Display for XcvInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            XcvInstruction::cv_lb { rd, rs1, offset } => {
+                write!(f, "cv.lb {}, {}", rd, offset.with_base(rs1))
+            }
+            XcvInstruction::cv_lbu { rd, rs1, offset } => {
+                write!(f, "cv.lbu {}, {}", rd, offset.with_base(rs1))
+            }
+            XcvInstruction::cv_lh { rd, rs1, offset } => {
+                write!(f, "cv.lh {}, {}", rd, offset.with_base(rs1))
+            }
+            XcvInstruction::cv_lhu { rd, rs1, offset } => {
+                write!(f, "cv.lhu {}, {}", rd, offset.with_base(rs1))
+            }
+            XcvInstruction::cv_lw { rd, rs1, offset } => {
+                write!(f, "cv.lw {}, {}", rd, offset.with_base(rs1))
+            }
+            XcvInstruction::cv_elw { rd, rs1, imm } => {
+                write!(f, "cv.elw {}, {}({})", rd, imm, rs1)
+            }
+            XcvInstruction::cv_sb { rs2, rs1, offset } => {
+                write!(f, "cv.sb {}, {}", rs2, offset.with_base(rs1))
+            }
+            XcvInstruction::cv_sh { rs2, rs1, offset } => {
+                write!(f, "cv.sh {}, {}", rs2, offset.with_base(rs1))
+            }
+            XcvInstruction::cv_sw { rs2, rs1, offset } => {
+                write!(f, "cv.sw {}, {}", rs2, offset.with_base(rs1))
+            }
+            XcvInstruction::cv_starti { loop_index, uimm } => {
+                write!(f, "cv.starti {}, {}", loop_index, uimm)
+            }
+            XcvInstruction::cv_start { loop_index, rs1 } => {
+                write!(f, "cv.start {}, {}", loop_index, rs1)
+            }
+            XcvInstruction::cv_endi { loop_index, uimm } => {
+                write!(f, "cv.endi {}, {}", loop_index, uimm)
+            }
+            XcvInstruction::cv_end { loop_index, rs1 } => {
+                write!(f, "cv.end {}, {}", loop_index, rs1)
+            }
+            XcvInstruction::cv_counti { loop_index, uimm } => {
+                write!(f, "cv.counti {}, {}", loop_index, uimm)
+            }
+            XcvInstruction::cv_count { loop_index, rs1 } => {
+                write!(f, "cv.count {}, {}", loop_index, rs1)
+            }
+            XcvInstruction::cv_setupi {
+                loop_index,
+                uimm,
+                uimms,
+            } => {
+                write!(f, "cv.setupi {}, {}, {}", loop_index, uimm, uimms)
+            }
+            XcvInstruction::cv_setup {
+                loop_index,
+                rs1,
+                uimm,
+            } => {
+                write!(f, "cv.setup {}, {}, {}", loop_index, rs1, uimm)
+            }
+            XcvInstruction::cv_extract { rd, rs1, is3, is2 } => {
+                write!(f, "cv.extract {}, {}, {}, {}", rd, rs1, is3, is2)
+            }
+            XcvInstruction::cv_extractu { rd, rs1, is3, is2 } => {
+                write!(f, "cv.extractu {}, {}, {}, {}", rd, rs1, is3, is2)
+            }
+            XcvInstruction::cv_insert { rd, rs1, is3, is2 } => {
+                write!(f, "cv.insert {}, {}, {}, {}", rd, rs1, is3, is2)
+            }
+            XcvInstruction::cv_bclr { rd, rs1, is3, is2 } => {
+                write!(f, "cv.bclr {}, {}, {}, {}", rd, rs1, is3, is2)
+            }
+            XcvInstruction::cv_bset { rd, rs1, is3, is2 } => {
+                write!(f, "cv.bset {}, {}, {}, {}", rd, rs1, is3, is2)
+            }
+            XcvInstruction::cv_extractr { rd, rs1, rs2 } => {
+                write!(f, "cv.extractr {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_extractur { rd, rs1, rs2 } => {
+                write!(f, "cv.extractur {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_insertr { rd, rs1, rs2 } => {
+                write!(f, "cv.insertr {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_bclrr { rd, rs1, rs2 } => {
+                write!(f, "cv.bclrr {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_bsetr { rd, rs1, rs2 } => {
+                write!(f, "cv.bsetr {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_ror { rd, rs1, rs2 } => {
+                write!(f, "cv.ror {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_ff1 { rd, rs1 } => {
+                write!(f, "cv.ff1 {}, {}", rd, rs1)
+            }
+            XcvInstruction::cv_fl1 { rd, rs1 } => {
+                write!(f, "cv.fl1 {}, {}", rd, rs1)
+            }
+            XcvInstruction::cv_clb { rd, rs1 } => {
+                write!(f, "cv.clb {}, {}", rd, rs1)
+            }
+            XcvInstruction::cv_cnt { rd, rs1 } => {
+                write!(f, "cv.cnt {}, {}", rd, rs1)
+            }
+            XcvInstruction::cv_abs { rd, rs1 } => {
+                write!(f, "cv.abs {}, {}", rd, rs1)
+            }
+            XcvInstruction::cv_exths { rd, rs1 } => {
+                write!(f, "cv.exths {}, {}", rd, rs1)
+            }
+            XcvInstruction::cv_exthz { rd, rs1 } => {
+                write!(f, "cv.exthz {}, {}", rd, rs1)
+            }
+            XcvInstruction::cv_extbs { rd, rs1 } => {
+                write!(f, "cv.extbs {}, {}", rd, rs1)
+            }
+            XcvInstruction::cv_extbz { rd, rs1 } => {
+                write!(f, "cv.extbz {}, {}", rd, rs1)
+            }
+            XcvInstruction::cv_slet { rd, rs1, rs2 } => {
+                write!(f, "cv.slet {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_sletu { rd, rs1, rs2 } => {
+                write!(f, "cv.sletu {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_min { rd, rs1, rs2 } => {
+                write!(f, "cv.min {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_minu { rd, rs1, rs2 } => {
+                write!(f, "cv.minu {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_max { rd, rs1, rs2 } => {
+                write!(f, "cv.max {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_maxu { rd, rs1, rs2 } => {
+                write!(f, "cv.maxu {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_clipr { rd, rs1, rs2 } => {
+                write!(f, "cv.clipr {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_clipur { rd, rs1, rs2 } => {
+                write!(f, "cv.clipur {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_mac { rd, rs1, rs2 } => {
+                write!(f, "cv.mac {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_msu { rd, rs1, rs2 } => {
+                write!(f, "cv.msu {}, {}, {}", rd, rs1, rs2)
+            }
+            XcvInstruction::cv_clip { rd, rs1, is2 } => {
+                write!(f, "cv.clip {}, {}, {}", rd, rs1, is2)
+            }
+            XcvInstruction::cv_clipu { rd, rs1, is2 } => {
+                write!(f, "cv.clipu {}, {}, {}", rd, rs1, is2)
+            }
+            XcvInstruction::cv_abs_simd { rd, rs1, width } => {
+                write!(f, "cv.abs{} {}, {}", width, rd, rs1)
+            }
+            XcvInstruction::cv_add_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.add{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_sub_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.sub{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_avg_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.avg{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_avgu_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.avgu{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_min_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.min{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_minu_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.minu{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_max_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.max{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_maxu_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.maxu{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_srl_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.srl{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_sra_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.sra{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_sll_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.sll{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_or_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(f, "cv.or{}{} {}, {}, {}", rs2.suffix(), width, rd, rs1, rs2)
+            }
+            XcvInstruction::cv_xor_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.xor{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_and_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.and{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_dotup_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.dotup{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_dotusp_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.dotusp{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_dotsp_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.dotsp{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_sdotup_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.sdotup{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_sdotusp_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.sdotusp{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_sdotsp_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.sdotsp{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_cmpeq_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.cmpeq{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_cmpne_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.cmpne{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_cmpgt_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.cmpgt{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_cmpge_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.cmpge{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_cmplt_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.cmplt{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_cmple_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.cmple{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_cmpgtu_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.cmpgtu{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_cmpgeu_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.cmpgeu{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_cmpltu_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.cmpltu{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+            XcvInstruction::cv_cmpleu_simd {
+                rd,
+                rs1,
+                rs2,
+                width,
+            } => {
+                write!(
+                    f,
+                    "cv.cmpleu{}{} {}, {}, {}",
+                    rs2.suffix(),
+                    width,
+                    rd,
+                    rs1,
+                    rs2
+                )
+            }
+        }
+    }
+}
+
+impl XcvInstruction {
+    /// Returns the name of the extension of the instruction, one of [`XCV_EXTENSIONS`].
+    pub fn extension(&self) -> &'static str {
+        match self {
+            XcvInstruction::cv_lb { .. }
+            | XcvInstruction::cv_lbu { .. }
+            | XcvInstruction::cv_lh { .. }
+            | XcvInstruction::cv_lhu { .. }
+            | XcvInstruction::cv_lw { .. }
+            | XcvInstruction::cv_sb { .. }
+            | XcvInstruction::cv_sh { .. }
+            | XcvInstruction::cv_sw { .. } => "xcvmem",
+            XcvInstruction::cv_elw { .. } => "xcvelw",
+            XcvInstruction::cv_starti { .. }
+            | XcvInstruction::cv_start { .. }
+            | XcvInstruction::cv_endi { .. }
+            | XcvInstruction::cv_end { .. }
+            | XcvInstruction::cv_counti { .. }
+            | XcvInstruction::cv_count { .. }
+            | XcvInstruction::cv_setupi { .. }
+            | XcvInstruction::cv_setup { .. } => "xcvhwlp",
+            XcvInstruction::cv_extract { .. }
+            | XcvInstruction::cv_extractu { .. }
+            | XcvInstruction::cv_insert { .. }
+            | XcvInstruction::cv_bclr { .. }
+            | XcvInstruction::cv_bset { .. }
+            | XcvInstruction::cv_extractr { .. }
+            | XcvInstruction::cv_extractur { .. }
+            | XcvInstruction::cv_insertr { .. }
+            | XcvInstruction::cv_bclrr { .. }
+            | XcvInstruction::cv_bsetr { .. }
+            | XcvInstruction::cv_ror { .. }
+            | XcvInstruction::cv_ff1 { .. }
+            | XcvInstruction::cv_fl1 { .. }
+            | XcvInstruction::cv_clb { .. }
+            | XcvInstruction::cv_cnt { .. } => "xcvbitmanip",
+            XcvInstruction::cv_abs { .. }
+            | XcvInstruction::cv_exths { .. }
+            | XcvInstruction::cv_exthz { .. }
+            | XcvInstruction::cv_extbs { .. }
+            | XcvInstruction::cv_extbz { .. }
+            | XcvInstruction::cv_slet { .. }
+            | XcvInstruction::cv_sletu { .. }
+            | XcvInstruction::cv_min { .. }
+            | XcvInstruction::cv_minu { .. }
+            | XcvInstruction::cv_max { .. }
+            | XcvInstruction::cv_maxu { .. }
+            | XcvInstruction::cv_clip { .. }
+            | XcvInstruction::cv_clipu { .. }
+            | XcvInstruction::cv_clipr { .. }
+            | XcvInstruction::cv_clipur { .. } => "xcvalu",
+            XcvInstruction::cv_mac { .. } | XcvInstruction::cv_msu { .. } => "xcvmac",
+            XcvInstruction::cv_abs_simd { .. }
+            | XcvInstruction::cv_add_simd { .. }
+            | XcvInstruction::cv_sub_simd { .. }
+            | XcvInstruction::cv_avg_simd { .. }
+            | XcvInstruction::cv_avgu_simd { .. }
+            | XcvInstruction::cv_min_simd { .. }
+            | XcvInstruction::cv_minu_simd { .. }
+            | XcvInstruction::cv_max_simd { .. }
+            | XcvInstruction::cv_maxu_simd { .. }
+            | XcvInstruction::cv_srl_simd { .. }
+            | XcvInstruction::cv_sra_simd { .. }
+            | XcvInstruction::cv_sll_simd { .. }
+            | XcvInstruction::cv_or_simd { .. }
+            | XcvInstruction::cv_xor_simd { .. }
+            | XcvInstruction::cv_and_simd { .. }
+            | XcvInstruction::cv_dotup_simd { .. }
+            | XcvInstruction::cv_dotusp_simd { .. }
+            | XcvInstruction::cv_dotsp_simd { .. }
+            | XcvInstruction::cv_sdotup_simd { .. }
+            | XcvInstruction::cv_sdotusp_simd { .. }
+            | XcvInstruction::cv_sdotsp_simd { .. }
+            | XcvInstruction::cv_cmpeq_simd { .. }
+            | XcvInstruction::cv_cmpne_simd { .. }
+            | XcvInstruction::cv_cmpgt_simd { .. }
+            | XcvInstruction::cv_cmpge_simd { .. }
+            | XcvInstruction::cv_cmplt_simd { .. }
+            | XcvInstruction::cv_cmple_simd { .. }
+            | XcvInstruction::cv_cmpgtu_simd { .. }
+            | XcvInstruction::cv_cmpgeu_simd { .. }
+            | XcvInstruction::cv_cmpltu_simd { .. }
+            | XcvInstruction::cv_cmpleu_simd { .. } => "xcvsimd",
+        }
+    }
+}
+
+impl CustomInstruction for XcvInstruction {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_custom(&self, other: &dyn CustomInstruction) -> bool {
+        other.as_any().downcast_ref::<Self>() == Some(self)
+    }
+
+    fn memory_access(&self) -> Option<MemoryAccess> {
+        match self {
+            XcvInstruction::cv_lb { .. } | XcvInstruction::cv_lbu { .. } => {
+                Some(MemoryAccess::Load { size: 1 })
+            }
+            XcvInstruction::cv_lh { .. } | XcvInstruction::cv_lhu { .. } => {
+                Some(MemoryAccess::Load { size: 2 })
+            }
+            XcvInstruction::cv_lw { .. } | XcvInstruction::cv_elw { .. } => {
+                Some(MemoryAccess::Load { size: 4 })
+            }
+            XcvInstruction::cv_sb { .. } => Some(MemoryAccess::Store { size: 1 }),
+            XcvInstruction::cv_sh { .. } => Some(MemoryAccess::Store { size: 2 }),
+            XcvInstruction::cv_sw { .. } => Some(MemoryAccess::Store { size: 4 }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registers::{float_register_name, ABIRegister, Register};
+
+    fn abi(register: u8) -> Result<&'static str, DisassemblerError> {
+        Ok(ABIRegister::try_from_u8(register)?.as_str())
+    }
+
+    fn decode(instruction: u32) -> Result<String, DisassemblerError> {
+        Ok(XcvDecoder
+            .decode(instruction, abi, float_register_name::<ABIRegister>)?
+            .to_string())
+    }
+
+    #[test]
+    fn test_decode_load_immediate() {
+        let fields = Fields::new(0xFFF5C50B, abi).unwrap();
+        assert_eq!(
+            decode_load_immediate(&fields),
+            Ok(XcvInstruction::cv_lbu {
+                rd: "a0",
+                rs1: "a1",
+                offset: Addressing::PostIncrementImmediate(-1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_store_immediate() {
+        let fields = Fields::new(0xFEA5AC2B, abi).unwrap();
+        assert_eq!(
+            decode_store_immediate(&fields),
+            Ok(XcvInstruction::cv_sw {
+                rs2: "a0",
+                rs1: "a1",
+                offset: Addressing::PostIncrementImmediate(-8)
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_store_register_offset() {
+        let fields = Fields::new(0x2CA5B62B, abi).unwrap();
+        assert_eq!(
+            decode_register(&fields),
+            Ok(XcvInstruction::cv_sw {
+                rs2: "a0",
+                rs1: "a1",
+                offset: Addressing::RegisterOffset("a2")
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_alu_invalid_rs2() {
+        assert_eq!(
+            decode(0x48C5B52B),
+            Err(DisassemblerError::InvalidRs2Field(12))
+        );
+    }
+
+    #[test]
+    fn test_decode_hardware_loop() {
+        let fields = Fields::new(0x064546AB, abi).unwrap();
+        assert_eq!(
+            decode_hardware_loop(&fields),
+            Ok(XcvInstruction::cv_setupi {
+                loop_index: 1,
+                uimm: 100,
+                uimms: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_hardware_loop_invalid_fields() {
+        assert_eq!(
+            decode(0x0045402B),
+            Err(DisassemblerError::InvalidRs1Field(10))
+        );
+        assert_eq!(
+            decode(0x0035452B),
+            Err(DisassemblerError::InvalidImmediate(3))
+        );
+    }
+
+    #[test]
+    fn test_decode_bit_manipulation() {
+        let fields = Fields::new(0x0E45855B, abi).unwrap();
+        assert_eq!(
+            decode_bit_manipulation(&fields),
+            Ok(XcvInstruction::cv_extract {
+                rd: "a0",
+                rs1: "a1",
+                is3: 7,
+                is2: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_simd_signed_immediate() {
+        let fields = Fields::new(0x0BE5E57B, abi).unwrap();
+        assert_eq!(
+            decode_simd(&fields),
+            Ok(XcvInstruction::cv_sub_simd {
+                rd: "a0",
+                rs1: "a1",
+                rs2: SimdOperand::Immediate(-3),
+                width: SimdWidth::Halfword
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_simd_unsigned_immediate() {
+        assert_eq!(
+            decode(0x4FF5F57B),
+            Ok("cv.cmpleu.sci.b a0, a1, 63".to_string())
+        );
+    }
+
+    #[test]
+    fn test_decode_simd_invalid() {
+        assert_eq!(
+            decode(0x02C5857B),
+            Err(DisassemblerError::InvalidFunct7(0b0000001))
+        );
+        assert_eq!(
+            decode(0x00C5A57B),
+            Err(DisassemblerError::InvalidFunct3(0b010))
+        );
+    }
+
+    #[test]
+    fn test_decode_standard_opcode() {
+        assert_eq!(
+            decode(0x00C58533),
+            Err(DisassemblerError::InvalidOpcode(0b0110011))
+        );
+    }
+}
@@ -0,0 +1,1402 @@
+//! Decoder for the T-Head vendor extensions implemented by the C906 and C910 cores: XTheadBa,
+//! XTheadBb, XTheadBs, XTheadCondMov, XTheadMemIdx, XTheadMemPair, XTheadMac, XTheadFMemIdx,
+//! XTheadSync and XTheadCmo. All of them are encoded in the custom-0 opcode.
+//!
+//! Only the RV32 instructions are decoded; the word and doubleword forms that only exist on
+//! RV64 are rejected.
+//!
+//! ### Example
+//!
+//! ```
+//! use risc_v_disassembler::xthead::{XTheadDecoder, XTHEAD_OPCODE};
+//! use risc_v_disassembler::{parse_with_custom_decoders, CustomDecoders, Extensions};
+//!
+//! let mut decoders = CustomDecoders::new();
+//! decoders.register(XTHEAD_OPCODE, XTheadDecoder).unwrap();
+//!
+//! // th.addsl a0, a1, a2, 2
+//! let bytes = 0x04c5950bu32.to_le_bytes();
+//! let parsed_instruction =
+//!     parse_with_custom_decoders(&bytes, false, true, &Extensions::default(), &decoders).unwrap();
+//! assert_eq!(parsed_instruction.to_string(), "th.addsl a0, a1, a2, 2");
+//! ```
+
+use crate::custom::{CustomDecoder, CustomInstruction};
+use crate::instructions::{FloatFormat, Instruction32, MemoryAccess, PrivilegeLevel};
+use crate::macros::{extract_bits, sign_extend32};
+use crate::DisassemblerError;
+use std::any::Any;
+use std::fmt;
+
+/// The custom-0 opcode, which holds all T-Head instructions.
+pub const XTHEAD_OPCODE: u8 = 0b0001011;
+
+/// Names of the extensions decoded by [`XTheadDecoder`], as written in ISA strings.
+pub const XTHEAD_EXTENSIONS: [&str; 10] = [
+    "xtheadba",
+    "xtheadbb",
+    "xtheadbs",
+    "xtheadcondmov",
+    "xtheadmemidx",
+    "xtheadmempair",
+    "xtheadmac",
+    "xtheadfmemidx",
+    "xtheadsync",
+    "xtheadcmo",
+];
+
+/// [`CustomDecoder`] for the T-Head instructions, to be registered for [`XTHEAD_OPCODE`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct XTheadDecoder;
+
+impl CustomDecoder for XTheadDecoder {
+    fn decode(
+        &self,
+        instruction: Instruction32,
+        register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+        float_register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+    ) -> Result<Box<dyn CustomInstruction>, DisassemblerError> {
+        let fields = Fields::new(instruction, register_name, float_register_name)?;
+        let decoded = match fields.funct3 {
+            0b000 => decode_sync_cmo(&fields)?,
+            0b001 => decode_arithmetic(&fields)?,
+            0b010 | 0b011 => decode_extract(&fields)?,
+            0b100 => decode_load(&fields)?,
+            0b101 => decode_store(&fields)?,
+            _ => decode_float_memory(&fields)?,
+        };
+        Ok(Box::new(decoded))
+    }
+}
+
+/// Fields of a custom-0 instruction, with the register fields kept as numbers so that
+/// constraints between them can be checked before naming them.
+struct Fields {
+    rd: u8,
+    funct3: u8,
+    rs1: u8,
+    rs2: u8,
+    imm2: u8,
+    funct5: u8,
+    funct7: u8,
+    register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+    float_register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+}
+
+impl Fields {
+    fn new(
+        instruction: Instruction32,
+        register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+        float_register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+    ) -> Result<Self, DisassemblerError> {
+        Ok(Fields {
+            rd: extract_bits!(instruction, 7, 11)? as u8,
+            funct3: extract_bits!(instruction, 12, 14)? as u8,
+            rs1: extract_bits!(instruction, 15, 19)? as u8,
+            rs2: extract_bits!(instruction, 20, 24)? as u8,
+            imm2: extract_bits!(instruction, 25, 26)? as u8,
+            funct5: extract_bits!(instruction, 27, 31)? as u8,
+            funct7: extract_bits!(instruction, 25, 31)? as u8,
+            register_name,
+            float_register_name,
+        })
+    }
+
+    fn rd(&self) -> Result<&'static str, DisassemblerError> {
+        (self.register_name)(self.rd)
+    }
+
+    fn rs1(&self) -> Result<&'static str, DisassemblerError> {
+        (self.register_name)(self.rs1)
+    }
+
+    fn rs2(&self) -> Result<&'static str, DisassemblerError> {
+        (self.register_name)(self.rs2)
+    }
+
+    /// Names a floating point register in the naming style of the integer registers.
+    fn float_register(&self, register: u8) -> Result<&'static str, DisassemblerError> {
+        (self.float_register_name)(register)
+    }
+
+    /// The six bit immediate in bits 25-20 of `th.srri` and `th.tst`, which must be below 32 on
+    /// RV32.
+    fn shamt(&self) -> Result<u8, DisassemblerError> {
+        if self.imm2 & 0b01 != 0 {
+            return Err(DisassemblerError::InvalidImmediate(
+                ((self.imm2 & 0b01) << 5 | self.rs2) as i32,
+            ));
+        }
+        Ok(self.rs2)
+    }
+
+    /// The signed five bit offset held in the rs2 field of the increment addressing modes.
+    fn imm5(&self) -> Result<i8, DisassemblerError> {
+        Ok(sign_extend32!(self.rs2 as u32, 5)? as i8)
+    }
+}
+
+/// Parses XTheadSync and XTheadCmo, which have rd = x0 and select the operation through
+/// funct7 and the rs2 field.
+fn decode_sync_cmo(fields: &Fields) -> Result<XTheadInstruction, DisassemblerError> {
+    if fields.rd != 0 {
+        return Err(DisassemblerError::InvalidRdField(fields.rd));
+    }
+
+    match fields.funct7 {
+        0b0000010 => Ok(XTheadInstruction::th_sfence_vmas {
+            rs1: fields.rs1()?,
+            rs2: fields.rs2()?,
+        }),
+        0b0000000 => {
+            if fields.rs1 != 0 {
+                return Err(DisassemblerError::InvalidRs1Field(fields.rs1));
+            }
+            match fields.rs2 {
+                0b11000 => Ok(XTheadInstruction::th_sync),
+                0b11010 => Ok(XTheadInstruction::th_sync_i),
+                0b11011 => Ok(XTheadInstruction::th_sync_is),
+                0b11001 => Ok(XTheadInstruction::th_sync_s),
+                0b00001 => Ok(XTheadInstruction::th_dcache_call),
+                0b00011 => Ok(XTheadInstruction::th_dcache_ciall),
+                0b00010 => Ok(XTheadInstruction::th_dcache_iall),
+                0b10000 => Ok(XTheadInstruction::th_icache_iall),
+                0b10001 => Ok(XTheadInstruction::th_icache_ialls),
+                0b10101 => Ok(XTheadInstruction::th_l2cache_call),
+                0b10111 => Ok(XTheadInstruction::th_l2cache_ciall),
+                0b10110 => Ok(XTheadInstruction::th_l2cache_iall),
+                _ => Err(DisassemblerError::InvalidRs2Field(fields.rs2)),
+            }
+        }
+        0b0000001 => {
+            let rs1 = fields.rs1()?;
+            match fields.rs2 {
+                0b01001 => Ok(XTheadInstruction::th_dcache_cpa { rs1 }),
+                0b01011 => Ok(XTheadInstruction::th_dcache_cipa { rs1 }),
+                0b01010 => Ok(XTheadInstruction::th_dcache_ipa { rs1 }),
+                0b00101 => Ok(XTheadInstruction::th_dcache_cva { rs1 }),
+                0b00111 => Ok(XTheadInstruction::th_dcache_civa { rs1 }),
+                0b00110 => Ok(XTheadInstruction::th_dcache_iva { rs1 }),
+                0b00001 => Ok(XTheadInstruction::th_dcache_csw { rs1 }),
+                0b00011 => Ok(XTheadInstruction::th_dcache_cisw { rs1 }),
+                0b00010 => Ok(XTheadInstruction::th_dcache_isw { rs1 }),
+                0b01000 => Ok(XTheadInstruction::th_dcache_cpal1 { rs1 }),
+                0b00100 => Ok(XTheadInstruction::th_dcache_cval1 { rs1 }),
+                0b11000 => Ok(XTheadInstruction::th_icache_ipa { rs1 }),
+                0b10000 => Ok(XTheadInstruction::th_icache_iva { rs1 }),
+                _ => Err(DisassemblerError::InvalidRs2Field(fields.rs2)),
+            }
+        }
+        _ => Err(DisassemblerError::InvalidFunct7(fields.funct7)),
+    }
+}
+
+/// Parses XTheadBa, XTheadBb, XTheadBs, XTheadCondMov and XTheadMac, which share funct3 `001`.
+fn decode_arithmetic(fields: &Fields) -> Result<XTheadInstruction, DisassemblerError> {
+    let rd = fields.rd()?;
+    let rs1 = fields.rs1()?;
+
+    if fields.funct5 == 0b00000 {
+        return Ok(XTheadInstruction::th_addsl {
+            rd,
+            rs1,
+            rs2: fields.rs2()?,
+            imm2: fields.imm2,
+        });
+    }
+
+    match fields.funct7 {
+        0b0001000 | 0b0001001 => Ok(XTheadInstruction::th_srri {
+            rd,
+            rs1,
+            imm: fields.shamt()?,
+        }),
+        0b1000100 | 0b1000101 => Ok(XTheadInstruction::th_tst {
+            rd,
+            rs1,
+            imm: fields.shamt()?,
+        }),
+        0b1000000..=0b1000011 => {
+            if fields.rs2 != 0 {
+                return Err(DisassemblerError::InvalidRs2Field(fields.rs2));
+            }
+            match fields.funct7 {
+                0b1000010 => Ok(XTheadInstruction::th_ff0 { rd, rs1 }),
+                0b1000011 => Ok(XTheadInstruction::th_ff1 { rd, rs1 }),
+                0b1000001 => Ok(XTheadInstruction::th_rev { rd, rs1 }),
+                _ => Ok(XTheadInstruction::th_tstnbz { rd, rs1 }),
+            }
+        }
+        0b0100000 => Ok(XTheadInstruction::th_mveqz {
+            rd,
+            rs1,
+            rs2: fields.rs2()?,
+        }),
+        0b0100001 => Ok(XTheadInstruction::th_mvnez {
+            rd,
+            rs1,
+            rs2: fields.rs2()?,
+        }),
+        0b0010000 => Ok(XTheadInstruction::th_mula {
+            rd,
+            rs1,
+            rs2: fields.rs2()?,
+        }),
+        0b0010001 => Ok(XTheadInstruction::th_muls {
+            rd,
+            rs1,
+            rs2: fields.rs2()?,
+        }),
+        0b0010100 => Ok(XTheadInstruction::th_mulah {
+            rd,
+            rs1,
+            rs2: fields.rs2()?,
+        }),
+        0b0010101 => Ok(XTheadInstruction::th_mulsh {
+            rd,
+            rs1,
+            rs2: fields.rs2()?,
+        }),
+        _ => Err(DisassemblerError::InvalidFunct7(fields.funct7)),
+    }
+}
+
+/// Parses `th.ext` and `th.extu`, whose msb and lsb fields must be below 32 on RV32.
+fn decode_extract(fields: &Fields) -> Result<XTheadInstruction, DisassemblerError> {
+    let msb = fields.funct7 >> 1;
+    let lsb = (fields.imm2 & 0b01) << 5 | fields.rs2;
+    if msb >= 32 || lsb >= 32 {
+        return Err(DisassemblerError::InvalidImmediate(
+            (msb as i32) << 6 | lsb as i32,
+        ));
+    }
+
+    let rd = fields.rd()?;
+    let rs1 = fields.rs1()?;
+    match fields.funct3 {
+        0b010 => Ok(XTheadInstruction::th_ext { rd, rs1, msb, lsb }),
+        _ => Ok(XTheadInstruction::th_extu { rd, rs1, msb, lsb }),
+    }
+}
+
+/// Parses the XTheadMemIdx and XTheadMemPair loads. For the increment addressing modes rd must
+/// differ from rs1, and the two destinations of `th.lwd` must differ from each other and rs1.
+fn decode_load(fields: &Fields) -> Result<XTheadInstruction, DisassemblerError> {
+    let imm2 = fields.imm2;
+
+    if fields.funct5 == 0b11100 {
+        if fields.rd == fields.rs2 || fields.rd == fields.rs1 {
+            return Err(DisassemblerError::InvalidRdField(fields.rd));
+        }
+        if fields.rs2 == fields.rs1 {
+            return Err(DisassemblerError::InvalidRs2Field(fields.rs2));
+        }
+        return Ok(XTheadInstruction::th_lwd {
+            rd1: fields.rd()?,
+            rd2: fields.rs2()?,
+            rs1: fields.rs1()?,
+            imm2,
+        });
+    }
+
+    let rd = fields.rd()?;
+    let rs1 = fields.rs1()?;
+    if fields.funct5 & 0b11 == 0b00 {
+        let rs2 = fields.rs2()?;
+        return match fields.funct5 {
+            0b00000 => Ok(XTheadInstruction::th_lrb { rd, rs1, rs2, imm2 }),
+            0b10000 => Ok(XTheadInstruction::th_lrbu { rd, rs1, rs2, imm2 }),
+            0b00100 => Ok(XTheadInstruction::th_lrh { rd, rs1, rs2, imm2 }),
+            0b10100 => Ok(XTheadInstruction::th_lrhu { rd, rs1, rs2, imm2 }),
+            0b01000 => Ok(XTheadInstruction::th_lrw { rd, rs1, rs2, imm2 }),
+            _ => Err(DisassemblerError::InvalidFunct7(fields.funct7)),
+        };
+    }
+
+    if fields.funct5 & 0b01 == 0b01 && fields.rd == fields.rs1 {
+        return Err(DisassemblerError::InvalidRdField(fields.rd));
+    }
+    let imm5 = fields.imm5()?;
+    match fields.funct5 {
+        0b00011 => Ok(XTheadInstruction::th_lbia {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b00001 => Ok(XTheadInstruction::th_lbib {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b10011 => Ok(XTheadInstruction::th_lbuia {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b10001 => Ok(XTheadInstruction::th_lbuib {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b00111 => Ok(XTheadInstruction::th_lhia {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b00101 => Ok(XTheadInstruction::th_lhib {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b10111 => Ok(XTheadInstruction::th_lhuia {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b10101 => Ok(XTheadInstruction::th_lhuib {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b01011 => Ok(XTheadInstruction::th_lwia {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b01001 => Ok(XTheadInstruction::th_lwib {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        _ => Err(DisassemblerError::InvalidFunct7(fields.funct7)),
+    }
+}
+
+/// Parses the XTheadMemIdx and XTheadMemPair stores, where rd holds the stored register.
+fn decode_store(fields: &Fields) -> Result<XTheadInstruction, DisassemblerError> {
+    let imm2 = fields.imm2;
+    let rd = fields.rd()?;
+    let rs1 = fields.rs1()?;
+
+    if fields.funct5 == 0b11100 {
+        return Ok(XTheadInstruction::th_swd {
+            rd1: rd,
+            rd2: fields.rs2()?,
+            rs1,
+            imm2,
+        });
+    }
+
+    if fields.funct5 & 0b11 == 0b00 {
+        let rs2 = fields.rs2()?;
+        return match fields.funct5 {
+            0b00000 => Ok(XTheadInstruction::th_srb { rd, rs1, rs2, imm2 }),
+            0b00100 => Ok(XTheadInstruction::th_srh { rd, rs1, rs2, imm2 }),
+            0b01000 => Ok(XTheadInstruction::th_srw { rd, rs1, rs2, imm2 }),
+            _ => Err(DisassemblerError::InvalidFunct7(fields.funct7)),
+        };
+    }
+
+    let imm5 = fields.imm5()?;
+    match fields.funct5 {
+        0b00011 => Ok(XTheadInstruction::th_sbia {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b00001 => Ok(XTheadInstruction::th_sbib {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b00111 => Ok(XTheadInstruction::th_shia {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b00101 => Ok(XTheadInstruction::th_shib {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b01011 => Ok(XTheadInstruction::th_swia {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        0b01001 => Ok(XTheadInstruction::th_swib {
+            rd,
+            rs1,
+            imm5,
+            imm2,
+        }),
+        _ => Err(DisassemblerError::InvalidFunct7(fields.funct7)),
+    }
+}
+
+/// Parses the XTheadFMemIdx loads (funct3 `110`) and stores (funct3 `111`).
+fn decode_float_memory(fields: &Fields) -> Result<XTheadInstruction, DisassemblerError> {
+    let rd = fields.float_register(fields.rd)?;
+    let rs1 = fields.rs1()?;
+    let rs2 = fields.rs2()?;
+    let imm2 = fields.imm2;
+
+    match (fields.funct3, fields.funct5) {
+        (0b110, 0b01000) => Ok(XTheadInstruction::th_flrw { rd, rs1, rs2, imm2 }),
+        (0b110, 0b01100) => Ok(XTheadInstruction::th_flrd { rd, rs1, rs2, imm2 }),
+        (0b111, 0b01000) => Ok(XTheadInstruction::th_fsrw { rd, rs1, rs2, imm2 }),
+        (0b111, 0b01100) => Ok(XTheadInstruction::th_fsrd { rd, rs1, rs2, imm2 }),
+        _ => Err(DisassemblerError::InvalidFunct7(fields.funct7)),
+    }
+}
+
+/// Instruction of the T-Head vendor extensions, as decoded by [`XTheadDecoder`].
+#[derive(Debug, PartialEq, Clone)]
+#[allow(non_camel_case_types)]
+pub enum XTheadInstruction {
+    th_addsl {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+    th_srri {
+        rd: &'static str,
+        rs1: &'static str,
+        imm: u8,
+    },
+    th_ext {
+        rd: &'static str,
+        rs1: &'static str,
+        msb: u8,
+        lsb: u8,
+    },
+    th_extu {
+        rd: &'static str,
+        rs1: &'static str,
+        msb: u8,
+        lsb: u8,
+    },
+    th_ff0 {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    th_ff1 {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    th_rev {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    th_tstnbz {
+        rd: &'static str,
+        rs1: &'static str,
+    },
+    th_tst {
+        rd: &'static str,
+        rs1: &'static str,
+        imm: u8,
+    },
+    th_mveqz {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    th_mvnez {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    th_mula {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    th_muls {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    th_mulah {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    th_mulsh {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    th_sfence_vmas {
+        rs1: &'static str,
+        rs2: &'static str,
+    },
+    th_sync,
+    th_sync_i,
+    th_sync_is,
+    th_sync_s,
+    th_dcache_call,
+    th_dcache_ciall,
+    th_dcache_iall,
+    th_icache_iall,
+    th_icache_ialls,
+    th_l2cache_call,
+    th_l2cache_ciall,
+    th_l2cache_iall,
+    th_dcache_cpa {
+        rs1: &'static str,
+    },
+    th_dcache_cipa {
+        rs1: &'static str,
+    },
+    th_dcache_ipa {
+        rs1: &'static str,
+    },
+    th_dcache_cva {
+        rs1: &'static str,
+    },
+    th_dcache_civa {
+        rs1: &'static str,
+    },
+    th_dcache_iva {
+        rs1: &'static str,
+    },
+    th_dcache_csw {
+        rs1: &'static str,
+    },
+    th_dcache_cisw {
+        rs1: &'static str,
+    },
+    th_dcache_isw {
+        rs1: &'static str,
+    },
+    th_dcache_cpal1 {
+        rs1: &'static str,
+    },
+    th_dcache_cval1 {
+        rs1: &'static str,
+    },
+    th_icache_ipa {
+        rs1: &'static str,
+    },
+    th_icache_iva {
+        rs1: &'static str,
+    },
+    th_lrb {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+    th_lrbu {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+    th_lrh {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+    th_lrhu {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+    th_lrw {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+    th_srb {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+    th_srh {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+    th_srw {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+    th_lbia {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_lbib {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_lbuia {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_lbuib {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_lhia {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_lhib {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_lhuia {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_lhuib {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_lwia {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_lwib {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_sbia {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_sbib {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_shia {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_shib {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_swia {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_swib {
+        rd: &'static str,
+        rs1: &'static str,
+        imm5: i8,
+        imm2: u8,
+    },
+    th_lwd {
+        rd1: &'static str,
+        rd2: &'static str,
+        rs1: &'static str,
+        imm2: u8,
+    },
+    th_swd {
+        rd1: &'static str,
+        rd2: &'static str,
+        rs1: &'static str,
+        imm2: u8,
+    },
+    th_flrw {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+    th_flrd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+    th_fsrw {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+    th_fsrd {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+        imm2: u8,
+    },
+}
+
+impl fmt::Display for XTheadInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            XTheadInstruction::th_addsl { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.addsl {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+            XTheadInstruction::th_srri { rd, rs1, imm } => {
+                write!(f, "th.srri {}, {}, {}", rd, rs1, imm)
+            }
+            XTheadInstruction::th_ext { rd, rs1, msb, lsb } => {
+                write!(f, "th.ext {}, {}, {}, {}", rd, rs1, msb, lsb)
+            }
+            XTheadInstruction::th_extu { rd, rs1, msb, lsb } => {
+                write!(f, "th.extu {}, {}, {}, {}", rd, rs1, msb, lsb)
+            }
+            XTheadInstruction::th_ff0 { rd, rs1 } => {
+                write!(f, "th.ff0 {}, {}", rd, rs1)
+            }
+            XTheadInstruction::th_ff1 { rd, rs1 } => {
+                write!(f, "th.ff1 {}, {}", rd, rs1)
+            }
+            XTheadInstruction::th_rev { rd, rs1 } => {
+                write!(f, "th.rev {}, {}", rd, rs1)
+            }
+            XTheadInstruction::th_tstnbz { rd, rs1 } => {
+                write!(f, "th.tstnbz {}, {}", rd, rs1)
+            }
+            XTheadInstruction::th_tst { rd, rs1, imm } => {
+                write!(f, "th.tst {}, {}, {}", rd, rs1, imm)
+            }
+            XTheadInstruction::th_mveqz { rd, rs1, rs2 } => {
+                write!(f, "th.mveqz {}, {}, {}", rd, rs1, rs2)
+            }
+            XTheadInstruction::th_mvnez { rd, rs1, rs2 } => {
+                write!(f, "th.mvnez {}, {}, {}", rd, rs1, rs2)
+            }
+            XTheadInstruction::th_mula { rd, rs1, rs2 } => {
+                write!(f, "th.mula {}, {}, {}", rd, rs1, rs2)
+            }
+            XTheadInstruction::th_muls { rd, rs1, rs2 } => {
+                write!(f, "th.muls {}, {}, {}", rd, rs1, rs2)
+            }
+            XTheadInstruction::th_mulah { rd, rs1, rs2 } => {
+                write!(f, "th.mulah {}, {}, {}", rd, rs1, rs2)
+            }
+            XTheadInstruction::th_mulsh { rd, rs1, rs2 } => {
+                write!(f, "th.mulsh {}, {}, {}", rd, rs1, rs2)
+            }
+            XTheadInstruction::th_sfence_vmas { rs1, rs2 } => {
+                write!(f, "th.sfence.vmas {}, {}", rs1, rs2)
+            }
+            XTheadInstruction::th_sync => write!(f, "th.sync"),
+            XTheadInstruction::th_sync_i => write!(f, "th.sync.i"),
+            XTheadInstruction::th_sync_is => write!(f, "th.sync.is"),
+            XTheadInstruction::th_sync_s => write!(f, "th.sync.s"),
+            XTheadInstruction::th_dcache_call => write!(f, "th.dcache.call"),
+            XTheadInstruction::th_dcache_ciall => write!(f, "th.dcache.ciall"),
+            XTheadInstruction::th_dcache_iall => write!(f, "th.dcache.iall"),
+            XTheadInstruction::th_icache_iall => write!(f, "th.icache.iall"),
+            XTheadInstruction::th_icache_ialls => write!(f, "th.icache.ialls"),
+            XTheadInstruction::th_l2cache_call => write!(f, "th.l2cache.call"),
+            XTheadInstruction::th_l2cache_ciall => write!(f, "th.l2cache.ciall"),
+            XTheadInstruction::th_l2cache_iall => write!(f, "th.l2cache.iall"),
+            XTheadInstruction::th_dcache_cpa { rs1 } => {
+                write!(f, "th.dcache.cpa {}", rs1)
+            }
+            XTheadInstruction::th_dcache_cipa { rs1 } => {
+                write!(f, "th.dcache.cipa {}", rs1)
+            }
+            XTheadInstruction::th_dcache_ipa { rs1 } => {
+                write!(f, "th.dcache.ipa {}", rs1)
+            }
+            XTheadInstruction::th_dcache_cva { rs1 } => {
+                write!(f, "th.dcache.cva {}", rs1)
+            }
+            XTheadInstruction::th_dcache_civa { rs1 } => {
+                write!(f, "th.dcache.civa {}", rs1)
+            }
+            XTheadInstruction::th_dcache_iva { rs1 } => {
+                write!(f, "th.dcache.iva {}", rs1)
+            }
+            XTheadInstruction::th_dcache_csw { rs1 } => {
+                write!(f, "th.dcache.csw {}", rs1)
+            }
+            XTheadInstruction::th_dcache_cisw { rs1 } => {
+                write!(f, "th.dcache.cisw {}", rs1)
+            }
+            XTheadInstruction::th_dcache_isw { rs1 } => {
+                write!(f, "th.dcache.isw {}", rs1)
+            }
+            XTheadInstruction::th_dcache_cpal1 { rs1 } => {
+                write!(f, "th.dcache.cpal1 {}", rs1)
+            }
+            XTheadInstruction::th_dcache_cval1 { rs1 } => {
+                write!(f, "th.dcache.cval1 {}", rs1)
+            }
+            XTheadInstruction::th_icache_ipa { rs1 } => {
+                write!(f, "th.icache.ipa {}", rs1)
+            }
+            XTheadInstruction::th_icache_iva { rs1 } => {
+                write!(f, "th.icache.iva {}", rs1)
+            }
+            XTheadInstruction::th_lrb { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.lrb {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+            XTheadInstruction::th_lrbu { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.lrbu {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+            XTheadInstruction::th_lrh { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.lrh {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+            XTheadInstruction::th_lrhu { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.lrhu {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+            XTheadInstruction::th_lrw { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.lrw {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+            XTheadInstruction::th_srb { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.srb {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+            XTheadInstruction::th_srh { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.srh {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+            XTheadInstruction::th_srw { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.srw {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+            XTheadInstruction::th_lbia {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.lbia {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_lbib {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.lbib {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_lbuia {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.lbuia {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_lbuib {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.lbuib {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_lhia {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.lhia {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_lhib {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.lhib {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_lhuia {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.lhuia {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_lhuib {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.lhuib {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_lwia {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.lwia {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_lwib {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.lwib {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_sbia {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.sbia {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_sbib {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.sbib {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_shia {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.shia {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_shib {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.shib {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_swia {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.swia {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_swib {
+                rd,
+                rs1,
+                imm5,
+                imm2,
+            } => {
+                write!(f, "th.swib {}, ({}), {}, {}", rd, rs1, imm5, imm2)
+            }
+            XTheadInstruction::th_lwd {
+                rd1,
+                rd2,
+                rs1,
+                imm2,
+            } => {
+                write!(f, "th.lwd {}, {}, ({}), {}, 3", rd1, rd2, rs1, imm2)
+            }
+            XTheadInstruction::th_swd {
+                rd1,
+                rd2,
+                rs1,
+                imm2,
+            } => {
+                write!(f, "th.swd {}, {}, ({}), {}, 3", rd1, rd2, rs1, imm2)
+            }
+            XTheadInstruction::th_flrw { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.flrw {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+            XTheadInstruction::th_flrd { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.flrd {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+            XTheadInstruction::th_fsrw { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.fsrw {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+            XTheadInstruction::th_fsrd { rd, rs1, rs2, imm2 } => {
+                write!(f, "th.fsrd {}, {}, {}, {}", rd, rs1, rs2, imm2)
+            }
+        }
+    }
+}
+
+impl XTheadInstruction {
+    /// Returns the name of the extension of the instruction, one of [`XTHEAD_EXTENSIONS`].
+    pub fn extension(&self) -> &'static str {
+        match self {
+            XTheadInstruction::th_addsl { .. } => "xtheadba",
+            XTheadInstruction::th_srri { .. }
+            | XTheadInstruction::th_ext { .. }
+            | XTheadInstruction::th_extu { .. }
+            | XTheadInstruction::th_ff0 { .. }
+            | XTheadInstruction::th_ff1 { .. }
+            | XTheadInstruction::th_rev { .. }
+            | XTheadInstruction::th_tstnbz { .. } => "xtheadbb",
+            XTheadInstruction::th_tst { .. } => "xtheadbs",
+            XTheadInstruction::th_mveqz { .. } | XTheadInstruction::th_mvnez { .. } => {
+                "xtheadcondmov"
+            }
+            XTheadInstruction::th_lrb { .. }
+            | XTheadInstruction::th_lrbu { .. }
+            | XTheadInstruction::th_lrh { .. }
+            | XTheadInstruction::th_lrhu { .. }
+            | XTheadInstruction::th_lrw { .. }
+            | XTheadInstruction::th_srb { .. }
+            | XTheadInstruction::th_srh { .. }
+            | XTheadInstruction::th_srw { .. }
+            | XTheadInstruction::th_lbia { .. }
+            | XTheadInstruction::th_lbib { .. }
+            | XTheadInstruction::th_lbuia { .. }
+            | XTheadInstruction::th_lbuib { .. }
+            | XTheadInstruction::th_lhia { .. }
+            | XTheadInstruction::th_lhib { .. }
+            | XTheadInstruction::th_lhuia { .. }
+            | XTheadInstruction::th_lhuib { .. }
+            | XTheadInstruction::th_lwia { .. }
+            | XTheadInstruction::th_lwib { .. }
+            | XTheadInstruction::th_sbia { .. }
+            | XTheadInstruction::th_sbib { .. }
+            | XTheadInstruction::th_shia { .. }
+            | XTheadInstruction::th_shib { .. }
+            | XTheadInstruction::th_swia { .. }
+            | XTheadInstruction::th_swib { .. } => "xtheadmemidx",
+            XTheadInstruction::th_lwd { .. } | XTheadInstruction::th_swd { .. } => "xtheadmempair",
+            XTheadInstruction::th_mula { .. }
+            | XTheadInstruction::th_muls { .. }
+            | XTheadInstruction::th_mulah { .. }
+            | XTheadInstruction::th_mulsh { .. } => "xtheadmac",
+            XTheadInstruction::th_flrw { .. }
+            | XTheadInstruction::th_flrd { .. }
+            | XTheadInstruction::th_fsrw { .. }
+            | XTheadInstruction::th_fsrd { .. } => "xtheadfmemidx",
+            XTheadInstruction::th_sfence_vmas { .. }
+            | XTheadInstruction::th_sync
+            | XTheadInstruction::th_sync_i
+            | XTheadInstruction::th_sync_is
+            | XTheadInstruction::th_sync_s => "xtheadsync",
+            XTheadInstruction::th_dcache_call
+            | XTheadInstruction::th_dcache_ciall
+            | XTheadInstruction::th_dcache_iall
+            | XTheadInstruction::th_icache_iall
+            | XTheadInstruction::th_icache_ialls
+            | XTheadInstruction::th_l2cache_call
+            | XTheadInstruction::th_l2cache_ciall
+            | XTheadInstruction::th_l2cache_iall
+            | XTheadInstruction::th_dcache_cpa { .. }
+            | XTheadInstruction::th_dcache_cipa { .. }
+            | XTheadInstruction::th_dcache_ipa { .. }
+            | XTheadInstruction::th_dcache_cva { .. }
+            | XTheadInstruction::th_dcache_civa { .. }
+            | XTheadInstruction::th_dcache_iva { .. }
+            | XTheadInstruction::th_dcache_csw { .. }
+            | XTheadInstruction::th_dcache_cisw { .. }
+            | XTheadInstruction::th_dcache_isw { .. }
+            | XTheadInstruction::th_dcache_cpal1 { .. }
+            | XTheadInstruction::th_dcache_cval1 { .. }
+            | XTheadInstruction::th_icache_ipa { .. }
+            | XTheadInstruction::th_icache_iva { .. } => "xtheadcmo",
+        }
+    }
+}
+
+impl CustomInstruction for XTheadInstruction {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_custom(&self, other: &dyn CustomInstruction) -> bool {
+        other.as_any().downcast_ref::<Self>() == Some(self)
+    }
+
+    fn memory_access(&self) -> Option<MemoryAccess> {
+        match self {
+            XTheadInstruction::th_lrb { .. }
+            | XTheadInstruction::th_lrbu { .. }
+            | XTheadInstruction::th_lbia { .. }
+            | XTheadInstruction::th_lbib { .. }
+            | XTheadInstruction::th_lbuia { .. }
+            | XTheadInstruction::th_lbuib { .. } => Some(MemoryAccess::Load { size: 1 }),
+            XTheadInstruction::th_lrh { .. }
+            | XTheadInstruction::th_lrhu { .. }
+            | XTheadInstruction::th_lhia { .. }
+            | XTheadInstruction::th_lhib { .. }
+            | XTheadInstruction::th_lhuia { .. }
+            | XTheadInstruction::th_lhuib { .. } => Some(MemoryAccess::Load { size: 2 }),
+            XTheadInstruction::th_lrw { .. }
+            | XTheadInstruction::th_lwia { .. }
+            | XTheadInstruction::th_lwib { .. }
+            | XTheadInstruction::th_flrw { .. } => Some(MemoryAccess::Load { size: 4 }),
+            XTheadInstruction::th_lwd { .. } | XTheadInstruction::th_flrd { .. } => {
+                Some(MemoryAccess::Load { size: 8 })
+            }
+            XTheadInstruction::th_srb { .. }
+            | XTheadInstruction::th_sbia { .. }
+            | XTheadInstruction::th_sbib { .. } => Some(MemoryAccess::Store { size: 1 }),
+            XTheadInstruction::th_srh { .. }
+            | XTheadInstruction::th_shia { .. }
+            | XTheadInstruction::th_shib { .. } => Some(MemoryAccess::Store { size: 2 }),
+            XTheadInstruction::th_srw { .. }
+            | XTheadInstruction::th_swia { .. }
+            | XTheadInstruction::th_swib { .. }
+            | XTheadInstruction::th_fsrw { .. } => Some(MemoryAccess::Store { size: 4 }),
+            XTheadInstruction::th_swd { .. } | XTheadInstruction::th_fsrd { .. } => {
+                Some(MemoryAccess::Store { size: 8 })
+            }
+            _ => None,
+        }
+    }
+
+    /// The cache operations can be enabled for user mode through `mxstatus.UCME`, which is not
+    /// taken into account, like other status bits.
+    fn privilege_level(&self) -> PrivilegeLevel {
+        match self {
+            XTheadInstruction::th_sfence_vmas { .. }
+            | XTheadInstruction::th_dcache_call
+            | XTheadInstruction::th_dcache_ciall
+            | XTheadInstruction::th_dcache_iall
+            | XTheadInstruction::th_icache_iall
+            | XTheadInstruction::th_icache_ialls
+            | XTheadInstruction::th_l2cache_call
+            | XTheadInstruction::th_l2cache_ciall
+            | XTheadInstruction::th_l2cache_iall
+            | XTheadInstruction::th_dcache_cpa { .. }
+            | XTheadInstruction::th_dcache_cipa { .. }
+            | XTheadInstruction::th_dcache_ipa { .. }
+            | XTheadInstruction::th_dcache_cva { .. }
+            | XTheadInstruction::th_dcache_civa { .. }
+            | XTheadInstruction::th_dcache_iva { .. }
+            | XTheadInstruction::th_dcache_csw { .. }
+            | XTheadInstruction::th_dcache_cisw { .. }
+            | XTheadInstruction::th_dcache_isw { .. }
+            | XTheadInstruction::th_dcache_cpal1 { .. }
+            | XTheadInstruction::th_dcache_cval1 { .. }
+            | XTheadInstruction::th_icache_ipa { .. }
+            | XTheadInstruction::th_icache_iva { .. } => PrivilegeLevel::Supervisor,
+            _ => PrivilegeLevel::User,
+        }
+    }
+
+    fn float_format(&self) -> Option<FloatFormat> {
+        match self {
+            XTheadInstruction::th_flrw { .. } | XTheadInstruction::th_fsrw { .. } => {
+                Some(FloatFormat::Single)
+            }
+            XTheadInstruction::th_flrd { .. } | XTheadInstruction::th_fsrd { .. } => {
+                Some(FloatFormat::Double)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::parsed_instructions::custom;
+    use crate::{parse_with_custom_decoders, CustomDecoders, Extensions, ParsedInstruction32};
+
+    /// Decodes an instruction through [`XTheadDecoder`] registered for [`XTHEAD_OPCODE`].
+    fn parse_xthead(
+        instruction: u32,
+        use_abi_register_names: bool,
+    ) -> Result<XTheadInstruction, DisassemblerError> {
+        let mut decoders = CustomDecoders::new();
+        decoders.register(XTHEAD_OPCODE, XTheadDecoder)?;
+        let parsed = parse_with_custom_decoders(
+            &instruction.to_le_bytes(),
+            false,
+            use_abi_register_names,
+            &Extensions::default(),
+            &decoders,
+        )?;
+        match parsed {
+            ParsedInstruction32::custom(custom { instruction }) => Ok(instruction
+                .as_any()
+                .downcast_ref::<XTheadInstruction>()
+                .unwrap()
+                .clone()),
+            parsed => panic!("{:?} is not a T-Head instruction", parsed),
+        }
+    }
+
+    fn decode(instruction: u32) -> Result<XTheadInstruction, DisassemblerError> {
+        parse_xthead(instruction, false)
+    }
+
+    #[test]
+    fn test_decode_th_addsl() {
+        assert_eq!(
+            decode(0x04C5950B),
+            Ok(XTheadInstruction::th_addsl {
+                rd: "x10",
+                rs1: "x11",
+                rs2: "x12",
+                imm2: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_th_srri_rv64_shamt() {
+        // th.srri x10, x11, 33
+        assert_eq!(
+            decode(0x1215950B),
+            Err(DisassemblerError::InvalidImmediate(33))
+        );
+    }
+
+    #[test]
+    fn test_decode_th_ext() {
+        assert_eq!(
+            decode(0x3C45A50B),
+            Ok(XTheadInstruction::th_ext {
+                rd: "x10",
+                rs1: "x11",
+                msb: 15,
+                lsb: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_th_mveqz() {
+        assert_eq!(
+            decode(0x40C5950B),
+            Ok(XTheadInstruction::th_mveqz {
+                rd: "x10",
+                rs1: "x11",
+                rs2: "x12"
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_th_dcache_cva() {
+        assert_eq!(
+            decode(0x0255000B),
+            Ok(XTheadInstruction::th_dcache_cva { rs1: "x10" })
+        );
+    }
+
+    #[test]
+    fn test_decode_th_sync_invalid_rd() {
+        assert_eq!(
+            decode(0x0180008B),
+            Err(DisassemblerError::InvalidRdField(1))
+        );
+    }
+
+    #[test]
+    fn test_decode_th_lrw() {
+        assert_eq!(
+            decode(0x46C5C50B),
+            Ok(XTheadInstruction::th_lrw {
+                rd: "x10",
+                rs1: "x11",
+                rs2: "x12",
+                imm2: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_th_lbia_negative_offset() {
+        assert_eq!(
+            decode(0x1FC5C50B),
+            Ok(XTheadInstruction::th_lbia {
+                rd: "x10",
+                rs1: "x11",
+                imm5: -4,
+                imm2: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_th_lbia_rd_equals_rs1() {
+        assert_eq!(
+            decode(0x1FC5C58B),
+            Err(DisassemblerError::InvalidRdField(11))
+        );
+    }
+
+    #[test]
+    fn test_decode_th_lwd() {
+        assert_eq!(
+            decode(0xE2C5C50B),
+            Ok(XTheadInstruction::th_lwd {
+                rd1: "x10",
+                rd2: "x12",
+                rs1: "x11",
+                imm2: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_th_lrd_rv64_only() {
+        assert_eq!(
+            decode(0x60C5C50B),
+            Err(DisassemblerError::InvalidFunct7(0b0110000))
+        );
+    }
+
+    #[test]
+    fn test_decode_th_flrw_abi() {
+        assert_eq!(
+            parse_xthead(0x40C5E50B, true),
+            Ok(XTheadInstruction::th_flrw {
+                rd: "fa0",
+                rs1: "a1",
+                rs2: "a2",
+                imm2: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::parsed_instructions::*;
+    use risc_v_disassembler::{
+        parse, parse_with_extensions, DisassemblerError, Extensions, MemoryAccess,
+        ParsedInstruction32,
+    };
+
+    fn atomic_extensions() -> Extensions {
+        Extensions {
+            zalrsc: true,
+            zaamo: true,
+            zabha: true,
+            zacas: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a vector of tuples containing (instruction hex, expected ParsedInstruction32) for atomic instructions, using ABI registers
+    fn get_atomic_test_cases_abi() -> Vec<(u32, ParsedInstruction32)> {
+        vec![
+            (
+                0x06C5A52F,
+                ParsedInstruction32::amoadd_w(amoadd_w {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                    ordering: MemoryOrdering::AcquireRelease,
+                }),
+            ),
+            (
+                0x1405A52F,
+                ParsedInstruction32::lr_w(lr_w {
+                    rd: "a0",
+                    rs1: "a1",
+                    ordering: MemoryOrdering::Acquire,
+                }),
+            ),
+            (
+                0x1AC5A52F,
+                ParsedInstruction32::sc_w(sc_w {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                    ordering: MemoryOrdering::Release,
+                }),
+            ),
+            (
+                0x08C5852F,
+                ParsedInstruction32::amoswap_b(amoswap_b {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                    ordering: MemoryOrdering::Relaxed,
+                }),
+            ),
+            (
+                0x80C5952F,
+                ParsedInstruction32::amomin_h(amomin_h {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                    ordering: MemoryOrdering::Relaxed,
+                }),
+            ),
+            (
+                0x28C5A52F,
+                ParsedInstruction32::amocas_w(amocas_w {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                    ordering: MemoryOrdering::Relaxed,
+                }),
+            ),
+            (
+                0x28C5B52F,
+                ParsedInstruction32::amocas_d(amocas_d {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                    ordering: MemoryOrdering::Relaxed,
+                }),
+            ),
+            (
+                0x28C5852F,
+                ParsedInstruction32::amocas_b(amocas_b {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                    ordering: MemoryOrdering::Relaxed,
+                }),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_atomic_instructions_le_abi() {
+        for (hex, expected) in get_atomic_test_cases_abi() {
+            let result =
+                parse_with_extensions(&hex.to_le_bytes(), false, true, &atomic_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_atomic_instructions_be_abi() {
+        for (hex, expected) in get_atomic_test_cases_abi() {
+            let result =
+                parse_with_extensions(&hex.to_be_bytes(), true, true, &atomic_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_atomic_printing() {
+        let extensions = atomic_extensions();
+
+        let result = parse_with_extensions(&0x06C5A52Fu32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result.unwrap().to_string(), "amoadd.w.aqrl a0, a2, (a1)");
+
+        let result = parse_with_extensions(&0x1405A52Fu32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result.unwrap().to_string(), "lr.w.aq a0, (a1)");
+
+        let result = parse_with_extensions(&0x28C5B52Fu32.to_le_bytes(), false, false, &extensions);
+        assert_eq!(result.unwrap().to_string(), "amocas.d x10, x12, (x11)");
+    }
+
+    #[test]
+    fn test_atomic_memory_access() {
+        let extensions = atomic_extensions();
+
+        let result = parse_with_extensions(&0x08C5852Fu32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(
+            result.unwrap().memory_access(),
+            Some(MemoryAccess::Atomic { size: 1 })
+        );
+
+        let result = parse_with_extensions(&0x28C5B52Fu32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(
+            result.unwrap().memory_access(),
+            Some(MemoryAccess::Atomic { size: 8 })
+        );
+
+        let result = parse_with_extensions(&0x1405A52Fu32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(
+            result.unwrap().memory_access(),
+            Some(MemoryAccess::Load { size: 4 })
+        );
+    }
+
+    #[test]
+    fn test_amocas_register_pairs() {
+        let extensions = atomic_extensions();
+
+        // amocas.d a0, a3, (a1)
+        let result = parse_with_extensions(&0x28D5B52Fu32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result, Err(DisassemblerError::InvalidRegisterPair(13)));
+
+        // amocas.q a0, a2, (a1) only exists on RV64
+        let result = parse_with_extensions(&0x28C5C52Fu32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct3(0b100)));
+    }
+
+    #[test]
+    fn test_zalrsc_zaamo_split() {
+        let zalrsc = Extensions {
+            zalrsc: true,
+            ..Default::default()
+        };
+        let result = parse_with_extensions(&0x06C5A52Fu32.to_le_bytes(), false, true, &zalrsc);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct7(0b0000011)));
+
+        let zaamo = Extensions {
+            zaamo: true,
+            ..Default::default()
+        };
+        let result = parse_with_extensions(&0x1405A52Fu32.to_le_bytes(), false, true, &zaamo);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct7(0b0001010)));
+        let result = parse_with_extensions(&0x06C5A52Fu32.to_le_bytes(), false, true, &zaamo);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_atomic_not_selected() {
+        let result = parse(&0x06C5A52Fu32.to_le_bytes(), false, false);
+        assert_eq!(result, Err(DisassemblerError::InvalidOpcode(0b0101111)));
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::parsed_instructions::*;
+    use risc_v_disassembler::{
+        parse_with_extensions, DisassemblerError, Extensions, FloatFormat, Lmul,
+        ParsedInstruction32,
+    };
+
+    fn bf16_extensions() -> Extensions {
+        Extensions {
+            zfbfmin: true,
+            zvfbfmin: true,
+            zvfbfwma: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a vector of tuples containing (instruction hex, expected output) for the BF16 instructions, using ABI registers
+    fn get_bf16_test_cases_abi() -> Vec<(u32, &'static str)> {
+        vec![
+            (0x4485F553, "fcvt.bf16.s fa0, fa1"),
+            (0x40658553, "fcvt.s.bf16 fa0, fa1, rne"),
+            (0x4A2E90D7, "vfncvtbf16.f.f.w v1, v2"),
+            (0x48169157, "vfwcvtbf16.f.f.v v2, v1, v0.t"),
+            (0xEE521157, "vfwmaccbf16.vv v2, v4, v5"),
+            (0xEE555157, "vfwmaccbf16.vf v2, fa0, v5"),
+        ]
+    }
+
+    #[test]
+    fn test_bf16_printing_abi() {
+        for (hex, expected) in get_bf16_test_cases_abi() {
+            let result = parse_with_extensions(&hex.to_le_bytes(), false, true, &bf16_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            let result = result.unwrap();
+            assert_eq!(result.to_string(), expected);
+            assert_eq!(result.float_format(), Some(FloatFormat::BFloat16));
+        }
+    }
+
+    #[test]
+    fn test_bf16_numbered_registers() {
+        let result = parse_with_extensions(
+            &0x4485F553u32.to_le_bytes(),
+            false,
+            false,
+            &bf16_extensions(),
+        );
+        assert_eq!(
+            result,
+            Ok(ParsedInstruction32::fcvt_bf16_s(fcvt_bf16_s {
+                rd: "f10",
+                rs1: "f11",
+                rm: RoundingMode::Dyn,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_bf16_distinct_from_half() {
+        let extensions = Extensions {
+            zfinx: true,
+            zhinx: true,
+            ..Default::default()
+        };
+
+        // fcvt.h.s a0, a1
+        let result = parse_with_extensions(&0x4405F553u32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result.unwrap().float_format(), Some(FloatFormat::Half));
+
+        // fcvt.bf16.s is not decoded without Zfbfmin
+        let result = parse_with_extensions(&0x4485F553u32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result, Err(DisassemblerError::InvalidRs2Field(0b01000)));
+    }
+
+    #[test]
+    fn test_bf16_widening_register_groups() {
+        let extensions = Extensions {
+            lmul: Lmul::M2,
+            ..bf16_extensions()
+        };
+
+        // vfwmaccbf16.vv v2, v4, v5 with a destination group of four registers
+        let result = parse_with_extensions(&0xEE521157u32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(
+            result,
+            Err(DisassemblerError::InvalidVectorRegisterGroup(2))
+        );
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::parsed_instructions::*;
+    use risc_v_disassembler::{
+        parse, parse_with_extensions, DisassemblerError, Extensions, ParsedInstruction32,
+    };
+
+    fn cmo_extensions() -> Extensions {
+        Extensions {
+            zicbom: true,
+            zicboz: true,
+            zicbop: true,
+            zicond: true,
+            zawrs: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a vector of tuples containing (instruction hex, expected ParsedInstruction32) for Zicbom, Zicboz, Zicbop, Zicond and Zawrs instructions, using ABI registers
+    fn get_cmo_test_cases_abi() -> Vec<(u32, ParsedInstruction32)> {
+        vec![
+            (
+                0x0015200F,
+                ParsedInstruction32::cbo_clean(cbo_clean { rs1: "a0" }),
+            ),
+            (
+                0x0025200F,
+                ParsedInstruction32::cbo_flush(cbo_flush { rs1: "a0" }),
+            ),
+            (
+                0x0005200F,
+                ParsedInstruction32::cbo_inval(cbo_inval { rs1: "a0" }),
+            ),
+            (
+                0x0045200F,
+                ParsedInstruction32::cbo_zero(cbo_zero { rs1: "a0" }),
+            ),
+            (
+                0x04056013,
+                ParsedInstruction32::prefetch_i(prefetch_i { rs1: "a0", imm: 64 }),
+            ),
+            (
+                0x00156013,
+                ParsedInstruction32::prefetch_r(prefetch_r { rs1: "a0", imm: 0 }),
+            ),
+            (
+                0xFE356013,
+                ParsedInstruction32::prefetch_w(prefetch_w {
+                    rs1: "a0",
+                    imm: -32,
+                }),
+            ),
+            (
+                0x0EC5D533,
+                ParsedInstruction32::czero_eqz(czero_eqz {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                }),
+            ),
+            (
+                0x0EC5F533,
+                ParsedInstruction32::czero_nez(czero_nez {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                }),
+            ),
+            (0x00D00073, ParsedInstruction32::wrs_nto(wrs_nto {})),
+            (0x01D00073, ParsedInstruction32::wrs_sto(wrs_sto {})),
+        ]
+    }
+
+    #[test]
+    fn test_cmo_instructions_le_abi() {
+        for (hex, expected) in get_cmo_test_cases_abi() {
+            let result = parse_with_extensions(&hex.to_le_bytes(), false, true, &cmo_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_cmo_instructions_be_abi() {
+        for (hex, expected) in get_cmo_test_cases_abi() {
+            let result = parse_with_extensions(&hex.to_be_bytes(), true, true, &cmo_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_cmo_printing() {
+        let extensions = cmo_extensions();
+
+        let result = parse_with_extensions(&0x0015200Fu32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result.unwrap().to_string(), "cbo.clean (a0)");
+
+        let result = parse_with_extensions(&0x04056013u32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result.unwrap().to_string(), "prefetch.i 64(a0)");
+
+        let result = parse_with_extensions(&0x0EC5D533u32.to_le_bytes(), false, false, &extensions);
+        assert_eq!(result.unwrap().to_string(), "czero.eqz x10, x11, x12");
+    }
+
+    #[test]
+    fn test_prefetch_precedence() {
+        // ori zero, a0, 1 is prefetch.r when Zicbop is selected and ori otherwise
+        let result = parse(&0x00156013u32.to_le_bytes(), false, true);
+        assert_eq!(
+            result,
+            Ok(ParsedInstruction32::ori(ori {
+                rd: "zero",
+                rs1: "a0",
+                imm: 1
+            }))
+        );
+
+        // ori zero, a0, 2 has no prefetch hint and stays ori
+        let result =
+            parse_with_extensions(&0x00256013u32.to_le_bytes(), false, true, &cmo_extensions());
+        assert_eq!(
+            result,
+            Ok(ParsedInstruction32::ori(ori {
+                rd: "zero",
+                rs1: "a0",
+                imm: 2
+            }))
+        );
+    }
+
+    #[test]
+    fn test_cmo_extensions_not_selected() {
+        let result = parse(&0x0015200Fu32.to_le_bytes(), false, false);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct3(0b010)));
+
+        let result = parse(&0x0EC5D533u32.to_le_bytes(), false, false);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct7(0b0000111)));
+
+        let result = parse(&0x00D00073u32.to_le_bytes(), false, false);
+        assert_eq!(result, Err(DisassemblerError::InvalidImmediate(0x00D)));
+    }
+}
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::parsed_instructions::*;
+    use risc_v_disassembler::{
+        parse, parse_with_extensions, DisassemblerError, Extensions, MemoryAccess,
+        ParsedInstruction32,
+    };
+
+    fn compressed_extensions() -> Extensions {
+        Extensions {
+            zcb: true,
+            zcmp: true,
+            zcmt: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a vector of tuples containing (instruction hex, expected ParsedInstruction32) for Zcb, Zcmp and Zcmt instructions, using ABI registers
+    fn get_compressed_test_cases_abi() -> Vec<(u16, ParsedInstruction32)> {
+        vec![
+            (
+                0x81C8,
+                ParsedInstruction32::c_lbu(c_lbu {
+                    rd: "a0",
+                    rs1: "a1",
+                    imm: 1,
+                }),
+            ),
+            (0x9D65, ParsedInstruction32::c_sext_b(c_sext_b { rd: "a0" })),
+            (
+                0x9D4D,
+                ParsedInstruction32::c_mul(c_mul {
+                    rd: "a0",
+                    rs2: "a1",
+                }),
+            ),
+            (
+                0xB882,
+                ParsedInstruction32::cm_push(cm_push {
+                    rlist: RegisterList {
+                        ra: "ra",
+                        ranges: vec![("s0", "s3")],
+                    },
+                    stack_adj: -32,
+                }),
+            ),
+            (
+                0xBE42,
+                ParsedInstruction32::cm_popret(cm_popret {
+                    rlist: RegisterList {
+                        ra: "ra",
+                        ranges: vec![],
+                    },
+                    stack_adj: 16,
+                }),
+            ),
+            (
+                0xAC26,
+                ParsedInstruction32::cm_mvsa01(cm_mvsa01 {
+                    r1s: "s0",
+                    r2s: "s1",
+                }),
+            ),
+            (0xA082, ParsedInstruction32::cm_jalt(cm_jalt { index: 32 })),
+        ]
+    }
+
+    #[test]
+    fn test_compressed_instructions_le_abi() {
+        for (hex, expected) in get_compressed_test_cases_abi() {
+            let result =
+                parse_with_extensions(&hex.to_le_bytes(), false, true, &compressed_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#06x}", hex);
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_compressed_instructions_be_abi() {
+        for (hex, expected) in get_compressed_test_cases_abi() {
+            let result =
+                parse_with_extensions(&hex.to_be_bytes(), true, true, &compressed_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#06x}", hex);
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_compressed_printing() {
+        let extensions = compressed_extensions();
+
+        let result = parse_with_extensions(&0xB882u16.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result.unwrap().to_string(), "cm.push {ra, s0-s3}, -32");
+
+        let result = parse_with_extensions(&0xB882u16.to_le_bytes(), false, false, &extensions);
+        assert_eq!(
+            result.unwrap().to_string(),
+            "cm.push {x1, x8-x9, x18-x19}, -32"
+        );
+
+        let result = parse_with_extensions(&0xBE42u16.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result.unwrap().to_string(), "cm.popret {ra}, 16");
+
+        let result = parse_with_extensions(&0x81C8u16.to_le_bytes(), false, true, &extensions);
+        let result = result.unwrap();
+        assert_eq!(result.to_string(), "c.lbu a0, 1(a1)");
+        assert_eq!(result.memory_access(), Some(MemoryAccess::Load { size: 1 }));
+
+        let result = parse_with_extensions(&0xA082u16.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result.unwrap().to_string(), "cm.jalt 32");
+    }
+
+    #[test]
+    fn test_compressed_not_selected() {
+        let result = parse(&0x81C8u16.to_le_bytes(), false, false);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct6(0b100000)));
+
+        let result = parse(&0xB882u16.to_le_bytes(), false, false);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct6(0b101110)));
+    }
+
+    #[test]
+    fn test_compressed_unsupported() {
+        // c.lw a0, 0(a1)
+        let result = parse_with_extensions(
+            &0x4188u16.to_le_bytes(),
+            false,
+            true,
+            &compressed_extensions(),
+        );
+        assert_eq!(
+            result,
+            Err(DisassemblerError::UnsupportedCompressedInstruction(0x4188))
+        );
+    }
+}
@@ -0,0 +1,316 @@
+# Machine-readable decode table for the 32 bit encodings checked by tests/decode_coverage.rs.
+#
+# Each row holds a class, the extensions it requires, the mnemonic and the encoding, given from
+# bit 31 down to bit 0 with `-` for operand bits and `_` as a separator between fields. The
+# first row that matches a word and whose extensions are all enabled classifies it:
+#
+#   valid     an instruction, which must decode to the given mnemonic
+#   hint      a HINT encoding of the given instruction, which must decode to it, and to the
+#             `hint` variant when HINT classification is enabled
+#   reserved  an encoding reserved by the specification, which must be rejected
+#
+# Words matching no row are illegal and must be rejected as well. The extension `i` stands for
+# the base set that is always decoded: RV32I, Zicsr, Zifencei and the privileged instructions.
+# Other extensions are named after the fields of `Extensions`, joined with `+` when several are
+# required. An optional last column lists vector register fields that must differ, as in
+# `vd!=vs2`, for rows that only match when their operands do not overlap.
+
+# RV32I HINTs, which must be listed before the instructions they are encoded as
+valid    i           addi            000000000000_00000_000_00000_0010011
+hint     i           lui             --------------------_00000_0110111
+hint     i           auipc           --------------------_00000_0010111
+valid    zicbop      prefetch.i      -------00000_-----_110_00000_0010011
+valid    zicbop      prefetch.r      -------00001_-----_110_00000_0010011
+valid    zicbop      prefetch.w      -------00011_-----_110_00000_0010011
+hint     i           addi            ------------_-----_000_00000_0010011
+hint     i           slti            ------------_-----_010_00000_0010011
+hint     i           sltiu           ------------_-----_011_00000_0010011
+hint     i           xori            ------------_-----_100_00000_0010011
+hint     i           ori             ------------_-----_110_00000_0010011
+hint     i           andi            ------------_-----_111_00000_0010011
+hint     i           slli            0000000_-----_-----_001_00000_0010011
+hint     i           srli            0000000_-----_-----_101_00000_0010011
+hint     i           srai            0100000_-----_-----_101_00000_0010011
+hint     i           add             0000000_-----_-----_000_00000_0110011
+hint     i           sub             0100000_-----_-----_000_00000_0110011
+hint     i           sll             0000000_-----_-----_001_00000_0110011
+hint     i           slt             0000000_-----_-----_010_00000_0110011
+hint     i           sltu            0000000_-----_-----_011_00000_0110011
+hint     i           xor             0000000_-----_-----_100_00000_0110011
+hint     i           srl             0000000_-----_-----_101_00000_0110011
+hint     i           sra             0100000_-----_-----_101_00000_0110011
+hint     i           or              0000000_-----_-----_110_00000_0110011
+hint     i           and             0000000_-----_-----_111_00000_0110011
+hint     i           fence           0000_0000_----_-----_000_-----_0001111
+hint     i           fence           0000_----_0000_-----_000_-----_0001111
+
+# RV32I
+valid    i           lui             --------------------_-----_0110111
+valid    i           auipc           --------------------_-----_0010111
+valid    i           jal             --------------------_-----_1101111
+valid    i           jalr            ------------_-----_000_-----_1100111
+valid    i           beq             -------_-----_-----_000_-----_1100011
+valid    i           bne             -------_-----_-----_001_-----_1100011
+valid    i           blt             -------_-----_-----_100_-----_1100011
+valid    i           bge             -------_-----_-----_101_-----_1100011
+valid    i           bltu            -------_-----_-----_110_-----_1100011
+valid    i           bgeu            -------_-----_-----_111_-----_1100011
+valid    i           lb              ------------_-----_000_-----_0000011
+valid    i           lh              ------------_-----_001_-----_0000011
+valid    i           lw              ------------_-----_010_-----_0000011
+valid    i           lbu             ------------_-----_100_-----_0000011
+valid    i           lhu             ------------_-----_101_-----_0000011
+valid    i           sb              -------_-----_-----_000_-----_0100011
+valid    i           sh              -------_-----_-----_001_-----_0100011
+valid    i           sw              -------_-----_-----_010_-----_0100011
+valid    i           addi            ------------_-----_000_-----_0010011
+valid    i           slti            ------------_-----_010_-----_0010011
+valid    i           sltiu           ------------_-----_011_-----_0010011
+valid    i           xori            ------------_-----_100_-----_0010011
+valid    i           ori             ------------_-----_110_-----_0010011
+valid    i           andi            ------------_-----_111_-----_0010011
+valid    i           slli            0000000_-----_-----_001_-----_0010011
+valid    i           srli            0000000_-----_-----_101_-----_0010011
+valid    i           srai            0100000_-----_-----_101_-----_0010011
+valid    i           add             0000000_-----_-----_000_-----_0110011
+valid    i           sub             0100000_-----_-----_000_-----_0110011
+valid    i           sll             0000000_-----_-----_001_-----_0110011
+valid    i           slt             0000000_-----_-----_010_-----_0110011
+valid    i           sltu            0000000_-----_-----_011_-----_0110011
+valid    i           xor             0000000_-----_-----_100_-----_0110011
+valid    i           srl             0000000_-----_-----_101_-----_0110011
+valid    i           sra             0100000_-----_-----_101_-----_0110011
+valid    i           or              0000000_-----_-----_110_-----_0110011
+valid    i           and             0000000_-----_-----_111_-----_0110011
+valid    i           fence           0000_----_----_-----_000_-----_0001111
+valid    i           fence.tso       1000_0011_0011_-----_000_-----_0001111
+reserved i           fence           ----_----_----_-----_000_-----_0001111
+valid    i           ecall           000000000000_00000_000_00000_1110011
+valid    i           ebreak          000000000001_00000_000_00000_1110011
+
+# Zifencei and Zicsr
+valid    i           fence.i         ------------_-----_001_-----_0001111
+valid    i           csrrw           ------------_-----_001_-----_1110011
+valid    i           csrrs           ------------_-----_010_-----_1110011
+valid    i           csrrc           ------------_-----_011_-----_1110011
+valid    i           csrrwi          ------------_-----_101_-----_1110011
+valid    i           csrrsi          ------------_-----_110_-----_1110011
+valid    i           csrrci          ------------_-----_111_-----_1110011
+
+# Privileged instructions, with Svinval and Smrnmi
+valid    i           sret            0001000_00010_00000_000_00000_1110011
+valid    i           mret            0011000_00010_00000_000_00000_1110011
+valid    i           wfi             0001000_00101_00000_000_00000_1110011
+valid    i           sfence.vma      0001001_-----_-----_000_00000_1110011
+valid    svinval     sinval.vma      0001011_-----_-----_000_00000_1110011
+valid    svinval     sfence.w.inval  0001100_00000_00000_000_00000_1110011
+valid    svinval     sfence.inval.ir 0001100_00001_00000_000_00000_1110011
+valid    smrnmi      mnret           0111000_00010_00000_000_00000_1110011
+
+# H
+valid    h           hfence.vvma     0010001_-----_-----_000_00000_1110011
+valid    h           hfence.gvma     0110001_-----_-----_000_00000_1110011
+valid    h+svinval   hinval.vvma     0010011_-----_-----_000_00000_1110011
+valid    h+svinval   hinval.gvma     0110011_-----_-----_000_00000_1110011
+valid    h           hlv.b           0110000_00000_-----_100_-----_1110011
+valid    h           hlv.bu          0110000_00001_-----_100_-----_1110011
+valid    h           hlv.h           0110010_00000_-----_100_-----_1110011
+valid    h           hlv.hu          0110010_00001_-----_100_-----_1110011
+valid    h           hlvx.hu         0110010_00011_-----_100_-----_1110011
+valid    h           hlv.w           0110100_00000_-----_100_-----_1110011
+valid    h           hlvx.wu         0110100_00011_-----_100_-----_1110011
+valid    h           hsv.b           0110001_-----_-----_100_00000_1110011
+valid    h           hsv.h           0110011_-----_-----_100_00000_1110011
+valid    h           hsv.w           0110101_-----_-----_100_00000_1110011
+
+# Zimop, Zawrs and Zicond
+valid    zimop       mop.r           1-00--0111--_-----_100_-----_1110011
+valid    zimop       mop.rr          1-00--1-----_-----_100_-----_1110011
+valid    zawrs       wrs.nto         000000001101_00000_000_00000_1110011
+valid    zawrs       wrs.sto         000000011101_00000_000_00000_1110011
+valid    zicond      czero.eqz       0000111_-----_-----_101_-----_0110011
+valid    zicond      czero.nez       0000111_-----_-----_111_-----_0110011
+
+# Zicbom and Zicboz
+valid    zicbom      cbo.inval       000000000000_-----_010_00000_0001111
+valid    zicbom      cbo.clean       000000000001_-----_010_00000_0001111
+valid    zicbom      cbo.flush       000000000010_-----_010_00000_0001111
+valid    zicboz      cbo.zero        000000000100_-----_010_00000_0001111
+
+# Zalrsc, Zaamo, Zabha and Zacas
+valid    zalrsc      lr.w            00010--_00000_-----_010_-----_0101111
+valid    zalrsc      sc.w            00011--_-----_-----_010_-----_0101111
+valid    zacas       amocas.w        00101--_-----_-----_010_-----_0101111
+valid    zacas       amocas.d        00101--_----0_-----_011_----0_0101111
+valid    zacas+zabha amocas.b        00101--_-----_-----_000_-----_0101111
+valid    zacas+zabha amocas.h        00101--_-----_-----_001_-----_0101111
+valid    zaamo       amoswap.w       00001--_-----_-----_010_-----_0101111
+valid    zaamo       amoadd.w        00000--_-----_-----_010_-----_0101111
+valid    zaamo       amoxor.w        00100--_-----_-----_010_-----_0101111
+valid    zaamo       amoand.w        01100--_-----_-----_010_-----_0101111
+valid    zaamo       amoor.w         01000--_-----_-----_010_-----_0101111
+valid    zaamo       amomin.w        10000--_-----_-----_010_-----_0101111
+valid    zaamo       amomax.w        10100--_-----_-----_010_-----_0101111
+valid    zaamo       amominu.w       11000--_-----_-----_010_-----_0101111
+valid    zaamo       amomaxu.w       11100--_-----_-----_010_-----_0101111
+valid    zaamo+zabha amoswap.b       00001--_-----_-----_000_-----_0101111
+valid    zaamo+zabha amoadd.b        00000--_-----_-----_000_-----_0101111
+valid    zaamo+zabha amoxor.b        00100--_-----_-----_000_-----_0101111
+valid    zaamo+zabha amoand.b        01100--_-----_-----_000_-----_0101111
+valid    zaamo+zabha amoor.b         01000--_-----_-----_000_-----_0101111
+valid    zaamo+zabha amomin.b        10000--_-----_-----_000_-----_0101111
+valid    zaamo+zabha amomax.b        10100--_-----_-----_000_-----_0101111
+valid    zaamo+zabha amominu.b       11000--_-----_-----_000_-----_0101111
+valid    zaamo+zabha amomaxu.b       11100--_-----_-----_000_-----_0101111
+valid    zaamo+zabha amoswap.h       00001--_-----_-----_001_-----_0101111
+valid    zaamo+zabha amoadd.h        00000--_-----_-----_001_-----_0101111
+valid    zaamo+zabha amoxor.h        00100--_-----_-----_001_-----_0101111
+valid    zaamo+zabha amoand.h        01100--_-----_-----_001_-----_0101111
+valid    zaamo+zabha amoor.h         01000--_-----_-----_001_-----_0101111
+valid    zaamo+zabha amomin.h        10000--_-----_-----_001_-----_0101111
+valid    zaamo+zabha amomax.h        10100--_-----_-----_001_-----_0101111
+valid    zaamo+zabha amominu.h       11000--_-----_-----_001_-----_0101111
+valid    zaamo+zabha amomaxu.h       11100--_-----_-----_001_-----_0101111
+
+# Zfinx, Zdinx and Zhinx, whose rounding modes 101 and 110 are reserved. Double precision
+# operands are even/odd register pairs on RV32, named by their even register
+reserved zfinx       op-fp           -------_-----_-----_101_-----_1010011
+reserved zfinx       op-fp           -------_-----_-----_110_-----_1010011
+reserved zfinx       madd            -----_--_-----_-----_101_-----_100--11
+reserved zfinx       madd            -----_--_-----_-----_110_-----_100--11
+valid    zfinx       fadd.s          0000000_-----_-----_---_-----_1010011
+valid    zfinx       fsub.s          0000100_-----_-----_---_-----_1010011
+valid    zfinx       fmul.s          0001000_-----_-----_---_-----_1010011
+valid    zfinx       fdiv.s          0001100_-----_-----_---_-----_1010011
+valid    zfinx       fsqrt.s         0101100_00000_-----_---_-----_1010011
+valid    zfinx       fsgnj.s         0010000_-----_-----_000_-----_1010011
+valid    zfinx       fsgnjn.s        0010000_-----_-----_001_-----_1010011
+valid    zfinx       fsgnjx.s        0010000_-----_-----_010_-----_1010011
+valid    zfinx       fmin.s          0010100_-----_-----_000_-----_1010011
+valid    zfinx       fmax.s          0010100_-----_-----_001_-----_1010011
+valid    zfinx       feq.s           1010000_-----_-----_010_-----_1010011
+valid    zfinx       flt.s           1010000_-----_-----_001_-----_1010011
+valid    zfinx       fle.s           1010000_-----_-----_000_-----_1010011
+valid    zfinx       fclass.s        1110000_00000_-----_001_-----_1010011
+valid    zfinx       fcvt.w.s        1100000_00000_-----_---_-----_1010011
+valid    zfinx       fcvt.wu.s       1100000_00001_-----_---_-----_1010011
+valid    zfinx       fcvt.s.w        1101000_00000_-----_---_-----_1010011
+valid    zfinx       fcvt.s.wu       1101000_00001_-----_---_-----_1010011
+valid    zfinx       fmadd.s         -----_00_-----_-----_---_-----_1000011
+valid    zfinx       fmsub.s         -----_00_-----_-----_---_-----_1000111
+valid    zfinx       fnmsub.s        -----_00_-----_-----_---_-----_1001011
+valid    zfinx       fnmadd.s        -----_00_-----_-----_---_-----_1001111
+valid    zdinx       fadd.d          0000001_----0_----0_---_----0_1010011
+valid    zdinx       fsub.d          0000101_----0_----0_---_----0_1010011
+valid    zdinx       fmul.d          0001001_----0_----0_---_----0_1010011
+valid    zdinx       fdiv.d          0001101_----0_----0_---_----0_1010011
+valid    zdinx       fsqrt.d         0101101_00000_----0_---_----0_1010011
+valid    zdinx       fsgnj.d         0010001_----0_----0_000_----0_1010011
+valid    zdinx       fsgnjn.d        0010001_----0_----0_001_----0_1010011
+valid    zdinx       fsgnjx.d        0010001_----0_----0_010_----0_1010011
+valid    zdinx       fmin.d          0010101_----0_----0_000_----0_1010011
+valid    zdinx       fmax.d          0010101_----0_----0_001_----0_1010011
+valid    zdinx       feq.d           1010001_----0_----0_010_-----_1010011
+valid    zdinx       flt.d           1010001_----0_----0_001_-----_1010011
+valid    zdinx       fle.d           1010001_----0_----0_000_-----_1010011
+valid    zdinx       fclass.d        1110001_00000_----0_001_-----_1010011
+valid    zdinx       fcvt.w.d        1100001_00000_----0_---_-----_1010011
+valid    zdinx       fcvt.wu.d       1100001_00001_----0_---_-----_1010011
+valid    zdinx       fcvt.d.w        1101001_00000_-----_---_----0_1010011
+valid    zdinx       fcvt.d.wu       1101001_00001_-----_---_----0_1010011
+valid    zdinx       fmadd.d         ----0_01_----0_----0_---_----0_1000011
+valid    zdinx       fmsub.d         ----0_01_----0_----0_---_----0_1000111
+valid    zdinx       fnmsub.d        ----0_01_----0_----0_---_----0_1001011
+valid    zdinx       fnmadd.d        ----0_01_----0_----0_---_----0_1001111
+valid    zhinx       fadd.h          0000010_-----_-----_---_-----_1010011
+valid    zhinx       fsub.h          0000110_-----_-----_---_-----_1010011
+valid    zhinx       fmul.h          0001010_-----_-----_---_-----_1010011
+valid    zhinx       fdiv.h          0001110_-----_-----_---_-----_1010011
+valid    zhinx       fsqrt.h         0101110_00000_-----_---_-----_1010011
+valid    zhinx       fsgnj.h         0010010_-----_-----_000_-----_1010011
+valid    zhinx       fsgnjn.h        0010010_-----_-----_001_-----_1010011
+valid    zhinx       fsgnjx.h        0010010_-----_-----_010_-----_1010011
+valid    zhinx       fmin.h          0010110_-----_-----_000_-----_1010011
+valid    zhinx       fmax.h          0010110_-----_-----_001_-----_1010011
+valid    zhinx       feq.h           1010010_-----_-----_010_-----_1010011
+valid    zhinx       flt.h           1010010_-----_-----_001_-----_1010011
+valid    zhinx       fle.h           1010010_-----_-----_000_-----_1010011
+valid    zhinx       fclass.h        1110010_00000_-----_001_-----_1010011
+valid    zhinx       fcvt.w.h        1100010_00000_-----_---_-----_1010011
+valid    zhinx       fcvt.wu.h       1100010_00001_-----_---_-----_1010011
+valid    zhinx       fcvt.h.w        1101010_00000_-----_---_-----_1010011
+valid    zhinx       fcvt.h.wu       1101010_00001_-----_---_-----_1010011
+valid    zhinx       fmadd.h         -----_10_-----_-----_---_-----_1000011
+valid    zhinx       fmsub.h         -----_10_-----_-----_---_-----_1000111
+valid    zhinx       fnmsub.h        -----_10_-----_-----_---_-----_1001011
+valid    zhinx       fnmadd.h        -----_10_-----_-----_---_-----_1001111
+valid    zdinx       fcvt.s.d        0100000_00001_----0_---_-----_1010011
+valid    zdinx       fcvt.d.s        0100001_00000_-----_---_----0_1010011
+valid    zhinx       fcvt.s.h        0100000_00010_-----_---_-----_1010011
+valid    zhinx       fcvt.h.s        0100010_00000_-----_---_-----_1010011
+valid    zdinx+zhinx fcvt.d.h        0100001_00010_-----_---_----0_1010011
+valid    zdinx+zhinx fcvt.h.d        0100010_00001_----0_---_-----_1010011
+
+# Zfbfmin, encoded as fcvt.h.s and fcvt.s.h with the source formats 01000 and 00110
+reserved zfbfmin     op-fp           -------_-----_-----_101_-----_1010011
+reserved zfbfmin     op-fp           -------_-----_-----_110_-----_1010011
+valid    zfbfmin     fcvt.bf16.s     0100010_01000_-----_---_-----_1010011
+valid    zfbfmin     fcvt.s.bf16     0100000_00110_-----_---_-----_1010011
+
+# Zvbb, Zvbc, Zvfbfmin and Zvfbfwma with LMUL 1, where masked instructions must not write v0
+# and the destination of widening instructions and the source of narrowing ones are pairs
+reserved zvbb        op-v            ------0_-----_-----_---_00000_1010111
+reserved zvbc        op-v            ------0_-----_-----_---_00000_1010111
+reserved zvfbfmin    op-v            ------0_-----_-----_---_00000_1010111
+reserved zvfbfwma    op-v            ------0_-----_-----_---_00000_1010111
+valid    zvbb        vandn.vv        000001-_-----_-----_000_-----_1010111
+valid    zvbb        vandn.vx        000001-_-----_-----_100_-----_1010111
+valid    zvbb        vrol.vv         010101-_-----_-----_000_-----_1010111
+valid    zvbb        vrol.vx         010101-_-----_-----_100_-----_1010111
+valid    zvbb        vror.vv         010100-_-----_-----_000_-----_1010111
+valid    zvbb        vror.vx         010100-_-----_-----_100_-----_1010111
+valid    zvbb        vror.vi         01010--_-----_-----_011_-----_1010111
+valid    zvbb        vwsll.vv        110101-_-----_-----_000_----0_1010111
+valid    zvbb        vwsll.vx        110101-_-----_-----_100_----0_1010111
+valid    zvbb        vwsll.vi        110101-_-----_-----_011_----0_1010111
+valid    zvbb        vbrev8.v        010010-_-----_01000_010_-----_1010111
+valid    zvbb        vrev8.v         010010-_-----_01001_010_-----_1010111
+valid    zvbb        vbrev.v         010010-_-----_01010_010_-----_1010111
+valid    zvbb        vclz.v          010010-_-----_01100_010_-----_1010111
+valid    zvbb        vctz.v          010010-_-----_01101_010_-----_1010111
+valid    zvbb        vcpop.v         010010-_-----_01110_010_-----_1010111
+valid    zvbc        vclmul.vv       001100-_-----_-----_010_-----_1010111
+valid    zvbc        vclmul.vx       001100-_-----_-----_110_-----_1010111
+valid    zvbc        vclmulh.vv      001101-_-----_-----_010_-----_1010111
+valid    zvbc        vclmulh.vx      001101-_-----_-----_110_-----_1010111
+valid    zvfbfmin    vfncvtbf16.f.f.w 010010-_----0_11101_001_-----_1010111
+valid    zvfbfmin    vfwcvtbf16.f.f.v 010010-_-----_01101_001_----0_1010111
+valid    zvfbfwma    vfwmaccbf16.vv  111011-_-----_-----_001_----0_1010111
+valid    zvfbfwma    vfwmaccbf16.vf  111011-_-----_-----_101_----0_1010111
+
+# Vector cryptography with LMUL 1 and SEW 32, which cannot be masked. The .vs forms, SHA-2 and
+# SM3 must not overlap their destination with the vector sources listed in the last column
+valid    zvkned      vaesdm.vv       1010001_-----_00000_010_-----_1010111
+valid    zvkned      vaesdm.vs       1010011_-----_00000_010_-----_1010111 vd!=vs2
+valid    zvkned      vaesdf.vv       1010001_-----_00001_010_-----_1010111
+valid    zvkned      vaesdf.vs       1010011_-----_00001_010_-----_1010111 vd!=vs2
+valid    zvkned      vaesem.vv       1010001_-----_00010_010_-----_1010111
+valid    zvkned      vaesem.vs       1010011_-----_00010_010_-----_1010111 vd!=vs2
+valid    zvkned      vaesef.vv       1010001_-----_00011_010_-----_1010111
+valid    zvkned      vaesef.vs       1010011_-----_00011_010_-----_1010111 vd!=vs2
+valid    zvkned      vaesz.vs        1010011_-----_00111_010_-----_1010111 vd!=vs2
+valid    zvkned      vaeskf1.vi      1000101_-----_-----_010_-----_1010111
+valid    zvkned      vaeskf2.vi      1010101_-----_-----_010_-----_1010111
+valid    zvknha      vsha2ms.vv      1011011_-----_-----_010_-----_1010111 vd!=vs1,vd!=vs2
+valid    zvknha      vsha2ch.vv      1011101_-----_-----_010_-----_1010111 vd!=vs1,vd!=vs2
+valid    zvknha      vsha2cl.vv      1011111_-----_-----_010_-----_1010111 vd!=vs1,vd!=vs2
+valid    zvknhb      vsha2ms.vv      1011011_-----_-----_010_-----_1010111 vd!=vs1,vd!=vs2
+valid    zvknhb      vsha2ch.vv      1011101_-----_-----_010_-----_1010111 vd!=vs1,vd!=vs2
+valid    zvknhb      vsha2cl.vv      1011111_-----_-----_010_-----_1010111 vd!=vs1,vd!=vs2
+valid    zvksed      vsm4k.vi        1000011_-----_-----_010_-----_1010111
+valid    zvksed      vsm4r.vv        1010001_-----_10000_010_-----_1010111
+valid    zvksed      vsm4r.vs        1010011_-----_10000_010_-----_1010111 vd!=vs2
+valid    zvksh       vsm3me.vv       1000001_-----_-----_010_-----_1010111 vd!=vs2
+valid    zvksh       vsm3c.vi        1010111_-----_-----_010_-----_1010111 vd!=vs2
+valid    zvkg        vghsh.vv        1011001_-----_-----_010_-----_1010111
+valid    zvkg        vgmul.vv        1010001_-----_10001_010_-----_1010111
@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::{
+        parse, parse_with_custom_decoders, CustomDecoder, CustomDecoders, CustomInstruction,
+        DisassemblerError, Extensions, MemoryAccess, ParsedInstruction32, PrivilegeLevel,
+    };
+    use std::any::Any;
+    use std::fmt;
+
+    /// Load with a register offset, `ldx rd, rs2(rs1)`, on custom-0.
+    #[derive(Debug, PartialEq)]
+    struct Ldx {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    }
+
+    impl fmt::Display for Ldx {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "ldx {}, {}({})", self.rd, self.rs2, self.rs1)
+        }
+    }
+
+    impl CustomInstruction for Ldx {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn eq_custom(&self, other: &dyn CustomInstruction) -> bool {
+            other.as_any().downcast_ref::<Self>() == Some(self)
+        }
+
+        fn memory_access(&self) -> Option<MemoryAccess> {
+            Some(MemoryAccess::Load { size: 4 })
+        }
+    }
+
+    struct LdxDecoder;
+
+    impl CustomDecoder for LdxDecoder {
+        fn decode(
+            &self,
+            instruction: u32,
+            register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+            _float_register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+        ) -> Result<Box<dyn CustomInstruction>, DisassemblerError> {
+            let funct3 = (instruction >> 12 & 0b111) as u8;
+            if funct3 != 0b010 {
+                return Err(DisassemblerError::InvalidFunct3(funct3));
+            }
+            Ok(Box::new(Ldx {
+                rd: register_name((instruction >> 7 & 0x1f) as u8)?,
+                rs1: register_name((instruction >> 15 & 0x1f) as u8)?,
+                rs2: register_name((instruction >> 20 & 0x1f) as u8)?,
+            }))
+        }
+    }
+
+    fn decoders() -> CustomDecoders {
+        let mut decoders = CustomDecoders::new();
+        decoders.register(0b0001011, LdxDecoder).unwrap();
+        decoders
+    }
+
+    #[test]
+    fn test_custom_instruction() {
+        let decoders = decoders();
+
+        // ldx a0, a2(a1)
+        let result = parse_with_custom_decoders(
+            &0x00C5A50Bu32.to_le_bytes(),
+            false,
+            true,
+            &Extensions::default(),
+            &decoders,
+        )
+        .unwrap();
+        assert_eq!(result.to_string(), "ldx a0, a2(a1)");
+        assert_eq!(result.memory_access(), Some(MemoryAccess::Load { size: 4 }));
+        assert_eq!(result.privilege_level(), PrivilegeLevel::User);
+        assert_eq!(result.float_format(), None);
+
+        let same = parse_with_custom_decoders(
+            &0x00C5A50Bu32.to_be_bytes(),
+            true,
+            true,
+            &Extensions::default(),
+            &decoders,
+        )
+        .unwrap();
+        assert_eq!(result, same);
+        assert!(matches!(result, ParsedInstruction32::custom(_)));
+    }
+
+    #[test]
+    fn test_custom_numbered_registers() {
+        let result = parse_with_custom_decoders(
+            &0x00C5A50Bu32.to_le_bytes(),
+            false,
+            false,
+            &Extensions::default(),
+            &decoders(),
+        );
+        assert_eq!(result.unwrap().to_string(), "ldx x10, x12(x11)");
+    }
+
+    #[test]
+    fn test_custom_decoder_error() {
+        let result = parse_with_custom_decoders(
+            &0x00C5850Bu32.to_le_bytes(),
+            false,
+            true,
+            &Extensions::default(),
+            &decoders(),
+        );
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct3(0b000)));
+    }
+
+    #[test]
+    fn test_custom_opcode_without_decoder() {
+        let result = parse(&0x00C5A50Bu32.to_le_bytes(), false, true);
+        assert_eq!(result, Err(DisassemblerError::InvalidOpcode(0b0001011)));
+
+        let result = parse_with_custom_decoders(
+            &0x00C5A52Bu32.to_le_bytes(),
+            false,
+            true,
+            &Extensions::default(),
+            &decoders(),
+        );
+        assert_eq!(result, Err(DisassemblerError::InvalidOpcode(0b0101011)));
+    }
+}
@@ -0,0 +1,463 @@
+//! Decode coverage checker: classifies 32 bit words against the machine-readable table in
+//! `tests/coverage/rv32.spec` and reports every word on which `parse_with_extensions` disagrees.
+//!
+//! The default tests check a deterministic sample of the encoding space, together with the
+//! neighbours of every table row, which catches ignored fixed bits such as a `funct7` field that
+//! is not checked. The full 2^32 sweep is ignored by default, and is run with
+//!
+//! ```text
+//! cargo test --release --test decode_coverage -- --ignored
+//! ```
+
+use risc_v_disassembler::{parse_with_extensions, Extensions, ParsedInstruction32};
+use std::fmt;
+use std::thread;
+
+const SPEC: &str = include_str!("coverage/rv32.spec");
+
+/// Number of disagreements printed for each configuration.
+const REPORTED_DISAGREEMENTS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Class {
+    Valid,
+    Hint,
+    Reserved,
+    Illegal,
+}
+
+/// Vector register field, given by the offset of its lowest bit.
+#[derive(Debug, Clone, Copy)]
+enum Field {
+    Vd = 7,
+    Vs1 = 15,
+    Vs2 = 20,
+}
+
+impl Field {
+    fn parse(name: &str, line: &str) -> Self {
+        match name {
+            "vd" => Field::Vd,
+            "vs1" => Field::Vs1,
+            "vs2" => Field::Vs2,
+            other => panic!("unknown field {} in spec row: {}", other, line),
+        }
+    }
+
+    fn extract(self, word: u32) -> u32 {
+        word >> self as u32 & 0x1f
+    }
+}
+
+#[derive(Debug)]
+struct Row {
+    class: Class,
+    extensions: Vec<String>,
+    name: String,
+    mask: u32,
+    value: u32,
+    /// Pairs of fields that must hold different registers.
+    distinct: Vec<(Field, Field)>,
+}
+
+impl Row {
+    fn matches(&self, word: u32) -> bool {
+        word & self.mask == self.value
+            && self
+                .distinct
+                .iter()
+                .all(|(first, second)| first.extract(word) != second.extract(word))
+    }
+}
+
+fn load_spec() -> Vec<Row> {
+    SPEC.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            assert!(
+                fields.len() == 4 || fields.len() == 5,
+                "malformed spec row: {}",
+                line
+            );
+
+            let class = match fields[0] {
+                "valid" => Class::Valid,
+                "hint" => Class::Hint,
+                "reserved" => Class::Reserved,
+                other => panic!("unknown class {} in spec row: {}", other, line),
+            };
+
+            let bits: Vec<char> = fields[3].chars().filter(|bit| *bit != '_').collect();
+            assert_eq!(bits.len(), 32, "encoding is not 32 bits wide: {}", line);
+            let (mut mask, mut value) = (0, 0);
+            for bit in bits {
+                mask <<= 1;
+                value <<= 1;
+                match bit {
+                    '0' => mask |= 1,
+                    '1' => {
+                        mask |= 1;
+                        value |= 1;
+                    }
+                    '-' => {}
+                    other => panic!("unknown bit {} in spec row: {}", other, line),
+                }
+            }
+
+            let distinct = fields
+                .get(4)
+                .map(|constraints| {
+                    constraints
+                        .split(',')
+                        .map(|constraint| {
+                            let (first, second) = constraint
+                                .split_once("!=")
+                                .unwrap_or_else(|| panic!("unknown constraint in: {}", line));
+                            (Field::parse(first, line), Field::parse(second, line))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            Row {
+                class,
+                extensions: fields[1].split('+').map(str::to_string).collect(),
+                name: fields[2].to_string(),
+                mask,
+                value,
+                distinct,
+            }
+        })
+        .collect()
+}
+
+fn has_extension(extensions: &Extensions, name: &str) -> bool {
+    match name {
+        "i" => true,
+        "svinval" => extensions.svinval,
+        "smrnmi" => extensions.smrnmi,
+        "h" => extensions.h,
+        "zicbom" => extensions.zicbom,
+        "zicboz" => extensions.zicboz,
+        "zicbop" => extensions.zicbop,
+        "zicond" => extensions.zicond,
+        "zawrs" => extensions.zawrs,
+        "zalrsc" => extensions.zalrsc,
+        "zaamo" => extensions.zaamo,
+        "zabha" => extensions.zabha,
+        "zacas" => extensions.zacas,
+        "zimop" => extensions.zimop,
+        "zfinx" => extensions.zfinx,
+        "zdinx" => extensions.zdinx,
+        "zhinx" => extensions.zhinx,
+        "zfbfmin" => extensions.zfbfmin,
+        "zvfbfmin" => extensions.zvfbfmin,
+        "zvfbfwma" => extensions.zvfbfwma,
+        "zvbb" => extensions.zvbb,
+        "zvbc" => extensions.zvbc,
+        "zvkned" => extensions.zvkned,
+        "zvknha" => extensions.zvknha,
+        "zvknhb" => extensions.zvknhb,
+        "zvksed" => extensions.zvksed,
+        "zvksh" => extensions.zvksh,
+        "zvkg" => extensions.zvkg,
+        other => panic!("unknown extension {} in spec", other),
+    }
+}
+
+/// Spec table restricted to the rows whose extensions are enabled in one configuration.
+struct Isa<'a> {
+    name: &'static str,
+    extensions: Extensions,
+    rows: Vec<&'a Row>,
+}
+
+impl<'a> Isa<'a> {
+    fn new(name: &'static str, extensions: Extensions, spec: &'a [Row]) -> Self {
+        let rows = spec
+            .iter()
+            .filter(|row| {
+                row.extensions
+                    .iter()
+                    .all(|extension| has_extension(&extensions, extension))
+            })
+            .collect();
+        Isa {
+            name,
+            extensions,
+            rows,
+        }
+    }
+
+    fn classify(&self, word: u32) -> Option<&'a Row> {
+        self.rows.iter().copied().find(|row| row.matches(word))
+    }
+
+    /// Returns a description of the disagreement between the spec table and the decoder for
+    /// `word`, if there is one.
+    fn check(&self, word: u32) -> Option<Disagreement> {
+        let row = self.classify(word);
+        let class = row.map_or(Class::Illegal, |row| row.class);
+        let result = parse_with_extensions(&word.to_le_bytes(), false, false, &self.extensions);
+
+        let agrees = match (&result, row) {
+            (Ok(instruction), Some(row)) if row.class == Class::Valid => {
+                has_mnemonic(instruction, &row.name)
+            }
+            (Ok(instruction), Some(row)) if row.class == Class::Hint => {
+                let classified = matches!(instruction, ParsedInstruction32::hint(_));
+                has_mnemonic(instruction, &row.name) && classified == self.extensions.hints
+            }
+            (Ok(_), _) => false,
+            (Err(_), _) => matches!(class, Class::Reserved | Class::Illegal),
+        };
+        if agrees {
+            return None;
+        }
+
+        Some(Disagreement {
+            word,
+            class,
+            name: row.map(|row| row.name.clone()),
+            decoded: match result {
+                Ok(instruction) => format!("{}", instruction),
+                Err(error) => format!("{:?}", error),
+            },
+        })
+    }
+}
+
+/// Returns whether the instruction is displayed with the mnemonic `name`, allowing for suffixes
+/// such as the memory ordering of atomics or the number of a may-be-operation.
+fn has_mnemonic(instruction: &ParsedInstruction32, name: &str) -> bool {
+    let text = instruction.to_string();
+    let mnemonic = text.split_whitespace().next().unwrap_or_default();
+    mnemonic == name
+        || mnemonic
+            .strip_prefix(name)
+            .is_some_and(|suffix| suffix.starts_with('.'))
+}
+
+struct Disagreement {
+    word: u32,
+    class: Class,
+    name: Option<String>,
+    decoded: String,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#010x}: expected {:?}", self.word, self.class)?;
+        if let Some(name) = &self.name {
+            write!(f, " {}", name)?;
+        }
+        write!(f, ", decoded {}", self.decoded)
+    }
+}
+
+fn configurations(spec: &[Row]) -> Vec<Isa<'_>> {
+    let float = Extensions {
+        zfinx: true,
+        zdinx: true,
+        zhinx: true,
+        ..Default::default()
+    };
+    let bf16 = Extensions {
+        zfbfmin: true,
+        zvfbfmin: true,
+        zvfbfwma: true,
+        ..Default::default()
+    };
+    let vector_crypto = Extensions {
+        zvbb: true,
+        zvbc: true,
+        zvkned: true,
+        zvknha: true,
+        zvksed: true,
+        zvksh: true,
+        zvkg: true,
+        ..Default::default()
+    };
+    let all = Extensions {
+        svinval: true,
+        smrnmi: true,
+        h: true,
+        zicbom: true,
+        zicboz: true,
+        zicbop: true,
+        zicond: true,
+        zawrs: true,
+        zalrsc: true,
+        zaamo: true,
+        zabha: true,
+        zacas: true,
+        zimop: true,
+        zfinx: true,
+        zdinx: true,
+        zhinx: true,
+        zfbfmin: true,
+        zvfbfmin: true,
+        zvfbfwma: true,
+        zvknhb: true,
+        ..vector_crypto
+    };
+
+    vec![
+        Isa::new("base", Extensions::default(), spec),
+        Isa::new(
+            "base with hints",
+            Extensions {
+                hints: true,
+                ..Default::default()
+            },
+            spec,
+        ),
+        Isa::new(
+            "atomics without zabha",
+            Extensions {
+                zalrsc: true,
+                zaamo: true,
+                zacas: true,
+                ..Default::default()
+            },
+            spec,
+        ),
+        Isa::new(
+            "zfinx",
+            Extensions {
+                zfinx: true,
+                ..Default::default()
+            },
+            spec,
+        ),
+        Isa::new("zfinx, zdinx and zhinx", float, spec),
+        Isa::new("bf16", bf16, spec),
+        Isa::new(
+            "bf16 with zfinx and zhinx",
+            Extensions {
+                zfinx: true,
+                zhinx: true,
+                ..bf16
+            },
+            spec,
+        ),
+        Isa::new("vector crypto", vector_crypto, spec),
+        Isa::new(
+            "zvknhb",
+            Extensions {
+                zvknhb: true,
+                ..Default::default()
+            },
+            spec,
+        ),
+        Isa::new("all", all, spec),
+        Isa::new("all with hints", Extensions { hints: true, ..all }, spec),
+    ]
+}
+
+/// Checks `words` against every configuration and panics with a report of the disagreements.
+fn check_words(words: &[u32]) {
+    let spec = load_spec();
+    let mut report = String::new();
+
+    for isa in configurations(&spec) {
+        let disagreements: Vec<Disagreement> =
+            words.iter().filter_map(|word| isa.check(*word)).collect();
+        if disagreements.is_empty() {
+            continue;
+        }
+
+        report += &format!("{}: {} disagreements\n", isa.name, disagreements.len());
+        for disagreement in disagreements.iter().take(REPORTED_DISAGREEMENTS) {
+            report += &format!("  {}\n", disagreement);
+        }
+    }
+
+    assert!(
+        report.is_empty(),
+        "decode coverage disagreements:\n{}",
+        report
+    );
+}
+
+/// Deterministic xorshift generator, so that failures can be reproduced.
+struct XorShift(u32);
+
+impl XorShift {
+    fn next(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+}
+
+#[test]
+fn test_spec_rows_are_reachable() {
+    let spec = load_spec();
+    for (index, row) in spec.iter().enumerate() {
+        let shadowed = spec[..index].iter().any(|earlier| {
+            earlier.extensions.iter().all(|name| name == "i")
+                && earlier.mask & row.mask == earlier.mask
+                && row.value & earlier.mask == earlier.value
+        });
+        assert!(!shadowed, "spec row {} is shadowed by a base row", row.name);
+    }
+}
+
+#[test]
+fn test_decode_coverage_sampled() {
+    let mut random = XorShift(0x2545_f491);
+    let words: Vec<u32> = (0..1 << 18).map(|_| random.next() | 0b11).collect();
+    check_words(&words);
+}
+
+#[test]
+fn test_decode_coverage_spec_neighbours() {
+    let spec = load_spec();
+    let mut random = XorShift(0x9e37_79b9);
+    let mut words = Vec::new();
+    for row in &spec {
+        for _ in 0..64 {
+            let word = row.value | random.next() & !row.mask;
+            words.push(word);
+            words.extend(
+                (0..32)
+                    .filter(|bit| row.mask & 1 << bit != 0)
+                    .map(|bit| word ^ 1 << bit),
+            );
+        }
+    }
+    check_words(&words);
+}
+
+#[test]
+#[ignore = "sweeps all 2^32 encodings, run in release mode"]
+fn test_decode_coverage_exhaustive() {
+    let threads = thread::available_parallelism().map_or(1, |threads| threads.get()) as u64;
+    let chunk = (1u64 << 32).div_ceil(threads);
+
+    let words: Vec<u32> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|thread| {
+                scope.spawn(move || {
+                    let spec = load_spec();
+                    let isas = configurations(&spec);
+                    let start = thread * chunk;
+                    let end = (start + chunk).min(1 << 32);
+                    (start..end)
+                        .map(|word| word as u32)
+                        .filter(|word| isas.iter().any(|isa| isa.check(*word).is_some()))
+                        .collect::<Vec<u32>>()
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+    check_words(&words);
+}
@@ -0,0 +1,284 @@
+//! Builds small RISC-V ELF files for the ELF tests, with one `PT_LOAD` segment per allocated
+//! section and the section name table as the last section.
+
+use risc_v_disassembler::elf::{
+    EM_RISCV, ET_EXEC, PT_LOAD, SHF_ALLOC, SHF_EXECINSTR, SHT_PROGBITS, SHT_RISCV_ATTRIBUTES,
+    SHT_STRTAB, SHT_SYMTAB,
+};
+
+pub struct Section {
+    pub name: &'static str,
+    pub section_type: u32,
+    pub flags: u64,
+    pub address: u64,
+    pub data: Vec<u8>,
+    /// Index of a related section, where the first section added has index 1.
+    pub link: u32,
+    pub info: u32,
+    pub entry_size: u64,
+}
+
+impl Section {
+    pub fn new(name: &'static str, section_type: u32, flags: u64, address: u64) -> Self {
+        Section {
+            name,
+            section_type,
+            flags,
+            address,
+            data: Vec::new(),
+            link: 0,
+            info: 0,
+            entry_size: 0,
+        }
+    }
+
+    pub fn text(name: &'static str, address: u64, data: &[u8]) -> Self {
+        Section {
+            data: data.to_vec(),
+            ..Section::new(name, SHT_PROGBITS, SHF_ALLOC | SHF_EXECINSTR, address)
+        }
+    }
+
+    /// Returns a `.riscv.attributes` section holding `Tag_RISCV_arch`.
+    pub fn attributes(arch: &str, big_endian: bool) -> Self {
+        let mut file = vec![1, 0, 0, 0, 0, 5];
+        file.extend(arch.as_bytes());
+        file.push(0);
+        let file_size = file.len() as u32;
+        file[1..5].copy_from_slice(&word32(file_size, big_endian));
+
+        let mut data = vec![b'A', 0, 0, 0, 0];
+        data.extend(b"riscv\0");
+        data.extend(file);
+        let subsection_size = data.len() as u32 - 1;
+        data[1..5].copy_from_slice(&word32(subsection_size, big_endian));
+
+        Section {
+            data,
+            ..Section::new(".riscv.attributes", SHT_RISCV_ATTRIBUTES, 0, 0)
+        }
+    }
+}
+
+/// An entry of the symbol table added by [`ElfBuilder::symbols`].
+pub struct SymbolEntry {
+    pub name: &'static str,
+    pub value: u64,
+    pub size: u64,
+    /// `st_info`, holding the binding in the high bits and the type in the low bits.
+    pub info: u8,
+    pub section_index: u16,
+}
+
+pub struct ElfBuilder {
+    pub elf64: bool,
+    pub big_endian: bool,
+    pub file_type: u16,
+    pub flags: u32,
+    pub entry: u64,
+    pub sections: Vec<Section>,
+}
+
+impl ElfBuilder {
+    pub fn new(elf64: bool, big_endian: bool, flags: u32) -> Self {
+        ElfBuilder {
+            elf64,
+            big_endian,
+            file_type: ET_EXEC,
+            flags,
+            entry: 0,
+            sections: Vec::new(),
+        }
+    }
+
+    pub fn section(mut self, section: Section) -> Self {
+        self.sections.push(section);
+        self
+    }
+
+    /// Adds a `.symtab` section holding the null symbol and `symbols`, followed by its `.strtab`.
+    pub fn symbols(mut self, symbols: &[SymbolEntry]) -> Self {
+        let mut names = vec![0u8];
+        let mut data = vec![0; if self.elf64 { 24 } else { 16 }];
+        for symbol in symbols {
+            let name = names.len() as u32;
+            names.extend(symbol.name.as_bytes());
+            names.push(0);
+
+            let mut entry = Vec::new();
+            self.word(&mut entry, name);
+            if self.elf64 {
+                entry.extend([symbol.info, 0]);
+                self.half(&mut entry, symbol.section_index);
+                self.address(&mut entry, symbol.value);
+                self.address(&mut entry, symbol.size);
+            } else {
+                self.address(&mut entry, symbol.value);
+                self.address(&mut entry, symbol.size);
+                entry.extend([symbol.info, 0]);
+                self.half(&mut entry, symbol.section_index);
+            }
+            data.extend(entry);
+        }
+
+        let strtab = self.sections.len() as u32 + 2;
+        self.sections.push(Section {
+            data,
+            link: strtab,
+            info: 1,
+            entry_size: if self.elf64 { 24 } else { 16 },
+            ..Section::new(".symtab", SHT_SYMTAB, 0, 0)
+        });
+        self.sections.push(Section {
+            data: names,
+            ..Section::new(".strtab", SHT_STRTAB, 0, 0)
+        });
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let (header_size, phentsize, shentsize) = match self.elf64 {
+            false => (52, 32, 40),
+            true => (64, 56, 64),
+        };
+        let loaded: Vec<&Section> = self
+            .sections
+            .iter()
+            .filter(|section| section.flags & SHF_ALLOC != 0)
+            .collect();
+
+        let mut names = vec![0u8];
+        let mut name_offsets = Vec::new();
+        for name in self
+            .sections
+            .iter()
+            .map(|section| section.name)
+            .chain([".shstrtab"])
+        {
+            name_offsets.push(names.len() as u32);
+            names.extend(name.as_bytes());
+            names.push(0);
+        }
+
+        let mut data_offsets = Vec::new();
+        let mut offset = header_size + loaded.len() * phentsize;
+        for data in self
+            .sections
+            .iter()
+            .map(|section| &section.data)
+            .chain([&names])
+        {
+            offset = offset.next_multiple_of(4);
+            data_offsets.push(offset as u64);
+            offset += data.len();
+        }
+        let shoff = offset.next_multiple_of(8);
+        let shnum = self.sections.len() + 2;
+
+        let mut file = Vec::new();
+        file.extend(b"\x7fELF");
+        file.push(if self.elf64 { 2 } else { 1 });
+        file.push(if self.big_endian { 2 } else { 1 });
+        file.extend([1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        self.half(&mut file, self.file_type);
+        self.half(&mut file, EM_RISCV);
+        self.word(&mut file, 1);
+        self.address(&mut file, self.entry);
+        self.address(&mut file, header_size as u64);
+        self.address(&mut file, shoff as u64);
+        self.word(&mut file, self.flags);
+        self.half(&mut file, header_size as u16);
+        self.half(&mut file, phentsize as u16);
+        self.half(&mut file, loaded.len() as u16);
+        self.half(&mut file, shentsize as u16);
+        self.half(&mut file, shnum as u16);
+        self.half(&mut file, shnum as u16 - 1);
+
+        for (section, offset) in self.sections.iter().zip(&data_offsets) {
+            if section.flags & SHF_ALLOC == 0 {
+                continue;
+            }
+            let size = section.data.len() as u64;
+            let flags = if section.flags & SHF_EXECINSTR != 0 {
+                0b101
+            } else {
+                0b110
+            };
+            self.word(&mut file, PT_LOAD);
+            if self.elf64 {
+                self.word(&mut file, flags);
+            }
+            for value in [*offset, section.address, section.address, size, size] {
+                self.address(&mut file, value);
+            }
+            if !self.elf64 {
+                self.word(&mut file, flags);
+            }
+            self.address(&mut file, 4);
+        }
+
+        for (data, offset) in self
+            .sections
+            .iter()
+            .map(|section| &section.data)
+            .chain([&names])
+            .zip(&data_offsets)
+        {
+            file.resize(*offset as usize, 0);
+            file.extend(data);
+        }
+        file.resize(shoff, 0);
+
+        file.extend(vec![0; shentsize]);
+        let shstrtab = Section {
+            data: names.clone(),
+            ..Section::new(".shstrtab", SHT_STRTAB, 0, 0)
+        };
+        for ((section, name), offset) in self
+            .sections
+            .iter()
+            .chain([&shstrtab])
+            .zip(&name_offsets)
+            .zip(&data_offsets)
+        {
+            self.word(&mut file, *name);
+            self.word(&mut file, section.section_type);
+            self.address(&mut file, section.flags);
+            self.address(&mut file, section.address);
+            self.address(&mut file, *offset);
+            self.address(&mut file, section.data.len() as u64);
+            self.word(&mut file, section.link);
+            self.word(&mut file, section.info);
+            self.address(&mut file, 4);
+            self.address(&mut file, section.entry_size);
+        }
+        file
+    }
+
+    fn half(&self, file: &mut Vec<u8>, value: u16) {
+        match self.big_endian {
+            true => file.extend(value.to_be_bytes()),
+            false => file.extend(value.to_le_bytes()),
+        }
+    }
+
+    fn word(&self, file: &mut Vec<u8>, value: u32) {
+        file.extend(word32(value, self.big_endian));
+    }
+
+    /// Writes an address, offset or size, which are words in ELF32 and doublewords in ELF64.
+    fn address(&self, file: &mut Vec<u8>, value: u64) {
+        match (self.elf64, self.big_endian) {
+            (false, _) => self.word(file, value as u32),
+            (true, true) => file.extend(value.to_be_bytes()),
+            (true, false) => file.extend(value.to_le_bytes()),
+        }
+    }
+}
+
+pub fn word32(value: u32, big_endian: bool) -> [u8; 4] {
+    match big_endian {
+        true => value.to_be_bytes(),
+        false => value.to_le_bytes(),
+    }
+}
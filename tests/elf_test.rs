@@ -0,0 +1,487 @@
+#![cfg(feature = "elf")]
+
+mod elf;
+
+#[cfg(test)]
+mod tests {
+    use super::elf::{ElfBuilder, Section, SymbolEntry};
+    use risc_v_disassembler::elf::{
+        Attributes, ElfClass, ElfError, ElfFile, ElfFlags, ElfSymbol, FloatAbi, SectionHeader,
+        EF_RISCV_RVC, EF_RISCV_RVE, ET_REL, PT_LOAD, SHF_ALLOC, SHF_WRITE, SHN_ABS, SHN_UNDEF,
+        SHT_NOBITS, SHT_PROGBITS, STB_GLOBAL, STB_LOCAL, STT_FILE, STT_FUNC, STT_NOTYPE,
+        STT_OBJECT, STT_SECTION,
+    };
+    use risc_v_disassembler::{DisassemblerError, Extensions, Symbol};
+
+    /// Returns the address and `Display` output, or `None` for an error, of every instruction
+    /// of the executable sections.
+    fn listing(elf: &ElfFile) -> Vec<(u64, Option<String>)> {
+        elf.executable_sections()
+            .flat_map(|section| elf.disassemble(section, true).unwrap())
+            .map(|disassembled| {
+                (
+                    disassembled.address,
+                    disassembled.instruction.ok().map(|i| i.to_string()),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_object_file() {
+        let data = include_bytes!("fixtures/attributes.o");
+        let elf = ElfFile::parse(data).unwrap();
+
+        assert_eq!(elf.header.class, ElfClass::Elf32);
+        assert!(!elf.header.is_big_endian);
+        assert_eq!(elf.header.file_type, ET_REL);
+        assert_eq!(
+            elf.flags(),
+            ElfFlags {
+                rvc: true,
+                float_abi: FloatAbi::Soft,
+                rve: false,
+                tso: false,
+            }
+        );
+        assert_eq!(
+            elf.attributes().unwrap(),
+            Some(Attributes {
+                arch: Some("rv32i2p0_a2p0_c2p0".to_string()),
+                stack_align: Some(16),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            elf.extensions().unwrap(),
+            Extensions {
+                zalrsc: true,
+                zaamo: true,
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            listing(&elf),
+            [
+                (0x0, Some("lr.w a0, (a1)".to_string())),
+                (0x4, Some("sc.w a2, a3, (a1)".to_string())),
+                (0x8, None),
+                (0xa, Some("addi a0, a0, 1".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_object_file_with_extensions() {
+        let data = include_bytes!("fixtures/attributes.o");
+        let elf = ElfFile::parse(data).unwrap();
+        let text = elf.section(".text").unwrap();
+        let extensions = Extensions {
+            zcb: true,
+            ..elf.extensions().unwrap()
+        };
+
+        let instruction = elf
+            .disassemble_with_extensions(text, false, &extensions)
+            .unwrap()
+            .nth(2)
+            .unwrap();
+        assert_eq!(instruction.address, 0x8);
+        assert_eq!(instruction.bytes, [0x61, 0x9d]);
+        assert_eq!(instruction.instruction.unwrap().to_string(), "c.zext.b x10");
+    }
+
+    #[test]
+    fn test_executable_load_addresses() {
+        let data = ElfBuilder::new(false, false, EF_RISCV_RVC)
+            .section(Section::text(
+                ".text",
+                0x80000000,
+                &[0x93, 0x00, 0x51, 0x00, 0x6f, 0x00, 0x00, 0x00],
+            ))
+            .section(Section::text(
+                ".init",
+                0x80001000,
+                &[0x73, 0x00, 0x10, 0x00],
+            ))
+            .section(Section {
+                data: vec![1, 2, 3, 4],
+                ..Section::new(".data", SHT_PROGBITS, SHF_ALLOC | SHF_WRITE, 0x80002000)
+            })
+            .section(Section::new(
+                ".bss",
+                SHT_NOBITS,
+                SHF_ALLOC | SHF_WRITE,
+                0x80003000,
+            ))
+            .build();
+        let elf = ElfFile::parse(&data).unwrap();
+
+        let names: Vec<&str> = elf
+            .executable_sections()
+            .map(|section| section.name.as_str())
+            .collect();
+        assert_eq!(names, [".text", ".init"]);
+        assert_eq!(elf.segments.len(), 4);
+        assert!(elf
+            .segments
+            .iter()
+            .all(|segment| segment.segment_type == PT_LOAD));
+        assert_eq!(elf.section_data(elf.section(".bss").unwrap()), Ok(&[][..]));
+
+        assert_eq!(
+            listing(&elf),
+            [
+                (0x80000000, Some("addi ra, sp, 5".to_string())),
+                (0x80000004, Some("jal zero, 0".to_string())),
+                (0x80001000, Some("ebreak".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_elf64_big_endian() {
+        let data = ElfBuilder::new(true, true, 0x4)
+            .section(Section::text(
+                ".text",
+                0x1_0000_0000,
+                &[0x00, 0x51, 0x00, 0x93, 0x00, 0x10, 0x00, 0x73],
+            ))
+            .build();
+        let elf = ElfFile::parse(&data).unwrap();
+
+        assert_eq!(elf.header.class, ElfClass::Elf64);
+        assert!(elf.header.is_big_endian);
+        assert_eq!(elf.flags().float_abi, FloatAbi::Double);
+        assert_eq!(
+            elf.section_data(elf.section(".text").unwrap()).unwrap(),
+            [0x00, 0x51, 0x00, 0x93, 0x00, 0x10, 0x00, 0x73]
+        );
+        // The code of ELF64 files is RV64 code, whose instructions are not decoded.
+        let extensions = Extensions::default();
+        assert_eq!(
+            elf.disassemble_with_extensions(elf.section(".text").unwrap(), true, &extensions)
+                .err(),
+            Some(ElfError::Disassembler(DisassemblerError::UnsupportedXlen(
+                64
+            )))
+        );
+    }
+
+    #[test]
+    fn test_embedded_base() {
+        // addi a5, a4, 1; addi a6, a5, 1, whose a6 is x16, beyond the registers of RV32E.
+        let data = ElfBuilder::new(false, false, EF_RISCV_RVE)
+            .section(Section::text(
+                ".text",
+                0x100,
+                &[0x93, 0x07, 0x17, 0x00, 0x13, 0x88, 0x17, 0x00],
+            ))
+            .build();
+        let elf = ElfFile::parse(&data).unwrap();
+
+        assert!(elf.flags().rve);
+        assert!(elf.extensions().unwrap().e);
+        assert_eq!(
+            listing(&elf),
+            [(0x100, Some("addi a5, a4, 1".to_string())), (0x104, None)]
+        );
+    }
+
+    #[test]
+    fn test_without_compressed_instructions() {
+        // Without the RVC flag every instruction is a word, so the reserved 16 bit encoding
+        // 0x0000 takes the whole word with it.
+        let data = ElfBuilder::new(false, false, 0)
+            .section(Section::text(
+                ".text",
+                0x100,
+                &[0x00, 0x00, 0x00, 0x00, 0x73, 0x00, 0x10, 0x00],
+            ))
+            .build();
+        let elf = ElfFile::parse(&data).unwrap();
+
+        assert!(!elf.compressed().unwrap());
+        assert_eq!(
+            listing(&elf),
+            [(0x100, None), (0x104, Some("ebreak".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_extensions_from_attributes() {
+        let data = ElfBuilder::new(false, false, 0)
+            .section(Section::text(".text", 0, &[0x61, 0x9d]))
+            .section(Section::attributes(
+                "rv32i2p1_zicsr2p0_zca1p0_zcb1p0",
+                false,
+            ))
+            .build();
+        let elf = ElfFile::parse(&data).unwrap();
+
+        assert!(elf.compressed().unwrap());
+        assert!(elf.extensions().unwrap().zcb);
+        assert_eq!(listing(&elf), [(0, Some("c.zext.b a0".to_string()))]);
+    }
+
+    #[test]
+    fn test_invalid_arch_attribute() {
+        let data = ElfBuilder::new(false, false, 0)
+            .section(Section::attributes("armv7", false))
+            .build();
+        let elf = ElfFile::parse(&data).unwrap();
+
+        assert_eq!(
+            elf.extensions().err().map(|error| error.to_string()),
+            Some(DisassemblerError::InvalidIsaString("armv7".to_string()).to_string())
+        );
+    }
+
+    fn symbol(name: &'static str, value: u64, size: u64, info: u8, index: u16) -> SymbolEntry {
+        SymbolEntry {
+            name,
+            value,
+            size,
+            info,
+            section_index: index,
+        }
+    }
+
+    /// Returns a program calling `puts` with the address of `message`, in a file with the
+    /// symbols of `symbols` and sections `.text` (1) and `.rodata` (2).
+    fn program(elf64: bool, big_endian: bool, symbols: &[SymbolEntry]) -> Vec<u8> {
+        let words: [u32; 7] = [
+            0x00001517, // auipc a0, 4096
+            0xffc50513, // addi a0, a0, -4
+            0x00000097, // auipc ra, 0
+            0x00c080e7, // jalr ra, 12(ra)
+            0xfe050ae3, // beq a0, zero, -12
+            0x00008067, // jalr zero, 0(ra)
+            0x00008067, // jalr zero, 0(ra)
+        ];
+        // Instructions are little endian in big endian files too.
+        let text: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+        ElfBuilder::new(elf64, big_endian, 0)
+            .section(Section::text(".text", 0x10000, &text))
+            .section(Section {
+                data: b"hello\0".to_vec(),
+                ..Section::new(".rodata", SHT_PROGBITS, SHF_ALLOC, 0x10ffc)
+            })
+            .symbols(symbols)
+            .build()
+    }
+
+    fn program_symbols() -> Vec<SymbolEntry> {
+        vec![
+            symbol("program.c", 0, 0, STT_FILE, SHN_ABS),
+            symbol("", 0x10000, 0, STT_SECTION, 1),
+            symbol("$x", 0x10000, 0, STT_NOTYPE, 1),
+            symbol("printf", 0, 0, STB_GLOBAL << 4 | STT_FUNC, SHN_UNDEF),
+            symbol("main", 0x10000, 0x14, STB_GLOBAL << 4 | STT_FUNC, 1),
+            symbol("puts", 0x10014, 0x8, STB_LOCAL << 4 | STT_FUNC, 1),
+            symbol("message", 0x10ffc, 6, STB_LOCAL << 4 | STT_OBJECT, 2),
+        ]
+    }
+
+    #[test]
+    fn test_symbol_table() {
+        let data = program(false, false, &program_symbols());
+        let elf = ElfFile::parse(&data).unwrap();
+        let table = elf.symbol_table(elf.section(".symtab").unwrap()).unwrap();
+
+        assert_eq!(table.len(), 8);
+        assert_eq!(table[0].name, "");
+        assert_eq!(
+            table[5],
+            ElfSymbol {
+                name: "main".to_string(),
+                value: 0x10000,
+                size: 0x14,
+                symbol_type: STT_FUNC,
+                binding: STB_GLOBAL,
+                section_index: 1,
+            }
+        );
+
+        let symbols: Vec<Symbol> = elf.symbols().unwrap().iter().cloned().collect();
+        assert_eq!(
+            symbols,
+            [
+                Symbol::function("main", 0x10000, 0x14),
+                Symbol::function("puts", 0x10014, 0x8),
+                Symbol {
+                    size: 6,
+                    ..Symbol::new("message", 0x10ffc)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_symbolised_listing() {
+        let expected = [
+            "00010000 <main>:",
+            "   10000:\tla a0, message",
+            "   10008:\tcall puts",
+            "   10010:\tbeq a0, zero, -12 <main+0x4>",
+            "00010014 <puts>:",
+            "   10014:\tjalr zero, 0(ra)",
+            "   10018:\tjalr zero, 0(ra)",
+        ];
+        for big_endian in [false, true] {
+            let data = program(false, big_endian, &program_symbols());
+            let elf = ElfFile::parse(&data).unwrap();
+            let symbols = elf.symbols().unwrap();
+            let text = elf.section(".text").unwrap();
+
+            let listing: Vec<String> = elf
+                .disassemble(text, true)
+                .unwrap()
+                .listing(&symbols)
+                .map(|line| line.to_string())
+                .collect();
+            assert_eq!(listing, expected, "big endian: {}", big_endian);
+
+            // The symbols of ELF64 files are read alike, but their RV64 code is not decoded.
+            let data64 = program(true, big_endian, &program_symbols());
+            let elf64 = ElfFile::parse(&data64).unwrap();
+            assert_eq!(elf64.symbols().unwrap(), symbols);
+            assert_eq!(
+                elf64
+                    .disassemble(elf64.section(".text").unwrap(), true)
+                    .err(),
+                Some(ElfError::Disassembler(DisassemblerError::UnsupportedXlen(
+                    64
+                )))
+            );
+        }
+    }
+
+    #[test]
+    fn test_relocatable_symbols() {
+        // The symbols of a relocatable file are offsets in their section, which is placed at the
+        // address of the section.
+        let symbols = [
+            symbol("main", 0, 0x14, STB_GLOBAL << 4 | STT_FUNC, 1),
+            symbol("message", 0, 6, STT_OBJECT, 2),
+        ];
+        let mut relocatable = program(false, false, &symbols);
+        // e_type, which the builder sets to ET_EXEC.
+        relocatable[16] = ET_REL as u8;
+
+        let elf = ElfFile::parse(&relocatable).unwrap();
+        let addresses: Vec<(String, u64)> = elf
+            .symbols()
+            .unwrap()
+            .iter()
+            .map(|symbol| (symbol.name.clone(), symbol.address))
+            .collect();
+        assert_eq!(
+            addresses,
+            [
+                ("main".to_string(), 0x10000),
+                ("message".to_string(), 0x10ffc)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_section_headers() {
+        // A section whose header repeats that of .text is a different section, which holds
+        // none of the symbols of .text.
+        let data = program(false, false, &program_symbols());
+        let mut elf = ElfFile::parse(&data).unwrap();
+        let text = elf.section(".text").unwrap().clone();
+        let duplicate = SectionHeader {
+            index: elf.sections.len(),
+            ..text.clone()
+        };
+        elf.sections.push(duplicate.clone());
+
+        assert_eq!(elf.section_symbols(&text).unwrap().iter().count(), 2);
+        assert_eq!(elf.section_symbols(&duplicate).unwrap().iter().count(), 0);
+    }
+
+    #[test]
+    fn test_object_file_relocations() {
+        let data = include_bytes!("fixtures/relocations.o");
+        let elf = ElfFile::parse(data).unwrap();
+        let text = elf.section(".text").unwrap();
+        let symbols = elf.section_symbols(text).unwrap();
+        let relocations = elf.relocations(text).unwrap();
+        assert_eq!(relocations.len(), 16);
+        assert_eq!(relocations[0].to_string(), "R_RISCV_CALL printf");
+
+        let listing: Vec<String> = elf
+            .disassemble(text, true)
+            .unwrap()
+            .listing(&symbols)
+            .relocations(&relocations)
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(
+            listing,
+            [
+                "00000000 <main>:",
+                "       0:\tcall printf",
+                "00000008 <.Lpcrel_hi0>:",
+                "       8:\tla a0, message",
+                "      10:\tlui a1, %hi(message)",
+                "      14:\taddi a1, a1, %lo(message)",
+                "      18:\tlw a2, %lo(message)(a1)",
+                "      1c:\tbeq a0, a1, done",
+                "      20:\tjal zero, exit",
+                "00000024 <done>:",
+                "      24:\ttail puts",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mapping_symbols() {
+        // A jump table after the code of `main`, followed by code built for Zcb.
+        let text = [
+            0x67, 0x80, 0x00, 0x00, // jalr zero, 0(ra)
+            0x14, 0x00, 0x01, 0x00, // .word 0x00010014
+            0x00, 0x00, 0x00, 0x00, // .word 0x00000000
+            0x61, 0x9d, // c.zext.b a0
+            0x82, 0x80, // c.jr ra, which is not decoded
+        ];
+        let data = ElfBuilder::new(false, false, EF_RISCV_RVC)
+            .section(Section::text(".text", 0x10000, &text))
+            .symbols(&[
+                symbol("$x", 0x10000, 0, STT_NOTYPE, 1),
+                symbol("main", 0x10000, 0xc, STB_GLOBAL << 4 | STT_FUNC, 1),
+                symbol("$d", 0x10004, 0, STT_NOTYPE, 1),
+                symbol("$xrv32i2p1_c2p0_zcb1p0", 0x1000c, 0, STT_NOTYPE, 1),
+                symbol("zext", 0x1000c, 4, STB_GLOBAL << 4 | STT_FUNC, 1),
+                symbol("$local", 0x1000e, 0, STT_NOTYPE, 1),
+            ])
+            .build();
+        let elf = ElfFile::parse(&data).unwrap();
+        let symbols = elf.symbols().unwrap();
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols.mappings().len(), 3);
+
+        let listing: Vec<String> = elf
+            .disassemble(elf.section(".text").unwrap(), true)
+            .unwrap()
+            .listing(&symbols)
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(listing.len(), 7);
+        assert_eq!(
+            listing[..6],
+            [
+                "00010000 <main>:",
+                "   10000:\tjalr zero, 0(ra)",
+                "   10004:\t.word 0x00010014",
+                "   10008:\t.word 0x00000000",
+                "0001000c <zext>:",
+                "   1000c:\tc.zext.b a0",
+            ]
+        );
+        assert!(listing[6].starts_with("   1000e:\t"));
+    }
+}
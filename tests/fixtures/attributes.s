@@ -0,0 +1,12 @@
+# Assembled with: llvm-mc -triple=riscv32 -mattr=+a,+c -filetype=obj attributes.s -o attributes.o
+.attribute arch, "rv32i2p0_a2p0_c2p0"
+.attribute stack_align, 16
+
+.text
+.globl _start
+_start:
+    .option norvc
+    lr.w a0, (a1)
+    sc.w a2, a3, (a1)
+    .half 0x9d61
+    addi a0, a0, 1
@@ -0,0 +1,17 @@
+# Assembled and converted with:
+#   llvm-mc -triple=riscv32 -filetype=obj firmware.s -o firmware.o
+#   objcopy -I elf32-little -O ihex --change-section-address .text=0x80000000 \
+#       --change-section-address .rodata=0x80001000 firmware.o firmware.hex
+#   objcopy -I elf32-little -O srec --srec-forceS3 --change-section-address .text=0x80000000 \
+#       --change-section-address .rodata=0x80001000 firmware.o firmware.srec
+	.text
+	.globl	_start
+_start:
+	lui	a0, 0x80001
+	lw	a1, 0(a0)
+	addi	a1, a1, 1
+	sw	a1, 4(a0)
+	jalr	zero, 0(ra)
+
+	.section	.rodata
+	.word	0x12345678
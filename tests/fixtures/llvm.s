@@ -0,0 +1,73 @@
+# Assembled with `llvm-mc -triple=riscv32 -mattr=-relax -filetype=obj llvm.s -o llvm.o`, and
+# listed in llvm.objdump by `llvm-objdump -d llvm.o` of LLVM 14, from the directory
+# `tests/fixtures`.
+.text
+.globl main
+main:
+  lui a0, 0x12345
+  auipc a0, 2
+  addi a0, a0, -316
+  addi a0, zero, 5
+  addi a0, a1, 0
+  addi zero, zero, 0
+  xori a0, a1, -1
+  andi a0, a1, 255
+  sub a0, zero, a1
+  sltiu a0, a1, 1
+  sltu a0, zero, a1
+  slt a0, a1, zero
+  slt a0, zero, a1
+  slli a0, a1, 3
+  srai a0, a1, 31
+  lw a0, -4(a1)
+  sw a0, 4(a1)
+  lb a0, 0(zero)
+loop:
+  jalr zero, 0(ra)
+  jalr zero, 0(a1)
+  jalr zero, 8(a1)
+  jalr ra, 0(a1)
+  jalr ra, -8(a1)
+  jalr a0, 0(a1)
+  jalr a0, 8(a1)
+  beq a0, a1, main
+  beq a0, zero, loop
+  bne a0, zero, loop
+  bge zero, a0, loop
+  bge a0, zero, end
+  blt a0, zero, end
+  blt zero, a0, end
+  bltu a0, a1, end
+  jal zero, main
+  jal ra, loop
+  jal a0, end
+  csrrs a0, mstatus, zero
+  csrrw zero, mstatus, a1
+  csrrw a0, mstatus, a1
+  csrrs zero, mstatus, a1
+  csrrc zero, mstatus, a1
+  csrrsi zero, mstatus, 8
+  csrrwi zero, mstatus, 8
+  csrrci a0, mstatus, 8
+  csrrs a0, cycle, zero
+  csrrs a0, instreth, zero
+  csrrs a0, fcsr, zero
+  csrrw zero, fcsr, a0
+  csrrs a0, 0x7c0, zero
+  csrrs a0, pmpcfg1, zero
+  csrrw zero, cycle, zero
+  fence iorw, iorw
+  fence rw, rw
+  .word 0x0100000f
+  fence.i
+  fence.tso
+  ecall
+  ebreak
+  sfence.vma zero, zero
+  sfence.vma a0, zero
+  sfence.vma a0, a1
+  mret
+  wfi
+end:
+  .word 0x0000007f
+  ret
@@ -0,0 +1,20 @@
+# Assembled with:
+#   llvm-mc -triple=riscv32 -mattr=+relax -filetype=obj relocations.s -o relocations.o
+	.text
+	.globl	main
+	.type	main, @function
+main:
+	call	printf
+	lla	a0, message
+	lui	a1, %hi(message)
+	addi	a1, a1, %lo(message)
+	lw	a2, %lo(message)(a1)
+	beq	a0, a1, done
+	jal	zero, exit
+done:
+	tail	puts
+	.size	main, .-main
+
+	.section	.rodata
+message:
+	.asciz	"hello"
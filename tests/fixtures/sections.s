@@ -0,0 +1,12 @@
+# Assembled with `llvm-mc -triple=riscv32 -mattr=-relax -filetype=obj sections.s -o sections.o`,
+# and listed in sections.objdump by `llvm-objdump -d sections.o` of LLVM 14, from the directory
+# `tests/fixtures`. No symbol starts the section, so its code is labelled with the section name.
+.text
+  addi a0, zero, 5
+1:
+  addi a0, a0, -1
+  bnez a0, 1b
+.globl loop
+loop:
+  j 1b
+  ret
@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::parsed_instructions::*;
+    use risc_v_disassembler::{
+        parse, parse_with_extensions, DisassemblerError, Extensions, ParsedInstruction32,
+    };
+
+    fn hint_extensions() -> Extensions {
+        Extensions {
+            zimop: true,
+            zcmop: true,
+            hints: true,
+            ..Default::default()
+        }
+    }
+
+    fn hint(instruction: ParsedInstruction32) -> ParsedInstruction32 {
+        ParsedInstruction32::hint(risc_v_disassembler::parsed_instructions::hint {
+            instruction: Box::new(instruction),
+        })
+    }
+
+    /// Returns a vector of tuples containing (instruction hex, expected ParsedInstruction32) for HINT and may-be-operation instructions, using ABI registers
+    fn get_hint_test_cases_abi() -> Vec<(u32, ParsedInstruction32)> {
+        vec![
+            (
+                0x00150013,
+                hint(ParsedInstruction32::addi(addi {
+                    rd: "zero",
+                    rs1: "a0",
+                    imm: 1,
+                })),
+            ),
+            (
+                0x00351013,
+                hint(ParsedInstruction32::slli(slli {
+                    rd: "zero",
+                    rs1: "a0",
+                    shamt: 3,
+                })),
+            ),
+            (
+                0x00001037,
+                hint(ParsedInstruction32::lui(lui {
+                    rd: "zero",
+                    imm: 0x1000,
+                })),
+            ),
+            (
+                0x00C58033,
+                hint(ParsedInstruction32::add(add {
+                    rd: "zero",
+                    rs1: "a1",
+                    rs2: "a2",
+                })),
+            ),
+            (
+                0x00000013,
+                ParsedInstruction32::addi(addi {
+                    rd: "zero",
+                    rs1: "zero",
+                    imm: 0,
+                }),
+            ),
+            (
+                0xCDD5C573,
+                ParsedInstruction32::mop_r(mop_r {
+                    n: 29,
+                    rd: "a0",
+                    rs1: "a1",
+                }),
+            ),
+            (
+                0xC6C5C573,
+                ParsedInstruction32::mop_rr(mop_rr {
+                    n: 5,
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                }),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_hint_instructions_le_abi() {
+        for (hex, expected) in get_hint_test_cases_abi() {
+            let result = parse_with_extensions(&hex.to_le_bytes(), false, true, &hint_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_hint_instructions_be_abi() {
+        for (hex, expected) in get_hint_test_cases_abi() {
+            let result = parse_with_extensions(&hex.to_be_bytes(), true, true, &hint_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_hint_printing() {
+        let extensions = hint_extensions();
+
+        let result = parse_with_extensions(&0x00150013u32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result.unwrap().to_string(), "addi zero, a0, 1");
+
+        let result = parse_with_extensions(&0xCDD5C573u32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result.unwrap().to_string(), "mop.r.29 a0, a1");
+
+        let result = parse_with_extensions(&0xC6C5C573u32.to_le_bytes(), false, false, &extensions);
+        assert_eq!(result.unwrap().to_string(), "mop.rr.5 x10, x11, x12");
+    }
+
+    #[test]
+    fn test_compressed_hints() {
+        let extensions = hint_extensions();
+
+        let result = parse_with_extensions(&0x107Du16.to_le_bytes(), false, true, &extensions);
+        assert_eq!(
+            result,
+            Ok(hint(ParsedInstruction32::c_nop(c_nop { imm: -1 })))
+        );
+
+        let result = parse_with_extensions(&0x6081u16.to_le_bytes(), false, true, &extensions);
+        let result = result.unwrap();
+        assert_eq!(result, ParsedInstruction32::c_mop(c_mop { n: 1 }));
+        assert_eq!(result.to_string(), "c.mop.1");
+    }
+
+    #[test]
+    fn test_prefetch_takes_precedence_over_hint() {
+        let extensions = Extensions {
+            zicbop: true,
+            ..hint_extensions()
+        };
+        let result = parse_with_extensions(&0x00156013u32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(
+            result,
+            Ok(ParsedInstruction32::prefetch_r(prefetch_r {
+                rs1: "a0",
+                imm: 0,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_hints_not_selected() {
+        let result = parse(&0x00150013u32.to_le_bytes(), false, true);
+        assert_eq!(
+            result,
+            Ok(ParsedInstruction32::addi(addi {
+                rd: "zero",
+                rs1: "a0",
+                imm: 1,
+            }))
+        );
+
+        let result = parse(&0xCDD5C573u32.to_le_bytes(), false, false);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct3(0b100)));
+    }
+}
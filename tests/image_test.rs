@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::image::{Image, Region};
+    use risc_v_disassembler::Extensions;
+
+    /// The `.text` and `.rodata` sections of `firmware.s`, placed with a gap between them.
+    fn firmware_regions() -> Vec<Region> {
+        vec![
+            Region {
+                address: 0x8000_0000,
+                data: vec![
+                    0x37, 0x15, 0x00, 0x80, 0x83, 0x25, 0x05, 0x00, 0x93, 0x85, 0x15, 0x00, 0x23,
+                    0x22, 0xb5, 0x00, 0x67, 0x80, 0x00, 0x00,
+                ],
+            },
+            Region {
+                address: 0x8000_1000,
+                data: vec![0x78, 0x56, 0x34, 0x12],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_intel_hex_fixture() {
+        let image = Image::from_intel_hex(include_str!("fixtures/firmware.hex")).unwrap();
+        assert_eq!(image.regions, firmware_regions());
+        assert_eq!(image.entry, None);
+    }
+
+    #[test]
+    fn test_srecord_fixture() {
+        let image = Image::from_srecord(include_str!("fixtures/firmware.srec")).unwrap();
+        assert_eq!(image.regions, firmware_regions());
+        assert_eq!(image.entry, Some(0));
+    }
+
+    #[test]
+    fn test_disassemble_regions() {
+        let image = Image::from_intel_hex(include_str!("fixtures/firmware.hex")).unwrap();
+        let listing: Vec<String> = image.regions[0]
+            .disassemble(false, true, &Extensions::default())
+            .map(|disassembled| {
+                format!(
+                    "{:x}: {}",
+                    disassembled.address,
+                    disassembled.instruction.unwrap()
+                )
+            })
+            .collect();
+        assert_eq!(
+            listing,
+            [
+                "80000000: lui a0, -2147479552",
+                "80000004: lw a1, 0(a0)",
+                "80000008: addi a1, a1, 1",
+                "8000000c: sw a1, 4(a0)",
+                "80000010: jalr zero, 0(ra)",
+            ]
+        );
+    }
+}
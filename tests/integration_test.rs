@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use risc_v_disassembler::parse;
+    use risc_v_disassembler::{parse, parse_with_extensions, DisassemblerError, Extensions};
     use risc_v_disassembler::{parsed_instructions::*, ParsedInstruction32};
 
     /// Returns a vector of tuples containing (instruction hex, expected ParsedInstruction32) for RV32I instructions, using numbered registers
@@ -304,6 +304,33 @@ mod tests {
                     imm: 10,
                 }),
             ),
+            // MISC-MEM instructions
+            (
+                0x0FF0000F,
+                ParsedInstruction32::fence(fence {
+                    pred: 0b1111,
+                    succ: 0b1111,
+                }),
+            ),
+            (0x8330000F, ParsedInstruction32::fence_tso(fence_tso {})),
+            (0x0000100F, ParsedInstruction32::fence_i(fence_i {})),
+            // Zicsr instructions
+            (
+                0xC0002573,
+                ParsedInstruction32::csrrs(csrrs {
+                    rd: "x10",
+                    csr: 0xc00,
+                    rs1: "x0",
+                }),
+            ),
+            (
+                0x3002D073,
+                ParsedInstruction32::csrrwi(csrrwi {
+                    rd: "x0",
+                    csr: 0x300,
+                    uimm: 5,
+                }),
+            ),
         ]
     }
 
@@ -646,4 +673,34 @@ mod tests {
             assert_eq!(result.unwrap(), expected);
         }
     }
+
+    #[test]
+    fn test_rv32e_registers() {
+        let extensions = Extensions {
+            e: true,
+            zcmp: true,
+            ..Default::default()
+        };
+        let text = |bytes: &[u8], abi| {
+            parse_with_extensions(bytes, false, abi, &extensions).map(|i| i.to_string())
+        };
+        // addi a5, a4, 1; addi a6, a5, 1
+        assert_eq!(
+            text(&0x00170793u32.to_le_bytes(), true).as_deref(),
+            Ok("addi a5, a4, 1")
+        );
+        assert_eq!(
+            text(&0x00178813u32.to_le_bytes(), false),
+            Err(DisassemblerError::InvalidRegister(16))
+        );
+        // cm.push {ra, s0-s1}, -16; cm.push {ra, s0-s2}, -16, whose s2 is x18
+        assert_eq!(
+            text(&0xb862u16.to_le_bytes(), true).as_deref(),
+            Ok("cm.push {ra, s0-s1}, -16")
+        );
+        assert_eq!(
+            text(&0xb872u16.to_le_bytes(), true),
+            Err(DisassemblerError::InvalidRegister(18))
+        );
+    }
 }
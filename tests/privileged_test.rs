@@ -0,0 +1,236 @@
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::parsed_instructions::*;
+    use risc_v_disassembler::{
+        parse, parse_with_extensions, DisassemblerError, Extensions, MemoryAccess,
+        ParsedInstruction32, PrivilegeLevel,
+    };
+
+    fn privileged_extensions() -> Extensions {
+        Extensions {
+            svinval: true,
+            smrnmi: true,
+            h: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a vector of tuples containing (instruction hex, expected ParsedInstruction32, minimum privilege level) for SYSTEM instructions, using ABI registers
+    fn get_system_test_cases_abi() -> Vec<(u32, ParsedInstruction32, PrivilegeLevel)> {
+        vec![
+            (
+                0x00000073,
+                ParsedInstruction32::ecall(ecall {}),
+                PrivilegeLevel::User,
+            ),
+            (
+                0x00100073,
+                ParsedInstruction32::ebreak(ebreak {}),
+                PrivilegeLevel::User,
+            ),
+            (
+                0x10200073,
+                ParsedInstruction32::sret(sret {}),
+                PrivilegeLevel::Supervisor,
+            ),
+            (
+                0x30200073,
+                ParsedInstruction32::mret(mret {}),
+                PrivilegeLevel::Machine,
+            ),
+            (
+                0x70200073,
+                ParsedInstruction32::mnret(mnret {}),
+                PrivilegeLevel::Machine,
+            ),
+            (
+                0x10500073,
+                ParsedInstruction32::wfi(wfi {}),
+                PrivilegeLevel::Supervisor,
+            ),
+            (
+                0x12B50073,
+                ParsedInstruction32::sfence_vma(sfence_vma {
+                    rs1: "a0",
+                    rs2: "a1",
+                }),
+                PrivilegeLevel::Supervisor,
+            ),
+            (
+                0x16B50073,
+                ParsedInstruction32::sinval_vma(sinval_vma {
+                    rs1: "a0",
+                    rs2: "a1",
+                }),
+                PrivilegeLevel::Supervisor,
+            ),
+            (
+                0x18000073,
+                ParsedInstruction32::sfence_w_inval(sfence_w_inval {}),
+                PrivilegeLevel::Supervisor,
+            ),
+            (
+                0x18100073,
+                ParsedInstruction32::sfence_inval_ir(sfence_inval_ir {}),
+                PrivilegeLevel::Supervisor,
+            ),
+            (
+                0x62B50073,
+                ParsedInstruction32::hfence_gvma(hfence_gvma {
+                    rs1: "a0",
+                    rs2: "a1",
+                }),
+                PrivilegeLevel::Hypervisor,
+            ),
+            (
+                0x26B50073,
+                ParsedInstruction32::hinval_vvma(hinval_vvma {
+                    rs1: "a0",
+                    rs2: "a1",
+                }),
+                PrivilegeLevel::Hypervisor,
+            ),
+            (
+                0x6005C573,
+                ParsedInstruction32::hlv_b(hlv_b {
+                    rd: "a0",
+                    rs1: "a1",
+                }),
+                PrivilegeLevel::Hypervisor,
+            ),
+            (
+                0x6835C573,
+                ParsedInstruction32::hlvx_wu(hlvx_wu {
+                    rd: "a0",
+                    rs1: "a1",
+                }),
+                PrivilegeLevel::Hypervisor,
+            ),
+            (
+                0x6AC5C073,
+                ParsedInstruction32::hsv_w(hsv_w {
+                    rs1: "a1",
+                    rs2: "a2",
+                }),
+                PrivilegeLevel::Hypervisor,
+            ),
+            (
+                0xC0002573,
+                ParsedInstruction32::csrrs(csrrs {
+                    rd: "a0",
+                    csr: 0xc00,
+                    rs1: "zero",
+                }),
+                PrivilegeLevel::User,
+            ),
+            (
+                0x18051573,
+                ParsedInstruction32::csrrw(csrrw {
+                    rd: "a0",
+                    csr: 0x180,
+                    rs1: "a0",
+                }),
+                PrivilegeLevel::Supervisor,
+            ),
+            (
+                0x6005B073,
+                ParsedInstruction32::csrrc(csrrc {
+                    rd: "zero",
+                    csr: 0x600,
+                    rs1: "a1",
+                }),
+                PrivilegeLevel::Hypervisor,
+            ),
+            (
+                0x30046573,
+                ParsedInstruction32::csrrsi(csrrsi {
+                    rd: "a0",
+                    csr: 0x300,
+                    uimm: 8,
+                }),
+                PrivilegeLevel::Machine,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_system_instructions_le_abi() {
+        for (hex, expected, privilege_level) in get_system_test_cases_abi() {
+            let result =
+                parse_with_extensions(&hex.to_le_bytes(), false, true, &privileged_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            let result = result.unwrap();
+            assert_eq!(result.privilege_level(), privilege_level);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_system_instructions_be_abi() {
+        for (hex, expected, _) in get_system_test_cases_abi() {
+            let result =
+                parse_with_extensions(&hex.to_be_bytes(), true, true, &privileged_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_system_printing() {
+        let result = parse(&0x12B50073u32.to_le_bytes(), false, false).unwrap();
+        assert_eq!(result.to_string(), "sfence.vma x10, x11");
+
+        let result = parse(&0x30200073u32.to_le_bytes(), false, false).unwrap();
+        assert_eq!(result.to_string(), "mret");
+    }
+
+    #[test]
+    fn test_hypervisor_memory_access() {
+        let extensions = privileged_extensions();
+
+        let result = parse_with_extensions(&0x6835C573u32.to_le_bytes(), false, true, &extensions);
+        let result = result.unwrap();
+        assert_eq!(result.to_string(), "hlvx.wu a0, (a1)");
+        assert_eq!(result.memory_access(), Some(MemoryAccess::Load { size: 4 }));
+
+        let result = parse_with_extensions(&0x6AC5C073u32.to_le_bytes(), false, true, &extensions);
+        let result = result.unwrap();
+        assert_eq!(result.to_string(), "hsv.w a2, (a1)");
+        assert_eq!(
+            result.memory_access(),
+            Some(MemoryAccess::Store { size: 4 })
+        );
+
+        let result = parse_with_extensions(&0x62B50073u32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result.unwrap().memory_access(), None);
+    }
+
+    #[test]
+    fn test_system_extensions_not_selected() {
+        let result = parse(&0x16B50073u32.to_le_bytes(), false, false);
+        assert_eq!(result, Err(DisassemblerError::InvalidRs1Field(10)));
+
+        let result = parse(&0x70200073u32.to_le_bytes(), false, false);
+        assert_eq!(result, Err(DisassemblerError::InvalidImmediate(0x702)));
+
+        let result = parse(&0x6005C573u32.to_le_bytes(), false, false);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct3(0b100)));
+    }
+
+    #[test]
+    fn test_hypervisor_rv64_only() {
+        // hlv.d a0, (a1); hlv.wu a0, (a1); hsv.d a2, (a1)
+        let extensions = privileged_extensions();
+        for word in [0x6C05C573u32, 0x6815C573, 0x6EC5C073] {
+            let result = parse_with_extensions(&word.to_le_bytes(), false, true, &extensions);
+            assert!(result.is_err(), "{:#010x}", word);
+        }
+    }
+
+    #[test]
+    fn test_system_invalid_rd() {
+        // mret with rd = t0
+        let result = parse(&0x302002F3u32.to_le_bytes(), false, false);
+        assert_eq!(result, Err(DisassemblerError::InvalidRdField(5)));
+    }
+}
@@ -0,0 +1,1010 @@
+//! One line per instruction: its variant, the extensions it is decoded under, and the shape of
+//! its encoding with the fixed fields of the shape. Each shape maps random `Operands` onto valid
+//! operands, and encodes the instruction back into its fields. `encode` matches every variant,
+//! so a variant without a line here fails to compile.
+
+use super::Class::{Float, Int, Pair};
+use super::{
+    aqrl, b_type, compressed, i_type, j_type, r4_type, r_type, register, rm, s_type, saved, u_type,
+    v_type, vm, Encoding, Form, Operands, VECTOR,
+};
+use risc_v_disassembler::parsed_instructions::*;
+use risc_v_disassembler::ParsedInstruction32;
+
+const LOAD: u32 = 0x03;
+const MISC_MEM: u32 = 0x0f;
+const OP_IMM: u32 = 0x13;
+const AUIPC: u32 = 0x17;
+const OP: u32 = 0x33;
+const LUI: u32 = 0x37;
+const MADD: u32 = 0x43;
+const MSUB: u32 = 0x47;
+const NMSUB: u32 = 0x4b;
+const NMADD: u32 = 0x4f;
+const OP_FP: u32 = 0x53;
+const SYSTEM: u32 = 0x73;
+
+/// Encodes a compressed instruction in the CA layout, shared by Zcb and the Zcmp moves.
+fn ca(funct6: u32, rs1: u32, funct2: u32, rs2: u32, opcode: u32) -> Encoding {
+    Encoding::Half((funct6 << 10 | rs1 << 7 | funct2 << 5 | rs2 << 2 | opcode) as u16)
+}
+
+/// Returns the `funct2` field of a Zcb load or store. Byte offsets are held with their two bits
+/// swapped, while halfword offsets only use bit 5 and leave bit 6 to `select`.
+fn offset(imm: i32, half: bool, select: u32) -> u32 {
+    let imm = imm as u32;
+    match half {
+        true => imm >> 1 & 1 | select << 1,
+        false => imm >> 1 & 1 | (imm & 1) << 1,
+    }
+}
+
+/// Encodes a Zcmp push or pop from its register list and stack adjustment.
+fn push_pop(funct6: u32, funct2: u32, rlist: &RegisterList, stack_adj: i32) -> Encoding {
+    let saved_registers: u32 = rlist
+        .ranges
+        .iter()
+        .map(|(first, last)| saved(last) - saved(first) + 1)
+        .sum();
+    let (rlist, registers) = match saved_registers {
+        12 => (15, 13),
+        n => (n + 4, n + 1),
+    };
+    let spimm = (stack_adj.unsigned_abs() - (registers * 4).div_ceil(16) * 16) / 16;
+    Encoding::Half((funct6 << 10 | funct2 << 8 | rlist << 4 | spimm << 2 | 0b10) as u16)
+}
+
+macro_rules! generate {
+    (R, $v:ident, $o:ident, $opcode:expr, $funct3:expr, $funct7:expr) => {
+        $v {
+            rd: $o.x(0),
+            rs1: $o.x(1),
+            rs2: $o.x(2),
+        }
+    };
+    (I, $v:ident, $o:ident, $opcode:expr, $funct3:expr) => {
+        $v {
+            rd: $o.x(0),
+            rs1: $o.x(1),
+            imm: $o.imm12(),
+        }
+    };
+    (Shift, $v:ident, $o:ident, $funct3:expr, $funct7:expr) => {
+        $v {
+            rd: $o.x(0),
+            rs1: $o.x(1),
+            shamt: $o.bits(5),
+        }
+    };
+    (Load, $v:ident, $o:ident, $funct3:expr) => {
+        $v {
+            rd: $o.x(0),
+            imm: $o.imm12(),
+            rs1: $o.x(1),
+        }
+    };
+    (Store, $v:ident, $o:ident, $funct3:expr) => {
+        $v {
+            rs2: $o.x(2),
+            imm: $o.imm12(),
+            rs1: $o.x(1),
+        }
+    };
+    (Branch, $v:ident, $o:ident, $funct3:expr) => {
+        $v {
+            rs1: $o.x(1),
+            rs2: $o.x(2),
+            imm: $o.bimm(),
+        }
+    };
+    (Upper, $v:ident, $o:ident, $opcode:expr) => {
+        $v {
+            rd: $o.x(0),
+            imm: $o.imm20(),
+        }
+    };
+    (Jal, $v:ident, $o:ident) => {
+        $v {
+            rd: $o.x(0),
+            imm: $o.jimm(),
+        }
+    };
+    (Jalr, $v:ident, $o:ident) => {
+        $v {
+            rd: $o.x(0),
+            imm: $o.imm12(),
+            rs1: $o.x(1),
+        }
+    };
+    (Fixed, $v:ident, $o:ident, $word:expr) => {{
+        let _ = $o;
+        $v {}
+    }};
+    (Fence, $v:ident, $o:ident) => {
+        $v {
+            pred: $o.bits(4),
+            succ: ($o.imm >> 4 & 0xf) as u8,
+        }
+    };
+    (Csr, $v:ident, $o:ident, $funct3:expr) => {
+        $v {
+            rd: $o.x(0),
+            csr: ($o.imm & 0xfff) as u16,
+            rs1: $o.x(1),
+        }
+    };
+    (CsrI, $v:ident, $o:ident, $funct3:expr) => {
+        $v {
+            rd: $o.x(0),
+            csr: ($o.imm & 0xfff) as u16,
+            uimm: $o.registers[1],
+        }
+    };
+    (Fence2, $v:ident, $o:ident, $funct7:expr) => {
+        $v {
+            rs1: $o.x(1),
+            rs2: $o.x(2),
+        }
+    };
+    (Hlv, $v:ident, $o:ident, $funct7:expr, $rs2:expr) => {
+        $v {
+            rd: $o.x(0),
+            rs1: $o.x(1),
+        }
+    };
+    (Hsv, $v:ident, $o:ident, $funct7:expr) => {
+        $v {
+            rs2: $o.x(2),
+            rs1: $o.x(1),
+        }
+    };
+    (Cbo, $v:ident, $o:ident, $imm:expr) => {
+        $v { rs1: $o.x(1) }
+    };
+    (Prefetch, $v:ident, $o:ident, $rs2:expr) => {
+        $v {
+            imm: $o.imm12() & !0x1f,
+            rs1: $o.x(1),
+        }
+    };
+    (MopR, $v:ident, $o:ident) => {
+        $v {
+            n: $o.bits(5),
+            rd: $o.x(0),
+            rs1: $o.x(1),
+        }
+    };
+    (MopRR, $v:ident, $o:ident) => {
+        $v {
+            n: $o.bits(3),
+            rd: $o.x(0),
+            rs1: $o.x(1),
+            rs2: $o.x(2),
+        }
+    };
+    (FpRm3, $v:ident, $o:ident, $funct7:expr, $class:expr) => {
+        $v {
+            rd: $o.reg($class, 0),
+            rs1: $o.reg($class, 1),
+            rs2: $o.reg($class, 2),
+            rm: $o.rm(),
+        }
+    };
+    (FpRm2, $v:ident, $o:ident, $funct7:expr, $rs2:expr, $rd:expr, $rs1:expr) => {
+        $v {
+            rd: $o.reg($rd, 0),
+            rs1: $o.reg($rs1, 1),
+            rm: $o.rm(),
+        }
+    };
+    (Fp3, $v:ident, $o:ident, $funct7:expr, $funct3:expr, $rd:expr, $class:expr) => {
+        $v {
+            rd: $o.reg($rd, 0),
+            rs1: $o.reg($class, 1),
+            rs2: $o.reg($class, 2),
+        }
+    };
+    (Fp2, $v:ident, $o:ident, $funct7:expr, $funct3:expr, $rs2:expr, $rd:expr, $rs1:expr) => {
+        $v {
+            rd: $o.reg($rd, 0),
+            rs1: $o.reg($rs1, 1),
+        }
+    };
+    (Fma, $v:ident, $o:ident, $opcode:expr, $fmt:expr, $class:expr) => {
+        $v {
+            rd: $o.reg($class, 0),
+            rs1: $o.reg($class, 1),
+            rs2: $o.reg($class, 2),
+            rs3: $o.reg($class, 3),
+            rm: $o.rm(),
+        }
+    };
+    (Lr, $v:ident, $o:ident) => {
+        $v {
+            rd: $o.x(0),
+            rs1: $o.x(1),
+            ordering: $o.ordering(),
+        }
+    };
+    (Amo, $v:ident, $o:ident, $funct5:expr, $funct3:expr, $class:expr) => {
+        $v {
+            rd: $o.reg($class, 0),
+            rs1: $o.x(1),
+            rs2: $o.reg($class, 2),
+            ordering: $o.ordering(),
+        }
+    };
+    (Vvv, $v:ident, $o:ident, $funct6:expr, $funct3:expr, $widening:expr) => {
+        $v {
+            vd: $o.vd($o.masked, $widening),
+            vs2: $o.v(2),
+            vs1: $o.v(1),
+            vm: $o.vm(),
+        }
+    };
+    (Vvx, $v:ident, $o:ident, $funct6:expr, $funct3:expr, $widening:expr, $class:expr) => {
+        $v {
+            vd: $o.vd($o.masked, $widening),
+            vs2: $o.v(2),
+            rs1: $o.reg($class, 1),
+            vm: $o.vm(),
+        }
+    };
+    (Vvi, $v:ident, $o:ident, $funct6:expr, $widening:expr, $width:expr) => {
+        $v {
+            vd: $o.vd($o.masked, $widening),
+            vs2: $o.v(2),
+            uimm: $o.bits($width),
+            vm: $o.vm(),
+        }
+    };
+    (Vunary, $v:ident, $o:ident, $funct6:expr, $funct3:expr, $vs1:expr, $widening:expr, $wide:expr) => {
+        $v {
+            vd: $o.vd($o.masked, $widening),
+            vs2: match $wide {
+                true => VECTOR[($o.registers[2] & !1) as usize],
+                false => $o.v(2),
+            },
+            vm: $o.vm(),
+        }
+    };
+    (Vc2, $v:ident, $o:ident, $funct6:expr, $vs1:expr, $distinct:expr) => {{
+        let vd = $o.v(0);
+        $v {
+            vd,
+            vs2: $o.vs(2, vd, $distinct),
+        }
+    }};
+    (Vc3, $v:ident, $o:ident, $funct6:expr, $distinct_vs1:expr, $distinct_vs2:expr) => {{
+        let vd = $o.v(0);
+        $v {
+            vd,
+            vs2: $o.vs(2, vd, $distinct_vs2),
+            vs1: $o.vs(1, vd, $distinct_vs1),
+        }
+    }};
+    (Vci, $v:ident, $o:ident, $funct6:expr, $distinct:expr) => {{
+        let vd = $o.v(0);
+        $v {
+            vd,
+            vs2: $o.vs(2, vd, $distinct),
+            uimm: $o.bits(5),
+        }
+    }};
+    (CLoad, $v:ident, $o:ident, $funct6:expr, $half:expr, $select:expr) => {
+        $v {
+            rd: $o.c(0),
+            rs1: $o.c(1),
+            imm: match $half {
+                true => ($o.bits(1) << 1) as i32,
+                false => $o.bits(2) as i32,
+            },
+        }
+    };
+    (CStore, $v:ident, $o:ident, $funct6:expr, $half:expr, $select:expr) => {
+        $v {
+            rs1: $o.c(1),
+            rs2: $o.c(2),
+            imm: match $half {
+                true => ($o.bits(1) << 1) as i32,
+                false => $o.bits(2) as i32,
+            },
+        }
+    };
+    (CUnary, $v:ident, $o:ident, $rs2:expr) => {
+        $v { rd: $o.c(0) }
+    };
+    (CMul, $v:ident, $o:ident) => {
+        $v {
+            rd: $o.c(0),
+            rs2: $o.c(2),
+        }
+    };
+    (CmPushPop, $v:ident, $o:ident, $funct6:expr, $funct2:expr, $push:expr) => {{
+        let (rlist, stack_adj) = $o.push_pop();
+        $v {
+            rlist,
+            stack_adj: if $push { -stack_adj } else { stack_adj },
+        }
+    }};
+    (CmMv, $v:ident, $o:ident, $funct2:expr, $distinct:expr) => {{
+        let (r1s, r2s) = $o.saved_pair($distinct);
+        $v { r1s, r2s }
+    }};
+    (CmJt, $v:ident, $o:ident, $first:expr, $count:expr) => {
+        $v {
+            index: ($first + $o.imm % $count) as u8,
+        }
+    };
+    (CMop, $v:ident, $o:ident) => {
+        $v {
+            n: $o.bits(3) * 2 + 1,
+        }
+    };
+    (Hint, $v:ident, $o:ident) => {
+        $v {
+            instruction: Box::new(generate_hint($o)),
+        }
+    };
+}
+
+/// Generates one of the HINT encodings of the base ISA, or a `c.nop` with a nonzero immediate.
+fn generate_hint(o: &Operands) -> ParsedInstruction32 {
+    let mut x0 = o.clone();
+    x0.registers[0] = 0;
+    match o.registers[3] % 4 {
+        0 if o.registers[1] == 0 && o.imm12() == 0 => ParsedInstruction32::addi(addi {
+            rd: "x0",
+            rs1: "x0",
+            imm: 1,
+        }),
+        0 => ParsedInstruction32::addi(generate!(I, addi, x0, OP_IMM, 0b000)),
+        1 => ParsedInstruction32::lui(generate!(Upper, lui, x0, LUI)),
+        2 => ParsedInstruction32::fence(fence {
+            pred: o.bits(4),
+            succ: 0,
+        }),
+        _ => ParsedInstruction32::c_nop(c_nop {
+            imm: match (o.imm << 26) as i32 >> 26 {
+                0 => 1,
+                imm => imm,
+            },
+        }),
+    }
+}
+
+macro_rules! encode {
+    (R, $i:ident, $opcode:expr, $funct3:expr, $funct7:expr) => {
+        Encoding::Word(r_type(
+            $opcode,
+            $funct3,
+            $funct7,
+            register($i.rd),
+            register($i.rs1),
+            register($i.rs2),
+        ))
+    };
+    (I, $i:ident, $opcode:expr, $funct3:expr) => {
+        Encoding::Word(i_type(
+            $opcode,
+            $funct3,
+            register($i.rd),
+            register($i.rs1),
+            $i.imm,
+        ))
+    };
+    (Shift, $i:ident, $funct3:expr, $funct7:expr) => {
+        Encoding::Word(r_type(
+            OP_IMM,
+            $funct3,
+            $funct7,
+            register($i.rd),
+            register($i.rs1),
+            $i.shamt as u32,
+        ))
+    };
+    (Load, $i:ident, $funct3:expr) => {
+        Encoding::Word(i_type(
+            LOAD,
+            $funct3,
+            register($i.rd),
+            register($i.rs1),
+            $i.imm,
+        ))
+    };
+    (Store, $i:ident, $funct3:expr) => {
+        Encoding::Word(s_type($funct3, register($i.rs1), register($i.rs2), $i.imm))
+    };
+    (Branch, $i:ident, $funct3:expr) => {
+        Encoding::Word(b_type($funct3, register($i.rs1), register($i.rs2), $i.imm))
+    };
+    (Upper, $i:ident, $opcode:expr) => {
+        Encoding::Word(u_type($opcode, register($i.rd), $i.imm))
+    };
+    (Jal, $i:ident) => {
+        Encoding::Word(j_type(register($i.rd), $i.imm))
+    };
+    (Jalr, $i:ident) => {
+        Encoding::Word(i_type(
+            0x67,
+            0b000,
+            register($i.rd),
+            register($i.rs1),
+            $i.imm,
+        ))
+    };
+    (Fixed, $i:ident, $word:expr) => {
+        Encoding::Word($word)
+    };
+    (Fence, $i:ident) => {
+        Encoding::Word(($i.pred as u32) << 24 | ($i.succ as u32) << 20 | MISC_MEM)
+    };
+    (Csr, $i:ident, $funct3:expr) => {
+        Encoding::Word(i_type(
+            SYSTEM,
+            $funct3,
+            register($i.rd),
+            register($i.rs1),
+            $i.csr as i32,
+        ))
+    };
+    (CsrI, $i:ident, $funct3:expr) => {
+        Encoding::Word(i_type(
+            SYSTEM,
+            $funct3,
+            register($i.rd),
+            $i.uimm as u32,
+            $i.csr as i32,
+        ))
+    };
+    (Fence2, $i:ident, $funct7:expr) => {
+        Encoding::Word(r_type(
+            SYSTEM,
+            0b000,
+            $funct7,
+            0,
+            register($i.rs1),
+            register($i.rs2),
+        ))
+    };
+    (Hlv, $i:ident, $funct7:expr, $rs2:expr) => {
+        Encoding::Word(r_type(
+            SYSTEM,
+            0b100,
+            $funct7,
+            register($i.rd),
+            register($i.rs1),
+            $rs2,
+        ))
+    };
+    (Hsv, $i:ident, $funct7:expr) => {
+        Encoding::Word(r_type(
+            SYSTEM,
+            0b100,
+            $funct7,
+            0,
+            register($i.rs1),
+            register($i.rs2),
+        ))
+    };
+    (Cbo, $i:ident, $imm:expr) => {
+        Encoding::Word(i_type(MISC_MEM, 0b010, 0, register($i.rs1), $imm))
+    };
+    (Prefetch, $i:ident, $rs2:expr) => {
+        Encoding::Word(i_type(OP_IMM, 0b110, 0, register($i.rs1), $i.imm | $rs2))
+    };
+    (MopR, $i:ident) => {
+        Encoding::Word(
+            1 << 31
+                | ($i.n as u32 >> 4 & 1) << 30
+                | ($i.n as u32 >> 2 & 0b11) << 26
+                | 0b0111 << 22
+                | ($i.n as u32 & 0b11) << 20
+                | register($i.rs1) << 15
+                | 0b100 << 12
+                | register($i.rd) << 7
+                | SYSTEM,
+        )
+    };
+    (MopRR, $i:ident) => {
+        Encoding::Word(
+            1 << 31
+                | ($i.n as u32 >> 2 & 1) << 30
+                | ($i.n as u32 & 0b11) << 26
+                | 1 << 25
+                | register($i.rs2) << 20
+                | register($i.rs1) << 15
+                | 0b100 << 12
+                | register($i.rd) << 7
+                | SYSTEM,
+        )
+    };
+    (FpRm3, $i:ident, $funct7:expr, $class:expr) => {
+        Encoding::Word(r_type(
+            OP_FP,
+            rm(&$i.rm),
+            $funct7,
+            register($i.rd),
+            register($i.rs1),
+            register($i.rs2),
+        ))
+    };
+    (FpRm2, $i:ident, $funct7:expr, $rs2:expr, $rd:expr, $rs1:expr) => {
+        Encoding::Word(r_type(
+            OP_FP,
+            rm(&$i.rm),
+            $funct7,
+            register($i.rd),
+            register($i.rs1),
+            $rs2,
+        ))
+    };
+    (Fp3, $i:ident, $funct7:expr, $funct3:expr, $rd:expr, $class:expr) => {
+        Encoding::Word(r_type(
+            OP_FP,
+            $funct3,
+            $funct7,
+            register($i.rd),
+            register($i.rs1),
+            register($i.rs2),
+        ))
+    };
+    (Fp2, $i:ident, $funct7:expr, $funct3:expr, $rs2:expr, $rd:expr, $rs1:expr) => {
+        Encoding::Word(r_type(
+            OP_FP,
+            $funct3,
+            $funct7,
+            register($i.rd),
+            register($i.rs1),
+            $rs2,
+        ))
+    };
+    (Fma, $i:ident, $opcode:expr, $fmt:expr, $class:expr) => {
+        Encoding::Word(r4_type(
+            $opcode,
+            $fmt,
+            rm(&$i.rm),
+            register($i.rd),
+            register($i.rs1),
+            register($i.rs2),
+            register($i.rs3),
+        ))
+    };
+    (Lr, $i:ident) => {
+        Encoding::Word(r_type(
+            0x2f,
+            0b010,
+            0b00010 << 2 | aqrl(&$i.ordering),
+            register($i.rd),
+            register($i.rs1),
+            0,
+        ))
+    };
+    (Amo, $i:ident, $funct5:expr, $funct3:expr, $class:expr) => {
+        Encoding::Word(r_type(
+            0x2f,
+            $funct3,
+            $funct5 << 2 | aqrl(&$i.ordering),
+            register($i.rd),
+            register($i.rs1),
+            register($i.rs2),
+        ))
+    };
+    (Vvv, $i:ident, $funct6:expr, $funct3:expr, $widening:expr) => {
+        Encoding::Word(v_type(
+            $funct6,
+            $funct3,
+            vm(&$i.vm),
+            register($i.vd),
+            register($i.vs1),
+            register($i.vs2),
+        ))
+    };
+    (Vvx, $i:ident, $funct6:expr, $funct3:expr, $widening:expr, $class:expr) => {
+        Encoding::Word(v_type(
+            $funct6,
+            $funct3,
+            vm(&$i.vm),
+            register($i.vd),
+            register($i.rs1),
+            register($i.vs2),
+        ))
+    };
+    (Vvi, $i:ident, $funct6:expr, $widening:expr, $width:expr) => {
+        Encoding::Word(v_type(
+            $funct6 | $i.uimm as u32 >> 5,
+            0b011,
+            vm(&$i.vm),
+            register($i.vd),
+            $i.uimm as u32 & 0x1f,
+            register($i.vs2),
+        ))
+    };
+    (Vunary, $i:ident, $funct6:expr, $funct3:expr, $vs1:expr, $widening:expr, $wide:expr) => {
+        Encoding::Word(v_type(
+            $funct6,
+            $funct3,
+            vm(&$i.vm),
+            register($i.vd),
+            $vs1,
+            register($i.vs2),
+        ))
+    };
+    (Vc2, $i:ident, $funct6:expr, $vs1:expr, $distinct:expr) => {
+        Encoding::Word(v_type(
+            $funct6,
+            0b010,
+            1,
+            register($i.vd),
+            $vs1,
+            register($i.vs2),
+        ))
+    };
+    (Vc3, $i:ident, $funct6:expr, $distinct_vs1:expr, $distinct_vs2:expr) => {
+        Encoding::Word(v_type(
+            $funct6,
+            0b010,
+            1,
+            register($i.vd),
+            register($i.vs1),
+            register($i.vs2),
+        ))
+    };
+    (Vci, $i:ident, $funct6:expr, $distinct:expr) => {
+        Encoding::Word(v_type(
+            $funct6,
+            0b010,
+            1,
+            register($i.vd),
+            $i.uimm as u32,
+            register($i.vs2),
+        ))
+    };
+    (CLoad, $i:ident, $funct6:expr, $half:expr, $select:expr) => {
+        ca(
+            $funct6,
+            compressed($i.rs1),
+            offset($i.imm, $half, $select),
+            compressed($i.rd),
+            0b00,
+        )
+    };
+    (CStore, $i:ident, $funct6:expr, $half:expr, $select:expr) => {
+        ca(
+            $funct6,
+            compressed($i.rs1),
+            offset($i.imm, $half, $select),
+            compressed($i.rs2),
+            0b00,
+        )
+    };
+    (CUnary, $i:ident, $rs2:expr) => {
+        ca(0b100111, compressed($i.rd), 0b11, $rs2, 0b01)
+    };
+    (CMul, $i:ident) => {
+        ca(0b100111, compressed($i.rd), 0b10, compressed($i.rs2), 0b01)
+    };
+    (CmPushPop, $i:ident, $funct6:expr, $funct2:expr, $push:expr) => {
+        push_pop($funct6, $funct2, &$i.rlist, $i.stack_adj)
+    };
+    (CmMv, $i:ident, $funct2:expr, $distinct:expr) => {
+        ca(0b101011, saved($i.r1s), $funct2, saved($i.r2s), 0b10)
+    };
+    (CmJt, $i:ident, $first:expr, $count:expr) => {
+        Encoding::Half((0b101000 << 10 | ($i.index as u32) << 2 | 0b10) as u16)
+    };
+    (CMop, $i:ident) => {
+        Encoding::Half((0b011 << 13 | ($i.n as u32) << 7 | 0b01) as u16)
+    };
+    (Hint, $i:ident) => {
+        encode(&$i.instruction)
+    };
+}
+
+macro_rules! forms {
+    ($($variant:ident: [$($extension:ident),*] $shape:ident($($argument:expr),*);)*) => {
+        /// Every variant of `ParsedInstruction32`, except `custom` and the `c.nop` HINTs, which
+        /// are only decoded wrapped in `hint`.
+        pub static FORMS: &[Form] = &[$(Form {
+            name: stringify!($variant),
+            extensions: &[$(stringify!($extension)),*],
+            generate: |o| ParsedInstruction32::$variant(generate!($shape, $variant, o $(, $argument)*)),
+        },)*];
+
+        /// Encodes an instruction into its 16 or 32 bit word.
+        #[allow(unused_variables)]
+        pub fn encode(instruction: &ParsedInstruction32) -> Encoding {
+            match instruction {
+                $(ParsedInstruction32::$variant(i) => encode!($shape, i $(, $argument)*),)*
+                ParsedInstruction32::c_nop(i) => Encoding::Half(
+                    ((i.imm as u32 >> 5 & 1) << 12 | (i.imm as u32 & 0x1f) << 2 | 0b01) as u16,
+                ),
+                ParsedInstruction32::custom(_) => panic!("custom instructions have no encoding"),
+            }
+        }
+    };
+}
+
+forms! {
+    // RV32I
+    lui: [] Upper(LUI);
+    auipc: [] Upper(AUIPC);
+    jal: [] Jal();
+    jalr: [] Jalr();
+    beq: [] Branch(0b000);
+    bne: [] Branch(0b001);
+    blt: [] Branch(0b100);
+    bge: [] Branch(0b101);
+    bltu: [] Branch(0b110);
+    bgeu: [] Branch(0b111);
+    lb: [] Load(0b000);
+    lh: [] Load(0b001);
+    lw: [] Load(0b010);
+    lbu: [] Load(0b100);
+    lhu: [] Load(0b101);
+    sb: [] Store(0b000);
+    sh: [] Store(0b001);
+    sw: [] Store(0b010);
+    addi: [] I(OP_IMM, 0b000);
+    slti: [] I(OP_IMM, 0b010);
+    sltiu: [] I(OP_IMM, 0b011);
+    xori: [] I(OP_IMM, 0b100);
+    ori: [] I(OP_IMM, 0b110);
+    andi: [] I(OP_IMM, 0b111);
+    slli: [] Shift(0b001, 0b0000000);
+    srli: [] Shift(0b101, 0b0000000);
+    srai: [] Shift(0b101, 0b0100000);
+    add: [] R(OP, 0b000, 0b0000000);
+    sub: [] R(OP, 0b000, 0b0100000);
+    sll: [] R(OP, 0b001, 0b0000000);
+    slt: [] R(OP, 0b010, 0b0000000);
+    sltu: [] R(OP, 0b011, 0b0000000);
+    xor: [] R(OP, 0b100, 0b0000000);
+    srl: [] R(OP, 0b101, 0b0000000);
+    sra: [] R(OP, 0b101, 0b0100000);
+    or: [] R(OP, 0b110, 0b0000000);
+    and: [] R(OP, 0b111, 0b0000000);
+    fence: [] Fence();
+    fence_tso: [] Fixed(0x8330000f);
+    fence_i: [] Fixed(0x0000100f);
+    ecall: [] Fixed(0x00000073);
+    ebreak: [] Fixed(0x00100073);
+    csrrw: [] Csr(0b001);
+    csrrs: [] Csr(0b010);
+    csrrc: [] Csr(0b011);
+    csrrwi: [] CsrI(0b101);
+    csrrsi: [] CsrI(0b110);
+    csrrci: [] CsrI(0b111);
+
+    // Privileged
+    sret: [] Fixed(0x10200073);
+    mret: [] Fixed(0x30200073);
+    wfi: [] Fixed(0x10500073);
+    sfence_vma: [] Fence2(0b0001001);
+    mnret: [smrnmi] Fixed(0x70200073);
+    sinval_vma: [svinval] Fence2(0b0001011);
+    sfence_w_inval: [svinval] Fixed(0x18000073);
+    sfence_inval_ir: [svinval] Fixed(0x18100073);
+    hinval_vvma: [svinval, h] Fence2(0b0010011);
+    hinval_gvma: [svinval, h] Fence2(0b0110011);
+    hfence_vvma: [h] Fence2(0b0010001);
+    hfence_gvma: [h] Fence2(0b0110001);
+    hlv_b: [h] Hlv(0b0110000, 0b00000);
+    hlv_bu: [h] Hlv(0b0110000, 0b00001);
+    hlv_h: [h] Hlv(0b0110010, 0b00000);
+    hlv_hu: [h] Hlv(0b0110010, 0b00001);
+    hlvx_hu: [h] Hlv(0b0110010, 0b00011);
+    hlv_w: [h] Hlv(0b0110100, 0b00000);
+    hlvx_wu: [h] Hlv(0b0110100, 0b00011);
+    hsv_b: [h] Hsv(0b0110001);
+    hsv_h: [h] Hsv(0b0110011);
+    hsv_w: [h] Hsv(0b0110101);
+
+    // Zicbom, Zicboz, Zicbop, Zicond, Zawrs and Zimop
+    cbo_inval: [zicbom] Cbo(0b000);
+    cbo_clean: [zicbom] Cbo(0b001);
+    cbo_flush: [zicbom] Cbo(0b010);
+    cbo_zero: [zicboz] Cbo(0b100);
+    prefetch_i: [zicbop] Prefetch(0b00000);
+    prefetch_r: [zicbop] Prefetch(0b00001);
+    prefetch_w: [zicbop] Prefetch(0b00011);
+    czero_eqz: [zicond] R(OP, 0b101, 0b0000111);
+    czero_nez: [zicond] R(OP, 0b111, 0b0000111);
+    wrs_nto: [zawrs] Fixed(0x00d00073);
+    wrs_sto: [zawrs] Fixed(0x01d00073);
+    mop_r: [zimop] MopR();
+    mop_rr: [zimop] MopRR();
+
+    // Zfinx
+    fadd_s: [zfinx] FpRm3(0b0000000, Int);
+    fsub_s: [zfinx] FpRm3(0b0000100, Int);
+    fmul_s: [zfinx] FpRm3(0b0001000, Int);
+    fdiv_s: [zfinx] FpRm3(0b0001100, Int);
+    fsqrt_s: [zfinx] FpRm2(0b0101100, 0b00000, Int, Int);
+    fsgnj_s: [zfinx] Fp3(0b0010000, 0b000, Int, Int);
+    fsgnjn_s: [zfinx] Fp3(0b0010000, 0b001, Int, Int);
+    fsgnjx_s: [zfinx] Fp3(0b0010000, 0b010, Int, Int);
+    fmin_s: [zfinx] Fp3(0b0010100, 0b000, Int, Int);
+    fmax_s: [zfinx] Fp3(0b0010100, 0b001, Int, Int);
+    feq_s: [zfinx] Fp3(0b1010000, 0b010, Int, Int);
+    flt_s: [zfinx] Fp3(0b1010000, 0b001, Int, Int);
+    fle_s: [zfinx] Fp3(0b1010000, 0b000, Int, Int);
+    fclass_s: [zfinx] Fp2(0b1110000, 0b001, 0b00000, Int, Int);
+    fcvt_w_s: [zfinx] FpRm2(0b1100000, 0b00000, Int, Int);
+    fcvt_wu_s: [zfinx] FpRm2(0b1100000, 0b00001, Int, Int);
+    fcvt_s_w: [zfinx] FpRm2(0b1101000, 0b00000, Int, Int);
+    fcvt_s_wu: [zfinx] FpRm2(0b1101000, 0b00001, Int, Int);
+    fmadd_s: [zfinx] Fma(MADD, 0b00, Int);
+    fmsub_s: [zfinx] Fma(MSUB, 0b00, Int);
+    fnmsub_s: [zfinx] Fma(NMSUB, 0b00, Int);
+    fnmadd_s: [zfinx] Fma(NMADD, 0b00, Int);
+
+    // Zdinx
+    fadd_d: [zdinx] FpRm3(0b0000001, Pair);
+    fsub_d: [zdinx] FpRm3(0b0000101, Pair);
+    fmul_d: [zdinx] FpRm3(0b0001001, Pair);
+    fdiv_d: [zdinx] FpRm3(0b0001101, Pair);
+    fsqrt_d: [zdinx] FpRm2(0b0101101, 0b00000, Pair, Pair);
+    fsgnj_d: [zdinx] Fp3(0b0010001, 0b000, Pair, Pair);
+    fsgnjn_d: [zdinx] Fp3(0b0010001, 0b001, Pair, Pair);
+    fsgnjx_d: [zdinx] Fp3(0b0010001, 0b010, Pair, Pair);
+    fmin_d: [zdinx] Fp3(0b0010101, 0b000, Pair, Pair);
+    fmax_d: [zdinx] Fp3(0b0010101, 0b001, Pair, Pair);
+    feq_d: [zdinx] Fp3(0b1010001, 0b010, Int, Pair);
+    flt_d: [zdinx] Fp3(0b1010001, 0b001, Int, Pair);
+    fle_d: [zdinx] Fp3(0b1010001, 0b000, Int, Pair);
+    fclass_d: [zdinx] Fp2(0b1110001, 0b001, 0b00000, Int, Pair);
+    fcvt_w_d: [zdinx] FpRm2(0b1100001, 0b00000, Int, Pair);
+    fcvt_wu_d: [zdinx] FpRm2(0b1100001, 0b00001, Int, Pair);
+    fcvt_d_w: [zdinx] FpRm2(0b1101001, 0b00000, Pair, Int);
+    fcvt_d_wu: [zdinx] FpRm2(0b1101001, 0b00001, Pair, Int);
+    fmadd_d: [zdinx] Fma(MADD, 0b01, Pair);
+    fmsub_d: [zdinx] Fma(MSUB, 0b01, Pair);
+    fnmsub_d: [zdinx] Fma(NMSUB, 0b01, Pair);
+    fnmadd_d: [zdinx] Fma(NMADD, 0b01, Pair);
+    fcvt_s_d: [zdinx] FpRm2(0b0100000, 0b00001, Int, Pair);
+    fcvt_d_s: [zdinx] FpRm2(0b0100001, 0b00000, Pair, Int);
+
+    // Zhinx
+    fadd_h: [zhinx] FpRm3(0b0000010, Int);
+    fsub_h: [zhinx] FpRm3(0b0000110, Int);
+    fmul_h: [zhinx] FpRm3(0b0001010, Int);
+    fdiv_h: [zhinx] FpRm3(0b0001110, Int);
+    fsqrt_h: [zhinx] FpRm2(0b0101110, 0b00000, Int, Int);
+    fsgnj_h: [zhinx] Fp3(0b0010010, 0b000, Int, Int);
+    fsgnjn_h: [zhinx] Fp3(0b0010010, 0b001, Int, Int);
+    fsgnjx_h: [zhinx] Fp3(0b0010010, 0b010, Int, Int);
+    fmin_h: [zhinx] Fp3(0b0010110, 0b000, Int, Int);
+    fmax_h: [zhinx] Fp3(0b0010110, 0b001, Int, Int);
+    feq_h: [zhinx] Fp3(0b1010010, 0b010, Int, Int);
+    flt_h: [zhinx] Fp3(0b1010010, 0b001, Int, Int);
+    fle_h: [zhinx] Fp3(0b1010010, 0b000, Int, Int);
+    fclass_h: [zhinx] Fp2(0b1110010, 0b001, 0b00000, Int, Int);
+    fcvt_w_h: [zhinx] FpRm2(0b1100010, 0b00000, Int, Int);
+    fcvt_wu_h: [zhinx] FpRm2(0b1100010, 0b00001, Int, Int);
+    fcvt_h_w: [zhinx] FpRm2(0b1101010, 0b00000, Int, Int);
+    fcvt_h_wu: [zhinx] FpRm2(0b1101010, 0b00001, Int, Int);
+    fmadd_h: [zhinx] Fma(MADD, 0b10, Int);
+    fmsub_h: [zhinx] Fma(MSUB, 0b10, Int);
+    fnmsub_h: [zhinx] Fma(NMSUB, 0b10, Int);
+    fnmadd_h: [zhinx] Fma(NMADD, 0b10, Int);
+    fcvt_s_h: [zhinx] FpRm2(0b0100000, 0b00010, Int, Int);
+    fcvt_h_s: [zhinx] FpRm2(0b0100010, 0b00000, Int, Int);
+    fcvt_d_h: [zdinx, zhinx] FpRm2(0b0100001, 0b00010, Pair, Int);
+    fcvt_h_d: [zdinx, zhinx] FpRm2(0b0100010, 0b00001, Int, Pair);
+
+    // Zfbfmin
+    fcvt_bf16_s: [zfbfmin] FpRm2(0b0100010, 0b01000, Float, Float);
+    fcvt_s_bf16: [zfbfmin] FpRm2(0b0100000, 0b00110, Float, Float);
+
+    // Zalrsc, Zaamo, Zabha and Zacas
+    lr_w: [zalrsc] Lr();
+    sc_w: [zalrsc] Amo(0b00011, 0b010, Int);
+    amoswap_w: [zaamo] Amo(0b00001, 0b010, Int);
+    amoadd_w: [zaamo] Amo(0b00000, 0b010, Int);
+    amoxor_w: [zaamo] Amo(0b00100, 0b010, Int);
+    amoand_w: [zaamo] Amo(0b01100, 0b010, Int);
+    amoor_w: [zaamo] Amo(0b01000, 0b010, Int);
+    amomin_w: [zaamo] Amo(0b10000, 0b010, Int);
+    amomax_w: [zaamo] Amo(0b10100, 0b010, Int);
+    amominu_w: [zaamo] Amo(0b11000, 0b010, Int);
+    amomaxu_w: [zaamo] Amo(0b11100, 0b010, Int);
+    amoswap_b: [zaamo, zabha] Amo(0b00001, 0b000, Int);
+    amoadd_b: [zaamo, zabha] Amo(0b00000, 0b000, Int);
+    amoxor_b: [zaamo, zabha] Amo(0b00100, 0b000, Int);
+    amoand_b: [zaamo, zabha] Amo(0b01100, 0b000, Int);
+    amoor_b: [zaamo, zabha] Amo(0b01000, 0b000, Int);
+    amomin_b: [zaamo, zabha] Amo(0b10000, 0b000, Int);
+    amomax_b: [zaamo, zabha] Amo(0b10100, 0b000, Int);
+    amominu_b: [zaamo, zabha] Amo(0b11000, 0b000, Int);
+    amomaxu_b: [zaamo, zabha] Amo(0b11100, 0b000, Int);
+    amoswap_h: [zaamo, zabha] Amo(0b00001, 0b001, Int);
+    amoadd_h: [zaamo, zabha] Amo(0b00000, 0b001, Int);
+    amoxor_h: [zaamo, zabha] Amo(0b00100, 0b001, Int);
+    amoand_h: [zaamo, zabha] Amo(0b01100, 0b001, Int);
+    amoor_h: [zaamo, zabha] Amo(0b01000, 0b001, Int);
+    amomin_h: [zaamo, zabha] Amo(0b10000, 0b001, Int);
+    amomax_h: [zaamo, zabha] Amo(0b10100, 0b001, Int);
+    amominu_h: [zaamo, zabha] Amo(0b11000, 0b001, Int);
+    amomaxu_h: [zaamo, zabha] Amo(0b11100, 0b001, Int);
+    amocas_w: [zacas] Amo(0b00101, 0b010, Int);
+    amocas_d: [zacas] Amo(0b00101, 0b011, Pair);
+    amocas_b: [zacas, zabha] Amo(0b00101, 0b000, Int);
+    amocas_h: [zacas, zabha] Amo(0b00101, 0b001, Int);
+
+    // Zcb, Zcmp, Zcmt and Zcmop
+    c_lbu: [zcb] CLoad(0b100000, false, 0);
+    c_lhu: [zcb] CLoad(0b100001, true, 0);
+    c_lh: [zcb] CLoad(0b100001, true, 1);
+    c_sb: [zcb] CStore(0b100010, false, 0);
+    c_sh: [zcb] CStore(0b100011, true, 0);
+    c_zext_b: [zcb] CUnary(0b000);
+    c_sext_b: [zcb] CUnary(0b001);
+    c_zext_h: [zcb] CUnary(0b010);
+    c_sext_h: [zcb] CUnary(0b011);
+    c_not: [zcb] CUnary(0b101);
+    c_mul: [zcb] CMul();
+    cm_push: [zcmp] CmPushPop(0b101110, 0b00, true);
+    cm_pop: [zcmp] CmPushPop(0b101110, 0b10, false);
+    cm_popretz: [zcmp] CmPushPop(0b101111, 0b00, false);
+    cm_popret: [zcmp] CmPushPop(0b101111, 0b10, false);
+    cm_mvsa01: [zcmp] CmMv(0b01, true);
+    cm_mva01s: [zcmp] CmMv(0b11, false);
+    cm_jt: [zcmt] CmJt(0, 32);
+    cm_jalt: [zcmt] CmJt(32, 224);
+    c_mop: [zcmop] CMop();
+
+    // HINTs
+    hint: [hints] Hint();
+
+    // Zvbb and Zvbc
+    vandn_vv: [zvbb] Vvv(0b000001, 0b000, false);
+    vandn_vx: [zvbb] Vvx(0b000001, 0b100, false, Int);
+    vbrev_v: [zvbb] Vunary(0b010010, 0b010, 0b01010, false, false);
+    vbrev8_v: [zvbb] Vunary(0b010010, 0b010, 0b01000, false, false);
+    vrev8_v: [zvbb] Vunary(0b010010, 0b010, 0b01001, false, false);
+    vclz_v: [zvbb] Vunary(0b010010, 0b010, 0b01100, false, false);
+    vctz_v: [zvbb] Vunary(0b010010, 0b010, 0b01101, false, false);
+    vcpop_v: [zvbb] Vunary(0b010010, 0b010, 0b01110, false, false);
+    vrol_vv: [zvbb] Vvv(0b010101, 0b000, false);
+    vrol_vx: [zvbb] Vvx(0b010101, 0b100, false, Int);
+    vror_vv: [zvbb] Vvv(0b010100, 0b000, false);
+    vror_vx: [zvbb] Vvx(0b010100, 0b100, false, Int);
+    vror_vi: [zvbb] Vvi(0b010100, false, 6);
+    vwsll_vv: [zvbb] Vvv(0b110101, 0b000, true);
+    vwsll_vx: [zvbb] Vvx(0b110101, 0b100, true, Int);
+    vwsll_vi: [zvbb] Vvi(0b110101, true, 5);
+    vclmul_vv: [zvbc] Vvv(0b001100, 0b010, false);
+    vclmul_vx: [zvbc] Vvx(0b001100, 0b110, false, Int);
+    vclmulh_vv: [zvbc] Vvv(0b001101, 0b010, false);
+    vclmulh_vx: [zvbc] Vvx(0b001101, 0b110, false, Int);
+
+    // Vector cryptography
+    vaesef_vv: [zvkned] Vc2(0b101000, 0b00011, false);
+    vaesef_vs: [zvkned] Vc2(0b101001, 0b00011, true);
+    vaesem_vv: [zvkned] Vc2(0b101000, 0b00010, false);
+    vaesem_vs: [zvkned] Vc2(0b101001, 0b00010, true);
+    vaesdf_vv: [zvkned] Vc2(0b101000, 0b00001, false);
+    vaesdf_vs: [zvkned] Vc2(0b101001, 0b00001, true);
+    vaesdm_vv: [zvkned] Vc2(0b101000, 0b00000, false);
+    vaesdm_vs: [zvkned] Vc2(0b101001, 0b00000, true);
+    vaesz_vs: [zvkned] Vc2(0b101001, 0b00111, true);
+    vaeskf1_vi: [zvkned] Vci(0b100010, false);
+    vaeskf2_vi: [zvkned] Vci(0b101010, false);
+    vsha2ms_vv: [zvknha] Vc3(0b101101, true, true);
+    vsha2ch_vv: [zvknha] Vc3(0b101110, true, true);
+    vsha2cl_vv: [zvknha] Vc3(0b101111, true, true);
+    vsm4k_vi: [zvksed] Vci(0b100001, false);
+    vsm4r_vv: [zvksed] Vc2(0b101000, 0b10000, false);
+    vsm4r_vs: [zvksed] Vc2(0b101001, 0b10000, true);
+    vsm3me_vv: [zvksh] Vc3(0b100000, false, true);
+    vsm3c_vi: [zvksh] Vci(0b101011, true);
+    vghsh_vv: [zvkg] Vc3(0b101100, false, false);
+    vgmul_vv: [zvkg] Vc2(0b101000, 0b10001, false);
+
+    // Zvfbfmin and Zvfbfwma
+    vfncvtbf16_f_f_w: [zvfbfmin] Vunary(0b010010, 0b001, 0b11101, false, true);
+    vfwcvtbf16_f_f_v: [zvfbfmin] Vunary(0b010010, 0b001, 0b01101, true, false);
+    vfwmaccbf16_vv: [zvfbfwma] Vvv(0b111011, 0b001, true);
+    vfwmaccbf16_vf: [zvfbfwma] Vvx(0b111011, 0b101, true, Float);
+}
@@ -0,0 +1,375 @@
+//! An independent encoder and a random instruction generator for the round-trip property tests.
+//! `forms.rs` lists every variant of `ParsedInstruction32` once, together with the extensions
+//! it needs and the shape of its encoding.
+
+mod forms;
+
+pub use forms::{encode, FORMS};
+
+use proptest::prelude::*;
+use risc_v_disassembler::parsed_instructions::{
+    MemoryOrdering, RegisterList, RoundingMode, VectorMask,
+};
+use risc_v_disassembler::{Extensions, ParsedInstruction32};
+
+pub const NUMBERED: [&str; 32] = [
+    "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12", "x13", "x14",
+    "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24", "x25", "x26", "x27",
+    "x28", "x29", "x30", "x31",
+];
+
+pub const ABI: [&str; 32] = [
+    "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4",
+    "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4",
+    "t5", "t6",
+];
+
+pub const FLOAT: [&str; 32] = [
+    "f0", "f1", "f2", "f3", "f4", "f5", "f6", "f7", "f8", "f9", "f10", "f11", "f12", "f13", "f14",
+    "f15", "f16", "f17", "f18", "f19", "f20", "f21", "f22", "f23", "f24", "f25", "f26", "f27",
+    "f28", "f29", "f30", "f31",
+];
+
+pub const FLOAT_ABI: [&str; 32] = [
+    "ft0", "ft1", "ft2", "ft3", "ft4", "ft5", "ft6", "ft7", "fs0", "fs1", "fa0", "fa1", "fa2",
+    "fa3", "fa4", "fa5", "fa6", "fa7", "fs2", "fs3", "fs4", "fs5", "fs6", "fs7", "fs8", "fs9",
+    "fs10", "fs11", "ft8", "ft9", "ft10", "ft11",
+];
+
+pub const VECTOR: [&str; 32] = [
+    "v0", "v1", "v2", "v3", "v4", "v5", "v6", "v7", "v8", "v9", "v10", "v11", "v12", "v13", "v14",
+    "v15", "v16", "v17", "v18", "v19", "v20", "v21", "v22", "v23", "v24", "v25", "v26", "v27",
+    "v28", "v29", "v30", "v31",
+];
+
+/// Returns the number of a register given by any of its names.
+pub fn register(name: &str) -> u32 {
+    [&NUMBERED, &ABI, &FLOAT, &FLOAT_ABI, &VECTOR]
+        .iter()
+        .find_map(|names| names.iter().position(|n| *n == name))
+        .unwrap_or_else(|| panic!("unknown register {}", name)) as u32
+}
+
+/// Returns the three bit field of a compressed register, one of x8 to x15.
+pub fn compressed(name: &str) -> u32 {
+    register(name) - 8
+}
+
+/// Returns the index N of saved register `sN`.
+pub fn saved(name: &str) -> u32 {
+    match register(name) {
+        n @ (8 | 9) => n - 8,
+        n => n - 16,
+    }
+}
+
+/// Returns the register number of saved register `sN`.
+fn saved_register(n: u8) -> u8 {
+    match n {
+        0 | 1 => n + 8,
+        _ => n + 16,
+    }
+}
+
+/// A 16 or 32 bit instruction encoding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Encoding {
+    Half(u16),
+    Word(u32),
+}
+
+impl Encoding {
+    pub fn bytes(self, big_endian: bool) -> Vec<u8> {
+        match (self, big_endian) {
+            (Encoding::Half(half), false) => half.to_le_bytes().to_vec(),
+            (Encoding::Half(half), true) => half.to_be_bytes().to_vec(),
+            (Encoding::Word(word), false) => word.to_le_bytes().to_vec(),
+            (Encoding::Word(word), true) => word.to_be_bytes().to_vec(),
+        }
+    }
+}
+
+/// An instruction of one variant, with the extensions it is decoded under.
+pub struct Form {
+    pub name: &'static str,
+    pub extensions: &'static [&'static str],
+    pub generate: fn(&Operands) -> ParsedInstruction32,
+}
+
+impl Form {
+    pub fn extensions(&self) -> Extensions {
+        let mut extensions = Extensions::default();
+        for extension in self.extensions {
+            let enabled = match *extension {
+                "zfinx" => &mut extensions.zfinx,
+                "zdinx" => &mut extensions.zdinx,
+                "zhinx" => &mut extensions.zhinx,
+                "svinval" => &mut extensions.svinval,
+                "smrnmi" => &mut extensions.smrnmi,
+                "h" => &mut extensions.h,
+                "zicbom" => &mut extensions.zicbom,
+                "zicboz" => &mut extensions.zicboz,
+                "zicbop" => &mut extensions.zicbop,
+                "zicond" => &mut extensions.zicond,
+                "zawrs" => &mut extensions.zawrs,
+                "zalrsc" => &mut extensions.zalrsc,
+                "zaamo" => &mut extensions.zaamo,
+                "zabha" => &mut extensions.zabha,
+                "zacas" => &mut extensions.zacas,
+                "zcb" => &mut extensions.zcb,
+                "zcmp" => &mut extensions.zcmp,
+                "zcmt" => &mut extensions.zcmt,
+                "zimop" => &mut extensions.zimop,
+                "zcmop" => &mut extensions.zcmop,
+                "hints" => &mut extensions.hints,
+                "zvbb" => &mut extensions.zvbb,
+                "zvbc" => &mut extensions.zvbc,
+                "zvkned" => &mut extensions.zvkned,
+                "zvknha" => &mut extensions.zvknha,
+                "zvknhb" => &mut extensions.zvknhb,
+                "zvksed" => &mut extensions.zvksed,
+                "zvksh" => &mut extensions.zvksh,
+                "zvkg" => &mut extensions.zvkg,
+                "zfbfmin" => &mut extensions.zfbfmin,
+                "zvfbfmin" => &mut extensions.zvfbfmin,
+                "zvfbfwma" => &mut extensions.zvfbfwma,
+                _ => panic!("unknown extension {}", extension),
+            };
+            *enabled = true;
+        }
+        extensions
+    }
+}
+
+/// The kind of register an operand names.
+#[derive(Debug, Clone, Copy)]
+pub enum Class {
+    /// Any integer register.
+    Int,
+    /// An even integer register, holding a double in Zdinx or the pair of `amocas.d`.
+    Pair,
+    /// A floating-point register.
+    Float,
+}
+
+/// Random operand values, which each form maps onto the valid operands of its instruction.
+#[derive(Debug, Clone)]
+pub struct Operands {
+    pub registers: [u8; 4],
+    pub imm: u32,
+    pub rm: u8,
+    pub aqrl: u8,
+    pub masked: bool,
+}
+
+pub fn operands() -> impl Strategy<Value = Operands> {
+    (
+        prop::array::uniform4(0u8..32),
+        any::<u32>(),
+        0u8..6,
+        0u8..4,
+        any::<bool>(),
+    )
+        .prop_map(|(registers, imm, rm, aqrl, masked)| Operands {
+            registers,
+            imm,
+            rm,
+            aqrl,
+            masked,
+        })
+}
+
+impl Operands {
+    pub fn x(&self, k: usize) -> &'static str {
+        NUMBERED[self.registers[k] as usize]
+    }
+
+    pub fn reg(&self, class: Class, k: usize) -> &'static str {
+        let n = self.registers[k] as usize;
+        match class {
+            Class::Int => NUMBERED[n],
+            Class::Pair => NUMBERED[n & !1],
+            Class::Float => FLOAT[n],
+        }
+    }
+
+    /// Returns a compressed register, one of x8 to x15.
+    pub fn c(&self, k: usize) -> &'static str {
+        NUMBERED[8 + (self.registers[k] & 7) as usize]
+    }
+
+    pub fn bits(&self, width: u32) -> u8 {
+        (self.imm & ((1 << width) - 1)) as u8
+    }
+
+    pub fn imm12(&self) -> i32 {
+        (self.imm << 20) as i32 >> 20
+    }
+
+    pub fn bimm(&self) -> i32 {
+        ((self.imm << 19) as i32 >> 19) & !1
+    }
+
+    pub fn jimm(&self) -> i32 {
+        ((self.imm << 11) as i32 >> 11) & !1
+    }
+
+    pub fn imm20(&self) -> i32 {
+        (self.imm & 0xfffff000) as i32
+    }
+
+    pub fn rm(&self) -> RoundingMode {
+        match self.rm {
+            0 => RoundingMode::Rne,
+            1 => RoundingMode::Rtz,
+            2 => RoundingMode::Rdn,
+            3 => RoundingMode::Rup,
+            4 => RoundingMode::Rmm,
+            _ => RoundingMode::Dyn,
+        }
+    }
+
+    pub fn ordering(&self) -> MemoryOrdering {
+        match self.aqrl {
+            0b00 => MemoryOrdering::Relaxed,
+            0b10 => MemoryOrdering::Acquire,
+            0b01 => MemoryOrdering::Release,
+            _ => MemoryOrdering::AcquireRelease,
+        }
+    }
+
+    pub fn vm(&self) -> VectorMask {
+        match self.masked {
+            true => VectorMask::Masked,
+            false => VectorMask::Unmasked,
+        }
+    }
+
+    /// Returns a destination vector register, aligned to two registers when it is widened.
+    /// Masked instructions cannot write v0, which holds the mask.
+    pub fn vd(&self, masked: bool, widening: bool) -> &'static str {
+        let step = if widening { 2 } else { 1 };
+        let vd = self.registers[0] / step * step;
+        VECTOR[if masked && vd == 0 { step } else { vd } as usize]
+    }
+
+    /// Returns a vector source register, distinct from `vd` when `distinct` is set.
+    pub fn vs(&self, k: usize, vd: &str, distinct: bool) -> &'static str {
+        let vs = self.registers[k] as u32;
+        let vd = register(vd);
+        match distinct && vs == vd {
+            true => VECTOR[((vd + 1) % 32) as usize],
+            false => VECTOR[vs as usize],
+        }
+    }
+
+    pub fn v(&self, k: usize) -> &'static str {
+        VECTOR[self.registers[k] as usize]
+    }
+
+    /// Returns the register list and stack adjustment of a Zcmp push or pop.
+    pub fn push_pop(&self) -> (RegisterList, i32) {
+        let rlist = 4 + self.registers[0] % 12;
+        let spimm = self.bits(2) as i32;
+        let saved = if rlist == 15 { 12 } else { rlist - 4 };
+        let ranges = match saved {
+            0 => vec![],
+            1 | 2 => vec![("x8", NUMBERED[saved_register(saved - 1) as usize])],
+            _ => vec![
+                ("x8", "x9"),
+                ("x18", NUMBERED[saved_register(saved - 1) as usize]),
+            ],
+        };
+        let registers = saved as i32 + 1;
+        let stack_adj = (registers * 4 + 15) / 16 * 16 + spimm * 16;
+        (RegisterList { ra: "x1", ranges }, stack_adj)
+    }
+
+    /// Returns two saved registers among s0 to s7, which differ when `distinct` is set.
+    pub fn saved_pair(&self, distinct: bool) -> (&'static str, &'static str) {
+        let r1s = self.registers[0] & 7;
+        let mut r2s = self.registers[1] & 7;
+        if distinct && r1s == r2s {
+            r2s = (r2s + 1) % 8;
+        }
+        (
+            NUMBERED[saved_register(r1s) as usize],
+            NUMBERED[saved_register(r2s) as usize],
+        )
+    }
+}
+
+pub fn rm(rm: &RoundingMode) -> u32 {
+    match rm {
+        RoundingMode::Rne => 0b000,
+        RoundingMode::Rtz => 0b001,
+        RoundingMode::Rdn => 0b010,
+        RoundingMode::Rup => 0b011,
+        RoundingMode::Rmm => 0b100,
+        RoundingMode::Dyn => 0b111,
+    }
+}
+
+pub fn aqrl(ordering: &MemoryOrdering) -> u32 {
+    match ordering {
+        MemoryOrdering::Relaxed => 0b00,
+        MemoryOrdering::Acquire => 0b10,
+        MemoryOrdering::Release => 0b01,
+        MemoryOrdering::AcquireRelease => 0b11,
+    }
+}
+
+pub fn vm(vm: &VectorMask) -> u32 {
+    match vm {
+        VectorMask::Masked => 0,
+        VectorMask::Unmasked => 1,
+    }
+}
+
+pub fn r_type(opcode: u32, funct3: u32, funct7: u32, rd: u32, rs1: u32, rs2: u32) -> u32 {
+    funct7 << 25 | rs2 << 20 | rs1 << 15 | funct3 << 12 | rd << 7 | opcode
+}
+
+pub fn i_type(opcode: u32, funct3: u32, rd: u32, rs1: u32, imm: i32) -> u32 {
+    (imm as u32 & 0xfff) << 20 | rs1 << 15 | funct3 << 12 | rd << 7 | opcode
+}
+
+pub fn s_type(funct3: u32, rs1: u32, rs2: u32, imm: i32) -> u32 {
+    let imm = imm as u32;
+    (imm >> 5 & 0x7f) << 25 | rs2 << 20 | rs1 << 15 | funct3 << 12 | (imm & 0x1f) << 7 | 0x23
+}
+
+pub fn b_type(funct3: u32, rs1: u32, rs2: u32, imm: i32) -> u32 {
+    let imm = imm as u32;
+    (imm >> 12 & 1) << 31
+        | (imm >> 5 & 0x3f) << 25
+        | rs2 << 20
+        | rs1 << 15
+        | funct3 << 12
+        | (imm >> 1 & 0xf) << 8
+        | (imm >> 11 & 1) << 7
+        | 0x63
+}
+
+pub fn u_type(opcode: u32, rd: u32, imm: i32) -> u32 {
+    imm as u32 & 0xfffff000 | rd << 7 | opcode
+}
+
+pub fn j_type(rd: u32, imm: i32) -> u32 {
+    let imm = imm as u32;
+    (imm >> 20 & 1) << 31
+        | (imm >> 1 & 0x3ff) << 21
+        | (imm >> 11 & 1) << 20
+        | (imm >> 12 & 0xff) << 12
+        | rd << 7
+        | 0x6f
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn r4_type(opcode: u32, fmt: u32, rm: u32, rd: u32, rs1: u32, rs2: u32, rs3: u32) -> u32 {
+    rs3 << 27 | fmt << 25 | rs2 << 20 | rs1 << 15 | rm << 12 | rd << 7 | opcode
+}
+
+pub fn v_type(funct6: u32, funct3: u32, vm: u32, vd: u32, vs1: u32, vs2: u32) -> u32 {
+    r_type(0x57, funct3, funct6 << 1 | vm, vd, vs1, vs2)
+}
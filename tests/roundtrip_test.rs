@@ -0,0 +1,136 @@
+mod roundtrip;
+
+#[cfg(test)]
+mod tests {
+    use super::roundtrip::{encode, operands, Encoding, FORMS};
+    use proptest::prelude::*;
+    use risc_v_disassembler::{
+        parse, parse_with_extensions, DisassemblerError, Extensions, Lmul, ParsedInstruction32,
+    };
+
+    fn decode(
+        encoding: Encoding,
+        big_endian: bool,
+        abi: bool,
+        extensions: &Extensions,
+    ) -> Result<ParsedInstruction32, DisassemblerError> {
+        let bytes = encoding.bytes(big_endian);
+        match *extensions == Extensions::default() {
+            true => parse(&bytes, big_endian, abi),
+            false => parse_with_extensions(&bytes, big_endian, abi, extensions),
+        }
+    }
+
+    /// Returns the extension sets random words are decoded under: none, every extension with
+    /// and without HINTs, and every extension with register groups of two vector registers.
+    fn configurations() -> Vec<Extensions> {
+        let all = Extensions {
+            zfinx: true,
+            zdinx: true,
+            zhinx: true,
+            svinval: true,
+            smrnmi: true,
+            h: true,
+            zicbom: true,
+            zicboz: true,
+            zicbop: true,
+            zicond: true,
+            zawrs: true,
+            zalrsc: true,
+            zaamo: true,
+            zabha: true,
+            zacas: true,
+            zcb: true,
+            zcmp: true,
+            zcmt: true,
+            zimop: true,
+            zcmop: true,
+            zvbb: true,
+            zvbc: true,
+            zvkned: true,
+            zvknhb: true,
+            zvksed: true,
+            zvksh: true,
+            zvkg: true,
+            zfbfmin: true,
+            zvfbfmin: true,
+            zvfbfwma: true,
+            ..Default::default()
+        };
+        vec![
+            Extensions::default(),
+            all,
+            Extensions { hints: true, ..all },
+            Extensions {
+                lmul: Lmul::M2,
+                ..all
+            },
+        ]
+    }
+
+    /// Checks that a decoded word encodes back into a word that decodes to the same
+    /// instruction. The words themselves may differ, as fields such as the `rd` of `fence.i`
+    /// are ignored.
+    fn check_reencoding(encoding: Encoding, extensions: &Extensions) -> Result<(), TestCaseError> {
+        for big_endian in [false, true] {
+            for abi in [false, true] {
+                if let Ok(decoded) = decode(encoding, big_endian, abi, extensions) {
+                    let reencoded = encode(&decoded);
+                    let redecoded = decode(reencoded, big_endian, abi, extensions);
+                    prop_assert_eq!(
+                        redecoded.as_ref(),
+                        Ok(&decoded),
+                        "{:x?} re-encoded as {:x?}",
+                        encoding,
+                        reencoded
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_forms_are_unique() {
+        for (i, form) in FORMS.iter().enumerate() {
+            assert!(
+                FORMS[i + 1..].iter().all(|other| other.name != form.name),
+                "{} is listed twice",
+                form.name
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip_every_variant(operands in operands()) {
+            for form in FORMS {
+                let instruction = (form.generate)(&operands);
+                let encoding = encode(&instruction);
+                let extensions = form.extensions();
+
+                let abi_display = decode(encoding, false, true, &extensions)
+                    .map(|decoded| decoded.to_string());
+                prop_assert!(abi_display.is_ok(), "{}: {:x?} {:?}", form.name, encoding, abi_display);
+
+                for big_endian in [false, true] {
+                    let decoded = decode(encoding, big_endian, false, &extensions);
+                    prop_assert_eq!(decoded.as_ref(), Ok(&instruction), "{}: {:x?}", form.name, encoding);
+                    prop_assert_eq!(decoded.unwrap().to_string(), instruction.to_string());
+
+                    let decoded = decode(encoding, big_endian, true, &extensions).unwrap();
+                    prop_assert_eq!(encode(&decoded), encoding, "{}: {}", form.name, decoded);
+                    prop_assert_eq!(&decoded.to_string(), abi_display.as_ref().unwrap());
+                }
+            }
+        }
+
+        #[test]
+        fn test_random_words_round_trip(word in any::<u32>(), half in any::<u16>()) {
+            for extensions in configurations() {
+                check_reencoding(Encoding::Word(word), &extensions)?;
+                check_reencoding(Encoding::Half(half), &extensions)?;
+            }
+        }
+    }
+}
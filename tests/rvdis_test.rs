@@ -0,0 +1,323 @@
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::process::{Command, Output, Stdio};
+
+    fn rvdis(args: &[&str], stdin: &str) -> Output {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_rvdis"))
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(stdin.as_bytes())
+            .unwrap();
+        child.wait_with_output().unwrap()
+    }
+
+    fn stdout(args: &[&str], stdin: &str) -> String {
+        let output = rvdis(args, stdin);
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(
+            stdout(&["--isa", "rv32i_zcb", "00050513", "0x9d61"], ""),
+            "       0:\t00050513         \taddi a0, a0, 0\n\
+             \x20      4:\t9d61             \tc.zext.b a0\n"
+        );
+        assert_eq!(
+            stdout(&["--numeric", "--format=plain", "00050513"], ""),
+            "addi x10, x10, 0\n"
+        );
+    }
+
+    #[test]
+    fn test_stdin() {
+        let listing = stdout(
+            &["--isa", "rv32i_zcb", "-f", "plain"],
+            "00001517,\n00050513 0x9d61\n",
+        );
+        assert_eq!(listing, "auipc a0, 4096\naddi a0, a0, 0\nc.zext.b a0\n");
+        assert_eq!(
+            stdout(&["-", "-f", "plain"], "00008067"),
+            "jalr zero, 0(ra)\n"
+        );
+    }
+
+    #[test]
+    fn test_big_endian() {
+        // Without compressed instructions, as big endian buffers are decoded as words.
+        assert_eq!(
+            stdout(&["--big-endian", "00050513"], ""),
+            "       0:\t00050513         \taddi a0, a0, 0\n"
+        );
+    }
+
+    #[test]
+    fn test_gnu_format() {
+        assert_eq!(
+            stdout(&["--format", "gnu", "12345537", "00008067"], ""),
+            "       0:\t12345537          \tlui\ta0,0x12345\n\
+             \x20      4:\t00008067          \tret\n"
+        );
+        assert_eq!(
+            stdout(&["-f", "gnu", "--isa", "rv32i_zcb", "9d61", "0000007f"], ""),
+            "       0:\t9d61                \tzext.b\ta0,a0\n\
+             \x20      2:\t0000007f          \t.insn\t0x7f\n"
+        );
+    }
+
+    #[test]
+    fn test_llvm_format() {
+        assert_eq!(
+            stdout(&["--format", "llvm", "12345537", "00000073"], ""),
+            "       0: 37 55 34 12  \tlui\ta0, 74565\n\
+             \x20      4: 73 00 00 00  \tecall\t\n"
+        );
+        assert_eq!(
+            stdout(
+                &["-f", "llvm", "--isa", "rv32i_zcb", "9d61", "0000007f"],
+                ""
+            ),
+            "       0: 61 9d        \tc.zext.b\ta0\n\
+             \x20      2: 7f 00 00 00  \t<unknown>\n"
+        );
+    }
+
+    #[test]
+    fn test_color() {
+        assert_eq!(
+            stdout(&["--color", "always", "-f", "plain", "00c58533"], ""),
+            "\x1b[33madd\x1b[0m \x1b[34ma0\x1b[0m, \x1b[34ma1\x1b[0m, \x1b[34ma2\x1b[0m\n"
+        );
+        assert_eq!(
+            stdout(&["--color=always", "-f", "gnu", "-a", "0x100", "fe000ee3"], ""),
+            "     100:\tfe000ee3          \t\x1b[33mbeqz\x1b[0m\t\x1b[34mzero\x1b[0m,\x1b[36mfc\x1b[0m\n"
+        );
+        // Piped listings are not coloured unless asked to be.
+        assert_eq!(stdout(&["-f", "plain", "00c58533"], ""), "add a0, a1, a2\n");
+    }
+
+    #[test]
+    fn test_binary_file() {
+        let path = std::env::temp_dir().join(format!("rvdis-test-{}.bin", std::process::id()));
+        std::fs::write(&path, [0x13, 0x05, 0x05, 0x00, 0x67, 0x80, 0x00, 0x00]).unwrap();
+        let listing = stdout(&["--binary", path.to_str().unwrap(), "--base", "4096"], "");
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            listing,
+            "    1000:\t00050513         \taddi a0, a0, 0\n\
+             \x20   1004:\t00008067         \tjalr zero, 0(ra)\n"
+        );
+    }
+
+    #[test]
+    fn test_firmware_images() {
+        for args in [
+            ["--ihex", "tests/fixtures/firmware.hex"],
+            ["--srec", "tests/fixtures/firmware.srec"],
+        ] {
+            let listing = stdout(&args, "");
+            let lines: Vec<&str> = listing.lines().collect();
+            assert_eq!(lines[2], "80000008:\t00158593         \taddi a1, a1, 1");
+            // The gap before the data of `.rodata` starts a new region.
+            assert_eq!(lines[5], "");
+            assert!(lines[6].starts_with("80001000:\t5678"));
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        for (args, status) in [
+            (&["zz"][..], 1),
+            (&["--isa", "rv99", "13"], 1),
+            (&["--binary", "/nonexistent/image.bin"], 1),
+            (&["--srec", "tests/fixtures/firmware.hex"], 1),
+            (&["--format", "json", "13"], 2),
+            (&["--frobnicate"], 2),
+        ] {
+            let output = rvdis(args, "");
+            assert_eq!(output.status.code(), Some(status), "{:?}", args);
+            assert!(output.stdout.is_empty());
+            assert!(output.stderr.starts_with(b"rvdis: "));
+        }
+    }
+
+    #[cfg(feature = "xthead")]
+    #[test]
+    fn test_xthead() {
+        assert_eq!(
+            stdout(&["--isa", "rv32i_xtheadba", "-f", "plain", "04c5950b"], ""),
+            "th.addsl a0, a1, a2, 2\n"
+        );
+        // th.tst needs xtheadbs, which is not named.
+        assert_eq!(
+            stdout(&["--isa", "rv32i_xtheadba", "-f", "plain", "8815950b"], ""),
+            "Invalid opcode field with value 1011. The value is not valid for the given \
+             instruction.\n"
+        );
+    }
+
+    #[cfg(feature = "xcv")]
+    #[test]
+    fn test_xcv() {
+        assert_eq!(
+            stdout(&["--isa", "rv32i_xcvmem", "-f", "plain", "0045a50b"], ""),
+            "cv.lw a0, (a1), 4\n"
+        );
+        // cv.starti needs xcvhwlp, which is not named.
+        assert_eq!(
+            stdout(&["--isa", "rv32i_xcvmem", "-f", "plain", "0ff0402b"], ""),
+            "Invalid opcode field with value 101011. The value is not valid for the given \
+             instruction.\n"
+        );
+        assert_eq!(
+            stdout(
+                &["--isa", "rv32i_xcvmem_xcvhwlp", "-f", "plain", "0ff0402b"],
+                ""
+            ),
+            "cv.starti 0, 255\n"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_vendor_extensions() {
+        let mut isas = vec!["rv32i_xtheadba_xcvmem", "rv32i_xventanacondops"];
+        if cfg!(not(feature = "xthead")) {
+            isas.push("rv32i_xtheadba");
+        }
+        if cfg!(not(feature = "xcv")) {
+            isas.push("rv32i_xcvalu");
+        }
+        for isa in isas {
+            let output = rvdis(&["--isa", isa, "04c5950b"], "");
+            assert_eq!(output.status.code(), Some(2), "{}", isa);
+            assert!(output.stdout.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_help() {
+        assert!(stdout(&["--help"], "").starts_with("Usage: rvdis"));
+        assert_eq!(
+            stdout(&["-V"], ""),
+            format!("rvdis {}\n", env!("CARGO_PKG_VERSION"))
+        );
+    }
+
+    #[cfg(feature = "elf")]
+    #[test]
+    fn test_elf() {
+        let listing = stdout(&["--elf", "tests/fixtures/relocations.o"], "");
+        let lines: Vec<&str> = listing.lines().collect();
+        assert_eq!(
+            lines[..7],
+            [
+                "",
+                "tests/fixtures/relocations.o:     file format elf32-littleriscv",
+                "",
+                "Disassembly of section .text:",
+                "",
+                "00000000 <main>:",
+                "       0:\t00000097 000080e7\tcall printf",
+            ]
+        );
+        assert!(lines.contains(&"      10:\t000005b7         \tlui a1, %hi(message)"));
+        assert_eq!(
+            lines.last(),
+            Some(&"      24:\t00000317 00030067\ttail puts")
+        );
+
+        let plain = stdout(
+            &[
+                "-e",
+                "tests/fixtures/relocations.o",
+                "-f",
+                "plain",
+                "--no-pseudo",
+            ],
+            "",
+        );
+        assert_eq!(
+            plain.lines().take(2).collect::<Vec<_>>(),
+            ["auipc ra, 0 # R_RISCV_CALL printf", "jalr ra, 0(ra)"]
+        );
+    }
+
+    #[cfg(feature = "elf")]
+    #[test]
+    fn test_elf_gnu_format() {
+        let listing = stdout(&["--elf", "tests/fixtures/relocations.o", "-f", "gnu"], "");
+        let lines: Vec<&str> = listing.lines().collect();
+        assert_eq!(
+            lines[..10],
+            [
+                "",
+                "tests/fixtures/relocations.o:     file format elf32-littleriscv",
+                "",
+                "",
+                "Disassembly of section .text:",
+                "",
+                "00000000 <main>:",
+                "       0:\t00000097          \tauipc\tra,0x0",
+                "       4:\t000080e7          \tjalr\tra # 0 <main>",
+                "",
+            ]
+        );
+        assert!(lines.contains(&"      1c:\t00b50063          \tbeq\ta0,a1,1c <.Lpcrel_hi0+0x14>"));
+    }
+
+    /// Compares the listing of `tests/fixtures/llvm.o` with that of llvm-objdump, held in
+    /// `tests/fixtures/llvm.objdump`.
+    #[cfg(feature = "elf")]
+    #[test]
+    fn test_elf_llvm_format() {
+        let listing = stdout(&["--elf", "tests/fixtures/llvm.o", "-f", "llvm"], "");
+        let expected = std::fs::read_to_string("tests/fixtures/llvm.objdump").unwrap();
+        assert_eq!(listing.replacen("tests/fixtures/", "", 1), expected);
+    }
+
+    /// Compares the listing of `tests/fixtures/sections.o`, which has no symbol at the start of
+    /// its section, with that of llvm-objdump, held in `tests/fixtures/sections.objdump`.
+    #[cfg(feature = "elf")]
+    #[test]
+    fn test_elf_section_labels() {
+        let listing = stdout(&["--elf", "tests/fixtures/sections.o", "-f", "llvm"], "");
+        let expected = std::fs::read_to_string("tests/fixtures/sections.objdump").unwrap();
+        assert_eq!(listing.replacen("tests/fixtures/", "", 1), expected);
+
+        let listing = stdout(&["--elf", "tests/fixtures/sections.o", "-f", "gnu"], "");
+        let lines: Vec<&str> = listing.lines().collect();
+        assert_eq!(
+            lines[6..10],
+            [
+                "00000000 <.text>:",
+                "       0:\t00500513          \tli\ta0,5",
+                "       4:\tfff50513          \taddi\ta0,a0,-1",
+                "       8:\tfe051ee3          \tbnez\ta0,4 <.text+0x4>",
+            ]
+        );
+
+        let listing = stdout(&["--elf", "tests/fixtures/sections.o"], "");
+        assert!(!listing.contains("<.text"));
+    }
+
+    #[cfg(not(feature = "elf"))]
+    #[test]
+    fn test_elf_unsupported() {
+        let output = rvdis(&["--elf", "tests/fixtures/relocations.o"], "");
+        assert_eq!(output.status.code(), Some(1));
+    }
+}
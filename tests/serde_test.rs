@@ -0,0 +1,187 @@
+#![cfg(feature = "serde")]
+
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::parsed_instructions::*;
+    use risc_v_disassembler::trace::TraceEntry;
+    use risc_v_disassembler::{
+        disassemble, CustomDecoder, CustomDecoders, CustomInstruction, DisassemblerError,
+        Extensions, ParsedInstruction32,
+    };
+    use std::any::Any;
+    use std::fmt;
+
+    const FIXTURE: &str = include_str!("fixtures/trace.json");
+    const CUSTOM_FIXTURE: &str = include_str!("fixtures/custom_trace.json");
+
+    /// Returns the trace of `tests/fixtures/trace.json`, with ABI register names.
+    fn trace() -> Vec<TraceEntry> {
+        let bytes = [
+            0x93, 0x00, 0x51, 0x00, // addi ra, sp, 5
+            0x37, 0x55, 0x34, 0x12, // lui a0, 0x12345
+            0x03, 0xa5, 0xc5, 0xff, // lw a0, -4(a1)
+            0xe3, 0x1c, 0xb5, 0xfe, // bne a0, a1, -8
+            0x53, 0x95, 0xc5, 0x00, // fadd.s a0, a1, a2, rtz
+            0x2f, 0x25, 0xb6, 0x04, // amoadd.w.aq a0, a1, (a2)
+            0xd7, 0x40, 0x25, 0x04, // vandn.vx v1, v2, a0, v0.t
+            0x73, 0x25, 0x00, 0x30, // csrrs a0, mstatus, zero
+            0x82, 0xb8, // cm.push {ra, s0-s3}, -32
+            0x61, 0x9d, // c.zext.b a0
+            0x13, 0x00, 0x15, 0x00, // addi zero, a0, 1, a HINT
+        ];
+        let extensions = Extensions {
+            zfinx: true,
+            zaamo: true,
+            zvbb: true,
+            zcb: true,
+            zcmp: true,
+            hints: true,
+            ..Default::default()
+        };
+        disassemble(&bytes, 0x1000, false, true, &extensions)
+            .map(|disassembled| TraceEntry::from_disassembled(disassembled, false).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_trace_fixture() {
+        let trace = trace();
+        assert_eq!(
+            serde_json::to_string_pretty(&trace).unwrap() + "\n",
+            FIXTURE
+        );
+        assert_eq!(
+            serde_json::from_str::<Vec<TraceEntry>>(FIXTURE).unwrap(),
+            trace
+        );
+    }
+
+    /// A multiply-accumulate instruction of the custom-0 opcode.
+    #[derive(Debug, PartialEq)]
+    struct Mac {
+        rd: &'static str,
+        rs1: &'static str,
+        rs2: &'static str,
+    }
+
+    impl fmt::Display for Mac {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "mac {}, {}, {}", self.rd, self.rs1, self.rs2)
+        }
+    }
+
+    impl CustomInstruction for Mac {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn eq_custom(&self, other: &dyn CustomInstruction) -> bool {
+            other.as_any().downcast_ref::<Mac>() == Some(self)
+        }
+    }
+
+    struct MacDecoder;
+
+    impl CustomDecoder for MacDecoder {
+        fn decode(
+            &self,
+            instruction: u32,
+            register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+            _float_register_name: fn(u8) -> Result<&'static str, DisassemblerError>,
+        ) -> Result<Box<dyn CustomInstruction>, DisassemblerError> {
+            Ok(Box::new(Mac {
+                rd: register_name((instruction >> 7 & 0x1f) as u8)?,
+                rs1: register_name((instruction >> 15 & 0x1f) as u8)?,
+                rs2: register_name((instruction >> 20 & 0x1f) as u8)?,
+            }))
+        }
+    }
+
+    /// Instructions of custom decoders are serialized with their text only, as shown by
+    /// `tests/fixtures/custom_trace.json`, and cannot be deserialized.
+    #[test]
+    fn test_custom_fixture() {
+        let mut decoders = CustomDecoders::new();
+        decoders.register(0b0001011, MacDecoder).unwrap();
+        // mac a0, a1, a2
+        let bytes = 0x00c5850bu32.to_le_bytes();
+        let trace: Vec<TraceEntry> =
+            disassemble(&bytes, 0x1000, false, true, &Extensions::default())
+                .custom_decoders(&decoders)
+                .map(|disassembled| TraceEntry::from_disassembled(disassembled, false).unwrap())
+                .collect();
+        assert_eq!(
+            serde_json::to_string_pretty(&trace).unwrap() + "\n",
+            CUSTOM_FIXTURE
+        );
+        let error = serde_json::from_str::<Vec<TraceEntry>>(CUSTOM_FIXTURE).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("custom instructions cannot be deserialized"));
+    }
+
+    #[test]
+    fn test_dotted_mnemonics() {
+        let instruction = ParsedInstruction32::c_zext_b(c_zext_b { rd: "a0" });
+        let json = serde_json::to_string(&instruction).unwrap();
+        assert_eq!(json, r#"{"mnemonic":"c.zext.b","operands":{"rd":"a0"}}"#);
+        assert_eq!(
+            serde_json::from_str::<ParsedInstruction32>(&json).unwrap(),
+            instruction
+        );
+        assert!(serde_json::from_str::<ParsedInstruction32>(
+            r#"{"mnemonic":"c_zext_b","operands":{"rd":"a0"}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_instruction_roundtrip() {
+        let instruction = ParsedInstruction32::hint(hint {
+            instruction: Box::new(ParsedInstruction32::slli(slli {
+                rd: "zero",
+                rs1: "a0",
+                shamt: 3,
+            })),
+        });
+        let json = serde_json::to_string(&instruction).unwrap();
+        assert_eq!(
+            json,
+            r#"{"mnemonic":"hint","operands":{"instruction":{"mnemonic":"slli","operands":{"rd":"zero","rs1":"a0","shamt":3}}}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<ParsedInstruction32>(&json).unwrap(),
+            instruction
+        );
+    }
+
+    #[test]
+    fn test_unknown_register() {
+        let json = r#"{"mnemonic":"addi","operands":{"rd":"a0","rs1":"x32","imm":1}}"#;
+        let error = serde_json::from_str::<ParsedInstruction32>(json).unwrap_err();
+        assert!(error.to_string().contains("unknown register \"x32\""));
+    }
+
+    #[test]
+    fn test_error_roundtrip() {
+        for error in [
+            DisassemblerError::InvalidOpcode(0b1111111),
+            DisassemblerError::InvalidIsaString("rv32q".to_string()),
+            DisassemblerError::BitExtractionError("Index out of bounds"),
+        ] {
+            let json = serde_json::to_string(&error).unwrap();
+            assert_eq!(
+                serde_json::from_str::<DisassemblerError>(&json).unwrap(),
+                error
+            );
+        }
+        assert_eq!(
+            serde_json::to_string(&DisassemblerError::InvalidOpcode(0x7f)).unwrap(),
+            r#"{"InvalidOpcode":127}"#
+        );
+        assert!(
+            serde_json::from_str::<DisassemblerError>(r#"{"BitExtensionError":"unknown"}"#)
+                .is_err()
+        );
+    }
+}
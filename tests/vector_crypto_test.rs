@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::parsed_instructions::*;
+    use risc_v_disassembler::{
+        parse, parse_with_extensions, DisassemblerError, Extensions, Lmul, ParsedInstruction32,
+    };
+
+    fn vector_crypto_extensions(lmul: Lmul) -> Extensions {
+        Extensions {
+            zvbb: true,
+            zvbc: true,
+            zvkned: true,
+            zvknhb: true,
+            zvksed: true,
+            zvksh: true,
+            zvkg: true,
+            lmul,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a vector of tuples containing (instruction hex, expected output) for the vector bit-manipulation and cryptography instructions, using ABI registers
+    fn get_vector_crypto_test_cases_abi() -> Vec<(u32, &'static str)> {
+        vec![
+            (0x062180D7, "vandn.vv v1, v2, v3"),
+            (0x042540D7, "vandn.vx v1, v2, a0, v0.t"),
+            (0x4A842257, "vbrev8.v v4, v8"),
+            (0x4A872257, "vcpop.v v4, v8"),
+            (0x5621B0D7, "vror.vi v1, v2, 35"),
+            (0xD643B157, "vwsll.vi v2, v4, 7"),
+            (0x3221A0D7, "vclmul.vv v1, v2, v3"),
+            (0x3625E0D7, "vclmulh.vx v1, v2, a1"),
+            (0xA281A257, "vaesef.vv v4, v8"),
+            (0xA683A257, "vaesz.vs v4, v8"),
+            (0x8A81A257, "vaeskf1.vi v4, v8, 3"),
+            (0xB6862257, "vsha2ms.vv v4, v8, v12"),
+            (0xA6882257, "vsm4r.vs v4, v8"),
+            (0xAE82A257, "vsm3c.vi v4, v8, 5"),
+            (0xB2862257, "vghsh.vv v4, v8, v12"),
+            (0xA288A257, "vgmul.vv v4, v8"),
+        ]
+    }
+
+    #[test]
+    fn test_vector_crypto_printing_abi() {
+        let extensions = vector_crypto_extensions(Lmul::M1);
+        for (hex, expected) in get_vector_crypto_test_cases_abi() {
+            let result = parse_with_extensions(&hex.to_le_bytes(), false, true, &extensions);
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            assert_eq!(result.unwrap().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_vector_crypto_be() {
+        let extensions = vector_crypto_extensions(Lmul::M1);
+        let result = parse_with_extensions(&0x042540D7u32.to_be_bytes(), true, false, &extensions);
+        assert_eq!(
+            result,
+            Ok(ParsedInstruction32::vandn_vx(vandn_vx {
+                vd: "v1",
+                vs2: "v2",
+                rs1: "x10",
+                vm: VectorMask::Masked,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_vector_crypto_element_groups() {
+        let extensions = vector_crypto_extensions(Lmul::M4);
+
+        // vsha2ms.vv v4, v8, v12
+        let result = parse_with_extensions(&0xB6862257u32.to_le_bytes(), false, true, &extensions);
+        assert!(result.is_ok());
+
+        // vsha2ms.vv v4, v8, v4
+        let result = parse_with_extensions(&0xB6822257u32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result, Err(DisassemblerError::InvalidElementGroup(4)));
+
+        // vsm4r.vs v4, v6, where v6 lies in the destination group v4-v7
+        let result = parse_with_extensions(&0xA6682257u32.to_le_bytes(), false, true, &extensions);
+        assert_eq!(result, Err(DisassemblerError::InvalidElementGroup(6)));
+    }
+
+    #[test]
+    fn test_vector_crypto_register_groups() {
+        // vsha2ch.vv v5, v8, v12
+        let hex = 0xBA8622D7u32;
+        let result = parse_with_extensions(
+            &hex.to_le_bytes(),
+            false,
+            true,
+            &vector_crypto_extensions(Lmul::Mf2),
+        );
+        assert!(result.is_ok());
+
+        let result = parse_with_extensions(
+            &hex.to_le_bytes(),
+            false,
+            true,
+            &vector_crypto_extensions(Lmul::M2),
+        );
+        assert_eq!(
+            result,
+            Err(DisassemblerError::InvalidVectorRegisterGroup(5))
+        );
+
+        // vsm4r.vs v4, v8
+        let result = parse_with_extensions(
+            &0xA6882257u32.to_le_bytes(),
+            false,
+            true,
+            &vector_crypto_extensions(Lmul::M8),
+        );
+        assert_eq!(
+            result,
+            Err(DisassemblerError::InvalidVectorRegisterGroup(4))
+        );
+    }
+
+    #[test]
+    fn test_vector_crypto_not_selected() {
+        let result = parse(&0xA281A257u32.to_le_bytes(), false, true);
+        assert_eq!(result, Err(DisassemblerError::InvalidOpcode(0b1010111)));
+
+        // vadd.vv is part of the base V extension
+        let result = parse_with_extensions(
+            &0x022180D7u32.to_le_bytes(),
+            false,
+            true,
+            &vector_crypto_extensions(Lmul::M1),
+        );
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct6(0)));
+    }
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "xcv")]
+
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::xcv::{
+        Addressing, SimdOperand, SimdWidth, XcvDecoder, XcvInstruction, XCV_EXTENSIONS, XCV_OPCODES,
+    };
+    use risc_v_disassembler::{
+        parse_with_custom_decoders, parsed_instructions, CustomDecoders, DisassemblerError,
+        Extensions, MemoryAccess, ParsedInstruction32,
+    };
+
+    fn xcv_decoders() -> CustomDecoders {
+        let mut decoders = CustomDecoders::new();
+        for opcode in XCV_OPCODES {
+            decoders.register(opcode, XcvDecoder).unwrap();
+        }
+        decoders
+    }
+
+    fn parse_xcv(
+        hex: u32,
+        use_abi_register_names: bool,
+    ) -> Result<ParsedInstruction32, DisassemblerError> {
+        parse_with_custom_decoders(
+            &hex.to_le_bytes(),
+            false,
+            use_abi_register_names,
+            &Extensions::default(),
+            &xcv_decoders(),
+        )
+    }
+
+    /// Returns a vector of tuples containing (instruction hex, expected output) for CORE-V instructions, using ABI registers
+    fn get_xcv_test_cases_abi() -> Vec<(u32, &'static str)> {
+        vec![
+            (0x0045A50B, "cv.lw a0, (a1), 4"),
+            (0xFFF5C50B, "cv.lbu a0, (a1), -1"),
+            (0x0085B50B, "cv.elw a0, 8(a1)"),
+            (0xFEA5AC2B, "cv.sw a0, (a1), -8"),
+            (0x02C5B52B, "cv.lh a0, (a1), a2"),
+            (0x1AC5B52B, "cv.lhu a0, a2(a1)"),
+            (0x20A5B62B, "cv.sb a0, (a1), a2"),
+            (0x2CA5B62B, "cv.sw a0, a2(a1)"),
+            (0x40C5B52B, "cv.ror a0, a1, a2"),
+            (0x4805B52B, "cv.cnt a0, a1"),
+            (0x7055B52B, "cv.clip a0, a1, 5"),
+            (0x90C5B52B, "cv.mac a0, a1, a2"),
+            (0x58C5B52B, "cv.minu a0, a1, a2"),
+            (0x6605B52B, "cv.extbz a0, a1"),
+            (0x010040AB, "cv.starti 1, 16"),
+            (0x0205472B, "cv.setup 0, a0, 32"),
+            (0x064546AB, "cv.setupi 1, 100, 10"),
+            (0x0005452B, "cv.count 0, a0"),
+            (0x0E45855B, "cv.extract a0, a1, 7, 4"),
+            (0x8E45855B, "cv.insert a0, a1, 7, 4"),
+            (0x4685955B, "cv.bset a0, a1, 3, 8"),
+            (0x00C5857B, "cv.add.h a0, a1, a2"),
+            (0x00C5D57B, "cv.add.sc.b a0, a1, a2"),
+            (0x0BE5E57B, "cv.sub.sci.h a0, a1, -3"),
+            (0x5225F57B, "cv.sll.sci.b a0, a1, 5"),
+            (0x04C5857B, "cv.cmpeq.h a0, a1, a2"),
+            (0x90C5857B, "cv.dotsp.h a0, a1, a2"),
+            (0x7005957B, "cv.abs.b a0, a1"),
+        ]
+    }
+
+    #[test]
+    fn test_xcv_printing_abi() {
+        for (hex, expected) in get_xcv_test_cases_abi() {
+            let result = parse_xcv(hex, true);
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            assert_eq!(result.unwrap().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_xcv_numbered_registers() {
+        let result = parse_xcv(0x1AC5B52B, false).unwrap();
+        assert_eq!(result.to_string(), "cv.lhu x10, x12(x11)");
+    }
+
+    #[test]
+    fn test_xcv_equality() {
+        let result = parse_xcv(0x02C5B52B, true).unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::custom(parsed_instructions::custom {
+                instruction: Box::new(XcvInstruction::cv_lh {
+                    rd: "a0",
+                    rs1: "a1",
+                    offset: Addressing::PostIncrementRegister("a2"),
+                }),
+            })
+        );
+
+        let result = parse_xcv(0x00C5D57B, true).unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::custom(parsed_instructions::custom {
+                instruction: Box::new(XcvInstruction::cv_add_simd {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: SimdOperand::Scalar("a2"),
+                    width: SimdWidth::Byte,
+                }),
+            })
+        );
+    }
+
+    #[test]
+    fn test_xcv_memory_access() {
+        let result = parse_xcv(0x0045A50B, true).unwrap();
+        assert_eq!(result.memory_access(), Some(MemoryAccess::Load { size: 4 }));
+
+        let result = parse_xcv(0x20A5B62B, true).unwrap();
+        assert_eq!(
+            result.memory_access(),
+            Some(MemoryAccess::Store { size: 1 })
+        );
+
+        let result = parse_xcv(0x0205472B, true).unwrap();
+        assert_eq!(result.memory_access(), None);
+    }
+
+    #[test]
+    fn test_xcv_unsupported() {
+        // cv.bitrev is not decoded
+        let result = parse_xcv(0xC045955B, true);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct7(0b1100000)));
+
+        // cv.add.div2.h is not decoded
+        let result = parse_xcv(0x00C5A57B, true);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct3(0b010)));
+    }
+
+    #[test]
+    fn test_xcv_extension() {
+        // cv.lw, cv.elw, cv.starti, cv.ror, cv.minu, cv.mac and cv.add.h
+        let instructions = [
+            0x0045A50B, 0x0085B50B, 0x010040AB, 0x40C5B52B, 0x58C5B52B, 0x90C5B52B, 0x00C5857B,
+        ];
+        let extensions = instructions.map(|hex| match parse_xcv(hex, true) {
+            Ok(ParsedInstruction32::custom(parsed_instructions::custom { instruction })) => {
+                let instruction = instruction.as_any().downcast_ref::<XcvInstruction>();
+                instruction.unwrap().extension()
+            }
+            result => panic!("{:?}", result),
+        });
+        assert_eq!(extensions, XCV_EXTENSIONS);
+    }
+}
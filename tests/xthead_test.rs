@@ -0,0 +1,167 @@
+#![cfg(feature = "xthead")]
+
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::xthead::{
+        XTheadDecoder, XTheadInstruction, XTHEAD_EXTENSIONS, XTHEAD_OPCODE,
+    };
+    use risc_v_disassembler::{
+        parse, parse_with_custom_decoders, parsed_instructions, CustomDecoders, DisassemblerError,
+        Extensions, FloatFormat, MemoryAccess, ParsedInstruction32, PrivilegeLevel,
+    };
+
+    fn xthead_decoders() -> CustomDecoders {
+        let mut decoders = CustomDecoders::new();
+        decoders.register(XTHEAD_OPCODE, XTheadDecoder).unwrap();
+        decoders
+    }
+
+    fn parse_xthead(
+        hex: u32,
+        use_abi_register_names: bool,
+    ) -> Result<ParsedInstruction32, DisassemblerError> {
+        parse_with_custom_decoders(
+            &hex.to_le_bytes(),
+            false,
+            use_abi_register_names,
+            &Extensions::default(),
+            &xthead_decoders(),
+        )
+    }
+
+    /// Returns a vector of tuples containing (instruction hex, expected output) for T-Head instructions, using ABI registers
+    fn get_xthead_test_cases_abi() -> Vec<(u32, &'static str)> {
+        vec![
+            (0x04C5950B, "th.addsl a0, a1, a2, 2"),
+            (0x1045950B, "th.srri a0, a1, 4"),
+            (0x8875950B, "th.tst a0, a1, 7"),
+            (0x3C45A50B, "th.ext a0, a1, 15, 4"),
+            (0x3C45B50B, "th.extu a0, a1, 15, 4"),
+            (0x8405950B, "th.ff0 a0, a1"),
+            (0x8605950B, "th.ff1 a0, a1"),
+            (0x8205950B, "th.rev a0, a1"),
+            (0x8005950B, "th.tstnbz a0, a1"),
+            (0x40C5950B, "th.mveqz a0, a1, a2"),
+            (0x42C5950B, "th.mvnez a0, a1, a2"),
+            (0x20C5950B, "th.mula a0, a1, a2"),
+            (0x2AC5950B, "th.mulsh a0, a1, a2"),
+            (0x0180000B, "th.sync"),
+            (0x01B0000B, "th.sync.is"),
+            (0x0010000B, "th.dcache.call"),
+            (0x0255000B, "th.dcache.cva a0"),
+            (0x0305000B, "th.icache.iva a0"),
+            (0x04B5000B, "th.sfence.vmas a0, a1"),
+            (0x46C5C50B, "th.lrw a0, a1, a2, 3"),
+            (0xA6C5C50B, "th.lrhu a0, a1, a2, 3"),
+            (0x1FC5C50B, "th.lbia a0, (a1), -4, 3"),
+            (0x4AC5C50B, "th.lwib a0, (a1), 12, 1"),
+            (0xE2C5C50B, "th.lwd a0, a2, (a1), 1, 3"),
+            (0x40C5D50B, "th.srw a0, a1, a2, 0"),
+            (0x1A45D50B, "th.sbia a0, (a1), 4, 1"),
+            (0xE0C5D50B, "th.swd a0, a2, (a1), 0, 3"),
+            (0x40C5E50B, "th.flrw fa0, a1, a2, 0"),
+            (0x60C5F50B, "th.fsrd fa0, a1, a2, 0"),
+        ]
+    }
+
+    #[test]
+    fn test_xthead_printing_abi() {
+        for (hex, expected) in get_xthead_test_cases_abi() {
+            let result = parse_xthead(hex, true);
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            assert_eq!(result.unwrap().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_xthead_numbered_registers() {
+        let result = parse_xthead(0x40C5E50B, false).unwrap();
+        assert_eq!(result.to_string(), "th.flrw f10, x11, x12, 0");
+    }
+
+    #[test]
+    fn test_xthead_equality() {
+        let result = parse_xthead(0x40C5950B, true).unwrap();
+        assert_eq!(
+            result,
+            ParsedInstruction32::custom(parsed_instructions::custom {
+                instruction: Box::new(XTheadInstruction::th_mveqz {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                }),
+            })
+        );
+    }
+
+    #[test]
+    fn test_xthead_metadata() {
+        let result = parse_xthead(0xE2C5C50B, true).unwrap();
+        assert_eq!(result.memory_access(), Some(MemoryAccess::Load { size: 8 }));
+
+        let result = parse_xthead(0x1A45D50B, true).unwrap();
+        assert_eq!(
+            result.memory_access(),
+            Some(MemoryAccess::Store { size: 1 })
+        );
+
+        let result = parse_xthead(0x60C5F50B, true).unwrap();
+        assert_eq!(result.float_format(), Some(FloatFormat::Double));
+
+        let result = parse_xthead(0x0255000B, true).unwrap();
+        assert_eq!(result.privilege_level(), PrivilegeLevel::Supervisor);
+        assert_eq!(result.memory_access(), None);
+
+        let result = parse_xthead(0x04C5950B, true).unwrap();
+        assert_eq!(result.privilege_level(), PrivilegeLevel::User);
+    }
+
+    #[test]
+    fn test_xthead_rv64_only() {
+        // th.srriw a0, a1, 4
+        let result = parse_xthead(0x1445950B, true);
+        assert_eq!(result, Err(DisassemblerError::InvalidFunct7(0b0001010)));
+
+        // th.extu a0, a1, 40, 4
+        let result = parse_xthead(0xA045B50B, true);
+        assert_eq!(
+            result,
+            Err(DisassemblerError::InvalidImmediate(40 << 6 | 4))
+        );
+    }
+
+    #[test]
+    fn test_xthead_invalid_register_constraints() {
+        // th.lwd a0, a0, (a1), 0, 3
+        let result = parse_xthead(0xE0A5C50B, true);
+        assert_eq!(result, Err(DisassemblerError::InvalidRdField(10)));
+
+        // th.ff0 a0, a1 with rs2 = a2
+        let result = parse_xthead(0x84C5950B, true);
+        assert_eq!(result, Err(DisassemblerError::InvalidRs2Field(12)));
+    }
+
+    #[test]
+    fn test_xthead_not_registered() {
+        let result = parse(&0x04C5950Bu32.to_le_bytes(), false, true);
+        assert_eq!(result, Err(DisassemblerError::InvalidOpcode(XTHEAD_OPCODE)));
+    }
+
+    #[test]
+    fn test_xthead_extension() {
+        // th.addsl, th.srri, th.tst, th.mveqz, th.lrw, th.lwd, th.mula, th.flrw, th.sync and
+        // th.dcache.call
+        let instructions = [
+            0x04C5950B, 0x1045950B, 0x8875950B, 0x40C5950B, 0x46C5C50B, 0xE2C5C50B, 0x20C5950B,
+            0x40C5E50B, 0x0180000B, 0x0010000B,
+        ];
+        let extensions = instructions.map(|hex| match parse_xthead(hex, true) {
+            Ok(ParsedInstruction32::custom(parsed_instructions::custom { instruction })) => {
+                let instruction = instruction.as_any().downcast_ref::<XTheadInstruction>();
+                instruction.unwrap().extension()
+            }
+            result => panic!("{:?}", result),
+        });
+        assert_eq!(extensions, XTHEAD_EXTENSIONS);
+    }
+}
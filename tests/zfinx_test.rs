@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use risc_v_disassembler::parsed_instructions::*;
+    use risc_v_disassembler::{
+        parse, parse_with_extensions, DisassemblerError, Extensions, FloatFormat,
+        ParsedInstruction32,
+    };
+
+    fn zfinx_extensions() -> Extensions {
+        Extensions {
+            zfinx: true,
+            zdinx: true,
+            zhinx: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a vector of tuples containing (instruction hex, expected ParsedInstruction32) for Zfinx, Zdinx and Zhinx instructions, using ABI registers
+    fn get_zfinx_test_cases_abi() -> Vec<(u32, ParsedInstruction32)> {
+        vec![
+            (
+                0x00C5F553,
+                ParsedInstruction32::fadd_s(fadd_s {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                    rm: RoundingMode::Dyn,
+                }),
+            ),
+            (
+                0x12E60553,
+                ParsedInstruction32::fmul_d(fmul_d {
+                    rd: "a0",
+                    rs1: "a2",
+                    rs2: "a4",
+                    rm: RoundingMode::Rne,
+                }),
+            ),
+            (
+                0xC0059553,
+                ParsedInstruction32::fcvt_w_s(fcvt_w_s {
+                    rd: "a0",
+                    rs1: "a1",
+                    rm: RoundingMode::Rtz,
+                }),
+            ),
+            (
+                0x6CC5F543,
+                ParsedInstruction32::fmadd_h(fmadd_h {
+                    rd: "a0",
+                    rs1: "a1",
+                    rs2: "a2",
+                    rs3: "a3",
+                    rm: RoundingMode::Dyn,
+                }),
+            ),
+            (
+                0x4205F653,
+                ParsedInstruction32::fcvt_d_s(fcvt_d_s {
+                    rd: "a2",
+                    rs1: "a1",
+                    rm: RoundingMode::Dyn,
+                }),
+            ),
+            (
+                0xA28312D3,
+                ParsedInstruction32::flt_d(flt_d {
+                    rd: "t0",
+                    rs1: "t1",
+                    rs2: "s0",
+                }),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_zfinx_instructions_le_abi() {
+        for (hex, expected) in get_zfinx_test_cases_abi() {
+            let result =
+                parse_with_extensions(&hex.to_le_bytes(), false, true, &zfinx_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_zfinx_instructions_be_abi() {
+        for (hex, expected) in get_zfinx_test_cases_abi() {
+            let result = parse_with_extensions(&hex.to_be_bytes(), true, true, &zfinx_extensions());
+            assert!(result.is_ok(), "Failed to parse instruction {:#010x}", hex);
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_zfinx_numbered_registers() {
+        let result = parse_with_extensions(
+            &0x00C5F553u32.to_le_bytes(),
+            false,
+            false,
+            &zfinx_extensions(),
+        );
+        assert_eq!(result.unwrap().to_string(), "fadd.s x10, x11, x12");
+    }
+
+    #[test]
+    fn test_zfinx_printing() {
+        let result = parse_with_extensions(
+            &0xC0059553u32.to_le_bytes(),
+            false,
+            true,
+            &zfinx_extensions(),
+        )
+        .unwrap();
+        assert_eq!(result.to_string(), "fcvt.w.s a0, a1, rtz");
+        assert_eq!(result.float_format(), Some(FloatFormat::Single));
+
+        let result = parse_with_extensions(
+            &0x6CC5F543u32.to_le_bytes(),
+            false,
+            true,
+            &zfinx_extensions(),
+        )
+        .unwrap();
+        assert_eq!(result.to_string(), "fmadd.h a0, a1, a2, a3");
+        assert_eq!(result.float_format(), Some(FloatFormat::Half));
+    }
+
+    #[test]
+    fn test_conversion_float_format() {
+        // Conversions between floating point formats report their destination format.
+        let cases = [
+            (0x40167553u32, "fcvt.s.d a0, a2", FloatFormat::Single),
+            (0x42067553, "fcvt.d.s a0, a2", FloatFormat::Double),
+            (0x40267553, "fcvt.s.h a0, a2", FloatFormat::Single),
+            (0x44067553, "fcvt.h.s a0, a2", FloatFormat::Half),
+            (0x42267553, "fcvt.d.h a0, a2", FloatFormat::Double),
+            (0x44167553, "fcvt.h.d a0, a2", FloatFormat::Half),
+        ];
+        for (word, text, format) in cases {
+            let result =
+                parse_with_extensions(&word.to_le_bytes(), false, true, &zfinx_extensions())
+                    .unwrap();
+            assert_eq!(result.to_string(), text);
+            assert_eq!(result.float_format(), Some(format), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_zdinx_odd_register_pair() {
+        // fadd.d x11, x12, x14
+        let result = parse_with_extensions(
+            &0x02E675D3u32.to_le_bytes(),
+            false,
+            false,
+            &zfinx_extensions(),
+        );
+        assert_eq!(result, Err(DisassemblerError::InvalidRegisterPair(11)));
+    }
+
+    #[test]
+    fn test_zfinx_not_selected() {
+        let result = parse(&0x00C5F553u32.to_le_bytes(), false, false);
+        assert_eq!(result, Err(DisassemblerError::InvalidOpcode(0b1010011)));
+    }
+}